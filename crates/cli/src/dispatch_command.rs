@@ -1,5 +1,5 @@
 use crate::prelude::*;
-use klirr_render::prelude::render;
+use klirr_render::prelude::{RenderEngine, render, render_preview};
 
 fn init_data(provide_data: impl FnOnce(Data) -> Result<Data>) -> Result<()> {
     init_data_at(data_dir_create_if(true), provide_data)
@@ -13,7 +13,7 @@ fn validate_data() -> Result<()> {
     let base_path = data_dir();
     info!("Validating data directory at: {}", base_path.display());
 
-    read_data_from_disk_with_base_path(base_path)
+    validate_data_with_base_path(base_path)
         .map(|_| ())
         .inspect(|_| {
             info!("✅ Data directory is valid");
@@ -27,8 +27,25 @@ fn record_expenses(month: &YearAndMonth, expenses: &[Item]) -> Result<()> {
     record_expenses_with_base_path(month, expenses, data_dir())
 }
 
-fn record_month_off(month: &YearAndMonth) -> Result<()> {
-    record_month_off_with_base_path(month, data_dir())
+fn record_month_off(input: &MonthOffInput) -> Result<()> {
+    if *input.remove() {
+        remove_month_off_with_base_path(input.month(), data_dir())
+    } else {
+        record_month_off_with_base_path(input.month(), data_dir())
+    }
+}
+
+fn record_days_off(input: &DaysOffInput) -> Result<()> {
+    if *input.remove() {
+        remove_days_off_with_base_path(input.ranges(), data_dir())
+    } else {
+        record_days_off_with_base_path(input.ranges(), data_dir())
+    }
+}
+
+/// The passphrase in the environment variable, else the one `ask` returns.
+fn data_passphrase_else(ask: impl FnOnce() -> Result<String>) -> Result<String> {
+    std::env::var(ENV_VAR_DATA_PASSPHRASE).or_else(|_| ask())
 }
 
 /// Curry a function that takes two arguments into a function that takes one argument and returns another function.
@@ -39,17 +56,161 @@ fn curry<T, U, R>(f: impl FnOnce(T, U) -> R, u: U) -> impl FnOnce(T) -> R {
 
 pub fn run_data_command(command: &DataAdminInputCommands) -> Result<()> {
     match command {
-        DataAdminInputCommands::Init => init_data(curry(ask_for_data, None)),
+        DataAdminInputCommands::Init(input) => match input.from() {
+            Some(from) => init_data(|_| read_data_from_file(from)),
+            None => init_data(curry(ask_for_data, None)),
+        },
         DataAdminInputCommands::Validate => validate_data(),
         DataAdminInputCommands::Edit(input) => edit_data(curry(
             ask_for_data,
             Some(DataSelector::from(*input.selector())),
         )),
-        DataAdminInputCommands::MonthOff(month_off_input) => {
-            record_month_off(month_off_input.month())
+        DataAdminInputCommands::Set(input) => {
+            set_data_value_with_base_path(input.field(), input.value(), data_dir())
+        }
+        DataAdminInputCommands::MonthOff(month_off_input) => record_month_off(month_off_input),
+        DataAdminInputCommands::DaysOff(days_off_input) => record_days_off(days_off_input),
+        DataAdminInputCommands::Expenses(expenses_input) => run_expenses_command(expenses_input),
+        DataAdminInputCommands::Migrate(migrate_input) => {
+            migrate_data_with_base_path(data_dir(), *migrate_input.dry_run())
+                .map(|report| print!("{}", report))
+        }
+        DataAdminInputCommands::Encrypt => {
+            let passphrase = data_passphrase_else(ask_for_new_data_passphrase)?;
+            encrypt_data_with_base_path(data_dir(), &passphrase)
+                .map(|files| println!("Encrypted {} data files.", files.len()))
+        }
+        DataAdminInputCommands::Decrypt => {
+            let passphrase = data_passphrase_else(ask_for_data_passphrase)?;
+            decrypt_data_with_base_path(data_dir(), &passphrase)
+                .map(|files| println!("Decrypted {} data files.", files.len()))
+        }
+        DataAdminInputCommands::History => {
+            data_history_with_base_path(data_dir()).map(|history| print!("{}", history))
+        }
+        DataAdminInputCommands::Diff(diff_input) => {
+            diff_data_with_base_path(data_dir(), *diff_input.revision()).map(|changes| {
+                if changes.is_empty() {
+                    println!("No changes since revision #{}.", diff_input.revision());
+                }
+                for change in changes {
+                    print!("{}", change.diff());
+                }
+            })
+        }
+        DataAdminInputCommands::Undo(undo_input) => {
+            undo_data_with_base_path(data_dir(), *undo_input.to()).map(|undone| {
+                for revision in undone {
+                    println!("Undid {}", revision);
+                }
+            })
+        }
+    }
+}
+
+fn run_expenses_command(input: &ExpensesInput) -> Result<()> {
+    match input.command() {
+        Some(ExpensesInputCommands::List(list)) => {
+            expenses_with_base_path(list.month().as_ref(), data_dir())
+                .map(|expenses| print!("{}", expenses))
+        }
+        Some(ExpensesInputCommands::Remove(at)) => {
+            remove_expense_with_base_path(at.month(), *at.index(), data_dir()).map(|_| ())
+        }
+        Some(ExpensesInputCommands::Edit(at)) => {
+            edit_expense_with_base_path(at.month(), *at.index(), data_dir(), ask_for_expense)
+                .map(|_| ())
         }
-        DataAdminInputCommands::Expenses(expenses_input) => {
-            record_expenses(expenses_input.month(), expenses_input.expenses())
+        Some(ExpensesInputCommands::Move(move_input)) => {
+            move_expenses_with_base_path(move_input.from(), move_input.to(), data_dir()).map(|_| ())
+        }
+        Some(ExpensesInputCommands::Import(import)) => import_expenses_with_base_path(
+            import.month(),
+            &import.source(),
+            *import.preview(),
+            data_dir(),
+        )
+        .map(|import| print!("{}", import)),
+        Some(ExpensesInputCommands::Attach(attach)) => attach_receipt_with_base_path(
+            attach.month(),
+            *attach.index(),
+            attach.receipt(),
+            data_dir(),
+        )
+        .map(|_| ()),
+        Some(ExpensesInputCommands::Recurring(recurring)) => run_recurring_expenses(recurring),
+        None => record_expenses(
+            &input
+                .month()
+                .expect("Clap requires a month when no expenses subcommand is given"),
+            input.expenses(),
+        ),
+    }
+}
+
+fn run_recurring_expenses(input: &RecurringExpensesInput) -> Result<()> {
+    if let Some(name) = input.remove() {
+        remove_recurring_expense_with_base_path(name, data_dir()).map(|_| ())
+    } else if let Some(name) = input.skip() {
+        skip_recurring_expense_with_base_path(
+            name,
+            &input
+                .month()
+                .expect("Clap requires a month when skipping a recurring expense"),
+            data_dir(),
+        )
+    } else {
+        add_recurring_expenses_with_base_path(input.add(), data_dir())
+    }
+}
+
+pub fn run_payment_command(command: &PaymentInputCommands) -> Result<()> {
+    match command {
+        PaymentInputCommands::Record(input) => {
+            record_payment_with_base_path(input.payment(), data_dir())
+        }
+        PaymentInputCommands::Import(input) => import_bank_statement_with_base_path(
+            input.statement(),
+            input.format().map(StatementFormat::from),
+            data_dir(),
+        )
+        .map(|reconciliation| println!("{}", reconciliation)),
+    }
+}
+
+pub fn run_report_command(input: &ReportInput) -> Result<String> {
+    match input.command() {
+        Some(ReportInputCommands::Receivables) => {
+            receivables_with_base_path(data_dir(), &Date::today()).map(|r| r.to_string())
+        }
+        None => run_revenue_report_command(input),
+    }
+}
+
+fn run_revenue_report_command(input: &ReportInput) -> Result<String> {
+    let period = input
+        .period()?
+        .expect("Clap requires a year when no report subcommand is given");
+    let report = revenue_report_with_base_path(data_dir(), period)?
+        .format(ReportFormat::from(*input.format()))?;
+    let Some(out) = input.out() else {
+        return Ok(report);
+    };
+    std::fs::write(out, report).map_err(|e| Error::FailedToWriteDataToDisk {
+        underlying: format!("Failed to write report to {}: {}", out.display(), e),
+    })?;
+    Ok(format!(
+        "Saved revenue report for {} to {}",
+        period,
+        out.display()
+    ))
+}
+
+pub fn run_export_command(command: &ExportInputCommands) -> Result<PathBuf> {
+    match command {
+        ExportInputCommands::Sie(input) => {
+            let out = input.out_path();
+            export_sie_with_base_path(data_dir(), Year::from(*input.year()), &out).map(|_| out)
         }
     }
 }
@@ -72,9 +233,27 @@ fn run_invoice_command_with_base_path(
     input: InvoiceInput,
     data_path: impl AsRef<Path>,
 ) -> Result<PathBuf> {
-    let input = input.parsed()?;
-    info!("🔮 Starting PDF creation, input: {}...", input);
-    let pdf_location = create_pdf_with_data_base_path(data_path, input, render)?;
+    let pdf_location = if let Some(format) = input.preview_format()? {
+        let input = input.parsed()?;
+        info!(
+            "🔮 Starting {} preview creation, input: {}...",
+            format, input
+        );
+        create_preview_with_data_base_path(data_path, input, format, render_preview)?
+    } else if let Some(delivery) = input.email_delivery()? {
+        let input = input.parsed()?;
+        info!("🔮 Starting PDF and email creation, input: {}...", input);
+        let emailed = create_pdf_and_email_with_data_base_path(data_path, input, delivery, render)?;
+        info!("✅ Saved email draft to: '{}'", emailed.email().display());
+        emailed.pdf().clone()
+    } else if let Some(reminder) = input.parsed_reminder()? {
+        info!("🔮 Starting reminder PDF creation, input: {}...", reminder);
+        create_reminder_pdf_with_data_base_path(data_path, reminder, render)?
+    } else {
+        let input = input.parsed()?;
+        info!("🔮 Starting PDF creation, input: {}...", input);
+        create_pdf_with_data_base_path(data_path, input, render)?
+    };
     save_pdf_location_to_tmp_file(pdf_location.clone())?;
     Ok(pdf_location)
 }
@@ -83,6 +262,38 @@ pub fn run_invoice_command(input: InvoiceInput) -> Result<PathBuf> {
     run_invoice_command_with_base_path(input, data_dir())
 }
 
+/// Computes the invoice and formats it, without rendering it or saving any
+/// files, returning `None` if no dry run was requested.
+fn run_invoice_dry_run_command_with_base_path(
+    input: InvoiceInput,
+    data_path: impl AsRef<Path>,
+) -> Result<Option<String>> {
+    let Some(format) = input.dry_run_format()? else {
+        return Ok(None);
+    };
+    dry_run_invoice_with_base_path(data_path, input.parsed()?)?
+        .format(format)
+        .map(Some)
+}
+
+pub fn run_invoice_dry_run_command(input: InvoiceInput) -> Result<Option<String>> {
+    run_invoice_dry_run_command_with_base_path(input, data_dir())
+}
+
+/// Generates the invoices of a batch, reusing one [`RenderEngine`] for all
+/// renders, returning `None` if `input` is not a batch.
+pub fn run_invoice_batch_command(input: &InvoiceInput) -> Result<Option<BatchSummary>> {
+    let Some(batch) = input.parsed_batch()? else {
+        return Ok(None);
+    };
+    info!("🔮 Starting batch PDF creation, input: {}...", batch);
+    let engine = RenderEngine::new();
+    create_pdfs_batch_with_data_base_path(data_dir(), batch, |l18n, data, layout| {
+        engine.render(l18n, data, layout)
+    })
+    .map(Some)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -102,4 +313,49 @@ mod tests {
         let result = run_invoice_command_with_base_path(input, tempdir.path());
         assert!(result.is_ok(), "Expected run to succeed, got: {:?}", result);
     }
+
+    #[test]
+    fn test_run_invoice_command_email_draft() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        let tempfile = tempdir.path().join("out.pdf");
+        save_data_with_base_path(Data::sample(), tempdir.path()).unwrap();
+        let input = InvoiceInput::parse_from([
+            "invoice",
+            "--email-draft",
+            "--out",
+            &format!("{}", tempfile.as_path().display()),
+        ]);
+        let path = run_invoice_command_with_base_path(input, tempdir.path()).unwrap();
+        assert_eq!(path, tempfile);
+        assert!(tempdir.path().join("out.eml").exists());
+    }
+
+    #[test]
+    fn test_run_invoice_dry_run_command_json() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        save_data_with_base_path(Data::sample(), tempdir.path()).unwrap();
+        let input = InvoiceInput::parse_from(["invoice", "--dry-run", "json"]);
+        let json = run_invoice_dry_run_command_with_base_path(input, tempdir.path())
+            .unwrap()
+            .unwrap();
+        assert!(json.contains("\"worked_days\""));
+        assert_eq!(invoice_ledger(tempdir.path()).unwrap().iter().count(), 0);
+    }
+
+    #[test]
+    fn test_run_invoice_command_svg_preview() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        let tempfile = tempdir.path().join("out.pdf");
+        save_data_with_base_path(Data::sample(), tempdir.path()).unwrap();
+        let input = InvoiceInput::parse_from([
+            "invoice",
+            "--format",
+            "svg",
+            "--out",
+            &format!("{}", tempfile.as_path().display()),
+        ]);
+        let path = run_invoice_command_with_base_path(input, tempdir.path()).unwrap();
+        assert_eq!(path, tempdir.path().join("out.svg"));
+        assert!(std::fs::read_to_string(path).unwrap().starts_with("<svg"));
+    }
 }
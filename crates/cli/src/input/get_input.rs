@@ -25,27 +25,240 @@ pub enum Command {
 
     /// CLI arguments for admin tasks related to data.
     Data(DataAdminInput),
+
+    /// Record payments received towards issued invoices.
+    Payment(PaymentInput),
+
+    /// Reports about issued invoices, such as outstanding receivables.
+    Report(ReportInput),
+
+    /// Export invoices for bookkeeping, e.g. as SIE4 for Fortnox or Visma.
+    Export(ExportInput),
+}
+
+/// The CLI arguments for exporting invoices for bookkeeping.
+#[derive(Debug, Args, Getters, PartialEq)]
+pub struct ExportInput {
+    /// The format to export to.
+    #[command(subcommand)]
+    #[getset(get = "pub")]
+    command: ExportInputCommands,
+}
+
+/// The formats available for exporting invoices.
+#[derive(Debug, Subcommand, Unwrap, PartialEq)]
+pub enum ExportInputCommands {
+    /// Exports one verification per invoice of a year as a SIE4 file, using the
+    /// chart of accounts in `chart_of_accounts.ron` in the data directory, or
+    /// the BAS accounts 1510, 3011, 3590 and 2611 if not present.
+    Sie(SieExportInput),
+}
+
+/// Export the invoices of a year as a SIE4 file.
+#[derive(Debug, Args, Getters, PartialEq)]
+pub struct SieExportInput {
+    /// The fiscal year to export, e.g. `2025`.
+    #[arg(long, short = 'y')]
+    #[getset(get = "pub")]
+    year: u16,
+
+    /// The file to save the SIE file to, defaults to `klirr_<year>.se` in the
+    /// current directory.
+    #[arg(long, short = 'o')]
+    #[getset(get = "pub")]
+    out: Option<PathBuf>,
+}
+
+impl SieExportInput {
+    /// The path to save the SIE file to.
+    pub fn out_path(&self) -> PathBuf {
+        self.out
+            .clone()
+            .unwrap_or_else(|| PathBuf::from(format!("{}_{}.se", BINARY_NAME, self.year)))
+    }
+}
+
+/// The CLI arguments for payments received towards issued invoices.
+#[derive(Debug, Args, Getters, PartialEq)]
+pub struct PaymentInput {
+    /// The command to run for payments.
+    #[command(subcommand)]
+    #[getset(get = "pub")]
+    command: PaymentInputCommands,
+}
+
+/// The commands available for payments.
+#[derive(Debug, Subcommand, Unwrap, PartialEq)]
+pub enum PaymentInputCommands {
+    /// Records a payment received towards an issued invoice.
+    Record(RecordPaymentInput),
+    /// Imports a bank statement, matching incoming payments with open invoices
+    /// and recording the matches as payments.
+    Import(ImportStatementInput),
+}
+
+/// Import a bank statement, in ISO 20022 camt.053 XML or CSV format.
+#[derive(Debug, Args, Getters, PartialEq)]
+pub struct ImportStatementInput {
+    /// Path to the bank statement file.
+    #[getset(get = "pub")]
+    statement: PathBuf,
+
+    /// The format of the bank statement, inferred from the file extension
+    /// if not specified. The CSV format has the header `date,amount,currency,reference`.
+    #[arg(long, short = 'f', value_enum)]
+    #[getset(get = "pub")]
+    format: Option<StatementFormatInput>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+#[clap(rename_all = "kebab_case")]
+pub enum StatementFormatInput {
+    Camt053,
+    Csv,
+}
+
+impl From<StatementFormatInput> for StatementFormat {
+    fn from(format: StatementFormatInput) -> Self {
+        match format {
+            StatementFormatInput::Camt053 => StatementFormat::Camt053,
+            StatementFormatInput::Csv => StatementFormat::Csv,
+        }
+    }
+}
+
+/// Record a payment received towards an issued invoice.
+#[derive(Debug, Args, Getters, PartialEq)]
+pub struct RecordPaymentInput {
+    /// The number of the invoice the payment is for, e.g. `243`.
+    #[arg(long, short = 'i')]
+    #[getset(get = "pub")]
+    invoice: u16,
+
+    /// The kind of the invoice the payment is for, needed only if several
+    /// invoices have the same number.
+    #[arg(long, short = 'k', value_enum)]
+    #[getset(get = "pub")]
+    kind: Option<InvoiceKindInput>,
+
+    /// The amount paid, in the currency of the invoice, e.g. `9324`.
+    #[arg(long, short = 'a')]
+    #[getset(get = "pub")]
+    amount: Decimal,
+
+    /// The date the payment was received, e.g. `2025-06-28`, defaults to today.
+    #[arg(long, short = 'd')]
+    #[getset(get = "pub")]
+    date: Option<Date>,
+}
+
+impl RecordPaymentInput {
+    /// The payment specified by these arguments.
+    pub fn payment(&self) -> Payment {
+        let payment = Payment::builder()
+            .invoice(InvoiceNumber::from(self.invoice))
+            .amount(Cost::from(self.amount))
+            .date(self.date.unwrap_or_else(Date::today));
+        match self.kind {
+            Some(kind) => payment.kind(InvoiceKind::from(kind)).build(),
+            None => payment.build(),
+        }
+    }
+}
+
+/// The CLI arguments for reports about issued invoices, either a revenue
+/// report for a year or quarter, e.g. `report --year 2025 --quarter 2`, or
+/// one of the report subcommands.
+#[derive(Debug, Args, Getters, PartialEq)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
+pub struct ReportInput {
+    /// The report to print, if not a revenue report.
+    #[command(subcommand)]
+    #[getset(get = "pub")]
+    command: Option<ReportInputCommands>,
+
+    /// The year of the revenue report, e.g. `2025`.
+    #[arg(long, short = 'y', required = true)]
+    #[getset(get = "pub")]
+    year: Option<u16>,
+
+    /// The quarter of the revenue report, `1` to `4`, or the whole year if
+    /// not specified.
+    #[arg(long, short = 'q', value_parser = clap::value_parser!(u8).range(1..=4))]
+    #[getset(get = "pub")]
+    quarter: Option<u8>,
+
+    /// The format of the revenue report.
+    #[arg(long, short = 'f', value_enum, default_value_t = ReportFormatInput::Table)]
+    #[getset(get = "pub")]
+    format: ReportFormatInput,
+
+    /// A file to save the revenue report to, instead of printing it.
+    #[arg(long, short = 'o')]
+    #[getset(get = "pub")]
+    out: Option<PathBuf>,
+}
+
+impl ReportInput {
+    /// The period of the revenue report, if a year was specified.
+    pub fn period(&self) -> Result<Option<ReportPeriod>> {
+        let Some(year) = self.year else {
+            return Ok(None);
+        };
+        let quarter = self.quarter.map(Quarter::try_from).transpose()?;
+        Ok(Some(
+            ReportPeriod::builder()
+                .year(Year::from(year))
+                .quarter(quarter)
+                .build(),
+        ))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+#[clap(rename_all = "kebab_case")]
+pub enum ReportFormatInput {
+    Table,
+    Csv,
+    Json,
+}
+
+impl From<ReportFormatInput> for ReportFormat {
+    fn from(format: ReportFormatInput) -> Self {
+        match format {
+            ReportFormatInput::Table => ReportFormat::Table,
+            ReportFormatInput::Csv => ReportFormat::Csv,
+            ReportFormatInput::Json => ReportFormat::Json,
+        }
+    }
+}
+
+/// The reports available about issued invoices.
+#[derive(Debug, Subcommand, Unwrap, PartialEq)]
+pub enum ReportInputCommands {
+    /// Lists unpaid, partially paid and overdue invoices, with days overdue.
+    Receivables,
 }
 
 /// The CLI arguments for data management, such as initializing the data directory,
-/// validating the data, or recording expenses or month off.
+/// validating the data, or recording expenses, months off or days off.
 #[derive(Debug, Args, Getters, PartialEq)]
 pub struct DataAdminInput {
     /// The command to run for data management, such as initializing the data directory,
-    /// validating the data, or recording expenses or month off.
+    /// validating the data, or recording expenses, months off or days off.
     #[command(subcommand)]
     #[getset(get = "pub")]
     command: DataAdminInputCommands,
 }
 
 /// The commands available for data management, such as initializing the data directory,
-/// validating the data, or recording expenses or month off.
+/// validating the data, or recording expenses, months off or days off.
 #[derive(Debug, Subcommand, Unwrap, PartialEq)]
 pub enum DataAdminInputCommands {
     /// Initializes the data in the data directory, creating it if it does not exist.
     /// Such as information about you as a vendor and your client, payment information
     /// pricing etc
-    Init,
+    Init(InitDataInput),
     /// Validates the data in the data directory, checking if it is correctly formatted
     /// and if all required fields are present.
     Validate,
@@ -53,11 +266,87 @@ pub enum DataAdminInputCommands {
     /// with the existing data as default values so that user can press Enter
     /// to accept the existing values as defaults.
     Edit(EditDataInput),
+    /// Sets a single field of the data without the terminal UI, e.g.
+    /// `klirr data set vendor.company_name "Foo AB"`.
+    Set(SetDataInput),
     /// Records a month off for the specified month, which is used to calculate the invoice.
     MonthOff(MonthOffInput),
+    /// Records days off as ranges of dates, the weekdays of which are not
+    /// invoiced for.
+    DaysOff(DaysOffInput),
     /// Records expenses for the specified month, used to create expenses invoices
     /// and affects invoice number calculation.
     Expenses(ExpensesInput),
+    /// Migrates the data files written by an older version of klirr to the
+    /// current format, after backing them up. Data is also migrated when
+    /// read, this shows what changes.
+    Migrate(MigrateInput),
+    /// Lists the recorded revisions of the data, one per change made by
+    /// klirr, oldest first.
+    History,
+    /// Shows how the data files changed since a revision, listed by
+    /// `klirr data history`.
+    Diff(DataDiffInput),
+    /// Reverts the latest change of the data, or every change back to and
+    /// including a revision.
+    Undo(DataUndoInput),
+    /// Encrypts the data files with a passphrase, read from the environment
+    /// variable `KLIRR_DATA_PASSPHRASE` or asked for. Files written later are
+    /// encrypted too.
+    Encrypt,
+    /// Decrypts the data files, so that they are saved in plain text again.
+    Decrypt,
+}
+
+/// Show the changes of the data files since a revision.
+#[derive(Debug, Args, Getters, PartialEq)]
+pub struct DataDiffInput {
+    /// The number of the revision, as listed by `klirr data history`.
+    #[getset(get = "pub")]
+    revision: u32,
+}
+
+/// Revert changes of the data.
+#[derive(Debug, Args, Getters, PartialEq)]
+pub struct DataUndoInput {
+    /// Undo every change back to and including this revision, instead of
+    /// only the latest change.
+    #[arg(long)]
+    #[getset(get = "pub")]
+    to: Option<u32>,
+}
+
+/// Initialize the data, asking for each value or reading a file.
+#[derive(Debug, Args, Getters, PartialEq)]
+pub struct InitDataInput {
+    /// Read the full data from a `.json` or `.ron` file instead of asking
+    /// for it, e.g. to provision a new machine or in CI.
+    #[arg(long)]
+    #[getset(get = "pub")]
+    from: Option<PathBuf>,
+}
+
+/// Set a single field of the data.
+#[derive(Debug, Args, Getters, PartialEq)]
+pub struct SetDataInput {
+    /// The dotted path of the field, e.g. `vendor.company_name`,
+    /// `payment_info.iban` or `service_fees.services.0.unit_price`.
+    #[getset(get = "pub")]
+    field: String,
+
+    /// The new value, text as is and other values as JSON, e.g. `true` or
+    /// `["billing@example.com"]`.
+    #[getset(get = "pub")]
+    value: String,
+}
+
+/// Migrate the data files to the current format, or show what would change.
+#[derive(Debug, Args, Getters, PartialEq)]
+pub struct MigrateInput {
+    /// Print the migrations and the changes of each file without saving them.
+    #[arg(long)]
+    #[getset(get = "pub")]
+    dry_run: bool,
 }
 
 #[derive(Debug, Args, Getters, PartialEq)]
@@ -91,7 +380,7 @@ impl From<EditDataInputSelector> for DataSelector {
     }
 }
 
-/// Record a new month off for the specified month.
+/// Record a new month off for the specified month, or remove a recorded one.
 #[derive(Debug, Args, Getters, PartialEq)]
 pub struct MonthOffInput {
     /// The month to be added if not already present in the data directory.
@@ -103,21 +392,52 @@ pub struct MonthOffInput {
     )]
     #[getset(get = "pub")]
     month: YearAndMonth,
+
+    /// Removes the month from the record of months off instead of adding it.
+    #[arg(long, help = "Remove the month from the record of months off.")]
+    #[getset(get = "pub")]
+    remove: bool,
+}
+
+/// Record days off as ranges of dates, subtracted from the working days of
+/// their months, or remove recorded days off.
+#[derive(Debug, Args, Getters, PartialEq)]
+pub struct DaysOffInput {
+    /// The ranges of days off, both dates inclusive.
+    #[arg(
+        long = "range",
+        short = 'r',
+        required = true,
+        help = "A range of days off, e.g. `2025-07-01..2025-07-18`, or a single day, e.g. `2025-07-04`. Can be repeated."
+    )]
+    #[getset(get = "pub")]
+    ranges: Vec<DateRange>,
+
+    /// Removes the days from the record of days off instead of adding them.
+    #[arg(long, help = "Remove the days from the record of days off.")]
+    #[getset(get = "pub")]
+    remove: bool,
 }
 
 /// Record expenses for the specified month, which will be used to create expenses invoices
-/// and affects invoice number calculation.
+/// and affects invoice number calculation, or list, remove, edit or move recorded expenses.
 #[derive(Debug, Args, Getters, PartialEq)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 pub struct ExpensesInput {
+    /// What to do with recorded expenses, if not recording new expenses.
+    #[command(subcommand)]
+    #[getset(get = "pub")]
+    command: Option<ExpensesInputCommands>,
+
     /// The month for which the expenses are recorded.
     #[arg(
         long,
         short = 'm',
-        default_value = None,
+        required = true,
         help = "The month and year for which you wanna record expenses, e.g. `2025-05`. Note that we might expense for month of May even thought we had an expense in beginning of June, so this is not a strict month, but rather a month in which we want to record the expenses."
     )]
     #[getset(get = "pub")]
-    month: YearAndMonth,
+    month: Option<YearAndMonth>,
 
     /// The expenses to record for the month, which are specified as a list of items.
     /// Please note that the transaction date might be different from the month specified,
@@ -129,6 +449,213 @@ pub struct ExpensesInput {
     expenses: Vec<Item>,
 }
 
+/// The commands for managing recorded expenses.
+#[derive(Debug, Subcommand, Unwrap, PartialEq)]
+pub enum ExpensesInputCommands {
+    /// Lists the recorded expenses, with the index of each expense per month.
+    List(ExpensesListInput),
+    /// Removes a recorded expense.
+    Remove(ExpenseAtIndexInput),
+    /// Edits a recorded expense, prompting for each field, prefilled with
+    /// the current values.
+    Edit(ExpenseAtIndexInput),
+    /// Moves all expenses of a month to another month.
+    Move(ExpensesMoveInput),
+    /// Imports expenses from a CSV file, e.g. a card export, or from a folder
+    /// of receipts, skipping expenses already recorded.
+    Import(Box<ExpensesImportInput>),
+    /// Attaches a receipt to a recorded expense, copying the file into the
+    /// `receipts` folder of the data directory. Receipts are appended to the
+    /// expenses invoice.
+    Attach(ExpenseReceiptInput),
+    /// Adds, removes or skips expenses billed every month, which are merged
+    /// into the expenses of each month they are due in.
+    Recurring(RecurringExpensesInput),
+}
+
+/// Add, remove or skip expenses billed every month, e.g. a phone
+/// subscription. Expenses recorded for a month with the same name as a
+/// recurring expense override it in that month.
+#[derive(Debug, Args, Getters, PartialEq)]
+#[command(group(clap::ArgGroup::new("action").required(true).args(["add", "remove", "skip"])))]
+pub struct RecurringExpensesInput {
+    /// The recurring expenses to add, replacing recurring expenses with the
+    /// same names. Format for each expense is:
+    /// `name,amount,currency,quantity,day_of_month,start[,end]`, e.g.
+    /// `Phone subscription,25,EUR,1,28,2025-01`.
+    #[arg(long, short = 'a')]
+    #[getset(get = "pub")]
+    add: Vec<RecurringExpense>,
+
+    /// The name of a recurring expense to remove, e.g. `"Phone subscription"`.
+    #[arg(long)]
+    #[getset(get = "pub")]
+    remove: Option<String>,
+
+    /// The name of a recurring expense not to bill in `--month`.
+    #[arg(long, requires = "month")]
+    #[getset(get = "pub")]
+    skip: Option<String>,
+
+    /// The month in which to skip the recurring expense, e.g. `2025-08`.
+    #[arg(long, short = 'm')]
+    #[getset(get = "pub")]
+    month: Option<YearAndMonth>,
+}
+
+/// List the expenses of a month, or of all months.
+#[derive(Debug, Args, Getters, PartialEq)]
+pub struct ExpensesListInput {
+    /// The month to list expenses for, e.g. `2025-05`, or all months if not specified.
+    #[arg(long, short = 'm')]
+    #[getset(get = "pub")]
+    month: Option<YearAndMonth>,
+}
+
+/// An expense at an index of a month, as listed by `data expenses list`.
+#[derive(Debug, Args, Getters, PartialEq)]
+pub struct ExpenseAtIndexInput {
+    /// The month of the expense, e.g. `2025-05`.
+    #[arg(long, short = 'm')]
+    #[getset(get = "pub")]
+    month: YearAndMonth,
+
+    /// The index of the expense within the month, e.g. `0`.
+    #[arg(long, short = 'i')]
+    #[getset(get = "pub")]
+    index: usize,
+}
+
+/// Move all expenses of a month to another month.
+#[derive(Debug, Args, Getters, PartialEq)]
+pub struct ExpensesMoveInput {
+    /// The month to move the expenses from, e.g. `2025-05`.
+    #[arg(long)]
+    #[getset(get = "pub")]
+    from: YearAndMonth,
+
+    /// The month to move the expenses to, e.g. `2025-06`.
+    #[arg(long)]
+    #[getset(get = "pub")]
+    to: YearAndMonth,
+}
+
+/// A receipt to attach to the expense at an index of a month, as listed by
+/// `data expenses list`.
+#[derive(Debug, Args, Getters, PartialEq)]
+pub struct ExpenseReceiptInput {
+    /// The month of the expense, e.g. `2025-05`.
+    #[arg(long, short = 'm')]
+    #[getset(get = "pub")]
+    month: YearAndMonth,
+
+    /// The index of the expense within the month, e.g. `0`.
+    #[arg(long, short = 'i')]
+    #[getset(get = "pub")]
+    index: usize,
+
+    /// The receipt, a PDF, PNG or JPG file, e.g. `~/Downloads/coffee.pdf`.
+    #[arg(long, short = 'r')]
+    #[getset(get = "pub")]
+    receipt: PathBuf,
+}
+
+/// Import expenses for a month from a CSV file or a folder of receipts.
+#[derive(Debug, Args, Getters, PartialEq)]
+pub struct ExpensesImportInput {
+    /// The month for which the expenses are recorded, e.g. `2025-05`.
+    #[arg(long, short = 'm')]
+    #[getset(get = "pub")]
+    month: YearAndMonth,
+
+    /// A CSV file to import expenses from, e.g. a card export.
+    #[arg(long, conflicts_with = "folder", required_unless_present = "folder")]
+    #[getset(get = "pub")]
+    file: Option<PathBuf>,
+
+    /// A folder of receipts to import expenses from, one expense per file
+    /// named as `<date>_<name>_<amount>_<currency>[_<quantity>]`, e.g.
+    /// `2025-05-31_Coffee_2.5_EUR_3.pdf`.
+    #[arg(long)]
+    #[getset(get = "pub")]
+    folder: Option<PathBuf>,
+
+    /// The header of the CSV column with the name of the expense.
+    #[arg(long, default_value = "name")]
+    #[getset(get = "pub")]
+    name_column: String,
+
+    /// The header of the CSV column with the amount, the sign is ignored.
+    #[arg(long, default_value = "amount")]
+    #[getset(get = "pub")]
+    amount_column: String,
+
+    /// The header of the CSV column with the currency.
+    #[arg(long, default_value = "currency")]
+    #[getset(get = "pub")]
+    currency_column: String,
+
+    /// The currency of all expenses, for CSV files without a currency column.
+    #[arg(long)]
+    #[getset(get = "pub")]
+    currency: Option<Currency>,
+
+    /// The header of the CSV column with the quantity, if not specified the
+    /// quantity of every expense is `1`.
+    #[arg(long)]
+    #[getset(get = "pub")]
+    quantity_column: Option<String>,
+
+    /// The header of the CSV column with the transaction date.
+    #[arg(long, default_value = "date")]
+    #[getset(get = "pub")]
+    date_column: String,
+
+    /// The format of the transaction dates in the CSV file, e.g. `%d/%m/%Y`.
+    #[arg(long, default_value = "%Y-%m-%d")]
+    #[getset(get = "pub")]
+    date_format: String,
+
+    /// The decimal separator of amounts and quantities in the CSV file, e.g. `,`.
+    #[arg(long, default_value_t = '.')]
+    #[getset(get = "pub")]
+    decimal_separator: char,
+
+    /// The delimiter between columns in the CSV file, e.g. `;`.
+    #[arg(long, default_value_t = ',')]
+    #[getset(get = "pub")]
+    delimiter: char,
+
+    /// Print the expenses which would be imported without saving them.
+    #[arg(long)]
+    #[getset(get = "pub")]
+    preview: bool,
+}
+
+impl ExpensesImportInput {
+    /// The source to import expenses from.
+    pub fn source(&self) -> ExpenseSource {
+        match (&self.file, &self.folder) {
+            (Some(path), _) => ExpenseSource::Csv {
+                path: path.clone(),
+                format: ExpenseCsvFormat::builder()
+                    .name_column(self.name_column.clone())
+                    .amount_column(self.amount_column.clone())
+                    .currency_column(self.currency_column.clone())
+                    .currency(self.currency)
+                    .quantity_column(self.quantity_column.clone())
+                    .date_column(self.date_column.clone())
+                    .date_format(self.date_format.clone())
+                    .decimal_separator(self.decimal_separator)
+                    .delimiter(self.delimiter)
+                    .build(),
+            },
+            (None, Some(path)) => ExpenseSource::ReceiptFolder { path: path.clone() },
+            (None, None) => unreachable!("Clap requires either a file or a folder"),
+        }
+    }
+}
+
 /// The CLI arguments for generating an invoice PDF.
 #[derive(Debug, Clone, TypedBuilder, Getters, Parser)]
 #[command(name = "invoice")]
@@ -163,32 +690,125 @@ pub struct InvoiceInput {
     #[arg(long, short = 'o')]
     #[builder(setter(into, strip_option), default = None)]
     out: Option<PathBuf>,
+
+    /// The output format, a PDF or a PNG or SVG preview image of the invoice,
+    /// previews are not recorded as issued.
+    #[arg(long, short = 'f', value_enum, default_value_t)]
+    #[builder(setter(into), default)]
+    #[getset(get = "pub")]
+    format: OutputFormatInput,
+
+    /// Print the computed invoice, as a table or JSON, without rendering it
+    /// or saving any files.
+    #[arg(long, value_enum, num_args = 0..=1, default_missing_value = "table")]
+    #[builder(setter(into, strip_option), default = None)]
+    #[getset(get = "pub")]
+    dry_run: Option<DryRunFormatInput>,
+
+    /// Also save an `.eml` email draft to the billing emails of the client,
+    /// with the PDF attached, next to the PDF.
+    #[arg(long, conflicts_with_all = ["dry_run", "format"])]
+    #[builder(default)]
+    email_draft: bool,
+
+    /// Save an `.eml` email draft and send it using the SMTP server in `smtp.ron`.
+    #[arg(long, conflicts_with_all = ["dry_run", "format", "email_draft"])]
+    #[builder(default)]
+    send_email: bool,
+
+    /// The quantity of a service whose quantity is specified per run, e.g.
+    /// `--quantity "On-call stipend=2"`, repeat for several services.
+    #[arg(long = "quantity", short = 'q')]
+    #[builder(setter(into), default)]
+    #[getset(get = "pub")]
+    quantities: Vec<ServiceQuantityOverride>,
+
+    /// A discount of the subtotal of this invoice only, a percentage or a
+    /// fixed amount in the currency of the invoice, e.g. `--discount 10%`.
+    #[arg(long)]
+    #[builder(setter(into, strip_option), default = None)]
+    #[getset(get = "pub")]
+    discount: Option<AdjustmentAmount>,
+
+    /// A surcharge on the subtotal of this invoice only, e.g. `--surcharge 5%`.
+    #[arg(long)]
+    #[builder(setter(into, strip_option), default = None)]
+    #[getset(get = "pub")]
+    surcharge: Option<AdjustmentAmount>,
+
+    /// A credit deducted from this invoice only, e.g. `--credit 500`.
+    #[arg(long)]
+    #[builder(setter(into, strip_option), default = None)]
+    #[getset(get = "pub")]
+    credit: Option<AdjustmentAmount>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+#[clap(rename_all = "kebab_case")]
+pub enum DryRunFormatInput {
+    Table,
+    Json,
+}
+
+impl From<DryRunFormatInput> for DryRunFormat {
+    fn from(format: DryRunFormatInput) -> Self {
+        match format {
+            DryRunFormatInput::Table => DryRunFormat::Table,
+            DryRunFormatInput::Json => DryRunFormat::Json,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, ValueEnum)]
+#[clap(rename_all = "kebab_case")]
+pub enum OutputFormatInput {
+    #[default]
+    Pdf,
+    Png,
+    Svg,
+}
+
+impl OutputFormatInput {
+    /// The preview format, or `None` for PDF.
+    pub fn preview(&self) -> Option<PreviewFormat> {
+        match self {
+            Self::Pdf => None,
+            Self::Png => Some(PreviewFormat::Png),
+            Self::Svg => Some(PreviewFormat::Svg),
+        }
+    }
 }
 
 impl InvoiceInput {
     /// Maps `Option<TargetItems>` to `InvoicedItems`, e.g. for `TargetItems::Ooo { days }`
     /// we map from `Option<u8>` to `Option<Day>`.
     fn _invoiced_items(&self) -> Result<InvoicedItems> {
+        let days_off = |days: u8| -> Result<Option<Day>> {
+            if days == 0 {
+                Ok(None)
+            } else {
+                Ok(Some(Day::try_from(days)?))
+            }
+        };
         match self.items.clone().unwrap_or_default() {
             TargetItems::Ooo { days } => Ok(InvoicedItems::Service {
-                days_off: if days == 0 {
-                    None
-                } else {
-                    Some(Day::try_from(days)?)
-                },
+                days_off: days_off(days)?,
             }),
             TargetItems::Expenses => Ok(InvoicedItems::Expenses),
+            TargetItems::Combined { ooo } => Ok(InvoicedItems::Combined {
+                days_off: days_off(ooo)?,
+            }),
+            TargetItems::Reminder(_) => {
+                unreachable!("Reminders are parsed with `parsed_reminder`, not `parsed`")
+            }
+            TargetItems::Batch(_) => {
+                unreachable!("Batches are parsed with `parsed_batch`, not `parsed`")
+            }
         }
     }
 
-    /// Returns a `ValidInput` from the parsed command line arguments.
-    /// This function validates the input, e.g. checks if the output path exists,
-    /// and returns a `ValidInput` that can be used to generate the invoice.
-    ///
-    /// # Errors
-    /// Returns an error if the input is invalid, e.g. if the output path does not
-    /// exist or if the items are not specified correctly.
-    pub fn parsed(self) -> Result<ValidInput> {
+    /// Validates that the parent of the output path, if specified, exists.
+    fn validate_out(&self) -> Result<()> {
         if let Some(path) = &self.out {
             let parent = path
                 .parent()
@@ -199,34 +819,203 @@ impl InvoiceInput {
                 })?;
             }
         }
-        let items = self._invoiced_items()?;
-        let valid = ValidInput::builder()
-            .month(self.month.year_and_month())
-            .layout(*self.layout())
-            .items(items)
-            .language(*self.language())
-            .maybe_output_path(self.out)
-            .build();
-        Ok(valid)
+        Ok(())
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
 
-    mod data_admin_input {
+    /// Returns the preview format if a preview image should be rendered
+    /// instead of a PDF.
+    ///
+    /// # Errors
+    /// Returns an error if a preview is requested for a reminder or a batch.
+    pub fn preview_format(&self) -> Result<Option<PreviewFormat>> {
+        let Some(format) = self.format.preview() else {
+            return Ok(None);
+        };
+        match &self.items {
+            Some(TargetItems::Reminder(_)) => Err(Error::PreviewNotSupported {
+                items: "reminder".to_owned(),
+            }),
+            Some(TargetItems::Batch(_)) => Err(Error::PreviewNotSupported {
+                items: "batch".to_owned(),
+            }),
+            _ => Ok(Some(format)),
+        }
+    }
+
+    /// Returns the format of the dry run if the invoice should be computed
+    /// and printed instead of rendered.
+    ///
+    /// # Errors
+    /// Returns an error if a dry run is requested for a reminder or a batch.
+    pub fn dry_run_format(&self) -> Result<Option<DryRunFormat>> {
+        let Some(format) = self.dry_run else {
+            return Ok(None);
+        };
+        match &self.items {
+            Some(TargetItems::Reminder(_)) => Err(Error::DryRunNotSupported {
+                items: "reminder".to_owned(),
+            }),
+            Some(TargetItems::Batch(_)) => Err(Error::DryRunNotSupported {
+                items: "batch".to_owned(),
+            }),
+            _ => Ok(Some(DryRunFormat::from(format))),
+        }
+    }
+
+    /// Returns what to do with the email of the invoice, if it should be
+    /// emailed.
+    ///
+    /// # Errors
+    /// Returns an error if a reminder or a batch should be emailed.
+    pub fn email_delivery(&self) -> Result<Option<EmailDelivery>> {
+        let delivery = if self.send_email {
+            EmailDelivery::Send
+        } else if self.email_draft {
+            EmailDelivery::Draft
+        } else {
+            return Ok(None);
+        };
+        match &self.items {
+            Some(TargetItems::Reminder(_)) => Err(Error::EmailNotSupported {
+                items: "reminder".to_owned(),
+            }),
+            Some(TargetItems::Batch(_)) => Err(Error::EmailNotSupported {
+                items: "batch".to_owned(),
+            }),
+            _ => Ok(Some(delivery)),
+        }
+    }
+
+    /// Returns a `ValidReminderInput` if the items are `TargetItems::Reminder`,
+    /// else `None`, in which case `parsed` should be used.
+    ///
+    /// # Errors
+    /// Returns an error if the output path does not exist, or if quantities
+    /// of services, discounts, surcharges or credits are given, which apply
+    /// to invoices only.
+    pub fn parsed_reminder(&self) -> Result<Option<ValidReminderInput>> {
+        let Some(TargetItems::Reminder(reminder)) = &self.items else {
+            return Ok(None);
+        };
+        if !self.quantities.is_empty() || !self.adjustments().is_empty() {
+            return Err(Error::QuantitiesAndAdjustmentsNotSupported {
+                items: "reminder".to_owned(),
+            });
+        }
+        self.validate_out()?;
+        let valid = ValidReminderInput::builder()
+            .language(*self.language())
+            .number(InvoiceNumber::from(*reminder.number()))
+            .kind(reminder.kind().map(InvoiceKind::from))
+            .policy(reminder.policy())
+            .reminder_date(reminder.date().unwrap_or_else(Date::today))
+            .maybe_output_path(self.out.clone())
+            .build();
+        Ok(Some(valid))
+    }
+
+    /// Returns a `ValidBatchInput` if the items are `TargetItems::Batch`,
+    /// else `None`. The output path, if specified, is used as the directory
+    /// to save the invoices in.
+    ///
+    /// # Errors
+    /// Returns an error if the output directory does not exist, or if a
+    /// preview or an email is requested, which batches do not support.
+    pub fn parsed_batch(&self) -> Result<Option<ValidBatchInput>> {
+        let Some(TargetItems::Batch(batch)) = &self.items else {
+            return Ok(None);
+        };
+        self.preview_format()?;
+        self.email_delivery()?;
+        if let Some(directory) = self.out.as_ref().filter(|directory| !directory.is_dir()) {
+            Err(Error::SpecifiedOutputPathDoesNotExist {
+                path: directory.display().to_string(),
+            })?;
+        }
+        let valid = ValidBatchInput::builder()
+            .language(*self.language())
+            .layout(*self.layout())
+            .from(*batch.from())
+            .to(*batch.to())
+            .kinds(
+                batch
+                    .kinds()
+                    .iter()
+                    .map(|kind| InvoiceKind::from(*kind))
+                    .collect::<Vec<_>>(),
+            )
+            .output_directory(self.out.clone())
+            .service_quantities(self.quantities.clone())
+            .adjustments(self.adjustments())
+            .build();
+        Ok(Some(valid))
+    }
+
+    /// The adjustments of this invoice only, passed as `--discount`,
+    /// `--surcharge` and `--credit`.
+    fn adjustments(&self) -> Vec<Adjustment> {
+        [
+            (AdjustmentKind::Discount, self.discount),
+            (AdjustmentKind::Surcharge, self.surcharge),
+            (AdjustmentKind::Credit, self.credit),
+        ]
+        .into_iter()
+        .filter_map(|(kind, amount)| amount.map(|amount| Adjustment::of_subtotal(kind, amount)))
+        .collect()
+    }
+
+    /// Returns a `ValidInput` from the parsed command line arguments.
+    /// This function validates the input, e.g. checks if the output path exists,
+    /// and returns a `ValidInput` that can be used to generate the invoice.
+    ///
+    /// # Errors
+    /// Returns an error if the input is invalid, e.g. if the output path does not
+    /// exist or if the items are not specified correctly.
+    pub fn parsed(self) -> Result<ValidInput> {
+        self.validate_out()?;
+        let items = self._invoiced_items()?;
+        let adjustments = self.adjustments();
+        let valid = ValidInput::builder()
+            .month(self.month.year_and_month())
+            .layout(*self.layout())
+            .items(items)
+            .language(*self.language())
+            .maybe_output_path(self.out)
+            .service_quantities(self.quantities)
+            .adjustments(adjustments)
+            .build();
+        Ok(valid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod data_admin_input {
         use super::*;
 
         #[test]
         fn test_data_admin_init() {
             let input = CliArgs::parse_from([BINARY_NAME, "data", "init"]);
-            assert!(matches!(
-                input.command,
-                Command::Data(DataAdminInput {
-                    command: DataAdminInputCommands::Init
-                })
-            ));
+            let init = input.command.unwrap_data().command.unwrap_init();
+            assert_eq!(*init.from(), None);
+        }
+
+        #[test]
+        fn test_data_admin_init_from_file() {
+            let input = CliArgs::parse_from([BINARY_NAME, "data", "init", "--from", "data.json"]);
+            let init = input.command.unwrap_data().command.unwrap_init();
+            assert_eq!(*init.from(), Some(PathBuf::from("data.json")));
+        }
+
+        #[test]
+        fn test_data_admin_set() {
+            let input =
+                CliArgs::parse_from([BINARY_NAME, "data", "set", "vendor.company_name", "Foo AB"]);
+            let set = input.command.unwrap_data().command.unwrap_set();
+            assert_eq!(set.field(), "vendor.company_name");
+            assert_eq!(set.value(), "Foo AB");
         }
 
         #[test]
@@ -240,6 +1029,54 @@ mod tests {
             ));
         }
 
+        #[test]
+        fn test_data_admin_month_off_remove() {
+            let input = CliArgs::parse_from([
+                BINARY_NAME,
+                "data",
+                "month-off",
+                "-m",
+                "2025-05",
+                "--remove",
+            ]);
+            assert_eq!(
+                input.command.unwrap_data().command,
+                DataAdminInputCommands::MonthOff(MonthOffInput {
+                    month: YearAndMonth::may(2025),
+                    remove: true
+                })
+            );
+        }
+
+        #[test]
+        fn test_data_admin_days_off() {
+            let input = CliArgs::parse_from([
+                BINARY_NAME,
+                "data",
+                "days-off",
+                "-r",
+                "2025-07-01..2025-07-18",
+                "--range",
+                "2025-07-25",
+            ]);
+            assert_eq!(
+                input.command.unwrap_data().command,
+                DataAdminInputCommands::DaysOff(DaysOffInput {
+                    ranges: vec![
+                        DateRange::from_str("2025-07-01..2025-07-18").unwrap(),
+                        DateRange::from_str("2025-07-25").unwrap()
+                    ],
+                    remove: false
+                })
+            );
+        }
+
+        #[test]
+        fn test_data_admin_days_off_requires_range() {
+            let result = CliArgs::try_parse_from([BINARY_NAME, "data", "days-off", "--remove"]);
+            assert!(result.is_err());
+        }
+
         #[test]
         fn test_data_admin_expense() {
             let item_1_str = "Coffee,2.5,EUR,3.0,2025-05-31";
@@ -260,11 +1097,413 @@ mod tests {
             assert_eq!(
                 *input.command.unwrap_data().command(),
                 DataAdminInputCommands::Expenses(ExpensesInput {
-                    month: YearAndMonth::from_str("2025-05").unwrap(),
+                    command: None,
+                    month: Some(YearAndMonth::from_str("2025-05").unwrap()),
                     expenses: vec![item_1, item_2]
                 })
             );
         }
+
+        #[test]
+        fn test_data_admin_expenses_requires_month_without_subcommand() {
+            let result = CliArgs::try_parse_from([BINARY_NAME, "data", "expenses"]);
+            assert!(result.is_err());
+        }
+
+        #[test]
+        fn test_data_admin_expenses_list_all() {
+            let input = CliArgs::parse_from([BINARY_NAME, "data", "expenses", "list"]);
+            assert_eq!(
+                *input.command.unwrap_data().command(),
+                DataAdminInputCommands::Expenses(ExpensesInput {
+                    command: Some(ExpensesInputCommands::List(ExpensesListInput {
+                        month: None
+                    })),
+                    month: None,
+                    expenses: vec![]
+                })
+            );
+        }
+
+        #[test]
+        fn test_data_admin_expenses_remove() {
+            let input = CliArgs::parse_from([
+                BINARY_NAME,
+                "data",
+                "expenses",
+                "remove",
+                "--month",
+                "2025-05",
+                "--index",
+                "1",
+            ]);
+            let DataAdminInputCommands::Expenses(expenses) = input.command.unwrap_data().command
+            else {
+                panic!("Expected expenses command");
+            };
+            assert_eq!(
+                expenses.command,
+                Some(ExpensesInputCommands::Remove(ExpenseAtIndexInput {
+                    month: YearAndMonth::from_str("2025-05").unwrap(),
+                    index: 1
+                }))
+            );
+        }
+
+        #[test]
+        fn test_data_admin_expenses_move() {
+            let input = CliArgs::parse_from([
+                BINARY_NAME,
+                "data",
+                "expenses",
+                "move",
+                "--from",
+                "2025-05",
+                "--to",
+                "2025-06",
+            ]);
+            let DataAdminInputCommands::Expenses(expenses) = input.command.unwrap_data().command
+            else {
+                panic!("Expected expenses command");
+            };
+            assert_eq!(
+                expenses.command,
+                Some(ExpensesInputCommands::Move(ExpensesMoveInput {
+                    from: YearAndMonth::from_str("2025-05").unwrap(),
+                    to: YearAndMonth::from_str("2025-06").unwrap(),
+                }))
+            );
+        }
+
+        #[test]
+        fn test_expenses_attach() {
+            let input = CliArgs::parse_from([
+                BINARY_NAME,
+                "data",
+                "expenses",
+                "attach",
+                "--month",
+                "2025-05",
+                "--index",
+                "1",
+                "--receipt",
+                "coffee.pdf",
+            ]);
+            let DataAdminInputCommands::Expenses(expenses) = input.command.unwrap_data().command
+            else {
+                panic!("Expected expenses command");
+            };
+            assert_eq!(
+                expenses.command,
+                Some(ExpensesInputCommands::Attach(ExpenseReceiptInput {
+                    month: YearAndMonth::from_str("2025-05").unwrap(),
+                    index: 1,
+                    receipt: PathBuf::from("coffee.pdf"),
+                }))
+            );
+        }
+
+        #[test]
+        fn test_data_migrate_dry_run() {
+            let input = CliArgs::parse_from([BINARY_NAME, "data", "migrate", "--dry-run"]);
+            let migrate = input.command.unwrap_data().command.unwrap_migrate();
+            assert!(*migrate.dry_run());
+        }
+
+        #[test]
+        fn test_data_encrypt_decrypt() {
+            let input = CliArgs::parse_from([BINARY_NAME, "data", "encrypt"]);
+            assert_eq!(
+                input.command.unwrap_data().command,
+                DataAdminInputCommands::Encrypt
+            );
+            let input = CliArgs::parse_from([BINARY_NAME, "data", "decrypt"]);
+            assert_eq!(
+                input.command.unwrap_data().command,
+                DataAdminInputCommands::Decrypt
+            );
+        }
+
+        #[test]
+        fn test_data_history() {
+            let input = CliArgs::parse_from([BINARY_NAME, "data", "history"]);
+            assert_eq!(
+                input.command.unwrap_data().command,
+                DataAdminInputCommands::History
+            );
+        }
+
+        #[test]
+        fn test_data_diff() {
+            let input = CliArgs::parse_from([BINARY_NAME, "data", "diff", "3"]);
+            let diff = input.command.unwrap_data().command.unwrap_diff();
+            assert_eq!(*diff.revision(), 3);
+        }
+
+        #[test]
+        fn test_data_undo() {
+            let input = CliArgs::parse_from([BINARY_NAME, "data", "undo"]);
+            let undo = input.command.unwrap_data().command.unwrap_undo();
+            assert_eq!(*undo.to(), None);
+
+            let input = CliArgs::parse_from([BINARY_NAME, "data", "undo", "--to", "2"]);
+            let undo = input.command.unwrap_data().command.unwrap_undo();
+            assert_eq!(*undo.to(), Some(2));
+        }
+
+        fn parse_recurring(args: &[&str]) -> RecurringExpensesInput {
+            let input = CliArgs::try_parse_from(
+                [BINARY_NAME, "data", "expenses", "recurring"]
+                    .into_iter()
+                    .chain(args.iter().copied()),
+            )
+            .unwrap();
+            let DataAdminInputCommands::Expenses(expenses) = input.command.unwrap_data().command
+            else {
+                panic!("Expected expenses command");
+            };
+            expenses.command.unwrap().unwrap_recurring()
+        }
+
+        #[test]
+        fn test_data_expenses_recurring_add() {
+            let recurring = parse_recurring(&["--add", "Phone subscription,25,EUR,1,30,2025-01"]);
+            assert_eq!(recurring.add(), &vec![RecurringExpense::sample()]);
+        }
+
+        #[test]
+        fn test_data_expenses_recurring_skip() {
+            let recurring =
+                parse_recurring(&["--skip", "Phone subscription", "--month", "2025-08"]);
+            assert_eq!(recurring.skip().as_deref(), Some("Phone subscription"));
+            assert_eq!(*recurring.month(), Some(YearAndMonth::august(2025)));
+        }
+
+        #[test]
+        fn test_data_expenses_recurring_requires_an_action() {
+            let no_action = CliArgs::try_parse_from([BINARY_NAME, "data", "expenses", "recurring"]);
+            assert!(no_action.is_err());
+            let skip_without_month = CliArgs::try_parse_from([
+                BINARY_NAME,
+                "data",
+                "expenses",
+                "recurring",
+                "--skip",
+                "Phone subscription",
+            ]);
+            assert!(skip_without_month.is_err());
+        }
+
+        fn parse_import(args: &[&str]) -> ExpensesImportInput {
+            let input = CliArgs::try_parse_from(
+                [BINARY_NAME, "data", "expenses", "import"]
+                    .into_iter()
+                    .chain(args.iter().copied()),
+            )
+            .unwrap();
+            let DataAdminInputCommands::Expenses(expenses) = input.command.unwrap_data().command
+            else {
+                panic!("Expected expenses command");
+            };
+            *expenses.command.unwrap().unwrap_import()
+        }
+
+        #[test]
+        fn test_expenses_import_csv() {
+            let input = parse_import(&[
+                "--month",
+                "2025-05",
+                "--file",
+                "card_export.csv",
+                "--amount-column",
+                "Belopp",
+                "--currency",
+                "SEK",
+                "--decimal-separator",
+                ",",
+                "--delimiter",
+                ";",
+                "--preview",
+            ]);
+            assert!(*input.preview());
+            assert_eq!(
+                input.source(),
+                ExpenseSource::Csv {
+                    path: PathBuf::from("card_export.csv"),
+                    format: ExpenseCsvFormat::builder()
+                        .amount_column("Belopp")
+                        .currency(Currency::SEK)
+                        .decimal_separator(',')
+                        .delimiter(';')
+                        .build(),
+                }
+            );
+        }
+
+        #[test]
+        fn test_expenses_import_folder() {
+            let input = parse_import(&["--month", "2025-05", "--folder", "receipts"]);
+            assert!(!*input.preview());
+            assert_eq!(
+                input.source(),
+                ExpenseSource::ReceiptFolder {
+                    path: PathBuf::from("receipts")
+                }
+            );
+        }
+
+        #[test]
+        fn test_expenses_import_requires_file_or_folder() {
+            let result = CliArgs::try_parse_from([
+                BINARY_NAME,
+                "data",
+                "expenses",
+                "import",
+                "--month",
+                "2025-05",
+            ]);
+            assert!(result.is_err());
+        }
+
+        #[test]
+        fn test_expenses_import_file_conflicts_with_folder() {
+            let result = CliArgs::try_parse_from([
+                BINARY_NAME,
+                "data",
+                "expenses",
+                "import",
+                "--month",
+                "2025-05",
+                "--file",
+                "a.csv",
+                "--folder",
+                "receipts",
+            ]);
+            assert!(result.is_err());
+        }
+    }
+
+    mod payment_input {
+        use super::*;
+        use test_log::test;
+
+        #[test]
+        fn test_payment_record() {
+            let input = CliArgs::parse_from([
+                BINARY_NAME,
+                "payment",
+                "record",
+                "--invoice",
+                "243",
+                "--amount",
+                "9324",
+                "--date",
+                "2025-06-28",
+            ]);
+            let command = input.command.unwrap_payment().command;
+            let payment = command.unwrap_record().payment();
+            assert_eq!(*payment.invoice(), InvoiceNumber::from(243));
+            assert_eq!(*payment.amount(), Cost::from(9324));
+            assert_eq!(*payment.date(), Date::from_str("2025-06-28").unwrap());
+        }
+
+        #[test]
+        fn test_payment_import() {
+            let input = CliArgs::parse_from([
+                BINARY_NAME,
+                "payment",
+                "import",
+                "statement.xml",
+                "--format",
+                "camt053",
+            ]);
+            let command = input.command.unwrap_payment().command;
+            let import = command.unwrap_import();
+            assert_eq!(*import.statement(), PathBuf::from("statement.xml"));
+            assert_eq!(
+                import.format().map(StatementFormat::from),
+                Some(StatementFormat::Camt053)
+            );
+        }
+
+        #[test]
+        fn test_report_receivables() {
+            let input = CliArgs::parse_from([BINARY_NAME, "report", "receivables"]);
+            assert_eq!(
+                input.command.unwrap_report().command,
+                Some(ReportInputCommands::Receivables)
+            );
+        }
+
+        #[test]
+        fn test_export_sie() {
+            let input = CliArgs::parse_from([BINARY_NAME, "export", "sie", "--year", "2025"]);
+            let sie = input.command.unwrap_export().command.unwrap_sie();
+            assert_eq!(sie.year, 2025);
+            assert_eq!(sie.out_path(), PathBuf::from("klirr_2025.se"));
+        }
+
+        #[test]
+        fn test_export_sie_out() {
+            let input = CliArgs::parse_from([
+                BINARY_NAME,
+                "export",
+                "sie",
+                "-y",
+                "2025",
+                "-o",
+                "/tmp/bookkeeping.se",
+            ]);
+            let sie = input.command.unwrap_export().command.unwrap_sie();
+            assert_eq!(sie.out_path(), PathBuf::from("/tmp/bookkeeping.se"));
+        }
+
+        #[test]
+        fn test_report_revenue_quarter() {
+            let input = CliArgs::parse_from([
+                BINARY_NAME,
+                "report",
+                "--year",
+                "2025",
+                "--quarter",
+                "2",
+                "--format",
+                "csv",
+            ]);
+            let report = input.command.unwrap_report();
+            assert_eq!(report.command, None);
+            assert_eq!(report.format, ReportFormatInput::Csv);
+            assert_eq!(
+                report.period().unwrap(),
+                Some(
+                    ReportPeriod::builder()
+                        .year(Year::from(2025))
+                        .quarter(Quarter::Q2)
+                        .build()
+                )
+            );
+        }
+
+        #[test]
+        fn test_report_revenue_year() {
+            let input = CliArgs::parse_from([BINARY_NAME, "report", "-y", "2025"]);
+            let report = input.command.unwrap_report();
+            assert_eq!(report.format, ReportFormatInput::Table);
+            assert_eq!(report.period().unwrap().unwrap().quarter(), &None);
+        }
+
+        #[test]
+        fn test_report_revenue_invalid_quarter() {
+            let result =
+                CliArgs::try_parse_from([BINARY_NAME, "report", "--year", "2025", "-q", "5"]);
+            assert!(result.is_err());
+        }
+
+        #[test]
+        fn test_report_requires_year_or_subcommand() {
+            let result = CliArgs::try_parse_from([BINARY_NAME, "report"]);
+            assert!(result.is_err());
+        }
     }
 
     mod invoice_input {
@@ -310,6 +1549,306 @@ mod tests {
                 );
             }
 
+            #[test]
+            fn test_input_parsing_items_specified_reminder() {
+                let input = CliArgs::parse_from([
+                    BINARY_NAME,
+                    "invoice",
+                    "reminder",
+                    "243",
+                    "--reference-rate",
+                    "2.25",
+                    "--fee",
+                    "60",
+                    "--date",
+                    "2025-07-30",
+                ]);
+                let Some(TargetItems::Reminder(reminder)) = input.command.unwrap_invoice().items
+                else {
+                    panic!("Expected reminder items");
+                };
+                assert_eq!(*reminder.number(), 243);
+                assert_eq!(*reminder.margin(), Percentage::from(8u8));
+                assert_eq!(
+                    reminder.policy().interest_rate(),
+                    Percentage::from_str("10.25").unwrap()
+                );
+                assert_eq!(*reminder.policy().reminder_fee(), Some(Cost::from(60u8)));
+            }
+
+            #[test]
+            fn test_input_parsing_items_specified_batch() {
+                let input = CliArgs::parse_from([
+                    BINARY_NAME,
+                    "invoice",
+                    "batch",
+                    "--from",
+                    "2025-01",
+                    "--to",
+                    "2025-06",
+                    "--kinds",
+                    "services",
+                ]);
+                let batch = input
+                    .command
+                    .unwrap_invoice()
+                    .parsed_batch()
+                    .unwrap()
+                    .unwrap();
+                assert_eq!(*batch.from(), YearAndMonth::january(2025));
+                assert_eq!(*batch.to(), YearAndMonth::june(2025));
+                assert_eq!(batch.kinds(), &vec![InvoiceKind::Services]);
+                assert_eq!(batch.output_directory(), &None);
+            }
+
+            #[test]
+            fn test_input_parsing_batch_with_quantity_and_discount() {
+                let input = CliArgs::parse_from([
+                    BINARY_NAME,
+                    "invoice",
+                    "--quantity",
+                    "On-call stipend=2",
+                    "--discount",
+                    "10%",
+                    "batch",
+                    "--from",
+                    "2025-01",
+                    "--to",
+                    "2025-06",
+                ]);
+                let batch = input
+                    .command
+                    .unwrap_invoice()
+                    .parsed_batch()
+                    .unwrap()
+                    .unwrap();
+                assert_eq!(
+                    batch.service_quantities(),
+                    &vec![ServiceQuantityOverride::sample()]
+                );
+                assert_eq!(batch.adjustments().len(), 1);
+            }
+
+            #[test]
+            fn test_input_parsing_items_batch_default_kinds() {
+                let input = CliArgs::parse_from([
+                    BINARY_NAME,
+                    "invoice",
+                    "batch",
+                    "--from",
+                    "2025-01",
+                    "--to",
+                    "2025-06",
+                ]);
+                let batch = input
+                    .command
+                    .unwrap_invoice()
+                    .parsed_batch()
+                    .unwrap()
+                    .unwrap();
+                assert_eq!(
+                    batch.kinds(),
+                    &vec![InvoiceKind::Services, InvoiceKind::Expenses]
+                );
+            }
+
+            #[test]
+            fn test_input_parsing_items_batch_out_must_be_dir() {
+                let input = CliArgs::parse_from([
+                    BINARY_NAME,
+                    "invoice",
+                    "--out",
+                    "/this/does/not/exist",
+                    "batch",
+                    "--from",
+                    "2025-01",
+                    "--to",
+                    "2025-06",
+                ]);
+                assert!(input.command.unwrap_invoice().parsed_batch().is_err());
+            }
+
+            #[test]
+            fn test_input_parsing_items_batch_rejects_preview_and_email() {
+                for flags in [&["--format", "png"][..], &["--email-draft"]] {
+                    let input = CliArgs::parse_from(
+                        [BINARY_NAME, "invoice"]
+                            .into_iter()
+                            .chain(flags.iter().copied())
+                            .chain(["batch", "--from", "2025-01", "--to", "2025-06"]),
+                    );
+                    assert!(
+                        input.command.unwrap_invoice().parsed_batch().is_err(),
+                        "{:?}",
+                        flags
+                    );
+                }
+            }
+
+            #[test]
+            fn test_input_parsing_email_draft() {
+                let input = CliArgs::parse_from([BINARY_NAME, "invoice", "--email-draft"]);
+                assert_eq!(
+                    input.command.unwrap_invoice().email_delivery().unwrap(),
+                    Some(EmailDelivery::Draft)
+                );
+            }
+
+            #[test]
+            fn test_input_parsing_send_email_expenses() {
+                let input =
+                    CliArgs::parse_from([BINARY_NAME, "invoice", "--send-email", "expenses"]);
+                assert_eq!(
+                    input.command.unwrap_invoice().email_delivery().unwrap(),
+                    Some(EmailDelivery::Send)
+                );
+            }
+
+            #[test]
+            fn test_input_parsing_email_draft_conflicts_with_dry_run() {
+                let result =
+                    CliArgs::try_parse_from([BINARY_NAME, "invoice", "--email-draft", "--dry-run"]);
+                assert!(result.is_err());
+            }
+
+            #[test]
+            fn test_input_parsing_dry_run_defaults_to_table() {
+                let input = CliArgs::parse_from([BINARY_NAME, "invoice", "--dry-run"]);
+                assert_eq!(
+                    input.command.unwrap_invoice().dry_run_format().unwrap(),
+                    Some(DryRunFormat::Table)
+                );
+            }
+
+            #[test]
+            fn test_input_parsing_dry_run_json_expenses() {
+                let input =
+                    CliArgs::parse_from([BINARY_NAME, "invoice", "--dry-run", "json", "expenses"]);
+                let invoice = input.command.unwrap_invoice();
+                assert_eq!(invoice.dry_run_format().unwrap(), Some(DryRunFormat::Json));
+                assert_eq!(*invoice.parsed().unwrap().items(), InvoicedItems::Expenses);
+            }
+
+            #[test]
+            fn test_input_parsing_combined() {
+                let input = CliArgs::parse_from([BINARY_NAME, "invoice", "combined", "--ooo", "2"]);
+                let invoice = input.command.unwrap_invoice();
+                assert_eq!(
+                    *invoice.parsed().unwrap().items(),
+                    InvoicedItems::Combined {
+                        days_off: Some(Day::try_from(2).unwrap())
+                    }
+                );
+            }
+
+            #[test]
+            fn test_input_parsing_service_quantities() {
+                let input = CliArgs::parse_from([
+                    BINARY_NAME,
+                    "invoice",
+                    "--quantity",
+                    "On-call stipend=2",
+                    "-q",
+                    "Support hours=7.5",
+                ]);
+                let invoice = input.command.unwrap_invoice();
+                let parsed = invoice.parsed().unwrap();
+                assert_eq!(
+                    parsed.service_quantities(),
+                    &vec![
+                        ServiceQuantityOverride::sample(),
+                        "Support hours=7.5".parse().unwrap(),
+                    ]
+                );
+            }
+
+            #[test]
+            fn test_input_parsing_adjustments() {
+                let input = CliArgs::parse_from([
+                    BINARY_NAME,
+                    "invoice",
+                    "--discount",
+                    "10%",
+                    "--credit",
+                    "500",
+                ]);
+                let parsed = input.command.unwrap_invoice().parsed().unwrap();
+                assert_eq!(
+                    parsed.adjustments(),
+                    &vec![
+                        Adjustment::of_subtotal(AdjustmentKind::Discount, "10%".parse().unwrap()),
+                        Adjustment::of_subtotal(AdjustmentKind::Credit, "500".parse().unwrap()),
+                    ]
+                );
+            }
+
+            #[test]
+            fn test_input_parsing_no_dry_run() {
+                let input = CliArgs::parse_from([BINARY_NAME, "invoice"]);
+                assert_eq!(
+                    input.command.unwrap_invoice().dry_run_format().unwrap(),
+                    None
+                );
+            }
+
+            #[test]
+            fn test_input_parsing_format_png() {
+                let input = CliArgs::parse_from([BINARY_NAME, "invoice", "--format", "png"]);
+                assert_eq!(
+                    input.command.unwrap_invoice().preview_format().unwrap(),
+                    Some(PreviewFormat::Png)
+                );
+            }
+
+            #[test]
+            fn test_input_parsing_format_default_pdf() {
+                let input = CliArgs::parse_from([BINARY_NAME, "invoice", "expenses"]);
+                assert_eq!(
+                    input.command.unwrap_invoice().preview_format().unwrap(),
+                    None
+                );
+            }
+
+            #[test]
+            fn test_input_parsing_format_svg_reminder_unsupported() {
+                let input = CliArgs::parse_from([
+                    BINARY_NAME,
+                    "invoice",
+                    "-f",
+                    "svg",
+                    "reminder",
+                    "243",
+                    "--reference-rate",
+                    "2.25",
+                ]);
+                assert!(input.command.unwrap_invoice().preview_format().is_err());
+            }
+
+            #[test]
+            fn test_input_parsing_reminder_rejects_quantity_and_adjustments() {
+                for flags in [
+                    &["--quantity", "On-call stipend=2"][..],
+                    &["--discount", "10%"],
+                    &["--surcharge", "100"],
+                    &["--credit", "50"],
+                ] {
+                    let input = CliArgs::parse_from(
+                        [BINARY_NAME, "invoice"]
+                            .into_iter()
+                            .chain(flags.iter().copied())
+                            .chain(["reminder", "243", "--reference-rate", "2.25"]),
+                    );
+                    assert!(
+                        matches!(
+                            input.command.unwrap_invoice().parsed_reminder(),
+                            Err(Error::QuantitiesAndAdjustmentsNotSupported { .. })
+                        ),
+                        "{:?}",
+                        flags
+                    );
+                }
+            }
+
             #[test]
             fn test_input_parsing_items_default() {
                 let input = CliArgs::parse_from([BINARY_NAME, "invoice"]);
@@ -368,6 +1907,35 @@ mod tests {
                 );
             }
 
+            #[test]
+            fn test_parsed_reminder_none_for_invoice() {
+                let input = InvoiceInput::builder().items(TargetItems::Expenses).build();
+                assert!(input.parsed_reminder().unwrap().is_none());
+            }
+
+            #[test]
+            fn test_parsed_reminder() {
+                let input = InvoiceInput::parse_from([
+                    "invoice",
+                    "--language",
+                    "swedish",
+                    "reminder",
+                    "243",
+                    "-r",
+                    "2.25%",
+                    "--date",
+                    "2025-07-30",
+                ]);
+                let reminder = input.parsed_reminder().unwrap().unwrap();
+                assert_eq!(*reminder.number(), InvoiceNumber::from(243));
+                assert_eq!(*reminder.language(), Language::SV);
+                assert_eq!(
+                    *reminder.reminder_date(),
+                    Date::from_str("2025-07-30").unwrap()
+                );
+                assert!(reminder.policy().reminder_fee().is_none());
+            }
+
             #[test]
             #[should_panic]
             fn test_input_parsing_out_at_root_crashes() {
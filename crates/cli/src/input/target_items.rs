@@ -1,3 +1,4 @@
+use clap::{Args, ValueEnum};
 use derive_more::IsVariant;
 
 use crate::prelude::*;
@@ -12,9 +13,107 @@ pub enum TargetItems {
     /// Expenses mode, specify expenses in `input/data/expenses.json` for the
     /// target month.
     Expenses,
+    /// Services and expenses of the target month on a single invoice, in
+    /// separate sections with subtotals, optionally marking yourself out of
+    /// office for N days.
+    Combined {
+        /// Number of days out of office
+        #[arg(long, default_value_t = 0)]
+        ooo: u8,
+    },
+    /// Reminder of an overdue, previously issued, invoice, with late interest
+    /// and an optional reminder fee.
+    Reminder(ReminderInput),
+    /// Invoices for a range of months, skipping months off and months
+    /// without expenses, e.g. after parental leave.
+    Batch(BatchInput),
 }
 impl Default for TargetItems {
     fn default() -> Self {
         TargetItems::Ooo { days: 0 }
     }
 }
+
+/// The CLI arguments for rendering a reminder of an overdue invoice.
+#[derive(Debug, Clone, PartialEq, Args, Getters)]
+pub struct ReminderInput {
+    /// The number of the overdue invoice, e.g. `243`.
+    #[getset(get = "pub")]
+    number: u16,
+
+    /// The kind of the overdue invoice, needed only if several invoices
+    /// have the same number.
+    #[arg(long, value_enum)]
+    #[getset(get = "pub")]
+    kind: Option<InvoiceKindInput>,
+
+    /// The reference rate in percent, e.g. `2.25` for the Swedish reference
+    /// rate set by Riksbanken.
+    #[arg(long, short = 'r')]
+    #[getset(get = "pub")]
+    reference_rate: Percentage,
+
+    /// The margin in percent added on top of the reference rate.
+    #[arg(long, default_value = "8")]
+    #[getset(get = "pub")]
+    margin: Percentage,
+
+    /// An optional fixed reminder fee, in the currency of the invoice.
+    #[arg(long, short = 'f')]
+    #[getset(get = "pub")]
+    fee: Option<Decimal>,
+
+    /// The date of the reminder, up to which interest is calculated,
+    /// defaults to today, e.g. `2025-07-30`.
+    #[arg(long, short = 'd')]
+    #[getset(get = "pub")]
+    date: Option<Date>,
+}
+
+impl ReminderInput {
+    /// The late-payment interest policy specified by these arguments.
+    pub fn policy(&self) -> LateInterestPolicy {
+        LateInterestPolicy::builder()
+            .reference_rate(self.reference_rate)
+            .margin(self.margin)
+            .reminder_fee(self.fee.map(Cost::from))
+            .build()
+    }
+}
+
+/// The CLI arguments for generating invoices for a range of months.
+#[derive(Debug, Clone, PartialEq, Args, Getters)]
+pub struct BatchInput {
+    /// The first month to invoice, e.g. `2025-01`.
+    #[arg(long)]
+    #[getset(get = "pub")]
+    from: YearAndMonth,
+
+    /// The last month to invoice, inclusive, e.g. `2025-06`.
+    #[arg(long)]
+    #[getset(get = "pub")]
+    to: YearAndMonth,
+
+    /// The kinds of invoices to generate for each month, comma separated.
+    #[arg(long, short = 'k', value_enum, value_delimiter = ',', default_values_t = [InvoiceKindInput::Services, InvoiceKindInput::Expenses])]
+    #[getset(get = "pub")]
+    kinds: Vec<InvoiceKindInput>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+#[clap(rename_all = "kebab_case")]
+pub enum InvoiceKindInput {
+    Services,
+    Expenses,
+    Combined,
+}
+
+impl From<InvoiceKindInput> for InvoiceKind {
+    fn from(kind: InvoiceKindInput) -> Self {
+        match kind {
+            InvoiceKindInput::Services => InvoiceKind::Services,
+            InvoiceKindInput::Expenses => InvoiceKind::Expenses,
+            InvoiceKindInput::Combined => InvoiceKind::Combined,
+        }
+    }
+}
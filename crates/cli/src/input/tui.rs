@@ -1,9 +1,10 @@
 use crate::prelude::*;
 use inquire::{
-    CustomType, DateSelect, Text,
-    error::InquireResult,
+    Confirm, CustomType, DateSelect, Password, PasswordDisplayMode, Select, Text,
+    error::{InquireError, InquireResult},
     set_global_render_config,
     ui::{RenderConfig, StyleSheet},
+    validator::Validation,
 };
 
 const HOW_TO_SKIP_INSTRUCTION: &str = "Skip with ESC";
@@ -52,6 +53,25 @@ fn build_postal_address(
     Ok(address)
 }
 
+/// Prompts with `prompt` for a value parsed by `parse`, showing why the
+/// input is invalid, e.g. a wrong check digit, right away so that it can be
+/// corrected.
+fn prompt_parsed<T>(
+    prompt: Text<'_>,
+    parse: impl Fn(&str) -> Result<T> + Clone + 'static,
+) -> InquireResult<T> {
+    let validate = parse.clone();
+    let input = prompt
+        .with_validator(move |input: &str| {
+            Ok(match validate(input) {
+                Ok(_) => Validation::Valid,
+                Err(e) => Validation::Invalid(e.to_string().into()),
+            })
+        })
+        .prompt()?;
+    parse(&input).map_err(|e| InquireError::Custom(Box::new(e)))
+}
+
 trait WithOptionalDefault<'o, T> {
     fn with_optional_default(self, default: &'o Option<T>) -> Self;
 }
@@ -82,13 +102,25 @@ fn build_company(
             .with_default(default.company_name())
             .prompt()?;
 
-        let org_no = Text::new(&text("organisation number"))
-            .with_default(default.organisation_number())
-            .prompt()?;
+        let postal_address = build_postal_address(&owner, default.postal_address())?;
 
-        let vat = Text::new(&text("VAT number"))
-            .with_default(default.vat_number())
-            .prompt()?;
+        let country = postal_address.country().clone();
+        let org_no = prompt_parsed(
+            Text::new(&text("organisation number"))
+                .with_default(&default.organisation_number().to_string()),
+            move |input| {
+                let org_no = OrganisationNumber::from_str(input)?;
+                org_no.validate_in(&country)?;
+                Ok(org_no)
+            },
+        )?;
+
+        let vat = prompt_parsed(
+            Text::new(&text("VAT number"))
+                .with_default(&default.vat_number().to_string())
+                .with_help_message("Prefixed with the country code, e.g. 'FR81185293012'"),
+            VatNumber::from_str,
+        )?;
 
         let contact_person = Text::new(&text("contact person"))
             .with_optional_default(default.contact_person())
@@ -101,7 +133,26 @@ fn build_company(
             ))
             .prompt_skippable()?;
 
-        let postal_address = build_postal_address(&owner, default.postal_address())?;
+        let billing_emails = Text::new(&text("billing email addresses"))
+            .with_default(
+                &default
+                    .billing_emails()
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            )
+            .with_help_message(&format_help_skippable(
+                "Comma separated, used for emailing invoices".to_owned(),
+            ))
+            .prompt_skippable()?
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|email| !email.is_empty())
+            .map(EmailAddress::from_str)
+            .collect::<Result<Vec<_>>>()
+            .map_err(|e| InquireError::Custom(Box::new(e)))?;
 
         let company_info = default
             .clone()
@@ -109,7 +160,8 @@ fn build_company(
             .with_contact_person(contact_person)
             .with_organisation_number(org_no)
             .with_postal_address(postal_address)
-            .with_vat_number(vat);
+            .with_vat_number(vat)
+            .with_billing_emails(billing_emails);
 
         Ok(company_info)
     }
@@ -189,7 +241,7 @@ fn build_invoice_info(default: &ProtoInvoiceInfo) -> Result<ProtoInvoiceInfo> {
         .with_help_message(&format_help_skippable(
             "Used with the date of that invoice to calculate future invoice numbers.".to_owned(),
         ))
-        .with_default(default.offset().offset().clone())
+        .with_default(*default.offset().offset())
         .prompt_skippable()?
         .unwrap_or_default();
 
@@ -227,12 +279,65 @@ fn build_invoice_info(default: &ProtoInvoiceInfo) -> Result<ProtoInvoiceInfo> {
             ))
             .prompt_skippable()?;
 
+        let vat_rate = CustomType::<Percentage>::new("VAT rate (optional)")
+            .with_help_message(&format_help_skippable(
+                "Added on top of the net total of the invoice, e.g. '25', use '0' for no VAT"
+                    .to_owned(),
+            ))
+            .with_default(*default.vat_rate())
+            .prompt_skippable()?
+            .unwrap_or_default();
+
+        let numbering_format = CustomType::<InvoiceNumberFormat>::new("Invoice number format")
+            .with_help_message(&format_help_skippable(
+                "E.g. '{year}-{number:03}', using '{number}', '{year}', '{month}' and '{prefix}'"
+                    .to_owned(),
+            ))
+            .with_default(default.numbering().format().clone())
+            .prompt_skippable()?
+            .unwrap_or_default();
+
+        let sequential = Confirm::new("Number invoices sequentially?")
+            .with_help_message(
+                "Instead of deriving numbers from the month, count every issued invoice",
+            )
+            .with_default(default.numbering().counter().is_ledger_sequential())
+            .prompt_skippable()?
+            .unwrap_or_default();
+
+        let reset_yearly = Confirm::new("Restart invoice numbers every year?")
+            .with_default(*default.numbering().reset_yearly())
+            .prompt_skippable()?
+            .unwrap_or_default();
+
+        let numbering_prefix = Text::new("Invoice number prefix (optional)")
+            .with_help_message(&format_help_skippable(
+                "Used by '{prefix}' in the invoice number format, e.g. 'ACME-'".to_owned(),
+            ))
+            .with_default(default.numbering().prefix().as_deref().unwrap_or_default())
+            .prompt_skippable()?
+            .filter(|prefix| !prefix.is_empty());
+
+        let numbering = InvoiceNumbering::builder()
+            .format(numbering_format)
+            .counter(if sequential {
+                InvoiceNumberCounter::LedgerSequential
+            } else {
+                InvoiceNumberCounter::MonthlyDerived
+            })
+            .reset_yearly(reset_yearly)
+            .prefix(numbering_prefix)
+            .build();
+
         let info = ProtoInvoiceInfo::builder()
             .offset(offset)
             .purchase_order(purchase_order)
             .footer_text(footer_text)
             .emphasize_color_hex(emphasize_color_hex)
+            .vat_rate(vat_rate)
             .months_off_record(default.months_off_record().clone())
+            .numbering(numbering)
+            .days_off_record(default.days_off_record().clone())
             .build();
 
         Ok(info)
@@ -248,13 +353,15 @@ fn build_payment_info(default: &PaymentInformation) -> Result<PaymentInformation
         let bank_name = Text::new(&text("Bank Name"))
             .with_default(default.bank_name())
             .prompt()?;
-        let iban = Text::new(&text("IBAN"))
-            .with_default(default.iban())
-            .prompt()?;
+        let iban = prompt_parsed(
+            Text::new(&text("IBAN")).with_default(&default.iban().to_string()),
+            Iban::from_str,
+        )?;
 
-        let bic = Text::new(&text("BIC"))
-            .with_default(default.bic())
-            .prompt()?;
+        let bic = prompt_parsed(
+            Text::new(&text("BIC")).with_default(&default.bic().to_string()),
+            Bic::from_str,
+        )?;
 
         let currency = CustomType::<Currency>::new("Currency?")
             .with_help_message("The currency you want to use for the invoice, e.g. 'EUR'")
@@ -281,27 +388,148 @@ fn build_payment_info(default: &PaymentInformation) -> Result<PaymentInformation
     })
 }
 
+fn build_service_fee(default: &ServiceFee) -> InquireResult<ServiceFee> {
+    let text = |part: &str| format!("Service {part}?");
+    let name = Text::new(&text("Name"))
+        .with_default(default.name())
+        .prompt()?;
+
+    let unit_price = CustomType::<UnitPrice>::new("Unit price?")
+        .with_help_message("The price per unit, e.g. '1000' per day")
+        .with_default(*default.unit_price())
+        .prompt()?;
+
+    let fixed = match default.quantity() {
+        ServiceQuantity::Fixed(quantity) => *quantity,
+        _ => Quantity::ONE,
+    };
+    let rules = vec![
+        ServiceQuantity::WorkedDays,
+        ServiceQuantity::Fixed(fixed),
+        ServiceQuantity::PerRun,
+    ];
+    let cursor = rules
+        .iter()
+        .position(|rule| rule == default.quantity())
+        .unwrap_or_default();
+    let quantity = match Select::new(&text("Quantity"), rules)
+        .with_help_message("Worked days of the month, the same every month, or given per invoice")
+        .with_starting_cursor(cursor)
+        .prompt()?
+    {
+        ServiceQuantity::Fixed(_) => ServiceQuantity::Fixed(
+            CustomType::<Quantity>::new("Fixed quantity?")
+                .with_help_message("The quantity every month, e.g. '1'")
+                .with_default(fixed)
+                .prompt()?,
+        ),
+        rule => rule,
+    };
+
+    Ok(default
+        .clone()
+        .with_name(name)
+        .with_unit_price(unit_price)
+        .with_quantity(quantity))
+}
+
 fn build_service_fees(default: &ServiceFees) -> Result<ServiceFees> {
-    fn inner(default: &ServiceFees) -> InquireResult<ServiceFees> {
-        let text = |part: &str| format!("Service {part}?");
-        let name = Text::new(&text("Name"))
+    fn inner(default: &ServiceFees) -> InquireResult<Vec<ServiceFee>> {
+        let mut fees = Vec::new();
+        for (index, fee) in default.iter().enumerate() {
+            // The first service is always kept, there must be at least one.
+            let keep = index == 0
+                || Confirm::new(&format!("Keep service '{}'?", fee.name()))
+                    .with_default(true)
+                    .prompt()?;
+            if keep {
+                fees.push(build_service_fee(fee)?);
+            }
+        }
+        while Confirm::new("Add another service?")
+            .with_default(false)
+            .prompt()?
+        {
+            fees.push(build_service_fee(&ServiceFee::sample())?);
+        }
+        Ok(fees)
+    }
+    let fees = inner(default).map_err(|e| Error::InvalidServiceFees {
+        reason: format!("{:?}", e),
+    })?;
+    ServiceFees::new(fees)
+}
+
+/// Prompts for each field of an expense, prefilled with `default`.
+pub fn ask_for_expense(default: Item) -> Result<Item> {
+    fn inner(default: &Item) -> InquireResult<Item> {
+        let text = |part: &str| format!("Expense {part}?");
+        let name = Text::new(&text("name"))
             .with_default(default.name())
             .prompt()?;
 
-        let unit_price = CustomType::<UnitPrice>::new("Unit price?")
-            .with_help_message("The price per day, e.g. '1000'")
+        let unit_price = CustomType::<UnitPrice>::new(&text("unit price"))
+            .with_help_message("The price per item, e.g. '2.5'")
             .with_default(*default.unit_price())
             .prompt()?;
 
-        let service_fees = default.clone().with_name(name).with_unit_price(unit_price);
+        let currency = CustomType::<Currency>::new(&text("currency"))
+            .with_help_message("The currency the expense was paid in, e.g. 'EUR'")
+            .with_default(*default.currency())
+            .prompt()?;
+
+        let quantity = CustomType::<Decimal>::new(&text("quantity"))
+            .with_help_message("The number of items, e.g. '2'")
+            .with_default(Decimal::from(**default.quantity()))
+            .prompt()?;
 
-        Ok(service_fees)
+        let transaction_date = CustomType::<Date>::new(&text("transaction date"))
+            .with_help_message("The date of the expense, e.g. '2025-05-31'")
+            .with_default(*default.transaction_date())
+            .prompt()?;
+
+        Ok(Item::builder()
+            .name(name)
+            .unit_price(unit_price)
+            .currency(currency)
+            .quantity(Quantity::from(quantity))
+            .transaction_date(transaction_date)
+            .receipt(default.receipt().clone())
+            .build())
     }
-    inner(default).map_err(|e| Error::InvalidServiceFees {
+    inner(&default).map_err(|e| Error::InvalidExpenseItem {
+        invalid_string: default.to_string(),
         reason: format!("{:?}", e),
     })
 }
 
+/// Prompts for the passphrase of the encrypted data directory, asking
+/// twice if `confirm` is `true`, e.g. when choosing a new passphrase.
+fn ask_for_passphrase(confirm: bool) -> Result<String> {
+    let prompt = Password::new("Passphrase of the data?")
+        .with_display_mode(PasswordDisplayMode::Hidden)
+        .with_custom_confirmation_message("Repeat the passphrase:")
+        .with_custom_confirmation_error_message("The passphrases do not match.");
+    let prompt = if confirm {
+        prompt
+    } else {
+        prompt.without_confirmation()
+    };
+    prompt.prompt().map_err(|_| Error::MissingDataPassphrase {
+        env_var: ENV_VAR_DATA_PASSPHRASE.to_owned(),
+    })
+}
+
+/// Prompts for the passphrase of the encrypted data directory.
+pub fn ask_for_data_passphrase() -> Result<String> {
+    ask_for_passphrase(false)
+}
+
+/// Prompts twice for a new passphrase to encrypt the data directory with.
+pub fn ask_for_new_data_passphrase() -> Result<String> {
+    ask_for_passphrase(true)
+}
+
 pub fn ask_for_data(default: Data, data_selector: Option<DataSelector>) -> Result<Data> {
     set_global_render_config(
         RenderConfig::default_colored().with_canceled_prompt_indicator(
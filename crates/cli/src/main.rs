@@ -18,6 +18,7 @@ fn main() {
     use clap::Parser;
     use prelude::*;
     init_logging();
+    set_data_passphrase_prompt(ask_for_data_passphrase);
     let input = CliArgs::parse();
     run(input)
 }
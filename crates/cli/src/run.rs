@@ -29,6 +29,26 @@ pub fn run(input: CliArgs) {
                     open_path(path);
                 });
         }
+        Command::Invoice(invoice_input) if invoice_input.dry_run().is_some() => {
+            let _ = run_invoice_dry_run_command(invoice_input)
+                .inspect_err(|e| error!("Error computing invoice: {}", e))
+                .inspect(|dry_run| {
+                    if let Some(dry_run) = dry_run {
+                        println!("{}", dry_run);
+                    }
+                });
+        }
+        Command::Invoice(invoice_input)
+            if invoice_input.items().as_ref().is_some_and(|i| i.is_batch()) =>
+        {
+            let _ = run_invoice_batch_command(&invoice_input)
+                .inspect_err(|e| error!("Error creating PDFs: {}", e))
+                .inspect(|summary| {
+                    if let Some(summary) = summary {
+                        println!("{}", summary);
+                    }
+                });
+        }
         Command::Invoice(invoice_input) => {
             let _ = run_invoice_command(invoice_input)
                 .inspect_err(|e| error!("Error creating PDF: {}", e))
@@ -41,5 +61,28 @@ pub fn run(input: CliArgs) {
                 error!("Error running data admin command: {}", e);
             });
         }
+        Command::Payment(payment_input) => {
+            let _ = run_payment_command(payment_input.command()).inspect_err(|e| {
+                error!("Error running payment command: {}", e);
+            });
+        }
+        Command::Report(report_input) => {
+            let _ = run_report_command(&report_input)
+                .inspect_err(|e| {
+                    error!("Error creating report: {}", e);
+                })
+                .inspect(|report| {
+                    println!("{}", report);
+                });
+        }
+        Command::Export(export_input) => {
+            let _ = run_export_command(export_input.command())
+                .inspect_err(|e| {
+                    error!("Error exporting: {}", e);
+                })
+                .inspect(|path| {
+                    info!("Exported to {}", path.display());
+                });
+        }
     }
 }
@@ -48,6 +48,10 @@
 // Function to format numbers to two decimals
 #let format_amount(amount, currency) = {
   let amt = amount * 1.0
+  // Format negative amounts, e.g. discounts, as their absolute amount with a minus sign
+  if amt < 0 {
+    return "-" + format_amount(-amt, currency)
+  }
   let integer = calc.floor(amt)
   let frac = int(calc.round((amt - integer) * 100, digits: 0))
   let frac_str = str(frac)
@@ -56,6 +60,51 @@
   without_currency + " " + currency
 }
 
+// The cells of a row in the table of items, `index` being the position of the
+// item among all items of the invoice.
+#let item_row(l18n, is_expenses, index, row) = (
+  {
+    row.name
+    // Mark expenses billed every month
+    if "recurring" in row [ (#l18n.line_items.recurring)]
+    // Reference the receipt appended to the invoice, if any
+    if "receipt" in row [ (#l18n.line_items.receipt #str(index + 1))]
+  },
+  format_item_date(l18n, is_expenses, row.transaction_date),
+  format_amount(row.unit_price, row.currency),
+  str(row.quantity),
+  format_amount(row.total_cost, row.currency),
+  table.hline(stroke: (thickness: 0.2pt, dash: "dashed")),
+)
+
+// The cells of a section of the table of items, with a heading and a subtotal,
+// used when services and expenses are combined on one invoice. `offset` is
+// the position of the first item of the section among all items.
+#let item_section(l18n, title, is_expenses, items, offset, currency) = {
+  let subtotal = items.map(it => it.total_cost).sum(default: 0.0)
+  (
+    (table.cell(colspan: 5)[#strong(title)],)
+      + items.enumerate().map(((index, row)) => item_row(l18n, is_expenses, offset + index, row)).flatten()
+      + (
+        table.cell(colspan: 4, align: right)[#l18n.line_items.subtotal],
+        format_amount(subtotal, currency),
+        table.hline(stroke: 0.2pt),
+      )
+  )
+}
+
+// The label of an applied discount, surcharge or credit, e.g. "Discount 10% (Loyalty)"
+#let adjustment_label(l18n, adjustment) = {
+  let label = l18n.line_items.at(lower(adjustment.kind))
+  if "percentage" in adjustment {
+    label = label + " " + str(adjustment.percentage) + "%"
+  }
+  if "description" in adjustment {
+    label = label + " (" + adjustment.description + ")"
+  }
+  label + ":"
+}
+
 #let display_if_non_empty(value) = {
   if value != "" {
     value
@@ -183,9 +232,7 @@
     ]),
     block(fill: none, inset: 0pt, stroke: none, width: 100%, [
       // align the following block to the right margin
-      #ovalbox(100%, [#Large(strong[#l18n.invoice_info.invoice_identifier]) #text(fill: emphasize_color)[#strong(str(
-            data.information.number,
-          ))]])
+      #ovalbox(100%, [#Large(strong[#l18n.invoice_info.invoice_identifier]) #text(fill: emphasize_color)[#strong(data.information.identifier)]])
       // Conditionally display purchase order if it exists
       #if "purchase_order" in data.information {
         ovalbox(100%, [#strong[#l18n.invoice_info.purchase_order] #text(fill: emphasize_color)[#strong(
@@ -207,6 +254,11 @@
       }
       #strong[#l18n.invoice_info.vendor_contact] #data.vendor.contact_person \
       #strong[#l18n.invoice_info.terms] #data.payment_info.terms
+      // List the days off of a service invoice, if any
+      #if "days_off" in data.information [
+        \ #strong[#l18n.invoice_info.days_off]
+        #data.information.days_off.map(range => range.replace("..", "–")).join(", ")
+      ]
     ]),
   )
 
@@ -233,17 +285,45 @@
       [#strong(l18n.line_items.total_cost)],
     ),
     table.hline(stroke: 0.2pt),
-    ..for row in data.line_items.items {
-      (
-        row.name,
-        format_item_date(l18n, is_expenses, row.transaction_date),
-        format_amount(row.unit_price, row.currency),
-        str(row.quantity),
-        format_amount(row.total_cost, row.currency),
-        table.hline(stroke: (thickness: 0.2pt, dash: "dashed")),
-      )
+    ..if "combined_service_items" in data.line_items {
+      // Services and expenses in separate sections, each with a subtotal
+      let count = data.line_items.combined_service_items
+      let services = data.line_items.items.slice(0, count)
+      let expenses = data.line_items.items.slice(count)
+      let currency = data.payment_info.currency
+      item_section(l18n, l18n.line_items.services, false, services, 0, currency)
+      if expenses.len() > 0 {
+        item_section(l18n, l18n.line_items.expenses, true, expenses, count, currency)
+      }
+    } else {
+      data.line_items.items.enumerate().map(((index, row)) => item_row(l18n, is_expenses, index, row)).flatten()
     },
   )
+  // Subtotal and rows of discounts, surcharges and credits, if any
+  if "adjustments" in data.line_items {
+    align(right)[
+      #l18n.line_items.subtotal
+      #format_amount(grand_total, data.payment_info.currency) \
+      #for adjustment in data.line_items.adjustments [
+        #adjustment_label(l18n, adjustment)
+        #format_amount(adjustment.amount, data.payment_info.currency) \
+      ]
+    ]
+    for adjustment in data.line_items.adjustments { grand_total = grand_total + adjustment.amount }
+  }
+  // Net total and VAT rows, only if VAT is charged
+  let vat_rate = data.information.at("vat_rate", default: 0)
+  if vat_rate > 0 {
+    // Round half away from zero, just like klirr does when recording the invoice.
+    let vat = calc.round(grand_total * vat_rate / 100, digits: 2)
+    align(right)[
+      #l18n.line_items.net_total
+      #format_amount(grand_total, data.payment_info.currency) \
+      #l18n.line_items.vat (#str(vat_rate)%)
+      #format_amount(vat, data.payment_info.currency)
+    ]
+    grand_total = grand_total + vat
+  }
   // Grand Total Row
   align(right)[
     #set text(weight: "bold")
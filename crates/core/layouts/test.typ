@@ -5,8 +5,6 @@
     #l18n.invoice_info.invoice_identifier
 
     #let emphasize_color = rgb(data.information.emphasize_color_hex)
-    #text(fill: emphasize_color)[#strong(str(
-        data.information.number,
-      ))]
+    #text(fill: emphasize_color)[#strong(data.information.identifier)]
   ])
 }
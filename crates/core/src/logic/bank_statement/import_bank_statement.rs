@@ -0,0 +1,119 @@
+use crate::prelude::*;
+
+/// Parses the bank statement `statement` in `format`.
+pub fn parse_bank_statement(
+    statement: &str,
+    format: StatementFormat,
+) -> Result<Vec<BankTransaction>> {
+    match format {
+        StatementFormat::Camt053 => parse_camt053(statement),
+        StatementFormat::Csv => parse_csv_statement(statement),
+    }
+}
+
+/// Imports the bank statement at `statement_path`, matching its incoming
+/// payments with open invoices in the ledger at `data_path` and recording the
+/// matches as payments. If `format` is `None` it is inferred from the file
+/// extension. The data directory is locked while the payments are updated.
+///
+/// # Errors
+/// Returns an error if the statement cannot be read or parsed, or if the
+/// payments cannot be saved.
+pub fn import_bank_statement_with_base_path(
+    statement_path: impl AsRef<Path>,
+    format: Option<StatementFormat>,
+    data_path: impl AsRef<Path>,
+) -> Result<Reconciliation> {
+    let statement_path = statement_path.as_ref();
+    let data_path = data_path.as_ref();
+    let format = match format {
+        Some(format) => format,
+        None => StatementFormat::from_path(statement_path)?,
+    };
+    info!(
+        "Importing {} bank statement: {}",
+        format,
+        statement_path.display()
+    );
+    let statement = fs::read_to_string(statement_path).map_err(|e| Error::FileNotFound {
+        path: statement_path.display().to_string(),
+        underlying: format!("{:?}", e),
+    })?;
+    let transactions = parse_bank_statement(&statement, format)?;
+    let _lock = lock_migrated_data(data_path)?;
+    let ledger = invoice_ledger(data_path)?;
+    let mut payments = payments(data_path)?;
+    let reconciliation = reconcile(transactions, &ledger, &payments);
+    if !reconciliation.matched().is_empty() {
+        for matched in reconciliation.matched() {
+            payments.record(matched.payment().clone());
+        }
+        save_to_disk(
+            &payments,
+            path_to_ron_file_with_base(data_path, DATA_FILE_NAME_PAYMENTS),
+        )?;
+    }
+    info!(
+        "✅ Imported bank statement, matched #{} payments",
+        reconciliation.matched().len()
+    );
+    Ok(reconciliation)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    fn fixture(name: &str) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("fixtures")
+            .join(name)
+    }
+
+    fn assert_import_fixture(name: &str) {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        record_issued_invoice(tempdir.path(), IssuedInvoice::sample()).unwrap();
+
+        let reconciliation =
+            import_bank_statement_with_base_path(fixture(name), None, tempdir.path()).unwrap();
+        assert_eq!(reconciliation.matched().len(), 2);
+        assert_eq!(reconciliation.unmatched().len(), 1);
+        assert_eq!(
+            reconciliation.unmatched()[0].reference(),
+            "Refund from supplier"
+        );
+        let receivables =
+            receivables_with_base_path(tempdir.path(), &Date::from_str("2025-07-30").unwrap())
+                .unwrap();
+        assert!(receivables.is_empty(), "Invoice should be paid in full");
+
+        // Importing again does not record the payments twice.
+        let reconciliation =
+            import_bank_statement_with_base_path(fixture(name), None, tempdir.path()).unwrap();
+        assert!(reconciliation.matched().is_empty());
+        assert_eq!(reconciliation.already_recorded().len(), 2);
+        assert_eq!(payments(tempdir.path()).unwrap().iter().count(), 2);
+    }
+
+    #[test]
+    fn test_import_camt053_fixture() {
+        assert_import_fixture("camt053_statement.xml");
+    }
+
+    #[test]
+    fn test_import_csv_fixture() {
+        assert_import_fixture("csv_statement.csv");
+    }
+
+    #[test]
+    fn test_import_missing_file() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        let result = import_bank_statement_with_base_path(
+            tempdir.path().join("missing.csv"),
+            None,
+            tempdir.path(),
+        );
+        assert!(matches!(result, Err(Error::FileNotFound { .. })));
+    }
+}
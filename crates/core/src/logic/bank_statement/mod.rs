@@ -0,0 +1,9 @@
+mod import_bank_statement;
+mod parse_camt053;
+mod parse_csv_statement;
+mod reconcile;
+
+pub use import_bank_statement::*;
+pub use parse_camt053::*;
+pub use parse_csv_statement::*;
+pub use reconcile::*;
@@ -0,0 +1,186 @@
+use crate::prelude::*;
+use roxmltree::{Document, Node};
+
+fn camt053_error(underlying: impl std::fmt::Display) -> Error {
+    Error::FailedToParseBankStatement {
+        format: StatementFormat::Camt053.to_string(),
+        underlying: underlying.to_string(),
+    }
+}
+
+/// Returns the first child element of `node` with tag name `name`, ignoring
+/// the XML namespace, which differs between versions of camt.053.
+fn child<'a, 'input>(node: Node<'a, 'input>, name: &str) -> Option<Node<'a, 'input>> {
+    node.children()
+        .find(|n| n.is_element() && n.tag_name().name() == name)
+}
+
+/// Returns the first descendant element of `node` with tag name `name`.
+fn descendant<'a, 'input>(node: Node<'a, 'input>, name: &str) -> Option<Node<'a, 'input>> {
+    node.descendants()
+        .find(|n| n.is_element() && n.tag_name().name() == name)
+}
+
+/// Returns the trimmed text of all descendant elements of `node` with tag
+/// name `name`.
+fn texts_of<'a>(node: Node<'a, '_>, name: &str) -> Vec<&'a str> {
+    node.descendants()
+        .filter(|n| n.is_element() && n.tag_name().name() == name)
+        .filter_map(|n| n.text())
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+        .collect()
+}
+
+/// Parses the `Amt` element `node`, e.g. `<Amt Ccy="EUR">4000.00</Amt>`.
+fn amount_and_currency(node: Node) -> Result<(Cost, Currency)> {
+    let amount = node
+        .text()
+        .unwrap_or_default()
+        .trim()
+        .parse::<rust_decimal::Decimal>()
+        .map_err(camt053_error)?;
+    let currency = node
+        .attribute("Ccy")
+        .ok_or_else(|| camt053_error("Amt is missing Ccy attribute"))?
+        .parse::<Currency>()
+        .map_err(camt053_error)?;
+    Ok((Cost::from(amount), currency))
+}
+
+/// Parses the booking date of the entry `entry`, either `BookgDt/Dt` or
+/// `BookgDt/DtTm`, falling back to the value date `ValDt`.
+fn booking_date(entry: Node) -> Result<Date> {
+    let date = ["BookgDt", "ValDt"]
+        .iter()
+        .filter_map(|name| child(entry, name))
+        .filter_map(|node| child(node, "Dt").or_else(|| child(node, "DtTm")))
+        .filter_map(|node| node.text())
+        .next()
+        .ok_or_else(|| camt053_error("Entry is missing booking date"))?;
+    // `DtTm` is e.g. `2025-06-30T10:15:00`, we only care about the date.
+    Date::from_str(date.trim().get(..10).unwrap_or(date))
+}
+
+/// Parses the credit entries of an ISO 20022 `camt.053` bank statement, each
+/// entry's transaction details becoming a [`BankTransaction`] with the
+/// remittance information as reference. Debit entries are ignored.
+///
+/// # Errors
+/// Returns an error if the XML is invalid or an entry lacks amount, currency
+/// or booking date.
+pub fn parse_camt053(xml: &str) -> Result<Vec<BankTransaction>> {
+    let document = Document::parse(xml).map_err(camt053_error)?;
+    let mut transactions = Vec::new();
+    let entries = document
+        .descendants()
+        .filter(|n| n.is_element() && n.tag_name().name() == "Ntry");
+    for entry in entries {
+        let is_credit = child(entry, "CdtDbtInd")
+            .and_then(|n| n.text())
+            .is_some_and(|t| t.trim() == "CRDT");
+        if !is_credit {
+            continue;
+        }
+        let (entry_amount, entry_currency) = amount_and_currency(
+            child(entry, "Amt").ok_or_else(|| camt053_error("Entry is missing Amt"))?,
+        )?;
+        let date = booking_date(entry)?;
+        let details = entry
+            .descendants()
+            .filter(|n| n.is_element() && n.tag_name().name() == "TxDtls")
+            .collect::<Vec<_>>();
+        if details.len() <= 1 {
+            let mut references = texts_of(entry, "Ustrd");
+            references.extend(texts_of(entry, "Ref"));
+            references.extend(texts_of(entry, "AddtlNtryInf"));
+            transactions.push(
+                BankTransaction::builder()
+                    .date(date)
+                    .amount(entry_amount)
+                    .currency(entry_currency)
+                    .reference(references.join(" "))
+                    .build(),
+            );
+            continue;
+        }
+        // A batch booking, with one amount per transaction.
+        for detail in details {
+            let (amount, currency) = match descendant(detail, "Amt") {
+                Some(node) => amount_and_currency(node)?,
+                None => (entry_amount, entry_currency),
+            };
+            let mut references = texts_of(detail, "Ustrd");
+            references.extend(texts_of(detail, "Ref"));
+            transactions.push(
+                BankTransaction::builder()
+                    .date(date)
+                    .amount(amount)
+                    .currency(currency)
+                    .reference(references.join(" "))
+                    .build(),
+            );
+        }
+    }
+    Ok(transactions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    const FIXTURE: &str = include_str!("../../../fixtures/camt053_statement.xml");
+
+    #[test]
+    fn test_parse_camt053_fixture() {
+        let transactions = parse_camt053(FIXTURE).unwrap();
+        assert_eq!(transactions.len(), 3, "Debit should be skipped");
+        assert_eq!(
+            transactions[0],
+            BankTransaction::builder()
+                .date(Date::from_str("2025-06-28").unwrap())
+                .amount(Cost::from(dec!(4000.00)))
+                .currency(Currency::EUR)
+                .reference("2436")
+                .build()
+        );
+        assert_eq!(
+            *transactions[1].date(),
+            Date::from_str("2025-06-30").unwrap()
+        );
+        assert_eq!(transactions[1].reference(), "Payment");
+        assert_eq!(transactions[2].reference(), "Refund from supplier");
+    }
+
+    #[test]
+    fn test_parse_camt053_batch_booking() {
+        let xml = r#"<Document><BkToCstmrStmt><Stmt><Ntry>
+            <Amt Ccy="SEK">300</Amt><CdtDbtInd>CRDT</CdtDbtInd>
+            <BookgDt><Dt>2025-06-28</Dt></BookgDt>
+            <NtryDtls>
+              <TxDtls><AmtDtls><TxAmt><Amt Ccy="SEK">100</Amt></TxAmt></AmtDtls><RmtInf><Ustrd>Invoice 1</Ustrd></RmtInf></TxDtls>
+              <TxDtls><AmtDtls><TxAmt><Amt Ccy="SEK">200</Amt></TxAmt></AmtDtls><RmtInf><Ustrd>Invoice 2</Ustrd></RmtInf></TxDtls>
+            </NtryDtls>
+        </Ntry></Stmt></BkToCstmrStmt></Document>"#;
+        let transactions = parse_camt053(xml).unwrap();
+        assert_eq!(transactions.len(), 2);
+        assert_eq!(*transactions[1].amount(), Cost::from(dec!(200)));
+        assert_eq!(transactions[1].reference(), "Invoice 2");
+    }
+
+    #[test]
+    fn test_parse_camt053_invalid_xml() {
+        let result = parse_camt053("<Document>");
+        assert!(matches!(
+            result,
+            Err(Error::FailedToParseBankStatement { .. })
+        ));
+    }
+
+    #[test]
+    fn test_parse_camt053_missing_amount() {
+        let xml = r#"<Document><Ntry><CdtDbtInd>CRDT</CdtDbtInd></Ntry></Document>"#;
+        assert!(parse_camt053(xml).is_err());
+    }
+}
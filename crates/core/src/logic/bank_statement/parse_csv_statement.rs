@@ -0,0 +1,92 @@
+use crate::prelude::*;
+
+/// A row in a generic CSV statement, the amount is kept as string to not
+/// lose precision.
+#[derive(Debug, Deserialize)]
+struct CsvStatementRow {
+    date: Date,
+    amount: String,
+    currency: Currency,
+    #[serde(default)]
+    reference: String,
+}
+
+fn csv_error(underlying: impl std::fmt::Display) -> Error {
+    Error::FailedToParseBankStatement {
+        format: StatementFormat::Csv.to_string(),
+        underlying: underlying.to_string(),
+    }
+}
+
+/// Parses a generic CSV bank statement with the header
+/// `date,amount,currency,reference`, where credits have a positive amount.
+/// Debits, i.e. rows with a negative or zero amount, are ignored.
+///
+/// # Errors
+/// Returns an error if the CSV is malformed or a row cannot be parsed.
+///
+/// # Examples
+/// ```
+/// extern crate klirr_core;
+/// use klirr_core::prelude::*;
+/// let csv = "date,amount,currency,reference\n2025-06-28,9324.00,EUR,Invoice 243\n";
+/// let transactions = parse_csv_statement(csv).unwrap();
+/// assert_eq!(transactions, vec![BankTransaction::sample()]);
+/// ```
+pub fn parse_csv_statement(csv: &str) -> Result<Vec<BankTransaction>> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(csv.as_bytes());
+    let mut transactions = Vec::new();
+    for row in reader.deserialize::<CsvStatementRow>() {
+        let row = row.map_err(csv_error)?;
+        let amount = row
+            .amount
+            .parse::<rust_decimal::Decimal>()
+            .map_err(csv_error)?;
+        if amount <= rust_decimal::Decimal::ZERO {
+            continue;
+        }
+        transactions.push(
+            BankTransaction::builder()
+                .date(row.date)
+                .amount(Cost::from(amount))
+                .currency(row.currency)
+                .reference(row.reference)
+                .build(),
+        );
+    }
+    Ok(transactions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    const FIXTURE: &str = include_str!("../../../fixtures/csv_statement.csv");
+
+    #[test]
+    fn test_parse_csv_statement_fixture() {
+        let transactions = parse_csv_statement(FIXTURE).unwrap();
+        assert_eq!(transactions.len(), 3, "Debit should be skipped");
+        assert_eq!(*transactions[0].amount(), Cost::from(dec!(4000.00)));
+        assert_eq!(transactions[0].reference(), "Invoice 243");
+        assert_eq!(transactions[1].reference(), "");
+    }
+
+    #[test]
+    fn test_parse_csv_statement_invalid_amount() {
+        let csv = "date,amount,currency,reference\n2025-06-28,lots,EUR,Invoice 243\n";
+        assert!(matches!(
+            parse_csv_statement(csv),
+            Err(Error::FailedToParseBankStatement { .. })
+        ));
+    }
+
+    #[test]
+    fn test_parse_csv_statement_invalid_date() {
+        let csv = "date,amount,currency,reference\n28/06/2025,1,EUR,Invoice 243\n";
+        assert!(parse_csv_statement(csv).is_err());
+    }
+}
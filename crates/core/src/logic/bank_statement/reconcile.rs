@@ -0,0 +1,237 @@
+use crate::prelude::*;
+
+/// Returns true if `payment` is a record of `transaction`, i.e. has the same
+/// date, amount and reference, or the same date and amount if it was
+/// recorded by hand, without a reference.
+fn is_record_of(payment: &Payment, transaction: &BankTransaction) -> bool {
+    payment.date() == transaction.date()
+        && payment.amount() == transaction.amount()
+        && payment
+            .reference()
+            .as_ref()
+            .is_none_or(|reference| reference == transaction.reference())
+}
+
+/// Returns the single open invoice matching `predicate`, or `None` if none
+/// or several do.
+fn single_match<'a>(
+    open: &[&'a Receivable],
+    predicate: impl Fn(&Receivable) -> bool,
+) -> Option<&'a IssuedInvoice> {
+    let mut matching = open.iter().filter(|r| predicate(r));
+    match (matching.next(), matching.next()) {
+        (Some(receivable), None) => Some(receivable.invoice()),
+        _ => None,
+    }
+}
+
+/// Matches incoming bank `transactions` with open invoices in `ledger`, i.e.
+/// invoices not paid in full by `payments`.
+///
+/// A transaction is matched with an invoice in the same currency, either by
+/// its reference containing the invoice number, as printed on the invoice or
+/// not, or OCR reference, given that the amount does not exceed the
+/// outstanding amount, or else by its amount being exactly the outstanding
+/// amount of a single open invoice. Matches are
+/// taken into account when matching subsequent transactions. Transactions
+/// already recorded in `payments`, with the same date, amount and reference,
+/// are skipped, each recorded payment accounting for a single transaction.
+///
+/// # Examples
+/// ```
+/// extern crate klirr_core;
+/// use klirr_core::prelude::*;
+/// let reconciliation = reconcile(
+///     vec![BankTransaction::sample()],
+///     &InvoiceLedger::sample(),
+///     &Payments::default(),
+/// );
+/// assert_eq!(reconciliation.matched().len(), 1);
+/// assert!(reconciliation.unmatched().is_empty());
+/// ```
+pub fn reconcile(
+    transactions: Vec<BankTransaction>,
+    ledger: &InvoiceLedger,
+    payments: &Payments,
+) -> Reconciliation {
+    let mut reconciliation = Reconciliation::default();
+    let mut working = payments.clone();
+    let mut unaccounted = payments.iter().collect::<Vec<_>>();
+    for transaction in transactions {
+        if let Some(index) = unaccounted
+            .iter()
+            .position(|p| is_record_of(p, &transaction))
+        {
+            unaccounted.remove(index);
+            reconciliation.push_already_recorded(transaction);
+            continue;
+        }
+        let receivables = Receivables::new(ledger, &working, transaction.date());
+        let open = receivables
+            .iter()
+            .filter(|r| r.invoice().currency() == transaction.currency())
+            .collect::<Vec<_>>();
+
+        let by_reference = single_match(&open, |r| {
+            r.invoice().is_referenced_by(transaction.reference())
+                && **transaction.amount() <= **r.outstanding()
+        })
+        .map(|invoice| (invoice, MatchedBy::Reference));
+        let by_amount = || {
+            single_match(&open, |r| r.outstanding() == transaction.amount())
+                .map(|invoice| (invoice, MatchedBy::Amount))
+        };
+
+        match by_reference.or_else(by_amount) {
+            Some((invoice, matched_by)) => {
+                let matched = MatchedTransaction::new(transaction, invoice, matched_by);
+                working.record(matched.payment().clone());
+                reconciliation.push_matched(matched);
+            }
+            None => reconciliation.push_unmatched(transaction),
+        }
+    }
+    reconciliation
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    fn transaction(amount: rust_decimal::Decimal, reference: &str) -> BankTransaction {
+        BankTransaction::builder()
+            .date(Date::from_str("2025-06-28").unwrap())
+            .amount(Cost::from(amount))
+            .currency(Currency::EUR)
+            .reference(reference)
+            .build()
+    }
+
+    fn ledger() -> InvoiceLedger {
+        let mut ledger = InvoiceLedger::sample();
+        ledger.record(
+            IssuedInvoice::builder()
+                .number(InvoiceNumber::from(244))
+                .kind(InvoiceKind::Expenses)
+                .invoice_date(Date::from_str("2025-05-31").unwrap())
+                .due_date(Date::from_str("2025-06-30").unwrap())
+                .currency(Currency::EUR)
+                .total(Cost::from(dec!(150)))
+                .build(),
+        );
+        ledger
+    }
+
+    #[test]
+    fn test_match_by_ocr_then_by_remaining_amount() {
+        let reconciliation = reconcile(
+            vec![
+                transaction(dec!(4000), "2436"),
+                transaction(dec!(5324), "Payment"),
+            ],
+            &ledger(),
+            &Payments::default(),
+        );
+        let matched = reconciliation.matched();
+        assert_eq!(matched.len(), 2);
+        assert_eq!(*matched[0].matched_by(), MatchedBy::Reference);
+        assert_eq!(*matched[1].matched_by(), MatchedBy::Amount);
+        assert_eq!(*matched[1].payment().invoice(), InvoiceNumber::from(243));
+    }
+
+    #[test]
+    fn test_amount_exceeding_outstanding_is_unmatched() {
+        let reconciliation = reconcile(
+            vec![transaction(dec!(200), "Invoice 244")],
+            &ledger(),
+            &Payments::default(),
+        );
+        assert!(reconciliation.matched().is_empty());
+        assert_eq!(reconciliation.unmatched().len(), 1);
+    }
+
+    #[test]
+    fn test_other_currency_is_unmatched() {
+        let reconciliation = reconcile(
+            vec![
+                BankTransaction::builder()
+                    .date(Date::sample())
+                    .amount(Cost::from(dec!(150)))
+                    .currency(Currency::SEK)
+                    .reference("Invoice 244")
+                    .build(),
+            ],
+            &ledger(),
+            &Payments::default(),
+        );
+        assert_eq!(reconciliation.unmatched().len(), 1);
+    }
+
+    #[test]
+    fn test_already_recorded_is_skipped() {
+        let reconciliation = reconcile(
+            vec![transaction(dec!(4000), "Invoice 243")],
+            &ledger(),
+            &Payments::sample(),
+        );
+        assert!(reconciliation.matched().is_empty());
+        assert_eq!(reconciliation.already_recorded().len(), 1);
+    }
+
+    #[test]
+    fn test_same_amount_and_date_from_other_payer_is_not_skipped() {
+        let first = reconcile(
+            vec![transaction(dec!(150), "Invoice 244")],
+            &ledger(),
+            &Payments::default(),
+        );
+        let mut payments = Payments::default();
+        for matched in first.matched() {
+            payments.record(matched.payment().clone());
+        }
+        let reconciliation = reconcile(
+            vec![
+                transaction(dec!(150), "Invoice 244"),
+                transaction(dec!(150), "Other payer"),
+            ],
+            &ledger(),
+            &payments,
+        );
+        assert_eq!(reconciliation.already_recorded().len(), 1);
+        assert_eq!(
+            reconciliation.matched().len() + reconciliation.unmatched().len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_each_recorded_payment_skips_one_transaction() {
+        let reconciliation = reconcile(
+            vec![
+                transaction(dec!(4000), "Invoice 243"),
+                transaction(dec!(4000), "Invoice 243"),
+            ],
+            &ledger(),
+            &Payments::sample(),
+        );
+        assert_eq!(reconciliation.already_recorded().len(), 1);
+        assert_eq!(reconciliation.matched().len(), 1);
+    }
+
+    #[test]
+    fn test_display() {
+        let reconciliation = reconcile(
+            vec![
+                transaction(dec!(150), "Invoice 244"),
+                transaction(dec!(1), "Unknown"),
+            ],
+            &ledger(),
+            &Payments::default(),
+        );
+        let report = reconciliation.to_string();
+        assert!(report.contains("Matched #1 payments"), "{}", report);
+        assert!(report.contains("Unmatched #1 payments"), "{}", report);
+        assert!(report.contains("invoice 244 (by reference)"), "{}", report);
+    }
+}
@@ -19,7 +19,8 @@ impl YearAndMonth {
             4 | 6 | 9 | 11 => Day::try_from(30).expect("LEQ 31 days"),
             2 => {
                 let year = **self.year();
-                if (year % 4 == 0 && year % 100 != 0) || (year % 400 == 0) {
+                if (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400)
+                {
                     Day::try_from(29).expect("LEQ 31 days")
                 } else {
                     Day::try_from(28).expect("LEQ 31 days")
@@ -82,6 +83,52 @@ impl YearAndMonth {
             .build()
     }
 
+    /// Returns a new `YearAndMonth` that is one month later than this one.
+    /// If the month is December, it will return January of the next year.
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let month = YearAndMonth::december(2024);
+    /// let one_month_later = month.one_month_later();
+    /// assert_eq!(one_month_later, YearAndMonth::january(2025));
+    /// ```
+    pub fn one_month_later(&self) -> Self {
+        let mut year = **self.year();
+        let mut month = **self.month();
+
+        if month == 12 {
+            year += 1;
+            month = 1
+        } else {
+            month += 1
+        }
+
+        Self::builder()
+            .year(Year::from(year))
+            .month(Month::try_from(month).expect("Should return valid month"))
+            .build()
+    }
+
+    /// Returns all months from this `YearAndMonth` up to and including `end`,
+    /// in chronological order, or an empty vector if `end` is before this month.
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let months = YearAndMonth::november(2024).months_through(YearAndMonth::february(2025));
+    /// assert_eq!(months.len(), 4);
+    /// assert_eq!(months.last(), Some(&YearAndMonth::february(2025)));
+    /// ```
+    pub fn months_through(&self, end: impl Borrow<Self>) -> Vec<Self> {
+        let end = end.borrow();
+        let mut months = Vec::new();
+        let mut month = *self;
+        while month <= *end {
+            months.push(month);
+            month = month.one_month_later();
+        }
+        months
+    }
+
     /// Returns a new `YearAndMonth` that is one month later than this one - by
     /// reading the calendar - if the current month is December, it will return
     /// January of the next year.
@@ -123,19 +170,33 @@ impl YearAndMonth {
 
 impl ValidInput {
     /// Calculates the invoice number for the given `ProtoInvoiceInfo` based on
-    /// the target month and whether the items are expenses or services.
+    /// the target month and whether the items are expenses or services,
+    /// counted by the [`InvoiceNumberCounter`] of its numbering scheme.
     ///
-    /// See `calculate_invoice_number` for the logic.
-    pub fn invoice_number(&self, information: &ProtoInvoiceInfo) -> InvoiceNumber {
+    /// See `calculate_invoice_number` and `sequential_invoice_number` for the
+    /// logic.
+    pub fn invoice_number(
+        &self,
+        information: &ProtoInvoiceInfo,
+        ledger: &InvoiceLedger,
+    ) -> InvoiceNumber {
         let items = self.items();
         let target_month = self.month();
         let is_expenses = items.is_expenses();
-        calculate_invoice_number(
-            information.offset(),
-            target_month,
-            is_expenses,
-            information.months_off_record(),
-        )
+        let derived = || {
+            calculate_invoice_number(
+                information.offset(),
+                target_month,
+                is_expenses,
+                information.months_off_record(),
+            )
+        };
+        match information.numbering().counter() {
+            InvoiceNumberCounter::MonthlyDerived => derived(),
+            InvoiceNumberCounter::LedgerSequential => {
+                sequential_invoice_number(ledger, target_month, InvoiceKind::from(items), derived)
+            }
+        }
     }
 }
 
@@ -203,7 +264,8 @@ pub fn calculate_invoice_number(
     InvoiceNumber::from(invoice_number)
 }
 
-/// Calculates the number of working days in a given month, excluding weekends.
+/// Calculates the number of working days in a given month, excluding weekends
+/// and the weekdays in the record of days off.
 ///
 /// # Errors
 /// Returns an error if the target month is in the record of months off.
@@ -214,12 +276,19 @@ pub fn calculate_invoice_number(
 ///
 /// let target_month = YearAndMonth::january(2024);
 /// let months_off_record = MonthsOffRecord::new([]);
-/// let working_days = working_days_in_month(&target_month, &months_off_record);
+/// let days_off_record = DaysOffRecord::default();
+/// let working_days = working_days_in_month(&target_month, &months_off_record, &days_off_record);
 /// assert_eq!(working_days.unwrap(), 23); // January 2024 has 23
+///
+/// // Friday to Monday, of which two are weekdays.
+/// let days_off_record = DaysOffRecord::new(["2024-01-05..2024-01-08".parse().unwrap()]);
+/// let working_days = working_days_in_month(&target_month, &months_off_record, &days_off_record);
+/// assert_eq!(working_days.unwrap(), 21);
 /// ```
 pub fn working_days_in_month(
     target_month: &YearAndMonth,
     months_off_record: &MonthsOffRecord,
+    days_off_record: &DaysOffRecord,
 ) -> Result<u8> {
     if months_off_record.contains(target_month) {
         return Err(Error::TargetMonthMustNotBeInRecordOfMonthsOff {
@@ -250,7 +319,9 @@ pub fn working_days_in_month(
     let mut working_days = 0;
     while day <= last_day {
         match day.weekday() {
-            Weekday::Mon | Weekday::Tue | Weekday::Wed | Weekday::Thu | Weekday::Fri => {
+            Weekday::Mon | Weekday::Tue | Weekday::Wed | Weekday::Thu | Weekday::Fri
+                if !days_off_record.contains(&Date::from(day)) =>
+            {
                 working_days += 1;
             }
             _ => {}
@@ -334,7 +405,7 @@ mod tests {
             )
             .build();
 
-        let invoice_number = input.invoice_number(&information);
+        let invoice_number = input.invoice_number(&information, &InvoiceLedger::default());
         assert_eq!(invoice_number, expected.into());
     }
 
@@ -615,6 +686,20 @@ mod tests {
         assert_eq!(january_2025.one_month_earlier(), december_2024);
     }
 
+    #[test]
+    fn test_one_month_later_of_november() {
+        assert_eq!(
+            YearAndMonth::november(2024).one_month_later(),
+            YearAndMonth::december(2024)
+        );
+    }
+
+    #[test]
+    fn test_months_through_end_before_start_is_empty() {
+        let months = YearAndMonth::march(2025).months_through(YearAndMonth::january(2025));
+        assert!(months.is_empty());
+    }
+
     #[test]
     #[should_panic]
     fn test_elapsed_months_since_panic() {
@@ -652,7 +737,8 @@ mod tests {
     fn test_working_days_in_month_target_month_is_in_record_of_months_off() {
         let target_month = YearAndMonth::january(2024);
         let months_off_record = MonthsOffRecord::new([target_month]);
-        let result = working_days_in_month(&target_month, &months_off_record);
+        let result =
+            working_days_in_month(&target_month, &months_off_record, &DaysOffRecord::default());
         assert!(result.is_err());
     }
 
@@ -660,7 +746,21 @@ mod tests {
     fn test_working_days_in_month_target_month_december() {
         let target_month = YearAndMonth::december(2025);
         let months_off_record = MonthsOffRecord::new([]);
-        let result = working_days_in_month(&target_month, &months_off_record);
+        let result =
+            working_days_in_month(&target_month, &months_off_record, &DaysOffRecord::default());
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_working_days_in_month_days_off_in_other_months_are_ignored() {
+        let target_month = YearAndMonth::july(2025);
+        let months_off_record = MonthsOffRecord::new([]);
+        let days_off_record = DaysOffRecord::new([
+            "2025-06-30..2025-07-01".parse().unwrap(),
+            "2025-08-01".parse().unwrap(),
+        ]);
+        let result = working_days_in_month(&target_month, &months_off_record, &days_off_record);
+        // July 2025 has 23 working days, minus Tuesday the 1st.
+        assert_eq!(result.unwrap(), 22);
+    }
 }
@@ -1,14 +1,73 @@
 use crate::prelude::*;
 use serde::de::DeserializeOwned;
 
-fn input_data_at(
-    default_data: Data,
-    write_path: impl AsRef<Path>,
-    provide_data: impl FnOnce(Data) -> Result<Data>,
-) -> Result<()> {
-    let data = provide_data(default_data)?;
-    save_data_with_base_path(data, write_path)?;
-    Ok(())
+/// Saves `data` at `write_path`, recording the files as they were before in
+/// the history of the data, described by `description`. The caller must hold
+/// the [`DataLock`] of `write_path`.
+fn save_data_while_locked(data: Data, write_path: &Path, description: &str) -> Result<()> {
+    record_data_revision(write_path, description, &DATA_FILE_NAMES_OF_DATA)?;
+    save_data_with_base_path(data, write_path)
+}
+
+/// Merges `edited`, the data edited from `base`, into `current`, the data as
+/// saved now, which a concurrently running klirr may have changed since
+/// `base` was read. Each data file changed by the edit is taken from
+/// `edited`, every other one from `current`.
+///
+/// # Errors
+/// Returns [`Error::DataChangedMeanwhile`] if a data file was changed both by
+/// the edit and meanwhile.
+fn merge_edit(base: &Data, edited: Data, current: Data) -> Result<Data> {
+    fn pick<T: Clone + PartialEq>(file_name: &str, base: &T, edited: &T, current: &T) -> Result<T> {
+        if edited == base {
+            Ok(current.clone())
+        } else if current == base || current == edited {
+            Ok(edited.clone())
+        } else {
+            Err(Error::DataChangedMeanwhile {
+                file_name: file_name.to_owned(),
+            })
+        }
+    }
+    Ok(Data::builder()
+        .information(pick(
+            DATA_FILE_NAME_PROTO_INVOICE_INFO,
+            base.information(),
+            edited.information(),
+            current.information(),
+        )?)
+        .vendor(pick(
+            DATA_FILE_NAME_VENDOR,
+            base.vendor(),
+            edited.vendor(),
+            current.vendor(),
+        )?)
+        .client(pick(
+            DATA_FILE_NAME_CLIENT,
+            base.client(),
+            edited.client(),
+            current.client(),
+        )?)
+        .payment_info(pick(
+            DATA_FILE_NAME_PAYMENT,
+            base.payment_info(),
+            edited.payment_info(),
+            current.payment_info(),
+        )?)
+        .service_fees(pick(
+            DATA_FILE_NAME_SERVICE_FEES,
+            base.service_fees(),
+            edited.service_fees(),
+            current.service_fees(),
+        )?)
+        .expensed_months(pick(
+            DATA_FILE_NAME_EXPENSES,
+            base.expensed_months(),
+            edited.expensed_months(),
+            current.expensed_months(),
+        )?)
+        .ledger(current.ledger().clone())
+        .build())
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -35,40 +94,77 @@ impl DataSelector {
     }
 }
 
+/// Edits the data at `path` with `provide_data`, e.g. interactive prompts,
+/// which run without locking the data directory, so that a concurrently
+/// running klirr is not blocked meanwhile. The edit is then merged into the
+/// data as saved by then, see [`merge_edit`], and saved while locked.
 pub fn edit_data_at(
     path: impl AsRef<Path>,
     provide_data: impl FnOnce(Data) -> Result<Data>,
 ) -> Result<()> {
     let path = path.as_ref();
     info!("Editing data at: {}", path.display());
-    let existing = read_data_from_disk_with_base_path(path)?;
-    input_data_at(existing, path, provide_data)?;
+    let base = read_data_from_disk_with_base_path(path)?;
+    let edited = provide_data(base.clone())?;
+    let _lock = lock_migrated_data(path)?;
+    let current = read_data_from_disk_with_base_path(path)?;
+    save_data_while_locked(merge_edit(&base, edited, current)?, path, "Edit data")?;
     info!("✅ Data edit done");
     Ok(())
 }
 
+/// Initializes the data directory at `write_path` with the data returned by
+/// `provide_data`, e.g. interactive prompts, which run before the data
+/// directory is locked to save the data.
 pub fn init_data_at(
     write_path: impl AsRef<Path>,
     provide_data: impl FnOnce(Data) -> Result<Data>,
 ) -> Result<()> {
     let write_path = write_path.as_ref();
     info!("Initializing data directory at: {}", write_path.display());
-    input_data_at(Data::sample(), write_path, provide_data)?;
+    let data = provide_data(Data::sample())?;
+    let _lock = lock_migrated_data(write_path)?;
+    save_data_while_locked(data, write_path, "Initialize data")?;
     info!("✅ Data init done, you're ready: `{} invoice`", BINARY_NAME);
     Ok(())
 }
 
-fn mutate<D: Serialize + DeserializeOwned + Clone>(
+/// Sets the field at the dotted path `field` of the data at `data_path`,
+/// e.g. `vendor.company_name`, to `value`, see [`Data::with_value_at`].
+pub fn set_data_value_with_base_path(
+    field: &str,
+    value: &str,
     data_path: impl AsRef<Path>,
-    data_file_name: &str,
-    mutate: impl FnOnce(&mut D),
 ) -> Result<()> {
     let data_path = data_path.as_ref();
+    info!("Setting {} of data at: {}", field, data_path.display());
+    let _lock = lock_migrated_data(data_path)?;
+    let data = read_data_from_disk_with_base_path(data_path)?.with_value_at(field, value)?;
+    save_data_while_locked(data, data_path, &format!("Set {} to: {}", field, value))?;
+    info!("✅ Data set");
+    Ok(())
+}
+
+/// Loads the data file `data_file_name`, mutates it and saves it, unless
+/// `mutate` fails, returning the output of `mutate`. The file as it was
+/// before is recorded in the history of the data, described by
+/// `description`. The data directory is locked meanwhile, so that a
+/// concurrently running klirr cannot interleave its writes.
+fn mutate<D: Serialize + DeserializeOwned + Clone, R>(
+    data_path: impl AsRef<Path>,
+    data_file_name: &str,
+    description: String,
+    mutate: impl FnOnce(&mut D) -> Result<R>,
+) -> Result<R> {
+    let data_path = data_path.as_ref();
+    info!("{}", description);
+    let _lock = lock_migrated_data(data_path)?;
     let mut data = load_data::<D>(data_path, data_file_name)?.clone();
-    mutate(&mut data);
+    let output = mutate(&mut data)?;
+    record_data_revision(data_path, description, &[data_file_name])?;
     let path = path_to_ron_file_with_base(data_path, data_file_name);
     save_to_disk(&data, path)?;
-    Ok(())
+    Ok(output)
 }
 
 pub fn record_expenses_with_base_path(
@@ -76,12 +172,13 @@ pub fn record_expenses_with_base_path(
     expenses: &[Item],
     data_path: impl AsRef<Path>,
 ) -> Result<()> {
-    info!("Recording #{} expenses for: {}", expenses.len(), month);
     mutate(
         data_path,
         DATA_FILE_NAME_EXPENSES,
+        format!("Recording #{} expenses for: {}", expenses.len(), month),
         |data: &mut ExpensedMonths| {
             data.insert_expenses(month, expenses.to_vec());
+            Ok(())
         },
     )
     .inspect(|_| {
@@ -93,12 +190,13 @@ pub fn record_month_off_with_base_path(
     month: &YearAndMonth,
     data_path: impl AsRef<Path>,
 ) -> Result<()> {
-    info!("Recording month off for: {}", month);
     mutate(
         data_path,
         DATA_FILE_NAME_PROTO_INVOICE_INFO,
+        format!("Recording month off for: {}", month),
         |data: &mut ProtoInvoiceInfo| {
             data.insert_month_off(*month);
+            Ok(())
         },
     )
     .inspect(|_| {
@@ -106,6 +204,253 @@ pub fn record_month_off_with_base_path(
     })
 }
 
+/// Removes `month` from the record of months off, e.g. if it was recorded by
+/// mistake, making it invoiceable again.
+pub fn remove_month_off_with_base_path(
+    month: &YearAndMonth,
+    data_path: impl AsRef<Path>,
+) -> Result<()> {
+    mutate(
+        data_path,
+        DATA_FILE_NAME_PROTO_INVOICE_INFO,
+        format!("Removing month off: {}", month),
+        |data: &mut ProtoInvoiceInfo| data.remove_month_off(month),
+    )
+    .inspect(|_| {
+        info!("✅ Month off removed successfully");
+    })
+}
+
+/// Records the days of `ranges` as days off, which are not invoiced for.
+pub fn record_days_off_with_base_path(
+    ranges: &[DateRange],
+    data_path: impl AsRef<Path>,
+) -> Result<()> {
+    mutate(
+        data_path,
+        DATA_FILE_NAME_PROTO_INVOICE_INFO,
+        format!(
+            "Recording days off: {}",
+            ranges
+                .iter()
+                .map(DateRange::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        |data: &mut ProtoInvoiceInfo| {
+            ranges.iter().for_each(|range| data.insert_days_off(*range));
+            Ok(())
+        },
+    )
+    .inspect(|_| {
+        info!("✅ Days off recorded successfully");
+    })
+}
+
+/// Removes the days of `ranges` from the record of days off, failing without
+/// saving if none of the days of a range are recorded.
+pub fn remove_days_off_with_base_path(
+    ranges: &[DateRange],
+    data_path: impl AsRef<Path>,
+) -> Result<()> {
+    mutate(
+        data_path,
+        DATA_FILE_NAME_PROTO_INVOICE_INFO,
+        format!(
+            "Removing days off: {}",
+            ranges
+                .iter()
+                .map(DateRange::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        |data: &mut ProtoInvoiceInfo| {
+            ranges
+                .iter()
+                .try_for_each(|range| data.remove_days_off(range))
+        },
+    )
+    .inspect(|_| {
+        info!("✅ Days off removed successfully");
+    })
+}
+
+/// Returns the recorded expenses of `month`, or of all months if `month` is
+/// `None`.
+pub fn expenses_with_base_path(
+    month: Option<&YearAndMonth>,
+    data_path: impl AsRef<Path>,
+) -> Result<ExpensedMonths> {
+    expensed_months(data_path).map(|expenses| expenses.filtered(month))
+}
+
+/// Removes the expense at `index` of `month`, returning the removed expense.
+pub fn remove_expense_with_base_path(
+    month: &YearAndMonth,
+    index: usize,
+    data_path: impl AsRef<Path>,
+) -> Result<Item> {
+    mutate(
+        data_path,
+        DATA_FILE_NAME_EXPENSES,
+        format!("Removing expense at index {} of: {}", index, month),
+        |data: &mut ExpensedMonths| data.remove_expense(month, index),
+    )
+    .inspect(|removed| {
+        info!("✅ Expense removed successfully: {}", removed);
+    })
+}
+
+/// Replaces the expense at `index` of `month` with the expense returned by
+/// `provide_item`, which is passed the current expense, returning the new
+/// expense.
+pub fn edit_expense_with_base_path(
+    month: &YearAndMonth,
+    index: usize,
+    data_path: impl AsRef<Path>,
+    provide_item: impl FnOnce(Item) -> Result<Item>,
+) -> Result<Item> {
+    mutate(
+        data_path,
+        DATA_FILE_NAME_EXPENSES,
+        format!("Editing expense at index {} of: {}", index, month),
+        |data: &mut ExpensedMonths| {
+            let item = provide_item(data.expense(month, index)?)?;
+            data.replace_expense(month, index, item.clone())?;
+            Ok(item)
+        },
+    )
+    .inspect(|item| {
+        info!("✅ Expense edited successfully: {}", item);
+    })
+}
+
+/// Moves all expenses of `from` to `to`, returning the number of moved
+/// expenses.
+pub fn move_expenses_with_base_path(
+    from: &YearAndMonth,
+    to: &YearAndMonth,
+    data_path: impl AsRef<Path>,
+) -> Result<usize> {
+    mutate(
+        data_path,
+        DATA_FILE_NAME_EXPENSES,
+        format!("Moving expenses from {} to {}", from, to),
+        |data: &mut ExpensedMonths| data.move_expenses(from, to),
+    )
+    .inspect(|count| {
+        info!("✅ Moved #{} expenses successfully", count);
+    })
+}
+
+/// Adds `expenses` to the expenses billed every month, replacing recurring
+/// expenses with the same names.
+pub fn add_recurring_expenses_with_base_path(
+    expenses: &[RecurringExpense],
+    data_path: impl AsRef<Path>,
+) -> Result<()> {
+    mutate(
+        data_path,
+        DATA_FILE_NAME_EXPENSES,
+        format!("Adding #{} recurring expenses", expenses.len()),
+        |data: &mut ExpensedMonths| {
+            for expense in expenses {
+                if let Some(replaced) = data.add_recurring(expense.clone()) {
+                    info!("Replacing recurring expense: {}", replaced);
+                }
+            }
+            Ok(())
+        },
+    )
+    .inspect(|_| {
+        info!("✅ Recurring expenses added successfully");
+    })
+}
+
+/// Removes the recurring expense named `name`, returning the removed expense.
+pub fn remove_recurring_expense_with_base_path(
+    name: &str,
+    data_path: impl AsRef<Path>,
+) -> Result<RecurringExpense> {
+    mutate(
+        data_path,
+        DATA_FILE_NAME_EXPENSES,
+        format!("Removing recurring expense: {}", name),
+        |data: &mut ExpensedMonths| data.remove_recurring(name),
+    )
+    .inspect(|removed| {
+        info!("✅ Recurring expense removed successfully: {}", removed);
+    })
+}
+
+/// Skips the recurring expense named `name` in `month`.
+pub fn skip_recurring_expense_with_base_path(
+    name: &str,
+    month: &YearAndMonth,
+    data_path: impl AsRef<Path>,
+) -> Result<()> {
+    mutate(
+        data_path,
+        DATA_FILE_NAME_EXPENSES,
+        format!("Skipping recurring expense {} in: {}", name, month),
+        |data: &mut ExpensedMonths| data.skip_recurring(name, month),
+    )
+    .map(|skipped| {
+        if skipped {
+            info!("✅ Recurring expense skipped successfully");
+        } else {
+            warn!(
+                "Recurring expense {} is not due in {}, nothing to skip",
+                name, month
+            );
+        }
+    })
+}
+
+/// Records `payment` towards an issued invoice, which must be present in the
+/// ledger of issued invoices at `data_path`, along with the kind of the
+/// invoice paid. The data directory is locked meanwhile.
+pub fn record_payment_with_base_path(payment: Payment, data_path: impl AsRef<Path>) -> Result<()> {
+    let data_path = data_path.as_ref();
+    info!(
+        "Recording payment of {} towards invoice {}",
+        payment.amount(),
+        payment.invoice()
+    );
+    if **payment.amount() <= rust_decimal::Decimal::ZERO {
+        return Err(Error::NonPositivePaymentAmount {
+            amount: *payment.amount(),
+        });
+    }
+    let _lock = lock_migrated_data(data_path)?;
+    let ledger = invoice_ledger(data_path)?;
+    let invoice = ledger.get(payment.invoice(), *payment.kind())?;
+    let payment = Payment::builder()
+        .invoice(*invoice.number())
+        .kind(*invoice.kind())
+        .amount(*payment.amount())
+        .date(*payment.date())
+        .build();
+    let mut payments = payments(data_path)?;
+    payments.record(payment);
+    save_to_disk(
+        &payments,
+        path_to_ron_file_with_base(data_path, DATA_FILE_NAME_PAYMENTS),
+    )
+    .inspect(|_| {
+        info!("✅ Payment recorded successfully");
+    })
+}
+
+/// Returns all issued invoices at `data_path` not yet paid in full, with
+/// days overdue counted at `date`.
+pub fn receivables_with_base_path(data_path: impl AsRef<Path>, date: &Date) -> Result<Receivables> {
+    let data_path = data_path.as_ref();
+    let ledger = invoice_ledger(data_path)?;
+    let payments = payments(data_path)?;
+    Ok(Receivables::new(&ledger, &payments, date))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -180,6 +525,123 @@ mod tests {
         assert!(data.months_off_record().contains(&month));
     }
 
+    #[test]
+    fn test_remove_month_off_with_base_path() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        let month = YearAndMonth::may(2025);
+        save_to_disk(
+            &ProtoInvoiceInfo::sample(),
+            path_to_ron_file_with_base(tempdir.path(), DATA_FILE_NAME_PROTO_INVOICE_INFO),
+        )
+        .unwrap();
+        record_month_off_with_base_path(&month, tempdir.path()).unwrap();
+        remove_month_off_with_base_path(&month, tempdir.path()).unwrap();
+
+        let data = proto_invoice_info(tempdir.path()).unwrap();
+        assert_eq!(data, ProtoInvoiceInfo::sample());
+        assert!(matches!(
+            remove_month_off_with_base_path(&month, tempdir.path()),
+            Err(Error::MonthNotInRecordOfMonthsOff { .. })
+        ));
+    }
+
+    #[test]
+    fn test_record_and_remove_days_off_with_base_path() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        save_to_disk(
+            &ProtoInvoiceInfo::sample(),
+            path_to_ron_file_with_base(tempdir.path(), DATA_FILE_NAME_PROTO_INVOICE_INFO),
+        )
+        .unwrap();
+        let range: DateRange = "2025-07-01..2025-07-18".parse().unwrap();
+        record_days_off_with_base_path(&[range], tempdir.path()).unwrap();
+        let data = proto_invoice_info(tempdir.path()).unwrap();
+        assert_eq!(
+            data.days_off_record()
+                .days_off_in(&YearAndMonth::july(2025)),
+            &[range]
+        );
+
+        // Nothing is removed if any of the ranges are not recorded.
+        let result =
+            remove_days_off_with_base_path(&[range, "2025-08-01".parse().unwrap()], tempdir.path());
+        assert!(matches!(result, Err(Error::DaysOffNotInRecord { .. })));
+        assert_eq!(proto_invoice_info(tempdir.path()).unwrap(), data);
+
+        remove_days_off_with_base_path(&[range], tempdir.path()).unwrap();
+        let data = proto_invoice_info(tempdir.path()).unwrap();
+        assert!(data.days_off_record().is_empty());
+    }
+
+    fn tempdir_with_expenses() -> tempfile::TempDir {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        save_to_disk(
+            &ExpensedMonths::sample(),
+            path_to_ron_file_with_base(tempdir.path(), DATA_FILE_NAME_EXPENSES),
+        )
+        .unwrap();
+        tempdir
+    }
+
+    #[test]
+    fn test_remove_expense_with_base_path() {
+        let tempdir = tempdir_with_expenses();
+        let month = YearAndMonth::january(2024);
+        let removed = remove_expense_with_base_path(&month, 0, tempdir.path()).unwrap();
+        assert_eq!(removed, Item::sample_expense_coffee());
+        let expenses = expenses_with_base_path(Some(&month), tempdir.path()).unwrap();
+        assert_eq!(
+            expenses.get(&month).unwrap(),
+            vec![Item::sample_expense_sandwich()]
+        );
+    }
+
+    #[test]
+    fn test_remove_expense_with_base_path_out_of_bounds_does_not_save() {
+        let tempdir = tempdir_with_expenses();
+        let result = remove_expense_with_base_path(&YearAndMonth::january(2024), 9, tempdir.path());
+        assert!(result.is_err());
+        assert_eq!(
+            expensed_months(tempdir.path()).unwrap(),
+            ExpensedMonths::sample()
+        );
+    }
+
+    #[test]
+    fn test_edit_expense_with_base_path() {
+        let tempdir = tempdir_with_expenses();
+        let month = YearAndMonth::february(2024);
+        edit_expense_with_base_path(&month, 0, tempdir.path(), |existing| {
+            assert_eq!(existing, Item::sample_expense_breakfast());
+            let edited = Item::builder()
+                .name(existing.name().clone())
+                .unit_price(*existing.unit_price())
+                .currency(*existing.currency())
+                .quantity(Quantity::from(dec!(3)))
+                .transaction_date(*existing.transaction_date())
+                .build();
+            Ok(edited)
+        })
+        .unwrap();
+        let expenses = expensed_months(tempdir.path()).unwrap();
+        assert_eq!(
+            *expenses.get(&month).unwrap()[0].quantity(),
+            Quantity::from(dec!(3))
+        );
+    }
+
+    #[test]
+    fn test_move_expenses_with_base_path() {
+        let tempdir = tempdir_with_expenses();
+        let from = YearAndMonth::january(2024);
+        let to = YearAndMonth::may(2025);
+        let moved = move_expenses_with_base_path(&from, &to, tempdir.path()).unwrap();
+        assert_eq!(moved, 2);
+        let expenses = expensed_months(tempdir.path()).unwrap();
+        assert!(!expenses.contains(&from));
+        assert_eq!(expenses.get(&to).unwrap().len(), 2);
+    }
+
     #[test]
     fn test_record_expenses_with_base_path() {
         let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
@@ -198,6 +660,31 @@ mod tests {
         assert!(data.contains(&month));
     }
 
+    #[test]
+    fn test_add_skip_and_remove_recurring_expenses_with_base_path() {
+        let tempdir = tempdir_with_expenses();
+        let month = YearAndMonth::may(2025);
+        add_recurring_expenses_with_base_path(&[RecurringExpense::sample()], tempdir.path())
+            .unwrap();
+        let expenses = expensed_months(tempdir.path()).unwrap();
+        assert!(*expenses.get(&month).unwrap()[0].recurring());
+
+        skip_recurring_expense_with_base_path("Phone subscription", &month, tempdir.path())
+            .unwrap();
+        assert!(!expensed_months(tempdir.path()).unwrap().contains(&month));
+
+        remove_recurring_expense_with_base_path("Phone subscription", tempdir.path()).unwrap();
+        assert!(
+            expensed_months(tempdir.path())
+                .unwrap()
+                .recurring()
+                .is_empty()
+        );
+        assert!(
+            remove_recurring_expense_with_base_path("Phone subscription", tempdir.path()).is_err()
+        );
+    }
+
     #[test]
     fn test_data_selector_includes() {
         let all_selector = DataSelector::All;
@@ -253,4 +740,82 @@ mod tests {
         let edited_data = read_data_from_disk_with_base_path(tempdir.path()).unwrap();
         assert_eq!(*edited_data.client(), second);
     }
+
+    #[test]
+    fn test_set_data_value_with_base_path() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        save_data_with_base_path(Data::sample(), tempdir.path()).unwrap();
+        set_data_value_with_base_path("vendor.company_name", "Foo AB", tempdir.path()).unwrap();
+        let data = read_data_from_disk_with_base_path(tempdir.path()).unwrap();
+        assert_eq!(data.vendor().company_name(), "Foo AB");
+        assert_eq!(
+            data_history_with_base_path(tempdir.path()).unwrap()[0].description(),
+            "Set vendor.company_name to: Foo AB"
+        );
+        assert!(set_data_value_with_base_path("vendor.nope", "Foo AB", tempdir.path()).is_err());
+    }
+
+    #[test]
+    fn test_init_data_from_file() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        let json = tempdir.path().join("data.json");
+        std::fs::write(&json, serde_json::to_string(&Data::sample()).unwrap()).unwrap();
+        let ron = tempdir.path().join("data.ron");
+        std::fs::write(&ron, to_ron_string(&Data::sample()).unwrap()).unwrap();
+        for file in [json, ron] {
+            let data_path = tempdir.path().join("data");
+            init_data_at(&data_path, |_| read_data_from_file(&file)).unwrap();
+            assert_eq!(
+                read_data_from_disk_with_base_path(&data_path).unwrap(),
+                Data::sample()
+            );
+            std::fs::remove_dir_all(&data_path).unwrap();
+        }
+        let text = tempdir.path().join("data.txt");
+        std::fs::write(&text, "").unwrap();
+        assert!(matches!(
+            read_data_from_file(&text),
+            Err(Error::UnsupportedDataFileFormat { .. })
+        ));
+    }
+
+    #[test]
+    fn test_record_payment_with_base_path() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        record_issued_invoice(tempdir.path(), IssuedInvoice::sample()).unwrap();
+        record_payment_with_base_path(Payment::sample(), tempdir.path()).unwrap();
+        record_payment_with_base_path(Payment::sample(), tempdir.path()).unwrap();
+
+        let recorded = payments(tempdir.path()).unwrap();
+        assert_eq!(recorded.iter().count(), 2);
+
+        let receivables =
+            receivables_with_base_path(tempdir.path(), &Date::from_str("2025-07-01").unwrap())
+                .unwrap();
+        assert_eq!(*receivables[0].outstanding(), Cost::from(dec!(1324)));
+        assert_eq!(*receivables[0].days_overdue(), Some(1));
+    }
+
+    #[test]
+    fn test_record_payment_for_unknown_invoice_fails() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        let result = record_payment_with_base_path(Payment::sample(), tempdir.path());
+        assert!(matches!(result, Err(Error::InvoiceNotFoundInLedger { .. })));
+    }
+
+    #[test]
+    fn test_record_payment_non_positive_amount_fails() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        record_issued_invoice(tempdir.path(), IssuedInvoice::sample()).unwrap();
+        let payment = Payment::builder()
+            .invoice(InvoiceNumber::from(243))
+            .amount(Cost::from(dec!(0)))
+            .date(Date::sample())
+            .build();
+        let result = record_payment_with_base_path(payment, tempdir.path());
+        assert!(matches!(
+            result,
+            Err(Error::NonPositivePaymentAmount { .. })
+        ));
+    }
 }
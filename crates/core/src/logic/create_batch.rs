@@ -0,0 +1,309 @@
+use crate::prelude::*;
+
+/// Why the invoice of `kind` for `month` should not be created, if any.
+fn skip_reason(data: &Data, month: &YearAndMonth, kind: InvoiceKind) -> Option<SkipReason> {
+    let information = data.information();
+    if month < information.offset().month() {
+        return Some(SkipReason::BeforeOffset);
+    }
+    match kind {
+        InvoiceKind::Services | InvoiceKind::Combined
+            if information.months_off_record().contains(month) =>
+        {
+            Some(SkipReason::MonthOff)
+        }
+        InvoiceKind::Expenses if !data.expensed_months().contains(month) => {
+            Some(SkipReason::NoExpenses)
+        }
+        _ => None,
+    }
+}
+
+/// Generates the invoices of all kinds in `input` for every month in its
+/// range, reading the data from disk at `data_base_path` once and recording
+/// each issued invoice in the [`InvoiceLedger`] at the same path.
+///
+/// Services and combined invoices are skipped for months in the
+/// [`MonthsOffRecord`] and expenses invoices for months without expenses.
+/// Since `render` is called once per invoice it should reuse whatever it can
+/// between calls.
+///
+/// # Errors
+/// Returns an error if the range of months is invalid, if `input` has both
+/// combined and separate kinds of invoices, or if any invoice fails to be
+/// created, in which case the invoices created before it are kept and
+/// recorded.
+pub fn create_pdfs_batch_with_data_base_path(
+    data_base_path: impl AsRef<Path>,
+    input: ValidBatchInput,
+    render: impl Fn(L18n, PreparedData, Layout) -> Result<Pdf>,
+) -> Result<BatchSummary> {
+    let data_base_path = data_base_path.as_ref();
+    let months = input.months()?;
+    let kinds = input.kinds();
+    if kinds.iter().any(InvoiceKind::is_combined) && !kinds.iter().all(InvoiceKind::is_combined) {
+        return Err(Error::CombinedAndSeparateKindsInBatch);
+    }
+    let mut data = read_data_from_disk_with_base_path(data_base_path)?;
+    let l18n = get_localization(input.language())?;
+    let exchange_rates_fetcher = ExchangeRatesFetcher::default();
+    let mut summary = BatchSummary::default();
+    for month in months {
+        for kind in input.kinds() {
+            let kind = *kind;
+            if let Some(reason) = skip_reason(&data, &month, kind) {
+                debug!("Skipping {} invoice for {}: {}", kind, month, reason);
+                summary.push_skipped(
+                    SkippedBatchInvoice::builder()
+                        .month(month)
+                        .kind(kind)
+                        .reason(reason)
+                        .build(),
+                );
+                continue;
+            }
+            let items = match kind {
+                InvoiceKind::Services => InvoicedItems::Service { days_off: None },
+                InvoiceKind::Expenses => InvoicedItems::Expenses,
+                InvoiceKind::Combined => InvoicedItems::Combined { days_off: None },
+            };
+            let valid_input = ValidInput::builder()
+                .language(*input.language())
+                .layout(*input.layout())
+                .month(month)
+                .items(items)
+                .service_quantities(input.service_quantities().clone())
+                .adjustments(input.adjustments().clone())
+                .build();
+            info!("🔮 Creating {} invoice for {}...", kind, month);
+            let mut prepared = attach_receipts(
+                prepare_invoice_input_data(data.clone(), valid_input, &exchange_rates_fetcher)?,
+                data_base_path,
+            )?;
+            if let Some(directory) = input.output_directory() {
+                prepared = prepared.in_directory(directory);
+            }
+            let (path, issued) = render_and_issue(
+                l18n.clone(),
+                prepared,
+                *input.layout(),
+                &render,
+                data_base_path,
+                data.information().numbering(),
+            )?;
+            let number = *issued.number();
+            // Later invoices of the batch are numbered after this one.
+            let mut ledger = data.ledger().clone();
+            ledger.record(issued);
+            data = data.with_ledger(ledger);
+            summary.push_created(
+                BatchInvoice::builder()
+                    .month(month)
+                    .kind(kind)
+                    .number(number)
+                    .path(path)
+                    .build(),
+            );
+        }
+    }
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::{Cell, RefCell};
+    use test_log::test;
+
+    fn fake_render(_: L18n, _: PreparedData, _: Layout) -> Result<Pdf> {
+        Ok(Pdf::from(Vec::from(b"%PDF-1.4")))
+    }
+
+    #[test]
+    fn test_batch_skips_months_off_and_months_without_expenses() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let data = Data::sample();
+        let month_off = YearAndMonth::march(2021);
+        assert!(data.information().months_off_record().contains(&month_off));
+        save_data_with_base_path(data, tempdir.path()).unwrap();
+        let input = ValidBatchInput::builder()
+            .from(YearAndMonth::february(2021))
+            .to(YearAndMonth::april(2021))
+            .kinds(vec![InvoiceKind::Services])
+            .output_directory(tempdir.path().to_path_buf())
+            .build();
+        let render_count = Cell::new(0);
+        let summary = create_pdfs_batch_with_data_base_path(tempdir.path(), input, |l, d, t| {
+            render_count.set(render_count.get() + 1);
+            fake_render(l, d, t)
+        })
+        .unwrap();
+        assert_eq!(render_count.get(), 2);
+        assert_eq!(summary.created().len(), 2);
+        assert_eq!(
+            summary.skipped(),
+            &vec![
+                SkippedBatchInvoice::builder()
+                    .month(month_off)
+                    .kind(InvoiceKind::Services)
+                    .reason(SkipReason::MonthOff)
+                    .build()
+            ]
+        );
+        for invoice in summary.created() {
+            assert!(invoice.path().starts_with(tempdir.path()));
+            assert!(invoice.path().exists());
+        }
+        let ledger = invoice_ledger(tempdir.path()).unwrap();
+        assert_eq!(ledger.iter().count(), 2);
+    }
+
+    #[test]
+    fn test_batch_skips_expenses_when_none_recorded() {
+        let tempdir = tempfile::tempdir().unwrap();
+        save_data_with_base_path(Data::sample(), tempdir.path()).unwrap();
+        let input = ValidBatchInput::builder()
+            .from(YearAndMonth::january(2021))
+            .to(YearAndMonth::january(2021))
+            .kinds(vec![InvoiceKind::Expenses])
+            .build();
+        let summary =
+            create_pdfs_batch_with_data_base_path(tempdir.path(), input, fake_render).unwrap();
+        assert!(summary.created().is_empty());
+        assert_eq!(*summary.skipped()[0].reason(), SkipReason::NoExpenses);
+    }
+
+    #[test]
+    fn test_batch_numbers_invoices_sequentially() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let data = Data::sample();
+        let information = data
+            .information()
+            .clone()
+            .with_numbering(InvoiceNumbering::sample());
+        save_data_with_base_path(data.with_information(information), tempdir.path()).unwrap();
+        let input = || {
+            ValidBatchInput::builder()
+                .from(YearAndMonth::march(2025))
+                .to(YearAndMonth::may(2025))
+                .kinds(vec![InvoiceKind::Services])
+                .output_directory(tempdir.path().to_path_buf())
+                .build()
+        };
+        let summary =
+            create_pdfs_batch_with_data_base_path(tempdir.path(), input(), fake_render).unwrap();
+        let numbers = summary
+            .created()
+            .iter()
+            .map(|invoice| **invoice.number())
+            .collect::<Vec<_>>();
+        assert_eq!(numbers.len(), 3);
+        assert_eq!(numbers[1], numbers[0] + 1);
+        assert_eq!(numbers[2], numbers[0] + 2);
+        let file_name = |index: usize| {
+            summary.created()[index]
+                .path()
+                .file_name()
+                .unwrap()
+                .to_string_lossy()
+                .to_string()
+        };
+        assert!(file_name(0).ends_with("_invoice_ACME-2025-001.pdf"));
+        assert!(file_name(2).ends_with("_invoice_ACME-2025-003.pdf"));
+
+        // Generating the invoices again reuses their numbers.
+        let again =
+            create_pdfs_batch_with_data_base_path(tempdir.path(), input(), fake_render).unwrap();
+        assert_eq!(again, summary);
+        let ledger = invoice_ledger(tempdir.path()).unwrap();
+        assert_eq!(ledger.iter().count(), 3);
+        assert_eq!(
+            ledger.iter().last().unwrap().identifier().to_string(),
+            "ACME-2025-003"
+        );
+    }
+
+    #[test]
+    fn test_batch_of_combined_and_separate_kinds_fails() {
+        let tempdir = tempfile::tempdir().unwrap();
+        save_data_with_base_path(Data::sample(), tempdir.path()).unwrap();
+        let input = ValidBatchInput::builder()
+            .from(YearAndMonth::february(2021))
+            .to(YearAndMonth::april(2021))
+            .kinds(vec![InvoiceKind::Services, InvoiceKind::Combined])
+            .output_directory(tempdir.path().to_path_buf())
+            .build();
+        let result = create_pdfs_batch_with_data_base_path(tempdir.path(), input, fake_render);
+        assert!(matches!(
+            result,
+            Err(Error::CombinedAndSeparateKindsInBatch)
+        ));
+        let data = read_data_from_disk_with_base_path(tempdir.path()).unwrap();
+        assert_eq!(data.ledger().iter().count(), 0);
+    }
+
+    #[test]
+    fn test_batch_with_service_quantity_per_run() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let service_fees = ServiceFees::new([
+            ServiceFee::sample(),
+            ServiceFee::builder()
+                .name("On-call stipend")
+                .unit_price(UnitPrice::from(dec!(500)))
+                .quantity(ServiceQuantity::PerRun)
+                .build(),
+        ])
+        .unwrap();
+        save_data_with_base_path(
+            Data::sample().with_service_fees(service_fees),
+            tempdir.path(),
+        )
+        .unwrap();
+        let input = |service_quantities: Vec<ServiceQuantityOverride>| {
+            ValidBatchInput::builder()
+                .from(YearAndMonth::march(2025))
+                .to(YearAndMonth::april(2025))
+                .kinds(vec![InvoiceKind::Services])
+                .output_directory(tempdir.path().to_path_buf())
+                .service_quantities(service_quantities)
+                .build()
+        };
+        assert!(matches!(
+            create_pdfs_batch_with_data_base_path(tempdir.path(), input(vec![]), fake_render),
+            Err(Error::MissingServiceQuantity { .. })
+        ));
+
+        let rendered = RefCell::new(Vec::new());
+        let summary = create_pdfs_batch_with_data_base_path(
+            tempdir.path(),
+            input(vec![ServiceQuantityOverride::sample()]),
+            |l, data: PreparedData, t| {
+                rendered.borrow_mut().push(data.clone());
+                fake_render(l, data, t)
+            },
+        )
+        .unwrap();
+        assert_eq!(summary.created().len(), 2);
+        for data in rendered.borrow().iter() {
+            let stipend = data
+                .line_items()
+                .items()
+                .iter()
+                .find(|item| item.name() == "On-call stipend")
+                .unwrap();
+            assert_eq!(*stipend.quantity(), Quantity::from(dec!(2)));
+        }
+    }
+
+    #[test]
+    fn test_batch_invalid_range() {
+        let tempdir = tempfile::tempdir().unwrap();
+        save_data_with_base_path(Data::sample(), tempdir.path()).unwrap();
+        let input = ValidBatchInput::builder()
+            .from(YearAndMonth::june(2025))
+            .to(YearAndMonth::january(2025))
+            .build();
+        let result = create_pdfs_batch_with_data_base_path(tempdir.path(), input, fake_render);
+        assert!(result.is_err());
+    }
+}
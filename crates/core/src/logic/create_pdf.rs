@@ -2,13 +2,25 @@ use crate::prelude::*;
 
 /// Compile the Typst source into a PDF and save it at the specified path, by
 /// reading data from disk at the provided path and using the provided `ValidInput`.
+///
+/// The issued invoice is recorded in the [`InvoiceLedger`] at the same path.
 pub fn create_pdf_with_data_base_path(
     data_base_path: impl AsRef<Path>,
     input: ValidInput,
     render: impl Fn(L18n, PreparedData, Layout) -> Result<Pdf>,
 ) -> Result<PathBuf> {
+    let data_base_path = data_base_path.as_ref();
     let data = read_data_from_disk_with_base_path(data_base_path)?;
-    create_pdf_with_data(data, input, render)
+    let numbering = data.information().numbering().clone();
+    let l18n = get_localization(input.language())?;
+    let layout = *input.layout();
+    let data = attach_receipts(
+        prepare_invoice_input_data(data, input, ExchangeRatesFetcher::default())?,
+        data_base_path,
+    )?;
+    let (output_path, _) =
+        render_and_issue(l18n, data, layout, render, data_base_path, &numbering)?;
+    Ok(output_path)
 }
 
 /// Compile the Typst source into a PDF and save it at the specified path, using
@@ -21,11 +33,52 @@ pub fn create_pdf_with_data(
     let l18n = get_localization(input.language())?;
     let layout = *input.layout();
     let data = prepare_invoice_input_data(data, input, ExchangeRatesFetcher::default())?;
+    render_and_save(l18n, data, layout, render)
+}
+
+/// Renders `data` using `render` and saves the PDF at the output path of `data`.
+pub(crate) fn render_and_save(
+    l18n: L18n,
+    data: PreparedData,
+    layout: Layout,
+    render: impl Fn(L18n, PreparedData, Layout) -> Result<Pdf>,
+) -> Result<PathBuf> {
+    let (output_path, pdf) = render_pdf(l18n, data, layout, render)?;
+    save_pdf(pdf, &output_path)
+}
+
+/// Renders `data` using `render`, then saves the PDF at the output path of
+/// `data` and records the issued invoice in the [`InvoiceLedger`] at
+/// `data_base_path`, see [`issue_invoice`], returning where the PDF was
+/// saved and the issued invoice.
+pub(crate) fn render_and_issue(
+    l18n: L18n,
+    data: PreparedData,
+    layout: Layout,
+    render: impl Fn(L18n, PreparedData, Layout) -> Result<Pdf>,
+    data_base_path: &Path,
+    numbering: &InvoiceNumbering,
+) -> Result<(PathBuf, IssuedInvoice)> {
+    let issued = IssuedInvoice::from(&data);
+    let (output_path, pdf) = render_pdf(l18n, data, layout, render)?;
+    let output_path = issue_invoice(data_base_path, issued.clone(), numbering, || {
+        save_pdf(pdf, &output_path)
+    })?;
+    Ok((output_path, issued))
+}
+
+/// Renders `data` using `render`, returning the PDF and the output path of
+/// `data`, whose folder is created if needed.
+fn render_pdf(
+    l18n: L18n,
+    data: PreparedData,
+    layout: Layout,
+    render: impl Fn(L18n, PreparedData, Layout) -> Result<Pdf>,
+) -> Result<(PathBuf, Pdf)> {
     let output_path = data.absolute_path()?;
     create_folder_to_parent_of_path_if_needed(&output_path)?;
     let pdf = render(l18n, data, layout)?;
-    save_pdf(pdf, &output_path)?;
-    Ok(output_path)
+    Ok((output_path, pdf))
 }
 
 /// Saves the PDF file `pdf` to the specified path `pdf_path`.
@@ -64,6 +117,26 @@ mod tests {
         assert_eq!(result, dummy_pdf_data);
     }
 
+    #[test]
+    fn test_create_pdf_with_data_base_path_records_ledger() {
+        let tempdir = tempfile::tempdir().unwrap();
+        save_data_with_base_path(Data::sample(), tempdir.path()).unwrap();
+        let out = tempdir.path().join("invoice.pdf");
+        let input = ValidInput::builder()
+            .maybe_output_path(out.clone())
+            .month(YearAndMonth::sample())
+            .items(InvoicedItems::Service { days_off: None })
+            .build();
+        create_pdf_with_data_base_path(tempdir.path(), input, |_, _, _| {
+            Ok(Pdf::from(Vec::from(b"%PDF-1.4")))
+        })
+        .unwrap();
+        let ledger = invoice_ledger(tempdir.path()).unwrap();
+        let issued = ledger.iter().collect::<Vec<_>>();
+        assert_eq!(issued.len(), 1);
+        assert_eq!(*issued[0].kind(), InvoiceKind::Services);
+    }
+
     #[test]
     fn test_save_pdf() {
         let tmp_file = NamedTempFile::new().unwrap();
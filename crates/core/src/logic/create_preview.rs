@@ -0,0 +1,73 @@
+use crate::prelude::*;
+
+/// Renders a preview image of the invoice specified by `input`, by reading
+/// data from disk at the provided path, and saves it next to where the PDF
+/// would be saved, with the extension of `format`.
+///
+/// Unlike [`create_pdf_with_data_base_path`] the invoice is not recorded in
+/// the [`InvoiceLedger`], since a preview is not issued.
+pub fn create_preview_with_data_base_path(
+    data_base_path: impl AsRef<Path>,
+    input: ValidInput,
+    format: PreviewFormat,
+    render_preview: impl Fn(L18n, PreparedData, Layout, PreviewFormat) -> Result<Preview>,
+) -> Result<PathBuf> {
+    let data = read_data_from_disk_with_base_path(data_base_path)?;
+    create_preview_with_data(data, input, format, render_preview)
+}
+
+/// Renders a preview image of the invoice specified by `input` using `data`,
+/// saving it with the extension of `format`.
+pub fn create_preview_with_data(
+    data: Data,
+    input: ValidInput,
+    format: PreviewFormat,
+    render_preview: impl Fn(L18n, PreparedData, Layout, PreviewFormat) -> Result<Preview>,
+) -> Result<PathBuf> {
+    let l18n = get_localization(input.language())?;
+    let layout = *input.layout();
+    let data = prepare_invoice_input_data(data, input, ExchangeRatesFetcher::default())?;
+    let output_path = data.absolute_path()?.with_extension(format.extension());
+    create_folder_to_parent_of_path_if_needed(&output_path)?;
+    let preview = render_preview(l18n, data, layout, format)?;
+    info!("Saving {} preview to: '{}'", format, output_path.display());
+    std::fs::write(&output_path, preview.as_ref()).map_err(|e| Error::SavePreview {
+        underlying: format!("Write preview to {}: {}", output_path.display(), e),
+    })?;
+    info!(
+        "✅ Saved {} preview to: '{}'",
+        format,
+        output_path.display()
+    );
+    Ok(output_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn test_create_preview_replaces_extension_and_does_not_record_ledger() {
+        let tempdir = tempfile::tempdir().unwrap();
+        save_data_with_base_path(Data::sample(), tempdir.path()).unwrap();
+        let input = ValidInput::builder()
+            .maybe_output_path(tempdir.path().join("invoice.pdf"))
+            .month(YearAndMonth::sample())
+            .items(InvoicedItems::Service { days_off: None })
+            .build();
+        let path = create_preview_with_data_base_path(
+            tempdir.path(),
+            input,
+            PreviewFormat::Svg,
+            |_, _, _, format| {
+                assert_eq!(format, PreviewFormat::Svg);
+                Ok(Preview::from(Vec::from(b"<svg></svg>")))
+            },
+        )
+        .unwrap();
+        assert_eq!(path, tempdir.path().join("invoice.svg"));
+        assert_eq!(std::fs::read(&path).unwrap(), b"<svg></svg>");
+        assert_eq!(invoice_ledger(tempdir.path()).unwrap().iter().count(), 0);
+    }
+}
@@ -0,0 +1,280 @@
+use crate::prelude::*;
+
+/// Compile a reminder of an overdue invoice into a PDF and save it, by reading
+/// data and the ledger of issued invoices from disk at the provided path.
+pub fn create_reminder_pdf_with_data_base_path(
+    data_base_path: impl AsRef<Path>,
+    input: ValidReminderInput,
+    render: impl Fn(L18n, PreparedData, Layout) -> Result<Pdf>,
+) -> Result<PathBuf> {
+    let data_base_path = data_base_path.as_ref();
+    let data = read_data_from_disk_with_base_path(data_base_path)?;
+    let ledger = invoice_ledger(data_base_path)?;
+    let payments = payments(data_base_path)?;
+    create_reminder_pdf_with_data(data, &ledger, &payments, input, render)
+}
+
+/// Compile a reminder of an overdue invoice into a PDF and save it, using the
+/// provided `Data`, `InvoiceLedger` and `Payments`.
+pub fn create_reminder_pdf_with_data(
+    data: Data,
+    ledger: &InvoiceLedger,
+    payments: &Payments,
+    input: ValidReminderInput,
+    render: impl Fn(L18n, PreparedData, Layout) -> Result<Pdf>,
+) -> Result<PathBuf> {
+    let l18n = get_localization(input.language())?;
+    let data = prepare_reminder_data(data, ledger, payments, &input, l18n.content().reminder())?;
+    render_and_save(l18n, data, Layout::Reminder, render)
+}
+
+/// Prepares the data for rendering a reminder of the invoice with number
+/// specified in `input`, which must have been issued and be overdue.
+///
+/// The line items of the reminder are the outstanding amount of the invoice,
+/// i.e. the total less any recorded `payments`, the late interest on it from
+/// the due date until the reminder date and the reminder fee, if any.
+///
+/// # Errors
+/// Returns an error if the invoice is not in the ledger, is paid in full or
+/// is not overdue at the reminder date.
+pub fn prepare_reminder_data(
+    data: Data,
+    ledger: &InvoiceLedger,
+    payments: &Payments,
+    input: &ValidReminderInput,
+    l18n: &L18nReminder,
+) -> Result<PreparedData> {
+    let issued = ledger.get(input.number(), *input.kind())?;
+    let reminder_date = *input.reminder_date();
+    let receivable =
+        Receivable::new(issued, payments, &reminder_date).ok_or(Error::InvoiceAlreadyPaid {
+            number: *issued.number(),
+        })?;
+    let Some(days_overdue) = *receivable.days_overdue() else {
+        return Err(Error::InvoiceIsNotOverdue {
+            number: *issued.number(),
+            due_date: *issued.due_date(),
+            reminder_date,
+        });
+    };
+    let outstanding = *receivable.outstanding();
+    let policy = input.policy();
+    let currency = *issued.currency();
+    let interest_rate = policy.interest_rate();
+
+    let line_item = |name: String, date: Date, cost: Cost| {
+        let item = Item::builder()
+            .name(name)
+            .transaction_date(date)
+            .quantity(Quantity::from(Decimal::ONE))
+            .unit_price(UnitPrice::from(*cost))
+            .currency(currency)
+            .build();
+        ItemConvertedIntoTargetCurrency::builder()
+            .in_source_currency(item)
+            .total_cost(cost)
+            .build()
+    };
+
+    let mut items = vec![
+        line_item(
+            format!("{} {}", l18n.outstanding_amount(), issued.identifier()),
+            *issued.invoice_date(),
+            outstanding,
+        ),
+        line_item(
+            format!(
+                "{} {}, {} {}",
+                l18n.late_interest(),
+                interest_rate,
+                days_overdue,
+                l18n.days()
+            ),
+            reminder_date,
+            policy.late_interest(outstanding, days_overdue as i64),
+        ),
+    ];
+    if let Some(fee) = policy.reminder_fee() {
+        items.push(line_item(l18n.reminder_fee().clone(), reminder_date, *fee));
+    }
+
+    let vendor_name = data.vendor().company_name().replace(' ', "_");
+    let output_path = input
+        .maybe_output_path()
+        .as_ref()
+        .cloned()
+        .map(OutputPath::AbsolutePath)
+        .unwrap_or_else(|| {
+            OutputPath::Name(format!(
+                "{}_{}_reminder_invoice_{}.pdf",
+                reminder_date,
+                vendor_name,
+                issued.identifier().file_name_safe()
+            ))
+        });
+
+    let information = InvoiceInfoFull::builder()
+        .number(*issued.number())
+        .identifier(issued.identifier())
+        .invoice_date(*issued.invoice_date())
+        .due_date(*issued.due_date())
+        .emphasize_color_hex(
+            data.information()
+                .emphasize_color_hex()
+                .clone()
+                .unwrap_or_default(),
+        )
+        .footer_text(data.information().footer_text().clone())
+        .purchase_order(data.information().purchase_order().clone())
+        .reminder(
+            ReminderInfo::builder()
+                .reminder_date(reminder_date)
+                .days_overdue(days_overdue)
+                .interest_rate(interest_rate)
+                .build(),
+        )
+        .build();
+
+    Ok(PreparedData::builder()
+        .information(information)
+        .vendor(data.vendor().clone())
+        .client(data.client().clone())
+        .line_items(
+            LineItemsFlat::builder()
+                .is_expenses(false)
+                .items(items)
+                .build(),
+        )
+        .payment_info(data.payment_info().clone())
+        .output_path(output_path)
+        .build())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::NamedTempFile;
+    use test_log::test;
+
+    fn reminder_input(date: &str) -> ValidReminderInput {
+        ValidReminderInput::builder()
+            .number(InvoiceNumber::from(243))
+            .policy(LateInterestPolicy::sample())
+            .reminder_date(Date::from_str(date).unwrap())
+            .build()
+    }
+
+    #[test]
+    fn test_prepare_reminder_data() {
+        let data = prepare_reminder_data(
+            Data::sample(),
+            &InvoiceLedger::sample(),
+            &Payments::default(),
+            &reminder_input("2025-07-30"),
+            &L18nReminder::english(),
+        )
+        .unwrap();
+        let items = data.line_items().items();
+        assert_eq!(items.len(), 3);
+        assert_eq!(items[0].name(), "Outstanding amount, invoice 243");
+        assert_eq!(**items[0].total_cost(), dec!(9324));
+        assert_eq!(items[1].name(), "Late interest 10.25%, 30 days");
+        assert_eq!(**items[1].total_cost(), dec!(78.55));
+        assert_eq!(items[2].name(), "Reminder fee");
+        assert_eq!(**items[2].total_cost(), dec!(60));
+        let reminder = data.information().reminder().clone().unwrap();
+        assert_eq!(*reminder.days_overdue(), 30);
+        assert_eq!(
+            data.absolute_path()
+                .unwrap()
+                .file_name()
+                .unwrap()
+                .to_string_lossy(),
+            "2025-07-30_Lupin_et_Associés_reminder_invoice_243.pdf"
+        );
+    }
+
+    #[test]
+    fn test_prepare_reminder_data_partially_paid() {
+        let data = prepare_reminder_data(
+            Data::sample(),
+            &InvoiceLedger::sample(),
+            &Payments::sample(),
+            &reminder_input("2025-07-30"),
+            &L18nReminder::english(),
+        )
+        .unwrap();
+        let items = data.line_items().items();
+        assert_eq!(**items[0].total_cost(), dec!(5324));
+        // 5324 * 10.25% * 30 / 365 = 44.8537...
+        assert_eq!(**items[1].total_cost(), dec!(44.85));
+    }
+
+    #[test]
+    fn test_prepare_reminder_data_paid_in_full() {
+        let mut payments = Payments::default();
+        payments.record(
+            Payment::builder()
+                .invoice(InvoiceNumber::from(243))
+                .amount(Cost::from(dec!(9324)))
+                .date(Date::from_str("2025-06-28").unwrap())
+                .build(),
+        );
+        let result = prepare_reminder_data(
+            Data::sample(),
+            &InvoiceLedger::sample(),
+            &payments,
+            &reminder_input("2025-07-30"),
+            &L18nReminder::english(),
+        );
+        assert!(matches!(result, Err(Error::InvoiceAlreadyPaid { .. })));
+    }
+
+    #[test]
+    fn test_prepare_reminder_data_not_overdue() {
+        let result = prepare_reminder_data(
+            Data::sample(),
+            &InvoiceLedger::sample(),
+            &Payments::default(),
+            &reminder_input("2025-06-30"),
+            &L18nReminder::english(),
+        );
+        assert!(matches!(result, Err(Error::InvoiceIsNotOverdue { .. })));
+    }
+
+    #[test]
+    fn test_prepare_reminder_data_unknown_invoice() {
+        let result = prepare_reminder_data(
+            Data::sample(),
+            &InvoiceLedger::default(),
+            &Payments::default(),
+            &reminder_input("2025-07-30"),
+            &L18nReminder::english(),
+        );
+        assert!(matches!(result, Err(Error::InvoiceNotFoundInLedger { .. })));
+    }
+
+    #[test]
+    fn test_create_reminder_pdf_uses_reminder_layout() {
+        let out = NamedTempFile::new().unwrap().path().to_path_buf();
+        let input = ValidReminderInput::builder()
+            .number(InvoiceNumber::from(243))
+            .policy(LateInterestPolicy::sample())
+            .reminder_date(Date::from_str("2025-07-30").unwrap())
+            .maybe_output_path(out.clone())
+            .build();
+        let path = create_reminder_pdf_with_data(
+            Data::sample(),
+            &InvoiceLedger::sample(),
+            &Payments::default(),
+            input,
+            |_, _, layout| {
+                assert_eq!(layout, Layout::Reminder);
+                Ok(Pdf::from(Vec::from(b"%PDF-1.4")))
+            },
+        )
+        .unwrap();
+        assert_eq!(path, out);
+    }
+}
@@ -0,0 +1,281 @@
+use crate::prelude::*;
+
+/// The folder in the data directory with a file per revision of the data,
+/// e.g. `history/00001.ron`.
+pub const DATA_FOLDER_NAME_HISTORY: &str = "history";
+
+fn history_folder(base_path: impl AsRef<Path>) -> PathBuf {
+    base_path.as_ref().join(DATA_FOLDER_NAME_HISTORY)
+}
+
+fn revision_file_name(number: u32) -> String {
+    format!("{:05}", number)
+}
+
+fn read_file_if_exists(path: impl AsRef<Path>) -> Result<Option<String>> {
+    let path = path.as_ref();
+    if !path.exists() {
+        return Ok(None);
+    }
+    read_data_file(path).map(Some)
+}
+
+/// Reads all revisions of the data at `base_path`, oldest first, which is
+/// empty if no mutation has been recorded.
+pub fn data_history_with_base_path(base_path: impl AsRef<Path>) -> Result<DataHistory> {
+    let folder = history_folder(base_path);
+    if !folder.exists() {
+        return Ok(DataHistory::default());
+    }
+    let read_error = |e: std::io::Error| Error::FileNotFound {
+        path: folder.display().to_string(),
+        underlying: format!("{:?}", e),
+    };
+    let mut numbers = std::fs::read_dir(&folder)
+        .map_err(read_error)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .map_err(read_error)?
+        .into_iter()
+        .filter(|path| path.extension().is_some_and(|extension| extension == "ron"))
+        .filter_map(|path| path.file_stem()?.to_str()?.parse::<u32>().ok())
+        .collect::<Vec<_>>();
+    numbers.sort();
+    numbers
+        .into_iter()
+        .map(|number| load_data(&folder, &revision_file_name(number)))
+        .collect::<Result<Vec<DataRevision>>>()
+        .map(DataHistory::from)
+}
+
+/// Records the current contents of the data files `file_names` at
+/// `base_path` as a new revision described by `description`, called before
+/// the files are changed so that the change can be undone.
+pub fn record_data_revision(
+    base_path: impl AsRef<Path>,
+    description: impl Into<String>,
+    file_names: &[&str],
+) -> Result<DataRevision> {
+    let base_path = base_path.as_ref();
+    let number = data_history_with_base_path(base_path)?
+        .last()
+        .map(|revision| revision.number() + 1)
+        .unwrap_or(1);
+    let files = file_names
+        .iter()
+        .map(|name| {
+            read_file_if_exists(path_to_ron_file_with_base(base_path, name))
+                .map(|contents| ((*name).to_owned(), contents))
+        })
+        .collect::<Result<IndexMap<_, _>>>()?;
+    let revision = DataRevision::builder()
+        .number(number)
+        .created(Local::now().format("%Y-%m-%d %H:%M:%S").to_string())
+        .description(description)
+        .files(files)
+        .build();
+    let folder = history_folder(base_path);
+    create_folder_if_needed(&folder)?;
+    save_to_disk(
+        &revision,
+        path_to_ron_file_with_base(&folder, &revision_file_name(number)),
+    )?;
+    Ok(revision)
+}
+
+/// The changes of the data files at `base_path` since the revision
+/// `revision`, comparing the files as they were before that mutation with
+/// their current contents.
+///
+/// # Errors
+/// Returns an error if no revision `revision` is recorded.
+pub fn diff_data_with_base_path(
+    base_path: impl AsRef<Path>,
+    revision: u32,
+) -> Result<Vec<DataFileChange>> {
+    let base_path = base_path.as_ref();
+    let history = data_history_with_base_path(base_path)?;
+    let revision = history
+        .iter()
+        .find(|candidate| *candidate.number() == revision)
+        .ok_or(Error::UnknownDataRevision { revision })?;
+    revision
+        .files()
+        .iter()
+        .map(|(name, before)| {
+            let after = read_file_if_exists(path_to_ron_file_with_base(base_path, name))?;
+            Ok(DataFileChange::between(
+                format!("{}.ron", name),
+                before.as_deref().unwrap_or_default(),
+                after.as_deref().unwrap_or_default(),
+            ))
+        })
+        .collect::<Result<Vec<_>>>()
+        .map(|changes| changes.into_iter().flatten().collect())
+}
+
+/// Reverts the data at `base_path` to how it was before the mutation
+/// `to`, undoing it and every later one, or only the latest mutation if
+/// `to` is `None`. Returns the revisions undone, latest first, which are
+/// removed from the history.
+///
+/// # Errors
+/// Returns an error if no history is recorded or if no revision `to` is
+/// recorded.
+pub fn undo_data_with_base_path(
+    base_path: impl AsRef<Path>,
+    to: Option<u32>,
+) -> Result<Vec<DataRevision>> {
+    let base_path = base_path.as_ref();
+    let _lock = DataLock::acquire(base_path)?;
+    let history = data_history_with_base_path(base_path)?;
+    let latest = history.last().ok_or(Error::NoDataHistory)?;
+    let to = to.unwrap_or(*latest.number());
+    if !history.iter().any(|revision| *revision.number() == to) {
+        return Err(Error::UnknownDataRevision { revision: to });
+    }
+    let undone = history
+        .iter()
+        .rev()
+        .take_while(|revision| *revision.number() >= to)
+        .cloned()
+        .collect::<Vec<_>>();
+    let folder = history_folder(base_path);
+    for revision in &undone {
+        info!("Undoing #{}: {}", revision.number(), revision.description());
+        for (name, contents) in revision.files() {
+            let path = path_to_ron_file_with_base(base_path, name);
+            match contents {
+                Some(contents) => write_data_file(&path, contents)?,
+                None if path.exists() => {
+                    std::fs::remove_file(&path).map_err(|e| Error::FailedToWriteDataToDisk {
+                        underlying: format!("{:?}", e),
+                    })?
+                }
+                None => {}
+            }
+        }
+        std::fs::remove_file(path_to_ron_file_with_base(
+            &folder,
+            &revision_file_name(*revision.number()),
+        ))
+        .map_err(|e| Error::FailedToWriteDataToDisk {
+            underlying: format!("{:?}", e),
+        })?;
+    }
+    info!("✅ Undid {} revision(s) of the data", undone.len());
+    Ok(undone)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    fn data_dir() -> tempfile::TempDir {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        save_data_with_base_path(Data::sample(), tempdir.path()).unwrap();
+        tempdir
+    }
+
+    fn months_off(base_path: impl AsRef<Path>) -> usize {
+        let info: ProtoInvoiceInfo =
+            load_data(base_path, DATA_FILE_NAME_PROTO_INVOICE_INFO).unwrap();
+        info.months_off_record().len()
+    }
+
+    #[test]
+    fn test_history_is_empty_without_mutations() {
+        let tempdir = data_dir();
+        let history = data_history_with_base_path(tempdir.path()).unwrap();
+        assert!(history.is_empty());
+        assert_eq!(history.to_string(), "No history recorded.\n");
+        assert!(matches!(
+            undo_data_with_base_path(tempdir.path(), None),
+            Err(Error::NoDataHistory)
+        ));
+    }
+
+    #[test]
+    fn test_mutations_are_recorded_and_undone() {
+        let tempdir = data_dir();
+        let before = months_off(tempdir.path());
+        record_month_off_with_base_path(&YearAndMonth::may(2025), tempdir.path()).unwrap();
+        record_month_off_with_base_path(&YearAndMonth::june(2025), tempdir.path()).unwrap();
+        assert_eq!(months_off(tempdir.path()), before + 2);
+
+        let history = data_history_with_base_path(tempdir.path()).unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[1].description(), "Recording month off for: 2025-06");
+        assert_eq!(
+            history[1].files().keys().collect::<Vec<_>>(),
+            vec![DATA_FILE_NAME_PROTO_INVOICE_INFO]
+        );
+
+        let changes = diff_data_with_base_path(tempdir.path(), 1).unwrap();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].file_name(), "invoice_info.ron");
+
+        let undone = undo_data_with_base_path(tempdir.path(), None).unwrap();
+        assert_eq!(undone.len(), 1);
+        assert_eq!(months_off(tempdir.path()), before + 1);
+        assert_eq!(
+            data_history_with_base_path(tempdir.path()).unwrap().len(),
+            1
+        );
+
+        undo_data_with_base_path(tempdir.path(), Some(1)).unwrap();
+        assert_eq!(months_off(tempdir.path()), before);
+        assert!(
+            data_history_with_base_path(tempdir.path())
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_undo_to_several_revisions_back() {
+        let tempdir = data_dir();
+        let before = months_off(tempdir.path());
+        for month in [
+            YearAndMonth::may(2025),
+            YearAndMonth::june(2025),
+            YearAndMonth::july(2025),
+        ] {
+            record_month_off_with_base_path(&month, tempdir.path()).unwrap();
+        }
+        let undone = undo_data_with_base_path(tempdir.path(), Some(2)).unwrap();
+        assert_eq!(
+            undone
+                .iter()
+                .map(|revision| *revision.number())
+                .collect::<Vec<_>>(),
+            vec![3, 2]
+        );
+        assert_eq!(months_off(tempdir.path()), before + 1);
+    }
+
+    #[test]
+    fn test_undo_init_removes_created_files() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        init_data_at(tempdir.path(), Ok).unwrap();
+        let vendor = path_to_ron_file_with_base(tempdir.path(), DATA_FILE_NAME_VENDOR);
+        assert!(vendor.exists());
+        undo_data_with_base_path(tempdir.path(), None).unwrap();
+        assert!(!vendor.exists());
+    }
+
+    #[test]
+    fn test_unknown_revision() {
+        let tempdir = data_dir();
+        record_month_off_with_base_path(&YearAndMonth::may(2025), tempdir.path()).unwrap();
+        assert!(matches!(
+            diff_data_with_base_path(tempdir.path(), 7),
+            Err(Error::UnknownDataRevision { revision: 7 })
+        ));
+        assert!(matches!(
+            undo_data_with_base_path(tempdir.path(), Some(7)),
+            Err(Error::UnknownDataRevision { revision: 7 })
+        ));
+    }
+}
@@ -0,0 +1,89 @@
+use crate::prelude::*;
+
+/// Computes the invoice specified by `input`, by reading data from disk at
+/// the provided path, without rendering it or writing any files, not even
+/// newly fetched exchange rates to the cache.
+pub fn dry_run_invoice_with_base_path(
+    data_base_path: impl AsRef<Path>,
+    input: ValidInput,
+) -> Result<InvoiceDryRun> {
+    let data = read_data_from_disk_with_base_path(data_base_path)?;
+    dry_run_invoice(data, input, ExchangeRatesFetcher::read_only())
+}
+
+/// Computes the invoice specified by `input` using `data`, just like
+/// [`prepare_invoice_input_data`] does, but keeping the exchange rates used,
+/// without rendering it or writing any files.
+pub fn dry_run_invoice(
+    data: Data,
+    input: ValidInput,
+    exchange_rates_fetcher: impl FetchExchangeRates,
+) -> Result<InvoiceDryRun> {
+    info!("Computing invoice without rendering it, input: {}", input);
+    let partial = data.to_partial(input)?;
+    let exchange_rates = exchange_rates_fetcher
+        .fetch_for_line_items(*partial.payment_info().currency(), partial.line_items())?;
+    let prepared = partial.to_typst(exchange_rates.clone())?;
+    Ok(InvoiceDryRun::new(&prepared, exchange_rates))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    struct HardCodedRates;
+    impl FetchExchangeRates for HardCodedRates {
+        fn fetch_for_items(&self, _: Currency, _: Vec<Item>) -> Result<ExchangeRates> {
+            Ok(ExchangeRates::hard_coded())
+        }
+    }
+
+    #[test]
+    fn test_dry_run_services_subtracts_days_off() {
+        let input = |days_off: Option<u8>| {
+            ValidInput::builder()
+                .month(YearAndMonth::sample())
+                .items(InvoicedItems::Service {
+                    days_off: days_off.map(|d| Day::try_from(d).unwrap()),
+                })
+                .build()
+        };
+        let all = dry_run_invoice(Data::sample(), input(None), HardCodedRates).unwrap();
+        let with_days_off =
+            dry_run_invoice(Data::sample(), input(Some(2)), HardCodedRates).unwrap();
+        assert_eq!(
+            *all.worked_days().unwrap() - *with_days_off.worked_days().unwrap(),
+            dec!(2)
+        );
+        assert!(all.exchange_rates().is_empty());
+    }
+
+    #[test]
+    fn test_dry_run_expenses_keeps_exchange_rates() {
+        let input = ValidInput::builder()
+            .month(YearAndMonth::sample())
+            .items(InvoicedItems::Expenses)
+            .build();
+        let sut = dry_run_invoice(Data::sample(), input, HardCodedRates).unwrap();
+        assert_eq!(*sut.kind(), InvoiceKind::Expenses);
+        assert_eq!(sut.worked_days(), &None);
+        assert!(!sut.line_items().is_empty());
+        assert!(!sut.exchange_rates().contains_key(&Currency::EUR));
+        assert!(sut.exchange_rates().contains_key(&Currency::SEK));
+    }
+
+    #[test]
+    fn test_dry_run_with_base_path_writes_nothing() {
+        let tempdir = tempfile::tempdir().unwrap();
+        save_data_with_base_path(Data::sample(), tempdir.path()).unwrap();
+        let before = std::fs::read_dir(tempdir.path()).unwrap().count();
+        let input = ValidInput::builder()
+            .maybe_output_path(tempdir.path().join("invoice.pdf"))
+            .month(YearAndMonth::sample())
+            .build();
+        let sut = dry_run_invoice_with_base_path(tempdir.path(), input).unwrap();
+        assert_eq!(*sut.kind(), InvoiceKind::Services);
+        assert_eq!(std::fs::read_dir(tempdir.path()).unwrap().count(), before);
+    }
+}
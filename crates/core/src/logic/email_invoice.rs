@@ -0,0 +1,223 @@
+use crate::prelude::*;
+use lettre::{Message, SmtpTransport, Transport, transport::smtp::authentication::Credentials};
+
+/// The files created when emailing an invoice.
+#[derive(Clone, Debug, PartialEq, Eq, TypedBuilder, Getters)]
+pub struct EmailedInvoice {
+    /// Where the PDF of the invoice was saved.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    pdf: PathBuf,
+
+    /// Where the `.eml` draft was saved, next to the PDF.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    email: PathBuf,
+}
+
+/// Compiles the invoice into a PDF just like [`create_pdf_with_data_base_path`]
+/// does, and saves an `.eml` draft to the billing emails of the client with
+/// the PDF attached next to it. If `delivery` is [`EmailDelivery::Send`] the
+/// email is also sent using the SMTP server in `smtp.ron`.
+///
+/// The issued invoice is recorded in the [`InvoiceLedger`] at the same path.
+pub fn create_pdf_and_email_with_data_base_path(
+    data_base_path: impl AsRef<Path>,
+    input: ValidInput,
+    delivery: EmailDelivery,
+    render: impl Fn(L18n, PreparedData, Layout) -> Result<Pdf>,
+) -> Result<EmailedInvoice> {
+    let data_base_path = data_base_path.as_ref();
+    let smtp = match delivery {
+        EmailDelivery::Draft => None,
+        EmailDelivery::Send => Some(smtp_settings(data_base_path)?),
+    };
+    let data = read_data_from_disk_with_base_path(data_base_path)?;
+    let numbering = data.information().numbering().clone();
+    let l18n = get_localization(input.language())?;
+    let layout = *input.layout();
+    let data = attach_receipts(
+        prepare_invoice_input_data(data, input, ExchangeRatesFetcher::default())?,
+        data_base_path,
+    )?;
+    let email = InvoiceEmail::new(&data, l18n.content())?;
+    let (pdf_path, _) = render_and_issue(l18n, data, layout, render, data_base_path, &numbering)?;
+
+    let pdf = std::fs::read(&pdf_path).map_err(|e| Error::FailedToCreateEmail {
+        underlying: format!("Failed to read PDF {}: {}", pdf_path.display(), e),
+    })?;
+    let file_name = pdf_path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "invoice.pdf".to_owned());
+    let message = email.message(file_name, &Pdf::from(pdf))?;
+    let email_path = pdf_path.with_extension("eml");
+    info!("Saving email draft to: '{}'", email_path.display());
+    std::fs::write(&email_path, message.formatted()).map_err(|e| Error::SaveEmail {
+        underlying: format!("Write email to {}: {}", email_path.display(), e),
+    })?;
+    if let Some(smtp) = smtp {
+        let password = std::env::var(ENV_VAR_SMTP_PASSWORD).ok();
+        send_email(&message, &smtp, password)?;
+        info!(
+            "✅ Sent invoice to: {}",
+            email
+                .to()
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
+    Ok(EmailedInvoice::builder()
+        .pdf(pdf_path)
+        .email(email_path)
+        .build())
+}
+
+/// Sends `message` using the SMTP server `settings`, logging in with the
+/// user name of `settings` and `password` if a user name is set.
+///
+/// # Errors
+/// Returns an error if the connection to the server fails or the server
+/// rejects the message.
+pub fn send_email(
+    message: &Message,
+    settings: &SmtpSettings,
+    password: Option<String>,
+) -> Result<()> {
+    let error = |e: &dyn std::fmt::Display| Error::FailedToSendEmail {
+        underlying: e.to_string(),
+    };
+    let host = settings.host();
+    let builder = match settings.encryption() {
+        SmtpEncryption::None => SmtpTransport::builder_dangerous(host),
+        SmtpEncryption::StartTls => SmtpTransport::starttls_relay(host).map_err(|e| error(&e))?,
+        SmtpEncryption::Tls => SmtpTransport::relay(host).map_err(|e| error(&e))?,
+    }
+    .port(*settings.port());
+    let builder = match settings.username() {
+        Some(username) => builder.credentials(Credentials::new(
+            username.clone(),
+            password.unwrap_or_default(),
+        )),
+        None => builder,
+    };
+    debug!("☑️ Sending email using SMTP server {}...", host);
+    builder.build().send(message).map_err(|e| error(&e))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use test_log::test;
+
+    /// A minimal SMTP server accepting one message, returning the received
+    /// data once the client has quit.
+    fn smtp_stand_in() -> (u16, std::thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let handle = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut writer = stream.try_clone().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut reply = |line: &str| writer.write_all(format!("{line}\r\n").as_bytes());
+            reply("220 localhost ESMTP").unwrap();
+            let mut data = String::new();
+            let mut in_data = false;
+            let mut line = String::new();
+            while reader.read_line(&mut line).unwrap() > 0 {
+                if in_data {
+                    if line == ".\r\n" {
+                        in_data = false;
+                        reply("250 OK").unwrap();
+                    } else {
+                        data.push_str(&line);
+                    }
+                } else if line.starts_with("DATA") {
+                    in_data = true;
+                    reply("354 End data with <CR><LF>.<CR><LF>").unwrap();
+                } else if line.starts_with("QUIT") {
+                    reply("221 Bye").unwrap();
+                    break;
+                } else {
+                    reply("250 OK").unwrap();
+                }
+                line.clear();
+            }
+            data
+        });
+        (port, handle)
+    }
+
+    fn input(out: impl AsRef<Path>) -> ValidInput {
+        ValidInput::builder()
+            .maybe_output_path(out.as_ref().to_path_buf())
+            .month(YearAndMonth::sample())
+            .items(InvoicedItems::Service { days_off: None })
+            .build()
+    }
+
+    #[test]
+    fn test_email_draft_saved_next_to_pdf() {
+        let tempdir = tempfile::tempdir().unwrap();
+        save_data_with_base_path(Data::sample(), tempdir.path()).unwrap();
+        let emailed = create_pdf_and_email_with_data_base_path(
+            tempdir.path(),
+            input(tempdir.path().join("invoice.pdf")),
+            EmailDelivery::Draft,
+            |_, _, _| Ok(Pdf::from(Vec::from(b"%PDF-1.4"))),
+        )
+        .unwrap();
+        assert_eq!(emailed.email(), &tempdir.path().join("invoice.eml"));
+        let eml = std::fs::read_to_string(emailed.email()).unwrap();
+        assert!(eml.contains("To: billing@holmes.example"));
+        assert!(eml.contains("filename=\"invoice.pdf\""));
+        assert_eq!(invoice_ledger(tempdir.path()).unwrap().iter().count(), 1);
+    }
+
+    #[test]
+    fn test_send_requires_smtp_settings() {
+        let tempdir = tempfile::tempdir().unwrap();
+        save_data_with_base_path(Data::sample(), tempdir.path()).unwrap();
+        let result = create_pdf_and_email_with_data_base_path(
+            tempdir.path(),
+            input(tempdir.path().join("invoice.pdf")),
+            EmailDelivery::Send,
+            |_, _, _| Ok(Pdf::from(Vec::from(b"%PDF-1.4"))),
+        );
+        assert!(matches!(result, Err(Error::MissingSmtpSettings { .. })));
+        assert!(!tempdir.path().join("invoice.pdf").exists());
+    }
+
+    #[test]
+    fn test_send_using_local_smtp_server() {
+        let (port, server) = smtp_stand_in();
+        let tempdir = tempfile::tempdir().unwrap();
+        save_data_with_base_path(Data::sample(), tempdir.path()).unwrap();
+        let smtp = SmtpSettings::builder()
+            .host("127.0.0.1")
+            .port(port)
+            .encryption(SmtpEncryption::None)
+            .build();
+        save_to_disk(
+            &smtp,
+            path_to_ron_file_with_base(tempdir.path(), DATA_FILE_NAME_SMTP),
+        )
+        .unwrap();
+        create_pdf_and_email_with_data_base_path(
+            tempdir.path(),
+            input(tempdir.path().join("invoice.pdf")),
+            EmailDelivery::Send,
+            |_, _, _| Ok(Pdf::from(Vec::from(b"%PDF-1.4"))),
+        )
+        .unwrap();
+        let received = server.join().unwrap();
+        assert!(received.contains("Subject: Invoice"));
+        assert!(received.contains("To: billing@holmes.example"));
+        assert!(received.contains("Content-Type: application/pdf"));
+    }
+}
@@ -0,0 +1,244 @@
+use crate::prelude::*;
+
+/// All data files at `folder` and in its subfolders, e.g. `history` and
+/// `backups`, but not `encryption.ron`, which is never encrypted.
+fn data_files_in(folder: &Path) -> Result<Vec<PathBuf>> {
+    let read_error = |e: std::io::Error| Error::FileNotFound {
+        path: folder.display().to_string(),
+        underlying: format!("{:?}", e),
+    };
+    let mut files = Vec::new();
+    let mut paths = std::fs::read_dir(folder)
+        .map_err(read_error)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .map_err(read_error)?;
+    paths.sort();
+    for path in paths {
+        if path.is_dir() {
+            files.extend(data_files_in(&path)?);
+        } else if path.extension().is_some_and(|extension| extension == "ron")
+            && path != path_to_ron_file_with_base(folder, DATA_FILE_NAME_ENCRYPTION)
+        {
+            files.push(path);
+        }
+    }
+    Ok(files)
+}
+
+/// Encrypts every data file at `base_path` with `passphrase`, deriving the
+/// key with the scrypt cost `log_n`, and saves `encryption.ron` so that
+/// files written later are encrypted too.
+fn encrypt_data_with_cost(
+    base_path: impl AsRef<Path>,
+    passphrase: &str,
+    log_n: u8,
+) -> Result<Vec<PathBuf>> {
+    let base_path = base_path.as_ref();
+    let _lock = DataLock::acquire(base_path)?;
+    if data_encryption(base_path)?.is_some() {
+        return Err(Error::DataAlreadyEncrypted);
+    }
+    let encryption = new_data_encryption(passphrase, log_n)?;
+    let salt = verify_data_passphrase(&encryption, passphrase)?;
+    let mut encrypted = Vec::new();
+    for path in data_files_in(base_path)? {
+        let contents = std::fs::read_to_string(&path).map_err(|e| Error::FileNotFound {
+            path: path.display().to_string(),
+            underlying: format!("{:?}", e),
+        })?;
+        if EncryptedContents::is_encrypted(&contents) {
+            continue;
+        }
+        let contents = encrypt_contents(&contents, passphrase, log_n, &salt)?;
+        write_atomically(&path, contents.to_string())?;
+        encrypted.push(path);
+    }
+    write_atomically(
+        path_to_ron_file_with_base(base_path, DATA_FILE_NAME_ENCRYPTION),
+        to_ron_string(&encryption)?,
+    )?;
+    set_data_passphrase(passphrase);
+    info!(
+        "✅ Encrypted #{} data files at: {}",
+        encrypted.len(),
+        base_path.display()
+    );
+    Ok(encrypted)
+}
+
+/// Encrypts every data file at `base_path`, including its history, backups
+/// and the cache of exchange rates, with a key derived from `passphrase`.
+/// Files written later are encrypted too. Returns the paths of the files
+/// encrypted.
+///
+/// # Errors
+/// Returns an error if the data is already encrypted.
+pub fn encrypt_data_with_base_path(
+    base_path: impl AsRef<Path>,
+    passphrase: &str,
+) -> Result<Vec<PathBuf>> {
+    encrypt_data_with_cost(base_path, passphrase, DEFAULT_SCRYPT_LOG_N)
+}
+
+/// Decrypts every data file at `base_path` encrypted with `passphrase`, and
+/// removes `encryption.ron` so that files are written unencrypted again.
+/// Returns the paths of the files decrypted.
+///
+/// # Errors
+/// Returns an error if the data is not encrypted or if `passphrase` is
+/// wrong, in which case no file is changed.
+pub fn decrypt_data_with_base_path(
+    base_path: impl AsRef<Path>,
+    passphrase: &str,
+) -> Result<Vec<PathBuf>> {
+    let base_path = base_path.as_ref();
+    let _lock = DataLock::acquire(base_path)?;
+    let encryption = data_encryption(base_path)?.ok_or(Error::DataNotEncrypted)?;
+    verify_data_passphrase(&encryption, passphrase)?;
+    let mut decrypted = Vec::new();
+    for path in data_files_in(base_path)? {
+        let contents = std::fs::read_to_string(&path).map_err(|e| Error::FileNotFound {
+            path: path.display().to_string(),
+            underlying: format!("{:?}", e),
+        })?;
+        if !EncryptedContents::is_encrypted(&contents) {
+            continue;
+        }
+        let contents = decrypt_contents(&EncryptedContents::from_str(&contents)?, passphrase)?;
+        write_atomically(&path, contents)?;
+        decrypted.push(path);
+    }
+    let marker = path_to_ron_file_with_base(base_path, DATA_FILE_NAME_ENCRYPTION);
+    std::fs::remove_file(&marker).map_err(|e| Error::FailedToWriteDataToDisk {
+        underlying: format!("Failed to remove {}: {:?}", marker.display(), e),
+    })?;
+    info!(
+        "✅ Decrypted #{} data files at: {}",
+        decrypted.len(),
+        base_path.display()
+    );
+    Ok(decrypted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    /// Every test sets the same passphrase, since it is set for the whole
+    /// run.
+    const PASSPHRASE: &str = "correct horse battery staple";
+
+    /// Cheap enough for tests, too cheap for real use.
+    const TEST_LOG_N: u8 = 4;
+
+    fn encrypted_data_dir() -> tempfile::TempDir {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        save_data_with_base_path(Data::sample(), tempdir.path()).unwrap();
+        encrypt_data_with_cost(tempdir.path(), PASSPHRASE, TEST_LOG_N).unwrap();
+        tempdir
+    }
+
+    fn raw_contents(base_path: impl AsRef<Path>, name: &str) -> String {
+        std::fs::read_to_string(path_to_ron_file_with_base(base_path, name)).unwrap()
+    }
+
+    #[test]
+    fn test_encrypted_data_is_read_and_written_encrypted() {
+        let tempdir = encrypted_data_dir();
+        let vendor = raw_contents(tempdir.path(), DATA_FILE_NAME_VENDOR);
+        assert!(EncryptedContents::is_encrypted(&vendor));
+        assert!(!vendor.contains("Lupin"));
+
+        assert_eq!(
+            read_data_from_disk_with_base_path(tempdir.path()).unwrap(),
+            Data::sample()
+        );
+
+        record_month_off_with_base_path(&YearAndMonth::may(2025), tempdir.path()).unwrap();
+        assert!(EncryptedContents::is_encrypted(&raw_contents(
+            tempdir.path(),
+            DATA_FILE_NAME_PROTO_INVOICE_INFO
+        )));
+        let revision =
+            path_to_ron_file_with_base(tempdir.path().join(DATA_FOLDER_NAME_HISTORY), "00001");
+        assert!(EncryptedContents::is_encrypted(
+            &std::fs::read_to_string(revision).unwrap()
+        ));
+        undo_data_with_base_path(tempdir.path(), None).unwrap();
+        assert_eq!(
+            read_data_from_disk_with_base_path(tempdir.path()).unwrap(),
+            Data::sample()
+        );
+    }
+
+    #[test]
+    fn test_plaintext_file_in_encrypted_data_is_rejected() {
+        let tempdir = encrypted_data_dir();
+        std::fs::write(
+            path_to_ron_file_with_base(tempdir.path(), DATA_FILE_NAME_VENDOR),
+            to_ron_string(&CompanyInformation::sample_client()).unwrap(),
+        )
+        .unwrap();
+        assert!(matches!(
+            read_data_from_disk_with_base_path(tempdir.path()),
+            Err(Error::UnencryptedDataFile { .. })
+        ));
+
+        // Decrypting leaves the plaintext file as is and reads it again.
+        decrypt_data_with_base_path(tempdir.path(), PASSPHRASE).unwrap();
+        assert_eq!(
+            read_data_from_disk_with_base_path(tempdir.path())
+                .unwrap()
+                .vendor(),
+            &CompanyInformation::sample_client()
+        );
+    }
+
+    #[test]
+    fn test_decrypt_restores_plaintext() {
+        let tempdir = encrypted_data_dir();
+        let decrypted = decrypt_data_with_base_path(tempdir.path(), PASSPHRASE).unwrap();
+        assert_eq!(decrypted.len(), DATA_FILE_NAMES_OF_DATA.len());
+        assert!(!EncryptedContents::is_encrypted(&raw_contents(
+            tempdir.path(),
+            DATA_FILE_NAME_VENDOR
+        )));
+        assert_eq!(data_encryption(tempdir.path()).unwrap(), None);
+        assert_eq!(
+            read_data_from_disk_with_base_path(tempdir.path()).unwrap(),
+            Data::sample()
+        );
+    }
+
+    #[test]
+    fn test_decrypt_with_wrong_passphrase_changes_nothing() {
+        let tempdir = encrypted_data_dir();
+        let before = raw_contents(tempdir.path(), DATA_FILE_NAME_VENDOR);
+        assert!(matches!(
+            decrypt_data_with_base_path(tempdir.path(), "wrong"),
+            Err(Error::WrongDataPassphrase)
+        ));
+        assert_eq!(raw_contents(tempdir.path(), DATA_FILE_NAME_VENDOR), before);
+    }
+
+    #[test]
+    fn test_encrypt_twice_fails() {
+        let tempdir = encrypted_data_dir();
+        assert!(matches!(
+            encrypt_data_with_cost(tempdir.path(), PASSPHRASE, TEST_LOG_N),
+            Err(Error::DataAlreadyEncrypted)
+        ));
+    }
+
+    #[test]
+    fn test_decrypt_unencrypted_fails() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        save_data_with_base_path(Data::sample(), tempdir.path()).unwrap();
+        assert!(matches!(
+            decrypt_data_with_base_path(tempdir.path(), PASSPHRASE),
+            Err(Error::DataNotEncrypted)
+        ));
+    }
+}
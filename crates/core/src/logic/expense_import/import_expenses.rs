@@ -0,0 +1,103 @@
+use crate::prelude::*;
+
+/// Imports the expenses in `source` for `month`, skipping expenses already
+/// recorded for any month at `data_path`. If `is_preview` is true nothing is
+/// saved.
+///
+/// # Errors
+/// Returns an error if `source` cannot be read or parsed, or if the expenses
+/// cannot be saved.
+pub fn import_expenses_with_base_path(
+    month: &YearAndMonth,
+    source: &ExpenseSource,
+    is_preview: bool,
+    data_path: impl AsRef<Path>,
+) -> Result<ExpenseImport> {
+    let data_path = data_path.as_ref();
+    info!(
+        "Importing expenses for {} from: {}",
+        month,
+        source.path().display()
+    );
+    let (items, skipped_files) = match source {
+        ExpenseSource::Csv { path, format } => {
+            let csv = std::fs::read_to_string(path).map_err(|e| Error::FileNotFound {
+                path: path.display().to_string(),
+                underlying: format!("{:?}", e),
+            })?;
+            (parse_expenses_csv(&csv, format)?, Vec::new())
+        }
+        ExpenseSource::ReceiptFolder { path } => parse_receipt_folder(path)?,
+    };
+    let existing = expensed_months(data_path)?;
+    let (duplicates, imported): (Vec<_>, Vec<_>) = items
+        .into_iter()
+        .partition(|item| existing.contains_expense(item));
+    if !is_preview && !imported.is_empty() {
+        record_expenses_with_base_path(month, &imported, data_path)?;
+    }
+    Ok(ExpenseImport::builder()
+        .month(*month)
+        .is_preview(is_preview)
+        .imported(imported)
+        .duplicates(duplicates)
+        .skipped_files(skipped_files)
+        .build())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    fn setup() -> (tempfile::TempDir, ExpenseSource) {
+        let tempdir = tempfile::tempdir().unwrap();
+        save_to_disk(
+            &ExpensedMonths::sample(),
+            path_to_ron_file_with_base(tempdir.path(), DATA_FILE_NAME_EXPENSES),
+        )
+        .unwrap();
+        let csv_path = tempdir.path().join("card_export.csv");
+        std::fs::write(
+            &csv_path,
+            "name,amount,currency,date\nCoffee,4.0,GBP,2025-05-31\nTaxi,180,SEK,2025-05-30\n",
+        )
+        .unwrap();
+        let source = ExpenseSource::Csv {
+            path: csv_path,
+            format: ExpenseCsvFormat::default(),
+        };
+        (tempdir, source)
+    }
+
+    #[test]
+    fn test_import_skips_recorded_expenses() {
+        let (tempdir, source) = setup();
+        let month = YearAndMonth::may(2025);
+        let import =
+            import_expenses_with_base_path(&month, &source, false, tempdir.path()).unwrap();
+        assert_eq!(import.imported().len(), 1);
+        assert_eq!(import.imported()[0].name(), "Taxi");
+        assert_eq!(import.duplicates().len(), 1, "Coffee is already recorded");
+        let expenses = expensed_months(tempdir.path()).unwrap();
+        assert_eq!(expenses.get(&month).unwrap(), import.imported().clone());
+
+        let again = import_expenses_with_base_path(&month, &source, false, tempdir.path()).unwrap();
+        assert!(again.imported().is_empty());
+        assert_eq!(again.duplicates().len(), 2);
+    }
+
+    #[test]
+    fn test_import_preview_saves_nothing() {
+        let (tempdir, source) = setup();
+        let import =
+            import_expenses_with_base_path(&YearAndMonth::may(2025), &source, true, tempdir.path())
+                .unwrap();
+        assert!(*import.is_preview());
+        assert_eq!(import.imported().len(), 1);
+        assert_eq!(
+            expensed_months(tempdir.path()).unwrap(),
+            ExpensedMonths::sample()
+        );
+    }
+}
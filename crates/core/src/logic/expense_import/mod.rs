@@ -0,0 +1,7 @@
+mod import_expenses;
+mod parse_expenses_csv;
+mod parse_receipt_folder;
+
+pub use import_expenses::*;
+pub use parse_expenses_csv::*;
+pub use parse_receipt_folder::*;
@@ -0,0 +1,177 @@
+use crate::prelude::*;
+
+fn csv_error(underlying: impl std::fmt::Display) -> Error {
+    Error::FailedToParseExpenses {
+        underlying: underlying.to_string(),
+    }
+}
+
+/// Normalizes the decimal `value` using `decimal_separator` into the format
+/// expected by [`Item`], removing thousands separators and the sign.
+fn normalize_decimal(value: &str, decimal_separator: char) -> String {
+    let thousands_separator = if decimal_separator == ',' { '.' } else { ',' };
+    value
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != thousands_separator)
+        .map(|c| if c == decimal_separator { '.' } else { c })
+        .collect::<String>()
+        .trim_start_matches(['-', '+'])
+        .to_owned()
+}
+
+/// Parses the expenses in `csv` using `format`, each row is validated just
+/// like expenses recorded with `klirr data expenses -e`. Rows without name
+/// and amount are skipped.
+///
+/// # Errors
+/// Returns an error if the CSV is malformed, a column is missing or a row
+/// cannot be parsed.
+///
+/// # Examples
+/// ```
+/// extern crate klirr_core;
+/// use klirr_core::prelude::*;
+/// let csv = "Datum;Text;Belopp\n31/05/2025;Coffee;-4,00\n";
+/// let format = ExpenseCsvFormat::builder()
+///     .name_column("Text")
+///     .amount_column("Belopp")
+///     .date_column("Datum")
+///     .date_format("%d/%m/%Y")
+///     .currency(Currency::GBP)
+///     .decimal_separator(',')
+///     .delimiter(';')
+///     .build();
+/// let items = parse_expenses_csv(csv, &format).unwrap();
+/// assert_eq!(items[0].to_string(), "Coffee: 4.00GBP #1 @2025-05-31");
+/// ```
+pub fn parse_expenses_csv(csv: &str, format: &ExpenseCsvFormat) -> Result<Vec<Item>> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .delimiter(*format.delimiter() as u8)
+        .from_reader(csv.as_bytes());
+    let headers = reader.headers().map_err(csv_error)?.clone();
+    let column = |header: &str| {
+        headers
+            .iter()
+            .position(|h| h == header)
+            .ok_or_else(|| csv_error(format!("No column named '{}'", header)))
+    };
+    let name_column = column(format.name_column())?;
+    let amount_column = column(format.amount_column())?;
+    let currency_column = match format.currency() {
+        Some(_) => None,
+        None => Some(column(format.currency_column())?),
+    };
+    let quantity_column = format
+        .quantity_column()
+        .as_deref()
+        .map(column)
+        .transpose()?;
+    let date_column = column(format.date_column())?;
+
+    let mut items = Vec::new();
+    for (row, record) in reader.records().enumerate() {
+        let record = record.map_err(csv_error)?;
+        let field = |index: usize| record.get(index).unwrap_or_default();
+        let name = field(name_column).replace(',', " ");
+        let amount = normalize_decimal(field(amount_column), *format.decimal_separator());
+        if name.is_empty() && amount.is_empty() {
+            continue;
+        }
+        let currency = match (format.currency(), currency_column) {
+            (Some(currency), _) => currency.to_string(),
+            (None, Some(index)) => field(index).to_owned(),
+            (None, None) => unreachable!("Currency column is required without currency"),
+        };
+        let quantity = quantity_column
+            .map(|index| normalize_decimal(field(index), *format.decimal_separator()))
+            .unwrap_or_else(|| "1".to_owned());
+        let date = chrono::NaiveDate::parse_from_str(field(date_column), format.date_format())
+            .map(Date::from)
+            .map_err(|e| {
+                csv_error(format!(
+                    "Row {}, invalid date '{}' for format '{}': {}",
+                    row + 1,
+                    field(date_column),
+                    format.date_format(),
+                    e
+                ))
+            })?;
+        items.push(Item::from_str(&format!(
+            "{},{},{},{},{}",
+            name, amount, currency, quantity, date
+        ))?);
+    }
+    Ok(items)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    const FIXTURE: &str = include_str!("../../../fixtures/card_export.csv");
+
+    #[test]
+    fn test_parse_expenses_csv_fixture() {
+        let format = ExpenseCsvFormat::builder()
+            .name_column("Description")
+            .amount_column("Amount")
+            .currency_column("Currency")
+            .quantity_column("Qty".to_owned())
+            .date_column("Date")
+            .date_format("%d.%m.%Y")
+            .decimal_separator(',')
+            .delimiter(';')
+            .build();
+        let items = parse_expenses_csv(FIXTURE, &format).unwrap();
+        assert_eq!(items.len(), 3, "Empty row should be skipped");
+        assert_eq!(items[0].name(), "Coffee");
+        assert_eq!(*items[0].unit_price(), UnitPrice::from(dec!(2.50)));
+        assert_eq!(*items[0].quantity(), Quantity::from(dec!(3)));
+        assert_eq!(items[1].name(), "Lunch  Bistro");
+        assert_eq!(*items[1].unit_price(), UnitPrice::from(dec!(1234.50)));
+        assert_eq!(*items[2].currency(), Currency::SEK);
+        assert_eq!(
+            *items[2].transaction_date(),
+            Date::from_str("2025-06-01").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_expenses_csv_default_format() {
+        let csv = "name,amount,currency,date\nCoffee,2.5,EUR,2025-05-31\n";
+        let items = parse_expenses_csv(csv, &ExpenseCsvFormat::default()).unwrap();
+        assert_eq!(
+            items,
+            vec![Item::from_str("Coffee,2.5,EUR,1,2025-05-31").unwrap()]
+        );
+    }
+
+    #[test]
+    fn test_parse_expenses_csv_missing_column() {
+        let csv = "name,amount,date\nCoffee,2.5,2025-05-31\n";
+        let result = parse_expenses_csv(csv, &ExpenseCsvFormat::default());
+        assert!(matches!(result, Err(Error::FailedToParseExpenses { .. })));
+    }
+
+    #[test]
+    fn test_parse_expenses_csv_invalid_date() {
+        let csv = "name,amount,currency,date\nCoffee,2.5,EUR,31/05/2025\n";
+        let result = parse_expenses_csv(csv, &ExpenseCsvFormat::default());
+        assert!(matches!(result, Err(Error::FailedToParseExpenses { .. })));
+    }
+
+    #[test]
+    fn test_parse_expenses_csv_invalid_currency() {
+        let csv = "name,amount,currency,date\nCoffee,2.5,XYZ,2025-05-31\n";
+        let result = parse_expenses_csv(csv, &ExpenseCsvFormat::default());
+        assert!(matches!(result, Err(Error::InvalidExpenseItem { .. })));
+    }
+
+    #[test]
+    fn test_normalize_decimal() {
+        assert_eq!(normalize_decimal("-1 234,50", ','), "1234.50");
+        assert_eq!(normalize_decimal("1,234.50", '.'), "1234.50");
+    }
+}
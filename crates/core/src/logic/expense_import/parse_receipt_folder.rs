@@ -0,0 +1,122 @@
+use crate::prelude::*;
+
+/// Parses the name of a receipt file, without extension, on the format
+/// `<date>_<name>_<amount>_<currency>[_<quantity>]`, where underscores in
+/// the name are replaced with spaces, returning `None` if it is not on that
+/// format.
+///
+/// # Examples
+/// ```
+/// extern crate klirr_core;
+/// use klirr_core::prelude::*;
+/// let item = parse_receipt_file_name("2025-05-31_Train_ticket_12,5_EUR").unwrap();
+/// assert_eq!(item.to_string(), "Train ticket: 12.5EUR #1 @2025-05-31");
+/// assert!(parse_receipt_file_name("notes").is_none());
+/// ```
+pub fn parse_receipt_file_name(stem: &str) -> Option<Item> {
+    let parts = stem.split('_').collect::<Vec<_>>();
+    let n = parts.len();
+    if n < 4 {
+        return None;
+    }
+    let (name, amount, currency, quantity) = if Currency::from_str(parts[n - 1]).is_ok() {
+        (&parts[1..n - 2], parts[n - 2], parts[n - 1], "1")
+    } else if n >= 5 {
+        (&parts[1..n - 3], parts[n - 3], parts[n - 2], parts[n - 1])
+    } else {
+        return None;
+    };
+    Item::from_str(&format!(
+        "{},{},{},{},{}",
+        name.join(" "),
+        amount.replace(',', "."),
+        currency,
+        quantity.replace(',', "."),
+        parts[0]
+    ))
+    .ok()
+}
+
+/// Parses the receipts in `folder`, one expense per file, named as
+/// `<date>_<name>_<amount>_<currency>[_<quantity>]`, e.g.
+/// `2025-05-31_Coffee_2.5_EUR_3.pdf`. Returns the expenses sorted by file
+/// name and the names of the files skipped since they are not named as
+/// receipts. Hidden files and subfolders are ignored.
+///
+/// # Errors
+/// Returns an error if `folder` cannot be read.
+pub fn parse_receipt_folder(folder: impl AsRef<Path>) -> Result<(Vec<Item>, Vec<String>)> {
+    let folder = folder.as_ref();
+    let read_error = |e: std::io::Error| Error::FailedToParseExpenses {
+        underlying: format!("Failed to read folder {}: {}", folder.display(), e),
+    };
+    let mut paths = std::fs::read_dir(folder)
+        .map_err(read_error)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .map_err(read_error)?;
+    paths.sort();
+    let mut items = Vec::new();
+    let mut skipped = Vec::new();
+    for path in paths.into_iter().filter(|path| path.is_file()) {
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        if file_name.starts_with('.') {
+            continue;
+        }
+        let stem = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        match parse_receipt_file_name(&stem) {
+            Some(item) => items.push(item),
+            None => skipped.push(file_name),
+        }
+    }
+    Ok((items, skipped))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn test_parse_receipt_file_name_with_quantity() {
+        let item = parse_receipt_file_name("2025-05-31_Coffee_2.5_EUR_3").unwrap();
+        assert_eq!(item, Item::from_str("Coffee,2.5,EUR,3,2025-05-31").unwrap());
+    }
+
+    #[test]
+    fn test_parse_receipt_file_name_invalid() {
+        assert!(parse_receipt_file_name("2025-05-31_Coffee_EUR").is_none());
+        assert!(parse_receipt_file_name("31-05-2025_Coffee_2.5_EUR").is_none());
+        assert!(parse_receipt_file_name("2025-05-31_Coffee_abc_EUR_1").is_none());
+    }
+
+    #[test]
+    fn test_parse_receipt_folder() {
+        let tempdir = tempfile::tempdir().unwrap();
+        for name in [
+            "2025-05-31_Coffee_2.5_EUR_3.pdf",
+            "2025-05-20_Breakfast_145_SEK.jpg",
+            "notes.txt",
+            ".DS_Store",
+        ] {
+            std::fs::write(tempdir.path().join(name), b"").unwrap();
+        }
+        std::fs::create_dir(tempdir.path().join("2025-05-01_Folder_1_EUR")).unwrap();
+        let (items, skipped) = parse_receipt_folder(tempdir.path()).unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].name(), "Breakfast");
+        assert_eq!(skipped, vec!["notes.txt".to_owned()]);
+    }
+
+    #[test]
+    fn test_parse_receipt_folder_missing() {
+        let result = parse_receipt_folder("/does/not/exist");
+        assert!(matches!(result, Err(Error::FailedToParseExpenses { .. })));
+    }
+}
@@ -0,0 +1,267 @@
+use crate::prelude::*;
+use rust_decimal::RoundingStrategy;
+
+/// Converts `totals` of an invoice dated `date` from `currency` into the
+/// currency of the bookkeeping, rounding each amount to two decimals.
+fn totals_in_bookkeeping_currency(
+    totals: &InvoiceTotals,
+    currency: Currency,
+    date: Date,
+    chart: &ChartOfAccounts,
+    fetcher: &impl FetchExchangeRates,
+) -> Result<InvoiceTotals> {
+    let bookkeeping_currency = *chart.currency();
+    if currency == bookkeeping_currency {
+        return Ok(*totals);
+    }
+    let rates = fetcher.fetch_for_items(
+        bookkeeping_currency,
+        vec![
+            Item::builder()
+                .name("Invoice")
+                .unit_price(UnitPrice::from(dec!(1)))
+                .currency(currency)
+                .quantity(Quantity::from(dec!(1)))
+                .transaction_date(date)
+                .build(),
+        ],
+    )?;
+    let convert = |amount: &Cost| -> Result<rust_decimal::Decimal> {
+        rates.convert(**amount, currency).map(|converted| {
+            converted.round_dp_with_strategy(2, RoundingStrategy::MidpointAwayFromZero)
+        })
+    };
+    let net = convert(totals.net())?;
+    let vat = convert(totals.vat())?;
+    Ok(InvoiceTotals::builder()
+        .net(Cost::from(net))
+        .vat(Cost::from(vat))
+        .gross(Cost::from(net + vat))
+        .build())
+}
+
+/// The verification of an invoice: debit accounts receivable with the gross
+/// amount, credit revenue with the net amount and output VAT with the VAT.
+fn verification(
+    row: &RevenueReportRow,
+    client: &CompanyInformation,
+    totals: &InvoiceTotals,
+    chart: &ChartOfAccounts,
+) -> SieVerification {
+    let transaction = |account: &Account, amount: rust_decimal::Decimal| {
+        SieTransaction::builder()
+            .account(*account.number())
+            .amount(Cost::from(amount))
+            .build()
+    };
+    let mut transactions = vec![
+        transaction(chart.accounts_receivable(), **totals.gross()),
+        transaction(chart.revenue(*row.kind()), -**totals.net()),
+    ];
+    if !totals.vat().is_zero() {
+        transactions.push(transaction(chart.output_vat(), -**totals.vat()));
+    }
+    SieVerification::builder()
+        .date(*row.date())
+        .text(format!(
+            "Faktura {} {}",
+            row.identifier(),
+            client.company_name()
+        ))
+        .transactions(transactions)
+        .build()
+}
+
+/// Creates a SIE4 document with one verification per invoice of `year`
+/// issued on or before `today`, using the same invoices and totals as
+/// [`revenue_report`], booked on the accounts of `chart`.
+///
+/// # Errors
+/// Returns an error if an invoice cannot be prepared or if exchange rates
+/// cannot be fetched.
+pub fn sie_document(
+    data: &Data,
+    chart: &ChartOfAccounts,
+    year: Year,
+    today: &Date,
+    fetcher: impl FetchExchangeRates,
+) -> Result<SieDocument> {
+    let period = ReportPeriod::builder().year(year).build();
+    let report = revenue_report(data, period, today, &fetcher)?;
+    let verifications = report
+        .invoices()
+        .iter()
+        .map(|row| {
+            let totals = totals_in_bookkeeping_currency(
+                row.totals(),
+                *row.currency(),
+                *row.date(),
+                chart,
+                &fetcher,
+            )?;
+            Ok(verification(row, data.client(), &totals, chart))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(SieDocument::builder()
+        .generated(*today)
+        .company_name(data.vendor().company_name().clone())
+        .organisation_number(data.vendor().organisation_number().to_string())
+        .year(year)
+        .currency(*chart.currency())
+        .accounts(chart.accounts().into_iter().cloned().collect::<Vec<_>>())
+        .verifications(verifications)
+        .build())
+}
+
+/// Exports the invoices of `year` from the data at `data_path` as a SIE4 file
+/// saved at `out`, using the chart of accounts in `chart_of_accounts.ron` if
+/// present, else the BAS defaults.
+pub fn export_sie_with_base_path(
+    data_path: impl AsRef<Path>,
+    year: Year,
+    out: impl AsRef<Path>,
+) -> Result<SieDocument> {
+    let data_path = data_path.as_ref();
+    let out = out.as_ref();
+    let data = read_data_from_disk_with_base_path(data_path)?;
+    let chart: ChartOfAccounts = load_data_or_default(data_path, DATA_FILE_NAME_CHART_OF_ACCOUNTS)?;
+    let document = sie_document(
+        &data,
+        &chart,
+        year,
+        &Date::today(),
+        ExchangeRatesFetcher::default(),
+    )?;
+    std::fs::write(out, document.to_pc8_bytes()).map_err(|e| Error::FailedToWriteDataToDisk {
+        underlying: format!("Failed to write SIE file to {}: {}", out.display(), e),
+    })?;
+    info!(
+        "✅ Exported {} verifications to {}",
+        document.verifications().len(),
+        out.display()
+    );
+    Ok(document)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    struct FixedRates;
+    impl FetchExchangeRates for FixedRates {
+        fn fetch_for_items(
+            &self,
+            target_currency: Currency,
+            _items: Vec<Item>,
+        ) -> Result<ExchangeRates> {
+            Ok(ExchangeRates::builder()
+                .target_currency(target_currency)
+                .rates(ExchangeRatesMap::from_iter([
+                    (Currency::EUR, UnitPrice::from(dec!(11.5))),
+                    (Currency::GBP, UnitPrice::from(dec!(13))),
+                    (Currency::SEK, UnitPrice::from(dec!(0.1))),
+                ]))
+                .build())
+        }
+    }
+
+    fn document(data: &Data) -> SieDocument {
+        sie_document(
+            data,
+            &ChartOfAccounts::default(),
+            Year::from(2024),
+            &Date::from_str("2025-01-15").unwrap(),
+            FixedRates,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_one_balanced_verification_per_invoice() {
+        let data = Data::sample();
+        let document = document(&data);
+        let report = revenue_report(
+            &data,
+            ReportPeriod::builder().year(Year::from(2024)).build(),
+            &Date::from_str("2025-01-15").unwrap(),
+            FixedRates,
+        )
+        .unwrap();
+        assert!(!document.verifications().is_empty());
+        assert_eq!(document.verifications().len(), report.invoices().len());
+        assert!(
+            document
+                .verifications()
+                .iter()
+                .all(SieVerification::is_balanced)
+        );
+    }
+
+    #[test]
+    fn test_vat_credited_to_output_vat() {
+        let data = Data::sample();
+        let information = data
+            .information()
+            .clone()
+            .with_vat_rate(Percentage::from(dec!(25)));
+        let document = document(&data.with_information(information));
+        let verification = document.verifications().first().unwrap();
+        let amounts = verification
+            .transactions()
+            .iter()
+            .map(|transaction| (*transaction.account(), **transaction.amount()))
+            .collect::<IndexMap<_, _>>();
+        // VAT is converted separately, so it may differ by rounding.
+        assert!((amounts[&2611] * dec!(4) - amounts[&3011]).abs() <= dec!(0.04));
+        assert_eq!(amounts[&1510], -(amounts[&3011] + amounts[&2611]));
+    }
+
+    #[test]
+    fn test_text_has_number_as_printed_on_invoice() {
+        let data = Data::sample();
+        let numbering = InvoiceNumbering::default()
+            .with_format(InvoiceNumberFormat::sample())
+            .with_prefix(Some("ACME-".to_owned()));
+        let information = data.information().clone().with_numbering(numbering);
+        let document = document(&data.with_information(information));
+        let verification = document.verifications().first().unwrap();
+        assert!(
+            verification.text().starts_with("Faktura ACME-2024-"),
+            "{}",
+            verification.text()
+        );
+    }
+
+    #[test]
+    fn test_no_conversion_in_bookkeeping_currency() {
+        let totals = InvoiceTotals::new(Cost::from(dec!(100)), Percentage::from(dec!(25)));
+        let converted = totals_in_bookkeeping_currency(
+            &totals,
+            Currency::SEK,
+            Date::sample(),
+            &ChartOfAccounts::default(),
+            &FixedRates,
+        )
+        .unwrap();
+        assert_eq!(converted, totals);
+    }
+
+    #[test]
+    fn test_export_writes_pc8_file() {
+        let tempdir = tempfile::tempdir().unwrap();
+        save_data_with_base_path(Data::sample(), tempdir.path()).unwrap();
+        // Book in the currency of the invoices, to not fetch exchange rates.
+        save_to_disk(
+            &ChartOfAccounts::builder().currency(Currency::EUR).build(),
+            path_to_ron_file_with_base(tempdir.path(), DATA_FILE_NAME_CHART_OF_ACCOUNTS),
+        )
+        .unwrap();
+        let out = tempdir.path().join("export.se");
+        let document = export_sie_with_base_path(tempdir.path(), Year::from(1999), &out).unwrap();
+        assert_eq!(document.verifications().len(), 12);
+        assert_eq!(*document.currency(), Currency::EUR);
+        let bytes = std::fs::read(out).unwrap();
+        assert!(bytes.starts_with(b"#FLAGGA 0"));
+    }
+}
@@ -0,0 +1,174 @@
+use crate::prelude::*;
+
+/// Returns the number of the invoice of `kind` for `month` when counting
+/// invoices sequentially, being the number of the same invoice in `ledger`
+/// if it has been issued before, since generating an invoice again must not
+/// use up a new number, or else the number following the highest number in
+/// `ledger`. If no invoice has been issued yet the number is `derived`.
+///
+/// # Examples
+/// ```
+/// extern crate klirr_core;
+/// use klirr_core::prelude::*;
+/// let ledger = InvoiceLedger::sample();
+/// let derived = || InvoiceNumber::from(1);
+/// // Generating the services invoice of May 2025 again.
+/// let again = sequential_invoice_number(&ledger, &YearAndMonth::may(2025), InvoiceKind::Services, derived);
+/// assert_eq!(again, InvoiceNumber::from(243));
+/// // The expenses invoice of the same month gets the next number.
+/// let next = sequential_invoice_number(&ledger, &YearAndMonth::may(2025), InvoiceKind::Expenses, derived);
+/// assert_eq!(next, InvoiceNumber::from(244));
+/// ```
+pub fn sequential_invoice_number(
+    ledger: &InvoiceLedger,
+    month: &YearAndMonth,
+    kind: InvoiceKind,
+    derived: impl FnOnce() -> InvoiceNumber,
+) -> InvoiceNumber {
+    if let Some(issued) = ledger.issued_for(month, kind) {
+        return *issued.number();
+    }
+    ledger
+        .last_number()
+        .map(|last| InvoiceNumber::from(*last + 1))
+        .unwrap_or_else(derived)
+}
+
+impl InvoiceNumbering {
+    /// Formats `number` as printed on the invoice dated `invoice_date`.
+    ///
+    /// If numbers reset yearly the number is counted from the lowest number
+    /// of the invoices issued the same year in `ledger`, or from `number`
+    /// itself if it is the first invoice of the year.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let numbering = InvoiceNumbering::sample();
+    /// let ledger = InvoiceLedger::sample();
+    /// let identifier = numbering.identifier(
+    ///     InvoiceNumber::from(244),
+    ///     &"2025-05-31".parse().unwrap(),
+    ///     &ledger,
+    /// );
+    /// assert_eq!(identifier.to_string(), "ACME-2025-002");
+    /// ```
+    pub fn identifier(
+        &self,
+        number: InvoiceNumber,
+        invoice_date: &Date,
+        ledger: &InvoiceLedger,
+    ) -> InvoiceIdentifier {
+        let counted = if *self.reset_yearly() {
+            let first = ledger
+                .first_number_in(invoice_date.year())
+                .map_or(number, |first| first.min(number));
+            InvoiceNumber::from(*number - *first + 1)
+        } else {
+            number
+        };
+        self.format()
+            .format(counted, invoice_date, self.prefix().as_deref())
+    }
+}
+
+/// Validates that the numbers of the invoices in `ledger` have no gaps, if
+/// `numbering` counts invoices sequentially.
+///
+/// # Errors
+/// Returns [`Error::InvoiceNumberGaps`] listing the missing numbers.
+pub fn validate_invoice_numbering(
+    numbering: &InvoiceNumbering,
+    ledger: &InvoiceLedger,
+) -> Result<()> {
+    if !numbering.counter().is_ledger_sequential() {
+        return Ok(());
+    }
+    let gaps = ledger.gaps();
+    if gaps.is_empty() {
+        return Ok(());
+    }
+    Err(Error::InvoiceNumberGaps {
+        missing: gaps
+            .iter()
+            .map(InvoiceNumber::to_string)
+            .collect::<Vec<_>>()
+            .join(", "),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    fn issued(number: u16, date: &str, kind: InvoiceKind) -> IssuedInvoice {
+        IssuedInvoice::builder()
+            .number(InvoiceNumber::from(number))
+            .kind(kind)
+            .invoice_date(Date::from_str(date).unwrap())
+            .due_date(Date::from_str(date).unwrap())
+            .currency(Currency::EUR)
+            .total(Cost::sample())
+            .build()
+    }
+
+    #[test]
+    fn test_sequential_invoice_number_empty_ledger_is_derived() {
+        let number = sequential_invoice_number(
+            &InvoiceLedger::default(),
+            &YearAndMonth::may(2025),
+            InvoiceKind::Services,
+            || InvoiceNumber::from(100),
+        );
+        assert_eq!(number, InvoiceNumber::from(100));
+    }
+
+    #[test]
+    fn test_identifier_resets_yearly() {
+        let mut ledger = InvoiceLedger::default();
+        ledger.record(issued(10, "2024-11-30", InvoiceKind::Services));
+        ledger.record(issued(11, "2024-12-31", InvoiceKind::Services));
+        let numbering = InvoiceNumbering::builder()
+            .format(InvoiceNumberFormat::from_str("{year}-{number:03}").unwrap())
+            .reset_yearly(true)
+            .build();
+        let january = Date::from_str("2025-01-31").unwrap();
+        let first = numbering.identifier(InvoiceNumber::from(12), &january, &ledger);
+        assert_eq!(first.to_string(), "2025-001");
+
+        ledger.record(issued(12, "2025-01-31", InvoiceKind::Services));
+        let february = Date::from_str("2025-02-28").unwrap();
+        let second = numbering.identifier(InvoiceNumber::from(13), &february, &ledger);
+        assert_eq!(second.to_string(), "2025-002");
+
+        // Generating the last invoice of 2024 again.
+        let december = Date::from_str("2024-12-31").unwrap();
+        let again = numbering.identifier(InvoiceNumber::from(11), &december, &ledger);
+        assert_eq!(again.to_string(), "2024-002");
+    }
+
+    #[test]
+    fn test_identifier_default_numbering_is_number() {
+        let identifier = InvoiceNumbering::default().identifier(
+            InvoiceNumber::from(243),
+            &Date::sample(),
+            &InvoiceLedger::sample(),
+        );
+        assert_eq!(identifier.to_string(), "243");
+    }
+
+    #[test]
+    fn test_validate_invoice_numbering_gaps() {
+        let mut ledger = InvoiceLedger::sample();
+        ledger.record(issued(246, "2025-06-30", InvoiceKind::Services));
+        let sequential = InvoiceNumbering::sample();
+        let result = validate_invoice_numbering(&sequential, &ledger);
+        assert!(matches!(
+            result,
+            Err(Error::InvoiceNumberGaps { missing }) if missing == "244, 245"
+        ));
+        assert!(validate_invoice_numbering(&InvoiceNumbering::default(), &ledger).is_ok());
+    }
+}
@@ -0,0 +1,94 @@
+use serde::de::DeserializeOwned;
+
+use crate::prelude::*;
+
+/// The names of the files in the data directory which are migrated, all but
+/// the cache of exchange rates, which is recreated when needed.
+pub const MIGRATED_DATA_FILE_NAMES: [&str; 10] = [
+    DATA_FILE_NAME_VENDOR,
+    DATA_FILE_NAME_CLIENT,
+    DATA_FILE_NAME_PAYMENT,
+    DATA_FILE_NAME_SERVICE_FEES,
+    DATA_FILE_NAME_PROTO_INVOICE_INFO,
+    DATA_FILE_NAME_EXPENSES,
+    DATA_FILE_NAME_LEDGER,
+    DATA_FILE_NAME_PAYMENTS,
+    DATA_FILE_NAME_CHART_OF_ACCOUNTS,
+    DATA_FILE_NAME_SMTP,
+];
+
+/// The contents of the data files present in a data directory, keyed by the
+/// name of the file without extension, e.g. `"vendor"`, which migrations
+/// rewrite in memory before they are saved.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deref)]
+pub struct DataFiles(IndexMap<String, String>);
+
+impl DataFiles {
+    /// Reads the contents of the files of [`MIGRATED_DATA_FILE_NAMES`] present
+    /// at `base_path`.
+    pub fn read(base_path: impl AsRef<Path>) -> Result<Self> {
+        let base_path = base_path.as_ref();
+        let mut files = IndexMap::new();
+        for name in MIGRATED_DATA_FILE_NAMES {
+            let path = path_to_ron_file_with_base(base_path, name);
+            if !path.exists() {
+                continue;
+            }
+            let contents = read_data_file(&path)?;
+            files.insert(name.to_owned(), contents);
+        }
+        Ok(Self(files))
+    }
+
+    /// Deserializes the contents of the file `name` as `T`, `None` if the
+    /// file is not present.
+    pub fn deserialize<T: DeserializeOwned>(&self, name: &str) -> Result<Option<T>> {
+        self.0
+            .get(name)
+            .map(|contents| deserialize_ron_str(contents))
+            .transpose()
+    }
+
+    /// Saves the contents of every file at `base_path`.
+    pub fn write(&self, base_path: impl AsRef<Path>) -> Result<()> {
+        let base_path = base_path.as_ref();
+        for (name, contents) in &self.0 {
+            write_data_file(path_to_ron_file_with_base(base_path, name), contents)?;
+        }
+        Ok(())
+    }
+
+    /// Rewrites the file `name`, if present, by deserializing it as `Old`,
+    /// the format of a schema version, and serializing it converted to `New`,
+    /// the format of the next schema version.
+    pub fn convert<Old, New>(&mut self, name: &str) -> Result<()>
+    where
+        Old: DeserializeOwned,
+        New: Serialize + From<Old>,
+    {
+        if let Some(contents) = self.0.get_mut(name) {
+            let old: Old = deserialize_ron_str(contents)?;
+            *contents = to_ron_string(&New::from(old))?;
+        }
+        Ok(())
+    }
+
+    /// The files changed in `migrated`, compared to these files, in the
+    /// order of [`MIGRATED_DATA_FILE_NAMES`].
+    pub fn changes(&self, migrated: &Self) -> Vec<DataFileChange> {
+        migrated
+            .0
+            .iter()
+            .filter_map(|(name, after)| {
+                let before = self.0.get(name).map(String::as_str).unwrap_or_default();
+                DataFileChange::between(format!("{}.ron", name), before, after)
+            })
+            .collect()
+    }
+}
+
+impl From<IndexMap<String, String>> for DataFiles {
+    fn from(files: IndexMap<String, String>) -> Self {
+        Self(files)
+    }
+}
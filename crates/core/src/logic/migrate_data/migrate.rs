@@ -0,0 +1,353 @@
+use crate::prelude::*;
+
+/// The folder in the data directory with backups of the data files made
+/// before migrating them.
+pub const DATA_FOLDER_NAME_BACKUPS: &str = "backups";
+
+/// Reads the schema version of the data at `base_path`, which is
+/// [`SchemaVersion::UNVERSIONED`] if it has no `schema_version.ron`.
+pub fn schema_version(base_path: impl AsRef<Path>) -> Result<SchemaVersion> {
+    let base_path = base_path.as_ref();
+    if !path_to_ron_file_with_base(base_path, DATA_FILE_NAME_SCHEMA_VERSION).exists() {
+        return Ok(SchemaVersion::UNVERSIONED);
+    }
+    load_data(base_path, DATA_FILE_NAME_SCHEMA_VERSION)
+}
+
+/// Copies `files`, at schema `version`, into a new folder in the `backups`
+/// folder at `base_path`, returning the path of the new folder.
+fn backup(
+    files: &DataFiles,
+    version: SchemaVersion,
+    base_path: impl AsRef<Path>,
+) -> Result<PathBuf> {
+    let folder = base_path
+        .as_ref()
+        .join(DATA_FOLDER_NAME_BACKUPS)
+        .join(format!(
+            "schema-{}-{}",
+            version,
+            Local::now().format("%Y%m%d%H%M%S")
+        ));
+    create_folder_if_needed(&folder)?;
+    files.write(&folder)?;
+    if version != SchemaVersion::UNVERSIONED {
+        save_to_disk(
+            &version,
+            path_to_ron_file_with_base(&folder, DATA_FILE_NAME_SCHEMA_VERSION),
+        )?;
+    }
+    Ok(folder)
+}
+
+/// Returns an error if the data at schema `version` was written by a newer
+/// version of klirr.
+fn check_not_too_new(version: SchemaVersion) -> Result<()> {
+    if version > SchemaVersion::CURRENT {
+        return Err(Error::DataSchemaTooNew {
+            found: version,
+            supported: SchemaVersion::CURRENT,
+        });
+    }
+    Ok(())
+}
+
+/// Reads the data files at `base_path`, migrated in memory to
+/// [`SchemaVersion::CURRENT`] if they are at an older schema version,
+/// without writing any file.
+///
+/// # Errors
+/// Returns an error if the data was written by a newer version of klirr, or
+/// if a file cannot be read in the format of its schema version.
+pub fn read_migrated_data_files(base_path: impl AsRef<Path>) -> Result<DataFiles> {
+    let base_path = base_path.as_ref();
+    let from = schema_version(base_path)?;
+    check_not_too_new(from)?;
+    let mut files = DataFiles::read(base_path)?;
+    for migration in migrations_from(from) {
+        migration.apply(&mut files)?;
+    }
+    Ok(files)
+}
+
+/// Migrates the data files at `base_path`, like
+/// [`migrate_data_with_base_path`], the caller holding the [`DataLock`]
+/// unless `dry_run` is `true`.
+fn migrate_data_while_locked(base_path: &Path, dry_run: bool) -> Result<MigrationReport> {
+    let from = schema_version(base_path)?;
+    check_not_too_new(from)?;
+    let report = MigrationReport::builder()
+        .from(from)
+        .to(SchemaVersion::CURRENT)
+        .dry_run(dry_run);
+    let files = DataFiles::read(base_path)?;
+    if from == SchemaVersion::CURRENT || files.is_empty() {
+        return Ok(report.build());
+    }
+
+    let mut migrated = files.clone();
+    let mut migrations = Vec::new();
+    for migration in migrations_from(from) {
+        debug!("☑️ Migrating data to schema {}", migration.to());
+        migration.apply(&mut migrated)?;
+        migrations.push((migration.to(), migration.description().to_owned()));
+    }
+    let changes = files.changes(&migrated);
+    let report = report.migrations(migrations).changes(changes);
+    if dry_run {
+        return Ok(report.backup(None).build());
+    }
+
+    let backup = backup(&files, from, base_path)?;
+    let file_names = MIGRATED_DATA_FILE_NAMES
+        .into_iter()
+        .chain([DATA_FILE_NAME_SCHEMA_VERSION])
+        .collect::<Vec<_>>();
+    record_data_revision(
+        base_path,
+        format!(
+            "Migrate data from schema {} to {}",
+            from,
+            SchemaVersion::CURRENT
+        ),
+        &file_names,
+    )?;
+    migrated.write(base_path)?;
+    save_to_disk(
+        &SchemaVersion::CURRENT,
+        path_to_ron_file_with_base(base_path, DATA_FILE_NAME_SCHEMA_VERSION),
+    )?;
+    info!(
+        "✅ Migrated data from schema {} to {}, backup at: {}",
+        from,
+        SchemaVersion::CURRENT,
+        backup.display()
+    );
+    Ok(report.backup(Some(backup)).build())
+}
+
+/// Migrates the data files at `base_path` to [`SchemaVersion::CURRENT`],
+/// one migration at a time, after copying them into the `backups` folder
+/// and recording them in the history of the data, locking the data
+/// directory meanwhile. If `dry_run` is `true` the files are left untouched
+/// and the report tells what would change.
+///
+/// # Errors
+/// Returns an error if the data was written by a newer version of klirr, or
+/// if a file cannot be read in the format of its schema version.
+pub fn migrate_data_with_base_path(
+    base_path: impl AsRef<Path>,
+    dry_run: bool,
+) -> Result<MigrationReport> {
+    let base_path = base_path.as_ref();
+    if dry_run {
+        return migrate_data_while_locked(base_path, true);
+    }
+    let _lock = DataLock::acquire(base_path)?;
+    migrate_data_while_locked(base_path, false)
+}
+
+/// Locks the data directory at `base_path` to change its files, first
+/// migrating them on disk if they are at an older schema version, so that
+/// files in the current format are never written next to files in an
+/// older one. Reading the data only migrates it in memory.
+///
+/// # Errors
+/// Returns an error if the lock is held by another running klirr, or if
+/// the data cannot be migrated.
+pub fn lock_migrated_data(base_path: impl AsRef<Path>) -> Result<DataLock> {
+    let base_path = base_path.as_ref();
+    let lock = DataLock::acquire(base_path)?;
+    migrate_data_while_locked(base_path, false)?;
+    Ok(lock)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    const LEGACY_COMPANY_INFORMATION: &str = r#"CompanyInformation(
+    contact_person: Some("Sherlock Holmes"),
+    organisation_number: "9876543-2101",
+    company_name: "Holmes Ltd",
+    postal_address: PostalAddress(
+        street_address: StreetAddress(
+            line_1: "221B Baker Street",
+            line_2: "",
+        ),
+        zip: "NW1 6XE",
+        country: "England",
+        city: "London",
+    ),
+    vat_number: "GB987654353",
+)"#;
+
+    const LEGACY_SERVICE_FEES: &str = r#"ServiceFees(
+    name: "Consulting",
+    unit_price: UnitPrice(1000.0),
+)"#;
+
+    const LEGACY_PROTO_INVOICE_INFO: &str = r##"ProtoInvoiceInfo(
+    offset: TimestampedInvoiceNumber(
+        offset: 17,
+        month: "2025-01",
+    ),
+    months_off_record: MonthsOffRecord(["2025-03"]),
+    purchase_order: Some("PO-1234"),
+    footer_text: None,
+    emphasize_color_hex: Some("#e6007a"),
+)"##;
+
+    const LEGACY_EXPENSES: &str = r#"ExpensedMonths(
+    explanation: "Expenses for months",
+    expenses_for_months: {},
+)"#;
+
+    /// A data directory written before the schema was versioned, with a
+    /// single service fee.
+    fn unversioned_data_dir() -> tempfile::TempDir {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        save_data_with_base_path(Data::sample(), tempdir.path()).unwrap();
+        std::fs::remove_file(path_to_ron_file_with_base(
+            tempdir.path(),
+            DATA_FILE_NAME_SCHEMA_VERSION,
+        ))
+        .unwrap();
+        for (name, contents) in [
+            (DATA_FILE_NAME_VENDOR, LEGACY_COMPANY_INFORMATION),
+            (DATA_FILE_NAME_CLIENT, LEGACY_COMPANY_INFORMATION),
+            (DATA_FILE_NAME_SERVICE_FEES, LEGACY_SERVICE_FEES),
+            (DATA_FILE_NAME_PROTO_INVOICE_INFO, LEGACY_PROTO_INVOICE_INFO),
+            (DATA_FILE_NAME_EXPENSES, LEGACY_EXPENSES),
+        ] {
+            std::fs::write(path_to_ron_file_with_base(tempdir.path(), name), contents).unwrap();
+        }
+        tempdir
+    }
+
+    fn service_fees_contents(base_path: impl AsRef<Path>) -> String {
+        std::fs::read_to_string(path_to_ron_file_with_base(
+            base_path,
+            DATA_FILE_NAME_SERVICE_FEES,
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_dry_run_reports_changes_without_touching_files() {
+        let tempdir = unversioned_data_dir();
+        let report = migrate_data_with_base_path(tempdir.path(), true).unwrap();
+        assert_eq!(*report.from(), SchemaVersion::UNVERSIONED);
+        assert_eq!(*report.to(), SchemaVersion::CURRENT);
+        assert_eq!(
+            report
+                .changes()
+                .iter()
+                .map(|change| change.file_name().as_str())
+                .collect::<Vec<_>>(),
+            [
+                "vendor.ron",
+                "client.ron",
+                "service_fees.ron",
+                "invoice_info.ron",
+                "expenses.ron",
+            ]
+        );
+        assert!(report.changes()[2].diff().contains("+    services: ["));
+        assert_eq!(*report.backup(), None);
+        assert_eq!(service_fees_contents(tempdir.path()), LEGACY_SERVICE_FEES);
+        assert_eq!(
+            schema_version(tempdir.path()).unwrap(),
+            SchemaVersion::UNVERSIONED
+        );
+        assert!(!tempdir.path().join(DATA_FOLDER_NAME_BACKUPS).exists());
+    }
+
+    #[test]
+    fn test_migrate_backs_up_and_rewrites_files() {
+        let tempdir = unversioned_data_dir();
+        let report = migrate_data_with_base_path(tempdir.path(), false).unwrap();
+        let backup = report.backup().clone().unwrap();
+        assert_eq!(service_fees_contents(&backup), LEGACY_SERVICE_FEES);
+        assert_eq!(
+            schema_version(tempdir.path()).unwrap(),
+            SchemaVersion::CURRENT
+        );
+        let service_fees: ServiceFees =
+            load_data(tempdir.path(), DATA_FILE_NAME_SERVICE_FEES).unwrap();
+        assert_eq!(service_fees.len(), 1);
+        assert_ne!(service_fees_contents(tempdir.path()), LEGACY_SERVICE_FEES);
+
+        let report = migrate_data_with_base_path(tempdir.path(), false).unwrap();
+        assert!(report.is_up_to_date());
+        assert_eq!(*report.backup(), None);
+    }
+
+    #[test]
+    fn test_reading_data_migrates_it_in_memory_only() {
+        let tempdir = unversioned_data_dir();
+        let before = std::fs::read_dir(tempdir.path()).unwrap().count();
+        let data = read_data_from_disk_with_base_path(tempdir.path()).unwrap();
+        assert_eq!(data.service_fees().len(), 1);
+        assert_eq!(
+            *data.service_fees()[0].quantity(),
+            ServiceQuantity::WorkedDays
+        );
+        assert_eq!(data.client().company_name(), "Holmes Ltd");
+        assert!(data.client().billing_emails().is_empty());
+        assert_eq!(
+            data.information().purchase_order(),
+            &Some(PurchaseOrder::from("PO-1234"))
+        );
+        assert!(
+            data.information()
+                .months_off_record()
+                .contains(&YearAndMonth::march(2025))
+        );
+        assert_eq!(*data.information().vat_rate(), Percentage::ZERO);
+        assert!(data.expensed_months().recurring().is_empty());
+        assert_eq!(service_fees_contents(tempdir.path()), LEGACY_SERVICE_FEES);
+        assert_eq!(
+            schema_version(tempdir.path()).unwrap(),
+            SchemaVersion::UNVERSIONED
+        );
+        assert_eq!(std::fs::read_dir(tempdir.path()).unwrap().count(), before);
+    }
+
+    #[test]
+    fn test_changing_data_migrates_it_first() {
+        let tempdir = unversioned_data_dir();
+        record_month_off_with_base_path(&YearAndMonth::may(2025), tempdir.path()).unwrap();
+        assert_eq!(
+            schema_version(tempdir.path()).unwrap(),
+            SchemaVersion::CURRENT
+        );
+        assert!(tempdir.path().join(DATA_FOLDER_NAME_BACKUPS).exists());
+        assert_ne!(service_fees_contents(tempdir.path()), LEGACY_SERVICE_FEES);
+
+        // The migration is recorded, and can be undone after the change.
+        let history = data_history_with_base_path(tempdir.path()).unwrap();
+        assert_eq!(history.len(), 2);
+        undo_data_with_base_path(tempdir.path(), Some(1)).unwrap();
+        assert_eq!(service_fees_contents(tempdir.path()), LEGACY_SERVICE_FEES);
+        assert_eq!(
+            schema_version(tempdir.path()).unwrap(),
+            SchemaVersion::UNVERSIONED
+        );
+    }
+
+    #[test]
+    fn test_data_of_newer_schema_is_not_migrated() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        save_data_with_base_path(Data::sample(), tempdir.path()).unwrap();
+        save_to_disk(
+            &SchemaVersion::CURRENT.next(),
+            path_to_ron_file_with_base(tempdir.path(), DATA_FILE_NAME_SCHEMA_VERSION),
+        )
+        .unwrap();
+        let result = migrate_data_with_base_path(tempdir.path(), true);
+        assert!(matches!(result, Err(Error::DataSchemaTooNew { .. })));
+        assert!(read_data_from_disk_with_base_path(tempdir.path()).is_err());
+    }
+}
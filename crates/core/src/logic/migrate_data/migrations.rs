@@ -0,0 +1,271 @@
+use crate::prelude::*;
+
+/// A step upgrading the data files from the schema version before `to` to
+/// `to`, rewriting the contents of the files in memory.
+#[derive(Clone, Copy, Debug)]
+pub struct Migration {
+    /// The schema version of the files after this migration.
+    to: SchemaVersion,
+
+    /// What this migration changes, shown by `klirr data migrate`.
+    description: &'static str,
+
+    /// Rewrites the files, which are at the schema version before `to`.
+    apply: fn(&mut DataFiles) -> Result<()>,
+}
+
+impl Migration {
+    /// The schema version of the files after this migration.
+    pub fn to(&self) -> SchemaVersion {
+        self.to
+    }
+
+    /// What this migration changes.
+    pub fn description(&self) -> &'static str {
+        self.description
+    }
+
+    /// Rewrites `files`, which are at the schema version before
+    /// [`Migration::to`].
+    pub fn apply(&self, files: &mut DataFiles) -> Result<()> {
+        (self.apply)(files)
+    }
+}
+
+/// All migrations, in order, each migrating to the version after the
+/// previous one, the last one to [`SchemaVersion::CURRENT`]. A migration is
+/// added whenever the format of a data file changes, converting the files
+/// from the format of the previous version, so that the current types only
+/// ever read the current format.
+pub const MIGRATIONS: &[Migration] = &[Migration {
+    to: SchemaVersion::new(1),
+    description: "Add the fields introduced since the files were created and convert the single service fee into a list of services",
+    apply: unversioned_to_v1::migrate,
+}];
+
+/// The migration of files created before the schema was versioned to
+/// version 1, with the formats of both frozen as they were. Fields whose
+/// format did not change are kept as they are written in the file.
+mod unversioned_to_v1 {
+    use super::*;
+    use ron::value::RawValue;
+
+    type Raw = Box<RawValue>;
+
+    /// A value written as `ron`, which must be valid RON.
+    fn raw(ron: &str) -> Raw {
+        RawValue::from_boxed_ron(ron.into()).expect("Valid RON")
+    }
+
+    pub(super) fn migrate(files: &mut DataFiles) -> Result<()> {
+        files.convert::<CompanyInformationV0, CompanyInformationV1>(DATA_FILE_NAME_VENDOR)?;
+        files.convert::<CompanyInformationV0, CompanyInformationV1>(DATA_FILE_NAME_CLIENT)?;
+        files.convert::<ServiceFeesV0, ServiceFeesV1>(DATA_FILE_NAME_SERVICE_FEES)?;
+        files
+            .convert::<ProtoInvoiceInfoV0, ProtoInvoiceInfoV1>(DATA_FILE_NAME_PROTO_INVOICE_INFO)?;
+        files.convert::<ExpensedMonthsV0, ExpensedMonthsV1>(DATA_FILE_NAME_EXPENSES)?;
+        Ok(())
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename = "CompanyInformation")]
+    struct CompanyInformationV0 {
+        contact_person: Option<Raw>,
+        organisation_number: Raw,
+        company_name: Raw,
+        postal_address: Raw,
+        vat_number: Raw,
+    }
+
+    /// Adds `billing_emails`, empty.
+    #[derive(Serialize)]
+    #[serde(rename = "CompanyInformation")]
+    struct CompanyInformationV1 {
+        contact_person: Option<Raw>,
+        organisation_number: Raw,
+        company_name: Raw,
+        postal_address: Raw,
+        vat_number: Raw,
+        billing_emails: Raw,
+    }
+
+    impl From<CompanyInformationV0> for CompanyInformationV1 {
+        fn from(v0: CompanyInformationV0) -> Self {
+            Self {
+                contact_person: v0.contact_person.map(RawValue::trim_boxed),
+                organisation_number: v0.organisation_number.trim_boxed(),
+                company_name: v0.company_name.trim_boxed(),
+                postal_address: v0.postal_address.trim_boxed(),
+                vat_number: v0.vat_number.trim_boxed(),
+                billing_emails: raw("[]"),
+            }
+        }
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename = "ServiceFees")]
+    struct ServiceFeesV0 {
+        name: Raw,
+        unit_price: Raw,
+    }
+
+    /// Converts the single service into a list of services, billed per
+    /// worked day.
+    #[derive(Serialize)]
+    #[serde(rename = "ServiceFees")]
+    struct ServiceFeesV1 {
+        services: Vec<ServiceFeeV1>,
+    }
+
+    #[derive(Serialize)]
+    #[serde(rename = "ServiceFee")]
+    struct ServiceFeeV1 {
+        name: Raw,
+        unit_price: Raw,
+        quantity: Raw,
+    }
+
+    impl From<ServiceFeesV0> for ServiceFeesV1 {
+        fn from(v0: ServiceFeesV0) -> Self {
+            Self {
+                services: vec![ServiceFeeV1 {
+                    name: v0.name.trim_boxed(),
+                    unit_price: v0.unit_price.trim_boxed(),
+                    quantity: raw("WorkedDays"),
+                }],
+            }
+        }
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename = "ProtoInvoiceInfo")]
+    struct ProtoInvoiceInfoV0 {
+        offset: Raw,
+        months_off_record: Raw,
+        purchase_order: Option<Raw>,
+        footer_text: Option<Raw>,
+        emphasize_color_hex: Option<Raw>,
+    }
+
+    /// Adds `days_off_record`, `vat_rate`, `numbering` and `adjustments`,
+    /// with no days off, no VAT, numbers derived from the month and no
+    /// adjustments.
+    #[derive(Serialize)]
+    #[serde(rename = "ProtoInvoiceInfo")]
+    struct ProtoInvoiceInfoV1 {
+        offset: Raw,
+        months_off_record: Raw,
+        days_off_record: Raw,
+        purchase_order: Option<Raw>,
+        footer_text: Option<Raw>,
+        emphasize_color_hex: Option<Raw>,
+        vat_rate: Raw,
+        numbering: Raw,
+        adjustments: Raw,
+    }
+
+    impl From<ProtoInvoiceInfoV0> for ProtoInvoiceInfoV1 {
+        fn from(v0: ProtoInvoiceInfoV0) -> Self {
+            Self {
+                offset: v0.offset.trim_boxed(),
+                months_off_record: v0.months_off_record.trim_boxed(),
+                days_off_record: raw("DaysOffRecord({})"),
+                purchase_order: v0.purchase_order.map(RawValue::trim_boxed),
+                footer_text: v0.footer_text.map(RawValue::trim_boxed),
+                emphasize_color_hex: v0.emphasize_color_hex.map(RawValue::trim_boxed),
+                vat_rate: raw("Percentage(0.0)"),
+                numbering: raw(
+                    r#"InvoiceNumbering(format: "{number}", counter: MonthlyDerived, reset_yearly: false)"#,
+                ),
+                adjustments: raw("[]"),
+            }
+        }
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename = "ExpensedMonths")]
+    struct ExpensedMonthsV0 {
+        explanation: Raw,
+        expenses_for_months: Raw,
+    }
+
+    /// Adds `recurring`, empty.
+    #[derive(Serialize)]
+    #[serde(rename = "ExpensedMonths")]
+    struct ExpensedMonthsV1 {
+        explanation: Raw,
+        expenses_for_months: Raw,
+        recurring: Raw,
+    }
+
+    impl From<ExpensedMonthsV0> for ExpensedMonthsV1 {
+        fn from(v0: ExpensedMonthsV0) -> Self {
+            Self {
+                explanation: v0.explanation.trim_boxed(),
+                expenses_for_months: v0.expenses_for_months.trim_boxed(),
+                recurring: raw("[]"),
+            }
+        }
+    }
+}
+
+/// The migrations of files at `version` to [`SchemaVersion::CURRENT`].
+pub fn migrations_from(version: SchemaVersion) -> impl Iterator<Item = &'static Migration> {
+    MIGRATIONS
+        .iter()
+        .filter(move |migration| migration.to > version)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn test_migrations_are_consecutive_up_to_current() {
+        let mut version = SchemaVersion::UNVERSIONED;
+        for migration in MIGRATIONS {
+            assert_eq!(migration.to(), version.next());
+            version = migration.to();
+        }
+        assert_eq!(version, SchemaVersion::CURRENT);
+    }
+
+    #[test]
+    fn test_unversioned_service_fees_are_converted_into_a_list() {
+        let mut files = DataFiles::from(IndexMap::from([(
+            DATA_FILE_NAME_SERVICE_FEES.to_owned(),
+            r#"ServiceFees(name: "Consulting", unit_price: UnitPrice(1000.0))"#.to_owned(),
+        )]));
+        unversioned_to_v1::migrate(&mut files).unwrap();
+        assert_eq!(
+            files[DATA_FILE_NAME_SERVICE_FEES],
+            r#"ServiceFees(
+    services: [
+        ServiceFee(
+            name: "Consulting",
+            unit_price: UnitPrice(1000.0),
+            quantity: WorkedDays,
+        ),
+    ],
+)"#
+        );
+        let fees = files
+            .deserialize::<ServiceFees>(DATA_FILE_NAME_SERVICE_FEES)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            fees.get("Consulting").unwrap().quantity(),
+            &ServiceQuantity::WorkedDays
+        );
+    }
+
+    #[test]
+    fn test_migrations_from_current_is_empty() {
+        assert_eq!(migrations_from(SchemaVersion::CURRENT).count(), 0);
+        assert_eq!(
+            migrations_from(SchemaVersion::UNVERSIONED).count(),
+            MIGRATIONS.len()
+        );
+    }
+}
@@ -0,0 +1,7 @@
+mod data_files;
+mod migrate;
+mod migrations;
+
+pub use data_files::*;
+pub use migrate::*;
+pub use migrations::*;
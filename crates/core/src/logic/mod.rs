@@ -1,17 +1,45 @@
+mod bank_statement;
 mod calendar_logic;
 mod command;
+mod create_batch;
 mod create_pdf;
+mod create_preview;
+mod create_reminder;
+mod data_history;
+mod dry_run_invoice;
+mod email_invoice;
+mod encrypt_data;
+mod expense_import;
+mod export_sie;
 mod file_path_logic;
+mod invoice_numbering;
+mod migrate_data;
 mod prepare_data;
 mod read_write_data;
+mod receipts;
+mod revenue_report;
 mod save_pdf_location_to_tmp_file;
 mod serde_to_typst;
 
+pub use bank_statement::*;
 pub use calendar_logic::*;
 pub use command::*;
+pub use create_batch::*;
 pub use create_pdf::*;
+pub use create_preview::*;
+pub use create_reminder::*;
+pub use data_history::*;
+pub use dry_run_invoice::*;
+pub use email_invoice::*;
+pub use encrypt_data::*;
+pub use expense_import::*;
+pub use export_sie::*;
 pub use file_path_logic::*;
+pub use invoice_numbering::*;
+pub use migrate_data::*;
 pub use prepare_data::*;
 pub use read_write_data::*;
+pub use receipts::*;
+pub use revenue_report::*;
 pub use save_pdf_location_to_tmp_file::*;
 pub use serde_to_typst::*;
@@ -75,6 +75,10 @@ pub type ExchangeRatesMap = IndexMap<Currency, UnitPrice>;
 #[derive(TypedBuilder)]
 pub struct ExchangeRatesFetcher<T = ()> {
     path_to_cache: PathBuf,
+    /// If newly fetched rates are saved to the cache, else the cache is only
+    /// read from.
+    #[builder(default = true)]
+    save_cache: bool,
     /// Useful for testing, allows to use a temporary directory for caching
     #[allow(dead_code)]
     extra: T,
@@ -85,11 +89,23 @@ impl Default for ExchangeRatesFetcher {
     fn default() -> Self {
         Self {
             path_to_cache: data_dir(),
+            save_cache: true,
             extra: (),
         }
     }
 }
 
+impl ExchangeRatesFetcher {
+    /// Uses exchange rates cached in the user's data directory, but never
+    /// writes newly fetched rates to it, e.g. for dry runs.
+    pub fn read_only() -> Self {
+        Self {
+            save_cache: false,
+            ..Self::default()
+        }
+    }
+}
+
 type FromCurrency = Currency;
 type ToCurrency = Currency;
 type ExchangeRate = UnitPrice;
@@ -145,6 +161,17 @@ impl CachedRates {
             Ok((rate, true))
         }
     }
+
+    /// Inserts all rates of `other` into this cache, keeping the rates of
+    /// `other` where both have a rate.
+    fn merge(&mut self, other: &CachedRates) {
+        for (date, rates_on_day) in &other.0 {
+            for (from, rates_from) in rates_on_day {
+                self._rates_for_day_and_from_currency(date, from)
+                    .extend(rates_from.iter().map(|(to, rate)| (*to, *rate)));
+            }
+        }
+    }
 }
 
 impl<T> ExchangeRatesFetcher<T> {
@@ -186,17 +213,31 @@ impl<T> ExchangeRatesFetcher<T> {
         })
     }
 
+    /// Merges `rates_by_day` into the cache on disk, reading it again while
+    /// the data directory is locked so that rates cached by a concurrently
+    /// running klirr are kept.
+    fn merge_into_cache(&self, rates_by_day: &CachedRates) -> Result<()> {
+        let _lock = DataLock::acquire(&self.path_to_cache)?;
+        let mut cache = self.load_cache_else_new();
+        cache.merge(rates_by_day);
+        self._save_cache(&cache)
+    }
+
     fn update_cache_if_needed(&self, rates_by_day: &CachedRates, fetched_new_rates: bool) {
         if !fetched_new_rates {
             debug!("ℹ️ No new rates fetched, used only cached rates.");
             return;
         }
+        if !self.save_cache {
+            debug!("ℹ️ Fetched new rates, not caching them.");
+            return;
+        }
         // Update cache
         debug!(
             "☑️ Fetched new rates, updating cache: {}",
             self.path_to_cache.display()
         );
-        match self._save_cache(rates_by_day) {
+        match self.merge_into_cache(rates_by_day) {
             Ok(_) => debug!("✅ Cached exchange rates updated."),
             Err(e) => {
                 // Failing to update cache is not critical, but we log it
@@ -417,6 +458,44 @@ mod tests {
         assert_eq!(loaded, cache, "Cache should be updated with new rates.");
     }
 
+    #[test]
+    fn test_read_only_cache_is_unchanged() {
+        let tempdir = tempdir().unwrap();
+        let fetcher = ExchangeRatesFetcher::builder()
+            .path_to_cache(tempdir.path().to_path_buf())
+            .save_cache(false)
+            .extra(())
+            .build();
+        let mut cache = CachedRates::default();
+        cache
+            ._rates_for_day_and_from_currency(Date::sample(), Currency::EUR)
+            .insert(Currency::USD, UnitPrice::from(dec!(1.2)));
+        fetcher.update_cache_if_needed(&cache, true);
+        assert!(fetcher._load_cache().is_err());
+    }
+
+    #[test]
+    fn test_update_cache_keeps_rates_cached_meanwhile() {
+        let tempdir = tempdir().unwrap();
+        let fetcher = ExchangeRatesFetcher::tmp(tempdir);
+        let mut cached_meanwhile = CachedRates::default();
+        cached_meanwhile
+            ._rates_for_day_and_from_currency(Date::sample(), Currency::GBP)
+            .insert(Currency::USD, UnitPrice::from(dec!(1.3)));
+        fetcher._save_cache(&cached_meanwhile).unwrap();
+
+        let mut fetched = CachedRates::default();
+        fetched
+            ._rates_for_day_and_from_currency(Date::sample(), Currency::EUR)
+            .insert(Currency::USD, UnitPrice::from(dec!(1.2)));
+        fetcher.update_cache_if_needed(&fetched, true);
+
+        let mut expected = cached_meanwhile;
+        expected.merge(&fetched);
+        assert_eq!(fetcher._load_cache().unwrap(), expected);
+        assert_eq!(expected.0[&Date::sample()].len(), 2);
+    }
+
     #[test]
     fn test_fetch_for_items_all_found_in_cache() {
         let tempdir = tempdir().unwrap();
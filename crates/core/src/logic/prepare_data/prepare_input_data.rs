@@ -9,18 +9,29 @@ pub trait FetchExchangeRates {
         target_currency: Currency,
         line_items: &LineItemsPricedInSourceCurrency,
     ) -> Result<ExchangeRates> {
-        let Ok(expenses) = line_items.clone().try_unwrap_expenses() else {
+        let expenses = line_items.expenses().to_vec();
+        if expenses.is_empty() {
             debug!("No expenses found, skipping exchange rate fetching.");
             return Ok(ExchangeRates::builder()
                 .target_currency(target_currency)
                 .rates(ExchangeRatesMap::new())
                 .build());
-        };
+        }
         debug!("☑️ Fetching rates for #{} expenses...", expenses.len());
         self.fetch_for_items(target_currency, expenses)
     }
 }
 
+impl<T: FetchExchangeRates> FetchExchangeRates for &T {
+    fn fetch_for_items(
+        &self,
+        target_currency: Currency,
+        items: Vec<Item>,
+    ) -> Result<ExchangeRates> {
+        (*self).fetch_for_items(target_currency, items)
+    }
+}
+
 pub fn prepare_invoice_input_data(
     data: Data,
     input: ValidInput,
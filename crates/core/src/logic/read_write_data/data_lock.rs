@@ -0,0 +1,169 @@
+use std::time::{Duration, Instant};
+
+use fs4::fs_std::FileExt;
+
+use crate::prelude::*;
+
+/// The name of the lock file in the data directory, held by a running klirr
+/// while it reads, modifies and writes data files.
+pub const DATA_LOCK_FILE_NAME: &str = ".klirr.lock";
+
+/// How long [`DataLock::acquire`] waits for another running klirr to
+/// release the lock before failing.
+pub const DATA_LOCK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How often a held lock is polled while waiting for it.
+const DATA_LOCK_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// An advisory, exclusive lock of a data directory, preventing two running
+/// klirr, e.g. a cron job and a manual run, from interleaving their writes.
+/// The lock is released when dropped.
+#[derive(Debug)]
+pub struct DataLock {
+    file: std::fs::File,
+}
+
+impl DataLock {
+    /// Locks the data directory at `base_path`, waiting up to
+    /// [`DATA_LOCK_TIMEOUT`] if it is locked by another running klirr.
+    ///
+    /// # Errors
+    /// Returns [`Error::DataDirectoryLocked`] if the lock is still held after
+    /// waiting.
+    pub fn acquire(base_path: impl AsRef<Path>) -> Result<Self> {
+        Self::acquire_with_timeout(base_path, DATA_LOCK_TIMEOUT)
+    }
+
+    /// Locks the data directory at `base_path`, waiting up to `timeout` if it
+    /// is locked by another running klirr.
+    ///
+    /// # Errors
+    /// Returns [`Error::DataDirectoryLocked`] if the lock is still held after
+    /// `timeout`.
+    pub fn acquire_with_timeout(base_path: impl AsRef<Path>, timeout: Duration) -> Result<Self> {
+        let base_path = base_path.as_ref();
+        create_folder_if_needed(base_path)?;
+        let path = base_path.join(DATA_LOCK_FILE_NAME);
+        let file = std::fs::OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(&path)
+            .map_err(|e| Error::FailedToWriteDataToDisk {
+                underlying: format!("Failed to open lock file {}: {:?}", path.display(), e),
+            })?;
+        let started = Instant::now();
+        loop {
+            let locked = file
+                .try_lock_exclusive()
+                .map_err(|e| Error::FailedToWriteDataToDisk {
+                    underlying: format!("Failed to lock {}: {:?}", path.display(), e),
+                })?;
+            if locked {
+                trace!("🔒 Locked data directory: {}", base_path.display());
+                return Ok(Self { file });
+            }
+            if started.elapsed() >= timeout {
+                return Err(Error::DataDirectoryLocked {
+                    path: path.display().to_string(),
+                });
+            }
+            debug!("⏳ Waiting for lock of: {}", base_path.display());
+            std::thread::sleep(DATA_LOCK_POLL_INTERVAL);
+        }
+    }
+}
+
+impl Drop for DataLock {
+    fn drop(&mut self) {
+        if let Err(e) = FileExt::unlock(&self.file) {
+            warn!("Failed to unlock data directory: {:?}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn test_lock_held_gives_clear_error() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        let _lock = DataLock::acquire(tempdir.path()).unwrap();
+        let result = DataLock::acquire_with_timeout(tempdir.path(), Duration::ZERO);
+        assert!(matches!(result, Err(Error::DataDirectoryLocked { .. })));
+    }
+
+    #[test]
+    fn test_lock_is_released_when_dropped() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        drop(DataLock::acquire(tempdir.path()).unwrap());
+        assert!(DataLock::acquire_with_timeout(tempdir.path(), Duration::ZERO).is_ok());
+    }
+
+    #[test]
+    fn test_mutate_fails_while_locked() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        save_data_with_base_path(Data::sample(), tempdir.path()).unwrap();
+        let _lock = DataLock::acquire(tempdir.path()).unwrap();
+        let result = record_month_off_with_base_path(&YearAndMonth::may(2025), tempdir.path());
+        assert!(matches!(result, Err(Error::DataDirectoryLocked { .. })));
+    }
+
+    #[test]
+    fn test_recording_issued_invoice_fails_while_locked() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        let _lock = DataLock::acquire(tempdir.path()).unwrap();
+        let result = record_issued_invoice(tempdir.path(), IssuedInvoice::sample());
+        assert!(matches!(result, Err(Error::DataDirectoryLocked { .. })));
+    }
+
+    #[test]
+    fn test_recording_payment_fails_while_locked() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        record_issued_invoice(tempdir.path(), IssuedInvoice::sample()).unwrap();
+        let _lock = DataLock::acquire(tempdir.path()).unwrap();
+        let result = record_payment_with_base_path(Payment::sample(), tempdir.path());
+        assert!(matches!(result, Err(Error::DataDirectoryLocked { .. })));
+    }
+
+    #[test]
+    fn test_edit_does_not_lock_while_prompting() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        save_data_with_base_path(Data::sample(), tempdir.path()).unwrap();
+        edit_data_at(tempdir.path(), |data| {
+            // E.g. a cron job recording expenses while the user is prompted.
+            record_expenses_with_base_path(
+                &YearAndMonth::may(2025),
+                &[Item::sample_expense_coffee()],
+                tempdir.path(),
+            )?;
+            Ok(data.with_client(CompanyInformation::sample_vendor()))
+        })
+        .unwrap();
+        let data = read_data_from_disk_with_base_path(tempdir.path()).unwrap();
+        assert_eq!(*data.client(), CompanyInformation::sample_vendor());
+        assert!(data.expensed_months().contains(&YearAndMonth::may(2025)));
+    }
+
+    #[test]
+    fn test_edit_of_data_changed_meanwhile_fails() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        save_data_with_base_path(Data::sample(), tempdir.path()).unwrap();
+        let result = edit_data_at(tempdir.path(), |data| {
+            record_month_off_with_base_path(&YearAndMonth::may(2025), tempdir.path())?;
+            let information = data
+                .information()
+                .clone()
+                .with_vat_rate(Percentage::from_str("25%").unwrap());
+            Ok(data.with_information(information))
+        });
+        assert!(matches!(
+            result,
+            Err(Error::DataChangedMeanwhile { file_name }) if file_name == DATA_FILE_NAME_PROTO_INVOICE_INFO
+        ));
+        let data = read_data_from_disk_with_base_path(tempdir.path()).unwrap();
+        assert_eq!(*data.information().vat_rate(), Percentage::ZERO);
+    }
+}
@@ -0,0 +1,284 @@
+use std::sync::Mutex;
+
+use chacha20poly1305::{
+    Key, XChaCha20Poly1305, XNonce,
+    aead::{Aead, NewAead},
+};
+
+use crate::prelude::*;
+
+/// The environment variable with the passphrase of an encrypted data
+/// directory, used instead of asking for it.
+pub const ENV_VAR_DATA_PASSPHRASE: &str = "KLIRR_DATA_PASSPHRASE";
+
+/// The base 2 logarithm of the scrypt cost parameter `N` used when
+/// encrypting a data directory, as recommended for interactive use.
+pub const DEFAULT_SCRYPT_LOG_N: u8 = 15;
+
+const KEY_LENGTH: usize = 32;
+const SALT_LENGTH: usize = 16;
+const NONCE_LENGTH: usize = 24;
+
+/// The text encrypted into [`DataEncryption::verifier`].
+const VERIFIER_PLAINTEXT: &str = BINARY_NAME;
+
+/// How many folders up from a data file to look for `encryption.ron`, which
+/// covers the files in `history` and in the folders of `backups`.
+const ENCRYPTION_SEARCH_DEPTH: usize = 3;
+
+type DerivedKeyId = (String, u8, Vec<u8>);
+type PassphrasePrompt = fn() -> Result<String>;
+
+lazy_static::lazy_static! {
+    static ref DATA_PASSPHRASE: Mutex<Option<String>> = Mutex::new(None);
+    static ref DATA_PASSPHRASE_PROMPT: Mutex<Option<PassphrasePrompt>> = Mutex::new(None);
+    /// Keys derived with scrypt, which is slow by design, so that each key is
+    /// derived at most once per run.
+    static ref DERIVED_KEYS: Mutex<HashMap<DerivedKeyId, [u8; KEY_LENGTH]>> =
+        Mutex::new(HashMap::new());
+}
+
+/// Sets the passphrase of encrypted data directories for the rest of this
+/// run.
+pub fn set_data_passphrase(passphrase: impl Into<String>) {
+    *DATA_PASSPHRASE.lock().expect("Passphrase lock poisoned") = Some(passphrase.into());
+}
+
+/// Sets the function asking the user for the passphrase the first time an
+/// encrypted file is read or written, if [`ENV_VAR_DATA_PASSPHRASE`] is not
+/// set.
+pub fn set_data_passphrase_prompt(prompt: PassphrasePrompt) {
+    *DATA_PASSPHRASE_PROMPT
+        .lock()
+        .expect("Passphrase prompt lock poisoned") = Some(prompt);
+}
+
+/// The passphrase set by [`set_data_passphrase`], else the one in
+/// [`ENV_VAR_DATA_PASSPHRASE`], else the one given to the prompt.
+fn data_passphrase() -> Result<String> {
+    let mut passphrase = DATA_PASSPHRASE.lock().expect("Passphrase lock poisoned");
+    if let Some(passphrase) = passphrase.as_ref() {
+        return Ok(passphrase.clone());
+    }
+    let prompt = *DATA_PASSPHRASE_PROMPT
+        .lock()
+        .expect("Passphrase prompt lock poisoned");
+    let provided = match (std::env::var(ENV_VAR_DATA_PASSPHRASE).ok(), prompt) {
+        (Some(from_env), _) => from_env,
+        (None, Some(prompt)) => prompt()?,
+        (None, None) => {
+            return Err(Error::MissingDataPassphrase {
+                env_var: ENV_VAR_DATA_PASSPHRASE.to_owned(),
+            });
+        }
+    };
+    *passphrase = Some(provided.clone());
+    Ok(provided)
+}
+
+fn random_bytes<const N: usize>() -> Result<[u8; N]> {
+    let mut bytes = [0u8; N];
+    getrandom::getrandom(&mut bytes).map_err(|e| Error::FailedToEncryptData {
+        underlying: format!("No randomness: {}", e),
+    })?;
+    Ok(bytes)
+}
+
+/// Derives the key of `passphrase` with scrypt, or returns the key derived
+/// earlier in this run.
+fn derive_key(passphrase: &str, log_n: u8, salt: &[u8]) -> Result<[u8; KEY_LENGTH]> {
+    let id = (passphrase.to_owned(), log_n, salt.to_vec());
+    if let Some(key) = DERIVED_KEYS.lock().expect("Keys lock poisoned").get(&id) {
+        return Ok(*key);
+    }
+    let to_error = |e: &dyn std::fmt::Display| Error::FailedToEncryptData {
+        underlying: format!("Failed to derive key: {}", e),
+    };
+    let params = scrypt::Params::new(log_n, 8, 1, KEY_LENGTH).map_err(|e| to_error(&e))?;
+    let mut key = [0u8; KEY_LENGTH];
+    scrypt::scrypt(passphrase.as_bytes(), salt, &params, &mut key).map_err(|e| to_error(&e))?;
+    DERIVED_KEYS
+        .lock()
+        .expect("Keys lock poisoned")
+        .insert(id, key);
+    Ok(key)
+}
+
+/// Encrypts `plaintext` with the key derived from `passphrase` with the
+/// scrypt cost `log_n` and `salt`, using a new random nonce.
+pub fn encrypt_contents(
+    plaintext: &str,
+    passphrase: &str,
+    log_n: u8,
+    salt: &[u8],
+) -> Result<EncryptedContents> {
+    let key = derive_key(passphrase, log_n, salt)?;
+    let nonce = random_bytes::<NONCE_LENGTH>()?;
+    let ciphertext = XChaCha20Poly1305::new(Key::from_slice(&key))
+        .encrypt(XNonce::from_slice(&nonce), plaintext.as_bytes())
+        .map_err(|e| Error::FailedToEncryptData {
+            underlying: format!("{:?}", e),
+        })?;
+    Ok(EncryptedContents::builder()
+        .log_n(log_n)
+        .salt(salt.to_vec())
+        .nonce(nonce.to_vec())
+        .ciphertext(ciphertext)
+        .build())
+}
+
+/// Decrypts `contents` with the key derived from `passphrase`.
+///
+/// # Errors
+/// Returns [`Error::WrongDataPassphrase`] if `passphrase` is not the one
+/// `contents` was encrypted with.
+pub fn decrypt_contents(contents: &EncryptedContents, passphrase: &str) -> Result<String> {
+    if contents.nonce().len() != NONCE_LENGTH {
+        return Err(Error::InvalidEncryptedContents);
+    }
+    let key = derive_key(passphrase, *contents.log_n(), contents.salt())?;
+    let plaintext = XChaCha20Poly1305::new(Key::from_slice(&key))
+        .decrypt(
+            XNonce::from_slice(contents.nonce()),
+            contents.ciphertext().as_slice(),
+        )
+        .map_err(|_| Error::WrongDataPassphrase)?;
+    String::from_utf8(plaintext).map_err(|_| Error::InvalidEncryptedContents)
+}
+
+/// Creates the settings of a data directory encrypted with `passphrase`,
+/// with a new random salt.
+pub fn new_data_encryption(passphrase: &str, log_n: u8) -> Result<DataEncryption> {
+    let salt = random_bytes::<SALT_LENGTH>()?;
+    let verifier = encrypt_contents(VERIFIER_PLAINTEXT, passphrase, log_n, &salt)?;
+    Ok(DataEncryption::builder()
+        .log_n(log_n)
+        .salt(to_hex(&salt))
+        .verifier(verifier.to_string().trim())
+        .build())
+}
+
+/// Checks that `passphrase` is the passphrase of `encryption`, returning
+/// the salt to encrypt with.
+///
+/// # Errors
+/// Returns [`Error::WrongDataPassphrase`] if it is not.
+pub fn verify_data_passphrase(encryption: &DataEncryption, passphrase: &str) -> Result<Vec<u8>> {
+    let verifier = EncryptedContents::from_str(encryption.verifier())?;
+    if decrypt_contents(&verifier, passphrase)? != VERIFIER_PLAINTEXT {
+        return Err(Error::WrongDataPassphrase);
+    }
+    from_hex(encryption.salt()).ok_or(Error::InvalidEncryptedContents)
+}
+
+/// Reads the encryption settings of the data directory at `base_path`,
+/// `None` if it is not encrypted.
+pub fn data_encryption(base_path: impl AsRef<Path>) -> Result<Option<DataEncryption>> {
+    let path = path_to_ron_file_with_base(base_path, DATA_FILE_NAME_ENCRYPTION);
+    if !path.exists() {
+        return Ok(None);
+    }
+    let contents = std::fs::read_to_string(&path).map_err(|e| Error::FileNotFound {
+        path: path.display().to_string(),
+        underlying: format!("{:?}", e),
+    })?;
+    deserialize_ron_str(&contents).map(Some)
+}
+
+/// The encryption settings of the data directory of the data file at
+/// `path`, which may be in a subfolder, e.g. `history`.
+fn data_encryption_of_file(path: &Path) -> Result<Option<DataEncryption>> {
+    for folder in path.ancestors().skip(1).take(ENCRYPTION_SEARCH_DEPTH) {
+        if let Some(encryption) = data_encryption(folder)? {
+            return Ok(Some(encryption));
+        }
+    }
+    Ok(None)
+}
+
+/// Reads the data file at `path`, decrypting it if it is encrypted. Files
+/// are only encrypted or decrypted in bulk by `encrypt` and `decrypt`, which
+/// read them directly, so a plaintext file in an encrypted data directory is
+/// not trusted.
+///
+/// # Errors
+/// Returns an error if the file cannot be read, if it is encrypted and no
+/// passphrase or the wrong one is given, or if it is not encrypted though
+/// its data directory is.
+pub fn read_data_file(path: impl AsRef<Path>) -> Result<String> {
+    let path = path.as_ref();
+    let contents = std::fs::read_to_string(path).map_err(|e| Error::FileNotFound {
+        path: path.display().to_string(),
+        underlying: format!("{:?}", e),
+    })?;
+    if !EncryptedContents::is_encrypted(&contents) {
+        if data_encryption_of_file(path)?.is_some() {
+            return Err(Error::UnencryptedDataFile {
+                path: path.display().to_string(),
+            });
+        }
+        return Ok(contents);
+    }
+    let encrypted = EncryptedContents::from_str(&contents)?;
+    decrypt_contents(&encrypted, &data_passphrase()?)
+}
+
+/// Writes `contents` to the data file at `path` atomically, encrypted if
+/// the data directory is encrypted.
+///
+/// # Errors
+/// Returns an error if the file cannot be written, or if the data directory
+/// is encrypted and no passphrase or the wrong one is given.
+pub fn write_data_file(path: impl AsRef<Path>, contents: impl AsRef<str>) -> Result<()> {
+    let path = path.as_ref();
+    let contents = contents.as_ref();
+    let Some(encryption) = data_encryption_of_file(path)? else {
+        return write_atomically(path, contents);
+    };
+    let passphrase = data_passphrase()?;
+    let salt = verify_data_passphrase(&encryption, &passphrase)?;
+    let encrypted = encrypt_contents(contents, &passphrase, *encryption.log_n(), &salt)?;
+    write_atomically(path, encrypted.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    /// Cheap enough for tests, too cheap for real use.
+    const TEST_LOG_N: u8 = 4;
+
+    #[test]
+    fn test_encrypt_decrypt_roundtrip() {
+        let salt = [7u8; SALT_LENGTH];
+        let encrypted = encrypt_contents("secret", "passphrase", TEST_LOG_N, &salt).unwrap();
+        assert!(EncryptedContents::is_encrypted(&encrypted.to_string()));
+        let parsed = EncryptedContents::from_str(&encrypted.to_string()).unwrap();
+        assert_eq!(decrypt_contents(&parsed, "passphrase").unwrap(), "secret");
+    }
+
+    #[test]
+    fn test_nonce_is_unique() {
+        let salt = [7u8; SALT_LENGTH];
+        let first = encrypt_contents("secret", "passphrase", TEST_LOG_N, &salt).unwrap();
+        let second = encrypt_contents("secret", "passphrase", TEST_LOG_N, &salt).unwrap();
+        assert_ne!(first.ciphertext(), second.ciphertext());
+    }
+
+    #[test]
+    fn test_wrong_passphrase() {
+        let salt = [7u8; SALT_LENGTH];
+        let encrypted = encrypt_contents("secret", "passphrase", TEST_LOG_N, &salt).unwrap();
+        assert!(matches!(
+            decrypt_contents(&encrypted, "wrong"),
+            Err(Error::WrongDataPassphrase)
+        ));
+        let encryption = new_data_encryption("passphrase", TEST_LOG_N).unwrap();
+        assert!(verify_data_passphrase(&encryption, "passphrase").is_ok());
+        assert!(matches!(
+            verify_data_passphrase(&encryption, "wrong"),
+            Err(Error::WrongDataPassphrase)
+        ));
+    }
+}
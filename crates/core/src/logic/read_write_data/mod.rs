@@ -1,5 +1,9 @@
+mod data_lock;
+mod encryption;
 mod get_localization;
 mod read_data_from_disk;
 
+pub use data_lock::*;
+pub use encryption::*;
 pub use get_localization::*;
 pub use read_data_from_disk::*;
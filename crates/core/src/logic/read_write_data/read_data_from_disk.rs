@@ -44,17 +44,44 @@ pub fn data_dir() -> PathBuf {
     data_dir_create_if(false)
 }
 
-pub fn save_to_disk<T: Serialize>(model: &T, path: impl AsRef<Path>) -> Result<()> {
+/// Serializes `model` into RON, the format of all files in the data directory.
+pub fn to_ron_string<T: Serialize>(model: &T) -> Result<String> {
     let ron_config = ron::ser::PrettyConfig::new().struct_names(true);
-    let serialized = ron::ser::to_string_pretty(model, ron_config).map_err(|e| {
-        Error::FailedToRonSerializeData {
-            type_name: type_name::<T>().to_owned(),
+    ron::ser::to_string_pretty(model, ron_config).map_err(|e| Error::FailedToRonSerializeData {
+        type_name: type_name::<T>().to_owned(),
+        underlying: format!("{:?}", e),
+    })
+}
+
+/// Writes `contents` to `path` atomically, by writing to a temporary file
+/// next to it which is then renamed to `path`, so that the file is never
+/// left half written if klirr is interrupted.
+pub fn write_atomically(path: impl AsRef<Path>, contents: impl AsRef<[u8]>) -> Result<()> {
+    let path = path.as_ref();
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let temporary = path.with_file_name(format!(".{}.tmp", file_name));
+    let write = || -> std::io::Result<()> {
+        let mut file = std::fs::File::create(&temporary)?;
+        std::io::Write::write_all(&mut file, contents.as_ref())?;
+        file.sync_all()?;
+        std::fs::rename(&temporary, path)
+    };
+    write().map_err(|e| {
+        let _ = std::fs::remove_file(&temporary);
+        Error::FailedToWriteDataToDisk {
             underlying: format!("{:?}", e),
         }
-    })?;
-    std::fs::write(path.as_ref(), serialized).map_err(|e| Error::FailedToWriteDataToDisk {
-        underlying: format!("{:?}", e),
-    })?;
+    })
+}
+
+/// Serializes `model` into RON and saves it at `path`, encrypted if the data
+/// directory is encrypted.
+pub fn save_to_disk<T: Serialize>(model: &T, path: impl AsRef<Path>) -> Result<()> {
+    let serialized = to_ron_string(model)?;
+    write_data_file(path.as_ref(), serialized)?;
     info!("✅ Successfully saved file at: {}", path.as_ref().display());
     Ok(())
 }
@@ -85,9 +112,44 @@ pub fn save_data_with_base_path(data: Data, base_path: impl AsRef<Path>) -> Resu
         data.expensed_months(),
         path_to_ron_file_with_base(base_path, DATA_FILE_NAME_EXPENSES),
     )?;
+    save_to_disk(
+        &SchemaVersion::CURRENT,
+        path_to_ron_file_with_base(base_path, DATA_FILE_NAME_SCHEMA_VERSION),
+    )?;
     Ok(())
 }
 
+/// Reads a full [`Data`] document from the JSON or RON file at `path`,
+/// depending on its extension, e.g. to initialize the data directory
+/// without the terminal UI.
+///
+/// # Errors
+/// Returns an error if the file cannot be read or parsed, or if the data is
+/// invalid.
+pub fn read_data_from_file(path: impl AsRef<Path>) -> Result<Data> {
+    let path = path.as_ref();
+    let contents = std::fs::read_to_string(path).map_err(|e| Error::FileNotFound {
+        path: path.display().to_string(),
+        underlying: format!("{:?}", e),
+    })?;
+    let extension = path
+        .extension()
+        .map(|extension| extension.to_string_lossy().to_lowercase());
+    let data: Data = match extension.as_deref() {
+        Some("json") => serde_json::from_str(&contents).map_err(|e| Error::Deserialize {
+            type_name: type_name::<Data>(),
+            error: e.to_string(),
+        })?,
+        Some("ron") => deserialize_ron_str(&contents)?,
+        _ => {
+            return Err(Error::UnsupportedDataFileFormat {
+                path: path.display().to_string(),
+            });
+        }
+    };
+    data.validate()
+}
+
 pub fn path_to_ron_file_with_base(base_path: impl AsRef<Path>, name: &str) -> PathBuf {
     base_path.as_ref().join(format!("{}.ron", name))
 }
@@ -96,6 +158,19 @@ pub fn load_data<T: DeserializeOwned>(base_path: impl AsRef<Path>, name: &str) -
     deserialize_contents_of_ron(path_to_ron_file_with_base(base_path, name))
 }
 
+/// Like [`load_data`] but returns `T::default()` if the file does not exist,
+/// used for files which are created when first needed.
+pub fn load_data_or_default<T: DeserializeOwned + Default>(
+    base_path: impl AsRef<Path>,
+    name: &str,
+) -> Result<T> {
+    let base_path = base_path.as_ref();
+    if !path_to_ron_file_with_base(base_path, name).exists() {
+        return Ok(T::default());
+    }
+    load_data(base_path, name)
+}
+
 pub const DATA_FILE_NAME_VENDOR: &str = "vendor";
 pub const DATA_FILE_NAME_CLIENT: &str = "client";
 pub const DATA_FILE_NAME_PAYMENT: &str = "payment";
@@ -103,55 +178,195 @@ pub const DATA_FILE_NAME_SERVICE_FEES: &str = "service_fees";
 pub const DATA_FILE_NAME_PROTO_INVOICE_INFO: &str = "invoice_info";
 pub const DATA_FILE_NAME_EXPENSES: &str = "expenses";
 pub const DATA_FILE_NAME_CACHED_RATES: &str = "cached_rates";
+pub const DATA_FILE_NAME_LEDGER: &str = "ledger";
+pub const DATA_FILE_NAME_PAYMENTS: &str = "payments";
+pub const DATA_FILE_NAME_CHART_OF_ACCOUNTS: &str = "chart_of_accounts";
+pub const DATA_FILE_NAME_SMTP: &str = "smtp";
+pub const DATA_FILE_NAME_SCHEMA_VERSION: &str = "schema_version";
+pub const DATA_FILE_NAME_ENCRYPTION: &str = "encryption";
+
+/// The names of the files written by [`save_data_with_base_path`].
+pub const DATA_FILE_NAMES_OF_DATA: [&str; 7] = [
+    DATA_FILE_NAME_VENDOR,
+    DATA_FILE_NAME_CLIENT,
+    DATA_FILE_NAME_PROTO_INVOICE_INFO,
+    DATA_FILE_NAME_PAYMENT,
+    DATA_FILE_NAME_SERVICE_FEES,
+    DATA_FILE_NAME_EXPENSES,
+    DATA_FILE_NAME_SCHEMA_VERSION,
+];
 
-fn client(base_path: impl AsRef<Path>) -> Result<CompanyInformation> {
-    load_data(base_path, DATA_FILE_NAME_CLIENT)
+pub fn proto_invoice_info(base_path: impl AsRef<Path>) -> Result<ProtoInvoiceInfo> {
+    load_data(base_path, DATA_FILE_NAME_PROTO_INVOICE_INFO)
 }
 
-fn vendor(base_path: impl AsRef<Path>) -> Result<CompanyInformation> {
-    load_data(base_path, DATA_FILE_NAME_VENDOR)
+pub fn expensed_months(base_path: impl AsRef<Path>) -> Result<ExpensedMonths> {
+    load_data(base_path, DATA_FILE_NAME_EXPENSES)
 }
 
-fn payment_info(base_path: impl AsRef<Path>) -> Result<PaymentInformation> {
-    load_data(base_path, DATA_FILE_NAME_PAYMENT)
+/// Reads the ledger of issued invoices, returning an empty ledger if no
+/// invoice has been issued yet.
+pub fn invoice_ledger(base_path: impl AsRef<Path>) -> Result<InvoiceLedger> {
+    load_data_or_default(base_path, DATA_FILE_NAME_LEDGER)
 }
 
-fn service_fees(base_path: impl AsRef<Path>) -> Result<ServiceFees> {
-    load_data(base_path, DATA_FILE_NAME_SERVICE_FEES)
+/// Reads all recorded payments, returning no payments if none has been
+/// recorded yet.
+pub fn payments(base_path: impl AsRef<Path>) -> Result<Payments> {
+    load_data_or_default(base_path, DATA_FILE_NAME_PAYMENTS)
 }
 
-pub fn proto_invoice_info(base_path: impl AsRef<Path>) -> Result<ProtoInvoiceInfo> {
-    load_data(base_path, DATA_FILE_NAME_PROTO_INVOICE_INFO)
+/// Reads the SMTP server used to send invoices.
+///
+/// # Errors
+/// Returns [`Error::MissingSmtpSettings`] if no SMTP server is configured.
+pub fn smtp_settings(base_path: impl AsRef<Path>) -> Result<SmtpSettings> {
+    let base_path = base_path.as_ref();
+    let path = path_to_ron_file_with_base(base_path, DATA_FILE_NAME_SMTP);
+    if !path.exists() {
+        return Err(Error::MissingSmtpSettings {
+            path: path.display().to_string(),
+        });
+    }
+    load_data(base_path, DATA_FILE_NAME_SMTP)
 }
 
-pub fn expensed_months(base_path: impl AsRef<Path>) -> Result<ExpensedMonths> {
-    load_data(base_path, DATA_FILE_NAME_EXPENSES)
+/// Records `issued` in the ledger of issued invoices saved at `base_path`,
+/// locking the data directory meanwhile, so that invoices issued by a
+/// concurrently running klirr are kept.
+pub fn record_issued_invoice(base_path: impl AsRef<Path>, issued: IssuedInvoice) -> Result<()> {
+    let base_path = base_path.as_ref();
+    let _lock = lock_migrated_data(base_path)?;
+    record_issued_invoice_while_locked(base_path, issued)
 }
 
+/// Saves the invoice `issued` using `save`, e.g. its PDF, and records it in
+/// the ledger of issued invoices saved at `base_path`, locking the data
+/// directory meanwhile.
+///
+/// If `numbering` counts invoices sequentially, the number of `issued` was
+/// allocated before the lock was taken, so it is first checked not to have
+/// been taken by an invoice issued by a concurrently running klirr since.
+///
+/// # Errors
+/// Returns [`Error::InvoiceNumberTaken`], without saving anything, if the
+/// number was taken, or an error if `save` or recording fails.
+pub fn issue_invoice<R>(
+    base_path: impl AsRef<Path>,
+    issued: IssuedInvoice,
+    numbering: &InvoiceNumbering,
+    save: impl FnOnce() -> Result<R>,
+) -> Result<R> {
+    let base_path = base_path.as_ref();
+    let _lock = lock_migrated_data(base_path)?;
+    if numbering.counter().is_ledger_sequential()
+        && invoice_ledger(base_path)?.iter().any(|recorded| {
+            recorded.number() == issued.number() && !recorded.is_same_invoice_as(&issued)
+        })
+    {
+        return Err(Error::InvoiceNumberTaken {
+            number: *issued.number(),
+        });
+    }
+    let saved = save()?;
+    record_issued_invoice_while_locked(base_path, issued)?;
+    Ok(saved)
+}
+
+/// Records `issued` in the ledger of issued invoices saved at `base_path`,
+/// which the caller must have locked.
+fn record_issued_invoice_while_locked(base_path: &Path, issued: IssuedInvoice) -> Result<()> {
+    let mut ledger = invoice_ledger(base_path)?;
+    ledger.record(issued);
+    save_to_disk(
+        &ledger,
+        path_to_ron_file_with_base(base_path, DATA_FILE_NAME_LEDGER),
+    )
+}
+
+/// Deserializes the data file `name`, read at `base_path` into `files`.
+///
+/// # Errors
+/// Returns an error if the file is missing or cannot be deserialized.
+fn required_data<T: DeserializeOwned>(
+    files: &DataFiles,
+    base_path: &Path,
+    name: &str,
+) -> Result<T> {
+    files.deserialize(name)?.ok_or_else(|| Error::FileNotFound {
+        path: path_to_ron_file_with_base(base_path, name)
+            .display()
+            .to_string(),
+        underlying: "No such file".to_owned(),
+    })
+}
+
+/// Reads the data at `base_path`, migrated in memory if it is at an older
+/// schema version, leaving the files untouched, see
+/// [`read_migrated_data_files`].
+///
+/// # Errors
+/// Returns an error if a file is missing or invalid, or if the data is
+/// invalid.
 pub fn read_data_from_disk_with_base_path(base_path: impl AsRef<Path>) -> Result<Data> {
     let base_path = base_path.as_ref();
-    // Read the input data from a file or other source.
-    // This is a placeholder function, you can add your own logic here.
     debug!("☑️ Reading data from disk...");
-    let client = client(base_path)?;
-    let vendor = vendor(base_path)?;
-    let payment_info = payment_info(base_path)?;
-    let service_prices = service_fees(base_path)?;
-    let proto_invoice_info = proto_invoice_info(base_path)?;
-    let expensed_months = expensed_months(base_path)?;
-
+    let files = read_migrated_data_files(base_path)?;
     let input_data = Data::builder()
-        .client(client)
-        .vendor(vendor)
-        .payment_info(payment_info)
-        .service_fees(service_prices)
-        .information(proto_invoice_info)
-        .expensed_months(expensed_months)
+        .client(required_data::<CompanyInformation>(
+            &files,
+            base_path,
+            DATA_FILE_NAME_CLIENT,
+        )?)
+        .vendor(required_data::<CompanyInformation>(
+            &files,
+            base_path,
+            DATA_FILE_NAME_VENDOR,
+        )?)
+        .payment_info(required_data::<PaymentInformation>(
+            &files,
+            base_path,
+            DATA_FILE_NAME_PAYMENT,
+        )?)
+        .service_fees(required_data::<ServiceFees>(
+            &files,
+            base_path,
+            DATA_FILE_NAME_SERVICE_FEES,
+        )?)
+        .information(required_data::<ProtoInvoiceInfo>(
+            &files,
+            base_path,
+            DATA_FILE_NAME_PROTO_INVOICE_INFO,
+        )?)
+        .expensed_months(required_data::<ExpensedMonths>(
+            &files,
+            base_path,
+            DATA_FILE_NAME_EXPENSES,
+        )?)
+        .ledger(
+            files
+                .deserialize::<InvoiceLedger>(DATA_FILE_NAME_LEDGER)?
+                .unwrap_or_default(),
+        )
         .build();
     debug!("✅ Read data from disk!");
     input_data.validate()
 }
 
+/// Reads the data at `base_path`, like
+/// [`read_data_from_disk_with_base_path`], and also checks that no numbers
+/// are missing from the ledger of issued invoices if invoices are numbered
+/// sequentially. Gaps are not checked on every read, so that the data can
+/// still be read and edited to fix them.
+///
+/// # Errors
+/// Returns an error if the data is invalid or if the ledger has gaps.
+pub fn validate_data_with_base_path(base_path: impl AsRef<Path>) -> Result<Data> {
+    let data = read_data_from_disk_with_base_path(base_path)?;
+    validate_invoice_numbering(data.information().numbering(), data.ledger())?;
+    Ok(data)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -165,4 +380,95 @@ mod tests {
         let loaded_data = read_data_from_disk_with_base_path(tempdir.path()).unwrap();
         assert_eq!(loaded_data, data, "Loaded data should match saved data");
     }
+
+    #[test]
+    fn invoice_ledger_defaults_to_empty() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        let ledger = invoice_ledger(tempdir.path()).unwrap();
+        assert_eq!(ledger, InvoiceLedger::default());
+    }
+
+    #[test]
+    fn record_and_read_invoice_ledger() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        record_issued_invoice(tempdir.path(), IssuedInvoice::sample()).unwrap();
+        let ledger = invoice_ledger(tempdir.path()).unwrap();
+        assert_eq!(ledger, InvoiceLedger::sample());
+    }
+
+    #[test]
+    fn issue_invoice_with_sequential_number_taken_meanwhile_fails() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        let numbering = InvoiceNumbering::sample();
+        assert!(numbering.counter().is_ledger_sequential());
+        // Another running klirr issued invoice 243 after we allocated it.
+        record_issued_invoice(tempdir.path(), IssuedInvoice::sample()).unwrap();
+        let ours = IssuedInvoice::builder()
+            .number(InvoiceNumber::from(243))
+            .kind(InvoiceKind::Expenses)
+            .invoice_date(Date::from_str("2025-05-31").unwrap())
+            .due_date(Date::from_str("2025-06-30").unwrap())
+            .currency(Currency::EUR)
+            .total(Cost::sample())
+            .build();
+        let saved = std::cell::Cell::new(false);
+        let result = issue_invoice(tempdir.path(), ours.clone(), &numbering, || {
+            saved.set(true);
+            Ok(())
+        });
+        assert!(matches!(
+            result,
+            Err(Error::InvoiceNumberTaken { number }) if *number == 243
+        ));
+        assert!(!saved.get());
+        assert_eq!(
+            invoice_ledger(tempdir.path()).unwrap(),
+            InvoiceLedger::sample()
+        );
+
+        // Issuing the same invoice again, or numbers derived from the month,
+        // which are shared between kinds, is fine.
+        issue_invoice(tempdir.path(), IssuedInvoice::sample(), &numbering, || {
+            Ok(())
+        })
+        .unwrap();
+        issue_invoice(
+            tempdir.path(),
+            ours,
+            &InvoiceNumbering::default(),
+            || Ok(()),
+        )
+        .unwrap();
+        assert_eq!(invoice_ledger(tempdir.path()).unwrap().iter().count(), 2);
+    }
+
+    #[test]
+    fn ledger_gaps_fail_validation_but_not_reading() {
+        let tempdir = tempfile::tempdir().expect("Failed to create temp dir");
+        let data = Data::sample();
+        let information = data
+            .information()
+            .clone()
+            .with_numbering(InvoiceNumbering::sample());
+        save_data_with_base_path(data.with_information(information), tempdir.path()).unwrap();
+        record_issued_invoice(tempdir.path(), IssuedInvoice::sample()).unwrap();
+        record_issued_invoice(
+            tempdir.path(),
+            IssuedInvoice::builder()
+                .number(InvoiceNumber::from(246))
+                .kind(InvoiceKind::Services)
+                .invoice_date(Date::from_str("2025-08-31").unwrap())
+                .due_date(Date::from_str("2025-09-30").unwrap())
+                .currency(Currency::EUR)
+                .total(Cost::sample())
+                .build(),
+        )
+        .unwrap();
+
+        assert!(read_data_from_disk_with_base_path(tempdir.path()).is_ok());
+        assert!(matches!(
+            validate_data_with_base_path(tempdir.path()),
+            Err(Error::InvoiceNumberGaps { missing }) if missing == "244, 245"
+        ));
+    }
 }
@@ -0,0 +1,187 @@
+use crate::prelude::*;
+
+pub const RECEIPTS_FOLDER_NAME: &str = "receipts";
+
+/// The folder in `data_base_path` where receipts are stored.
+pub fn receipts_directory(data_base_path: impl AsRef<Path>) -> PathBuf {
+    data_base_path.as_ref().join(RECEIPTS_FOLDER_NAME)
+}
+
+/// Reads the receipts of the line items of `data` from the receipts folder
+/// in `data_base_path`, and attaches them to `data` so that they are appended
+/// to the rendered PDF. Each receipt gets the number of its line item as
+/// reference.
+///
+/// # Errors
+/// Returns an error if any receipt is not a PDF, PNG or JPG file, or cannot
+/// be read.
+pub fn attach_receipts(
+    data: PreparedData,
+    data_base_path: impl AsRef<Path>,
+) -> Result<PreparedData> {
+    let directory = receipts_directory(data_base_path);
+    let receipts = data
+        .line_items()
+        .items()
+        .iter()
+        .enumerate()
+        .filter_map(|(index, item)| item.receipt().as_ref().map(|path| (index, item, path)))
+        .map(|(index, item, path)| {
+            let path = directory.join(path);
+            let format = ReceiptFormat::from_path(&path)?;
+            let content = std::fs::read(&path).map_err(|e| Error::FailedToReadReceipt {
+                path: path.display().to_string(),
+                underlying: format!("{:?}", e),
+            })?;
+            Ok(Receipt::builder()
+                .reference(index + 1)
+                .name(item.name().clone())
+                .format(format)
+                .content(content)
+                .build())
+        })
+        .collect::<Result<Vec<_>>>()?;
+    debug!("Attaching #{} receipts to the invoice", receipts.len());
+    Ok(data.with_receipts(receipts))
+}
+
+/// Copies the receipt `file` into the receipts folder in `data_path` and
+/// attaches it to the expense at `index` of `month`, returning the expense.
+///
+/// # Errors
+/// Returns an error if `file` is not a PDF, PNG or JPG file, if there is no
+/// expense at `index` of `month`, or if a receipt with the same file name
+/// is already in the receipts folder.
+pub fn attach_receipt_with_base_path(
+    month: &YearAndMonth,
+    index: usize,
+    file: impl AsRef<Path>,
+    data_path: impl AsRef<Path>,
+) -> Result<Item> {
+    let file = file.as_ref();
+    let data_path = data_path.as_ref();
+    ReceiptFormat::from_path(file)?;
+    let file_name = file
+        .file_name()
+        .ok_or_else(|| Error::FailedToReadReceipt {
+            path: file.display().to_string(),
+            underlying: "Not a file".to_owned(),
+        })?
+        .to_owned();
+    let target = receipts_directory(data_path).join(&file_name);
+    if target.exists() {
+        return Err(Error::ReceiptAlreadyExists {
+            path: target.display().to_string(),
+        });
+    }
+    edit_expense_with_base_path(month, index, data_path, |item| {
+        create_folder_to_parent_of_path_if_needed(&target)?;
+        std::fs::copy(file, &target).map_err(|e| Error::FailedToReadReceipt {
+            path: file.display().to_string(),
+            underlying: format!("{:?}", e),
+        })?;
+        Ok(item.with_receipt(file_name))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    fn prepared_with_expenses(items: Vec<Item>) -> PreparedData {
+        let line_items = LineItemsFlat::builder()
+            .is_expenses(true)
+            .items(items.into_iter().map(Item::with_total_cost).collect())
+            .build();
+        PreparedData::builder()
+            .information(InvoiceInfoFull::sample())
+            .vendor(CompanyInformation::sample())
+            .client(CompanyInformation::sample())
+            .line_items(line_items)
+            .payment_info(PaymentInformation::sample())
+            .output_path(OutputPath::Name("invoice.pdf".into()))
+            .build()
+    }
+
+    #[test]
+    fn test_attach_receipts_references_line_items() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let directory = receipts_directory(tempdir.path());
+        std::fs::create_dir_all(&directory).unwrap();
+        std::fs::write(directory.join("sandwich.png"), b"png").unwrap();
+        let data = prepared_with_expenses(vec![
+            Item::sample_expense_coffee(),
+            Item::sample_expense_sandwich().with_receipt("sandwich.png"),
+        ]);
+        let data = attach_receipts(data, tempdir.path()).unwrap();
+        assert_eq!(
+            data.receipts(),
+            &vec![
+                Receipt::builder()
+                    .reference(2)
+                    .name("Sandwich")
+                    .format(ReceiptFormat::Png)
+                    .content(b"png".to_vec())
+                    .build()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_attach_receipts_missing_file() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let data = prepared_with_expenses(vec![
+            Item::sample_expense_coffee().with_receipt("coffee.pdf"),
+        ]);
+        let result = attach_receipts(data, tempdir.path());
+        assert!(matches!(result, Err(Error::FailedToReadReceipt { .. })));
+    }
+
+    #[test]
+    fn test_attach_receipt_with_base_path() {
+        let tempdir = tempfile::tempdir().unwrap();
+        save_to_disk(
+            &ExpensedMonths::sample(),
+            path_to_ron_file_with_base(tempdir.path(), DATA_FILE_NAME_EXPENSES),
+        )
+        .unwrap();
+        let downloads = tempfile::tempdir().unwrap();
+        let file = downloads.path().join("breakfast.jpg");
+        std::fs::write(&file, b"jpg").unwrap();
+        let month = YearAndMonth::february(2024);
+
+        let item = attach_receipt_with_base_path(&month, 0, &file, tempdir.path()).unwrap();
+        assert_eq!(item.receipt(), &Some(PathBuf::from("breakfast.jpg")));
+        assert_eq!(
+            expensed_months(tempdir.path())
+                .unwrap()
+                .get(&month)
+                .unwrap(),
+            vec![Item::sample_expense_breakfast().with_receipt("breakfast.jpg")]
+        );
+        assert_eq!(
+            std::fs::read(receipts_directory(tempdir.path()).join("breakfast.jpg")).unwrap(),
+            b"jpg"
+        );
+
+        let result = attach_receipt_with_base_path(&month, 0, &file, tempdir.path());
+        assert!(matches!(result, Err(Error::ReceiptAlreadyExists { .. })));
+    }
+
+    #[test]
+    fn test_attach_receipt_with_base_path_invalid_index() {
+        let tempdir = tempfile::tempdir().unwrap();
+        save_to_disk(
+            &ExpensedMonths::sample(),
+            path_to_ron_file_with_base(tempdir.path(), DATA_FILE_NAME_EXPENSES),
+        )
+        .unwrap();
+        let file = tempdir.path().join("breakfast.pdf");
+        std::fs::write(&file, b"%PDF").unwrap();
+        let result =
+            attach_receipt_with_base_path(&YearAndMonth::february(2024), 5, &file, tempdir.path());
+        assert!(matches!(result, Err(Error::ExpenseIndexOutOfBounds { .. })));
+        assert!(!receipts_directory(tempdir.path()).exists());
+    }
+}
@@ -0,0 +1,179 @@
+use crate::prelude::*;
+
+/// Computes the revenue report for `period` from `data`, using exactly the
+/// same calculations as when rendering the invoices, i.e. [`Data::to_partial`]
+/// and exchange rates from `fetcher`, so that the numbers match the PDFs.
+///
+/// A services invoice is included for every month in the period from the
+/// month of the invoice number offset, except months off, and an expenses
+/// invoice for every month with recorded expenses. Only invoices with an
+/// invoice date on or before `today` are included. Services invoices are
+/// calculated with the recorded days off, just like when invoicing, but not
+/// with days off only given when the invoice was created.
+///
+/// # Errors
+/// Returns an error if an invoice cannot be prepared, e.g. if exchange rates
+/// cannot be fetched.
+pub fn revenue_report(
+    data: &Data,
+    period: ReportPeriod,
+    today: &Date,
+    fetcher: impl FetchExchangeRates,
+) -> Result<RevenueReport> {
+    let information = data.information();
+    let months = period.months().into_iter().filter(|month| {
+        month >= information.offset().month()
+            && !information.months_off_record().contains(month)
+            && month.to_date_end_of_month() <= *today
+    });
+    let mut rows = Vec::new();
+    for month in months {
+        let mut kinds = vec![InvoicedItems::Service { days_off: None }];
+        if data.expensed_months().contains(&month) {
+            kinds.push(InvoicedItems::Expenses);
+        }
+        for items in kinds {
+            let input = ValidInput::builder().month(month).items(items).build();
+            let prepared = prepare_invoice_input_data(data.clone(), input, &fetcher)?;
+            rows.push(RevenueReportRow::from(&prepared));
+        }
+    }
+    Ok(RevenueReport::new(period, rows))
+}
+
+/// Computes the revenue report for `period` from the data at `data_path`,
+/// counting invoices up until today.
+pub fn revenue_report_with_base_path(
+    data_path: impl AsRef<Path>,
+    period: ReportPeriod,
+) -> Result<RevenueReport> {
+    let data = read_data_from_disk_with_base_path(data_path)?;
+    revenue_report(
+        &data,
+        period,
+        &Date::today(),
+        ExchangeRatesFetcher::default(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    struct FixedRates;
+    impl FetchExchangeRates for FixedRates {
+        fn fetch_for_items(
+            &self,
+            target_currency: Currency,
+            _items: Vec<Item>,
+        ) -> Result<ExchangeRates> {
+            Ok(ExchangeRates::builder()
+                .target_currency(target_currency)
+                .rates(ExchangeRatesMap::from_iter([
+                    (Currency::GBP, UnitPrice::from(dec!(1.2))),
+                    (Currency::SEK, UnitPrice::from(dec!(0.1))),
+                ]))
+                .build())
+        }
+    }
+
+    fn period(quarter: Option<Quarter>) -> ReportPeriod {
+        ReportPeriod::builder()
+            .year(Year::from(2024))
+            .quarter(quarter)
+            .build()
+    }
+
+    #[test]
+    fn test_amounts_with_recorded_days_off() {
+        let mut information = ProtoInvoiceInfo::sample().with_vat_rate(Percentage::from(dec!(25)));
+        information.insert_days_off("2024-02-05..2024-02-07".parse().unwrap());
+        let data = Data::sample().with_information(information);
+        let report = revenue_report(
+            &data,
+            period(Some(Quarter::Q1)),
+            &Date::from_str("2025-01-01").unwrap(),
+            FixedRates,
+        )
+        .unwrap();
+
+        // 777 EUR per working day, 23 days in January, 21 - 3 days off in
+        // February and 21 days in March 2024.
+        let amounts = report
+            .invoices()
+            .iter()
+            .map(|row| {
+                (
+                    row.date().to_string(),
+                    **row.totals().net(),
+                    **row.totals().vat(),
+                    **row.totals().gross(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            amounts,
+            vec![
+                (
+                    "2024-01-31".to_owned(),
+                    dec!(17871),
+                    dec!(4467.75),
+                    dec!(22338.75)
+                ),
+                (
+                    "2024-02-29".to_owned(),
+                    dec!(13986),
+                    dec!(3496.5),
+                    dec!(17482.5)
+                ),
+                (
+                    "2024-03-31".to_owned(),
+                    dec!(16317),
+                    dec!(4079.25),
+                    dec!(20396.25)
+                ),
+            ]
+        );
+        let total = report.totals().get(&Currency::EUR).unwrap();
+        assert_eq!(**total.net(), dec!(48174));
+        assert_eq!(**total.gross(), dec!(60217.5));
+    }
+
+    #[test]
+    fn test_skips_future_months() {
+        let report = revenue_report(
+            &Data::sample(),
+            period(None),
+            &Date::from_str("2024-02-29").unwrap(),
+            FixedRates,
+        )
+        .unwrap();
+        assert!(
+            report
+                .invoices()
+                .iter()
+                .all(|row| *row.date() <= Date::from_str("2024-02-29").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_skips_months_off() {
+        let data = Data::sample();
+        let report = revenue_report(
+            &data,
+            period(None),
+            &Date::from_str("2025-01-01").unwrap(),
+            FixedRates,
+        )
+        .unwrap();
+        for row in report.invoices() {
+            assert!(
+                !data
+                    .information()
+                    .months_off_record()
+                    .contains(&YearAndMonth::from(*row.date()))
+            );
+        }
+    }
+}
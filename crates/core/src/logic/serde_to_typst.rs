@@ -33,18 +33,17 @@ fn to_typst_value(value: &Value, indent: usize) -> String {
     match value {
         Value::Object(map) => {
             // Flatten single-entry enum-like objects (e.g. { "Net": 30 }) to (net: 30)
-            if map.len() == 1 {
-                if let Some((variant, inner)) = map.iter().next() {
-                    if inner.is_number() || inner.is_string() || inner.is_object() {
-                        return format!(
-                            "(\n{}{}: {},\n{})",
-                            next_indent_str,
-                            variant.to_lowercase(),
-                            to_typst_value(inner, next_indent),
-                            indent_str
-                        );
-                    }
-                }
+            if map.len() == 1
+                && let Some((variant, inner)) = map.iter().next()
+                && (inner.is_number() || inner.is_string() || inner.is_object())
+            {
+                return format!(
+                    "(\n{}{}: {},\n{})",
+                    next_indent_str,
+                    variant.to_lowercase(),
+                    to_typst_value(inner, next_indent),
+                    indent_str
+                );
             }
 
             let fields = map
@@ -0,0 +1,143 @@
+use crate::prelude::*;
+
+/// A discount, surcharge or credit adjusting the net total of an invoice,
+/// e.g. a 10% loyalty discount or a goodwill credit of 500 EUR.
+///
+/// Percentages are calculated on the line items, never on other
+/// adjustments, and VAT is calculated on the adjusted net total.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, TypedBuilder, Getters, WithSetters)]
+pub struct Adjustment {
+    /// Whether this lowers or raises the amount to pay.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    kind: AdjustmentKind,
+
+    /// A percentage of the adjusted line items, or a fixed amount in the
+    /// currency of the invoice.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    amount: AdjustmentAmount,
+
+    /// Which line items this adjusts, defaults to all of them.
+    #[builder(setter(into), default)]
+    #[getset(get = "pub", set_with = "pub")]
+    #[serde(default)]
+    target: AdjustmentTarget,
+
+    /// An optional description shown next to the label of the row, e.g.
+    /// `"Loyalty discount"`.
+    #[builder(setter(into), default)]
+    #[getset(get = "pub")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+}
+
+impl Adjustment {
+    /// Creates an adjustment of the subtotal of all line items, e.g. a
+    /// discount passed when generating the invoice.
+    pub fn of_subtotal(kind: AdjustmentKind, amount: AdjustmentAmount) -> Self {
+        Self::builder().kind(kind).amount(amount).build()
+    }
+
+    /// Applies this adjustment to `items`, returning `None` if none of the
+    /// line items it targets are among `items`.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let items = vec![Item::sample().with_total_cost()];
+    /// let discount = Adjustment::of_subtotal(AdjustmentKind::Discount, "10%".parse().unwrap());
+    /// let applied = discount.apply(&items).unwrap();
+    /// assert_eq!(**applied.amount(), -(**items[0].total_cost() / dec!(10)));
+    /// ```
+    pub fn apply(&self, items: &[ItemConvertedIntoTargetCurrency]) -> Option<AppliedAdjustment> {
+        let targeted = self.target.select(items);
+        if targeted.is_empty() {
+            return None;
+        }
+        let base = Cost::from(
+            targeted
+                .iter()
+                .map(|item| **item.total_cost())
+                .sum::<rust_decimal::Decimal>(),
+        );
+        let amount = Cost::from(self.kind.sign() * *self.amount.of(base));
+        let applied = AppliedAdjustment::builder()
+            .kind(self.kind)
+            .description(self.description.clone())
+            .percentage(match self.amount {
+                AdjustmentAmount::Percentage(percentage) => Some(percentage),
+                AdjustmentAmount::Fixed(_) => None,
+            })
+            .amount(amount)
+            .build();
+        Some(applied)
+    }
+}
+
+impl HasSample for Adjustment {
+    fn sample() -> Self {
+        Self::builder()
+            .kind(AdjustmentKind::Discount)
+            .amount(AdjustmentAmount::sample())
+            .description("Loyalty discount".to_owned())
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    fn items() -> Vec<ItemConvertedIntoTargetCurrency> {
+        vec![
+            Item::sample_expense_breakfast().with_total_cost(),
+            Item::sample_expense_coffee().with_total_cost(),
+        ]
+    }
+
+    #[test]
+    fn test_surcharge_of_lines_only_adjusts_those_lines() {
+        let items = items();
+        let surcharge = Adjustment::builder()
+            .kind(AdjustmentKind::Surcharge)
+            .amount(AdjustmentAmount::from_str("50%").unwrap())
+            .target(AdjustmentTarget::Lines(vec![items[1].name().clone()]))
+            .build();
+        let applied = surcharge.apply(&items).unwrap();
+        assert_eq!(**applied.amount(), **items[1].total_cost() / dec!(2));
+    }
+
+    #[test]
+    fn test_fixed_credit_is_negative_without_percentage() {
+        let credit = Adjustment::of_subtotal(
+            AdjustmentKind::Credit,
+            AdjustmentAmount::from_str("500").unwrap(),
+        );
+        let applied = credit.apply(&items()).unwrap();
+        assert_eq!(*applied.amount(), Cost::from(dec!(-500)));
+        assert_eq!(*applied.percentage(), None);
+    }
+
+    #[test]
+    fn test_adjustment_of_missing_lines_is_not_applied() {
+        let discount = Adjustment::builder()
+            .kind(AdjustmentKind::Discount)
+            .amount(AdjustmentAmount::sample())
+            .target(AdjustmentTarget::Lines(vec!["Tooling licence".to_owned()]))
+            .build();
+        assert_eq!(discount.apply(&items()), None);
+    }
+
+    #[test]
+    fn test_serde_roundtrip() {
+        let adjustment = Adjustment::sample();
+        let ron = ron::to_string(&adjustment).unwrap();
+        assert_eq!(ron::from_str::<Adjustment>(&ron).unwrap(), adjustment);
+        let minimal: Adjustment =
+            ron::from_str("(kind: Credit, amount: Fixed(Cost(500.0)))").unwrap();
+        assert_eq!(*minimal.target(), AdjustmentTarget::Subtotal);
+    }
+}
@@ -0,0 +1,84 @@
+use crate::prelude::*;
+
+/// The size of an [`Adjustment`], either a percentage of the amount adjusted
+/// or a fixed amount, always positive, its [`AdjustmentKind`] deciding
+/// whether it lowers or raises the amount to pay.
+#[derive(Clone, Copy, Debug, Display, Serialize, Deserialize, PartialEq, Eq, Hash, IsVariant)]
+pub enum AdjustmentAmount {
+    /// A percentage of the amount adjusted, e.g. `10%`.
+    #[display("{}", _0)]
+    Percentage(Percentage),
+    /// A fixed amount in the currency of the invoice, e.g. `500`.
+    #[display("{}", _0)]
+    Fixed(Cost),
+}
+
+impl AdjustmentAmount {
+    /// The positive amount of this adjustment of `base`, rounded to two
+    /// decimals, half away from zero, just like VAT.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let base = Cost::from(dec!(9324.25));
+    /// let percentage: AdjustmentAmount = "10%".parse().unwrap();
+    /// assert_eq!(percentage.of(base), Cost::from(dec!(932.43)));
+    /// let fixed: AdjustmentAmount = "500".parse().unwrap();
+    /// assert_eq!(fixed.of(base), Cost::from(dec!(500)));
+    /// ```
+    pub fn of(&self, base: Cost) -> Cost {
+        match self {
+            Self::Percentage(percentage) => {
+                Cost::from((*base * percentage.as_fraction()).round_dp_with_strategy(
+                    2,
+                    rust_decimal::RoundingStrategy::MidpointAwayFromZero,
+                ))
+            }
+            Self::Fixed(amount) => *amount,
+        }
+    }
+}
+
+impl std::str::FromStr for AdjustmentAmount {
+    type Err = crate::prelude::Error;
+
+    /// Parses a percentage ending with `%`, e.g. `"10%"`, or else a fixed
+    /// amount, e.g. `"500"`, which must be positive.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// assert!("10%".parse::<AdjustmentAmount>().unwrap().is_percentage());
+    /// assert!("500.50".parse::<AdjustmentAmount>().unwrap().is_fixed());
+    /// assert!("-10%".parse::<AdjustmentAmount>().is_err());
+    /// assert!("ten".parse::<AdjustmentAmount>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::InvalidAdjustmentAmount {
+            invalid_string: s.to_owned(),
+        };
+        let s = s.trim();
+        let amount = match s.strip_suffix('%') {
+            Some(percentage) => Self::Percentage(Percentage::from_str(percentage)?),
+            None => Self::Fixed(Cost::from(
+                rust_decimal::Decimal::from_str(s).map_err(|_| invalid())?,
+            )),
+        };
+        let value = match amount {
+            Self::Percentage(percentage) => *percentage,
+            Self::Fixed(cost) => *cost,
+        };
+        if value <= rust_decimal::Decimal::ZERO {
+            return Err(invalid());
+        }
+        Ok(amount)
+    }
+}
+
+impl HasSample for AdjustmentAmount {
+    fn sample() -> Self {
+        Self::Percentage(Percentage::from(dec!(10)))
+    }
+}
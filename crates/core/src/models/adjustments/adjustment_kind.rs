@@ -0,0 +1,30 @@
+use crate::prelude::*;
+
+/// Whether an [`Adjustment`] lowers or raises the amount to pay.
+#[derive(Clone, Copy, Debug, Display, Serialize, Deserialize, PartialEq, Eq, Hash, IsVariant)]
+pub enum AdjustmentKind {
+    /// A reduction of the price, e.g. a 10% loyalty discount.
+    Discount,
+    /// An addition to the price, e.g. a 5% rush surcharge.
+    Surcharge,
+    /// An amount owed to the client deducted from the invoice, e.g. a
+    /// goodwill credit of 500 EUR.
+    Credit,
+}
+
+impl AdjustmentKind {
+    /// `1` for adjustments raising the amount to pay, `-1` for those
+    /// lowering it.
+    pub fn sign(&self) -> rust_decimal::Decimal {
+        match self {
+            Self::Surcharge => rust_decimal::Decimal::ONE,
+            Self::Discount | Self::Credit => rust_decimal::Decimal::NEGATIVE_ONE,
+        }
+    }
+}
+
+impl HasSample for AdjustmentKind {
+    fn sample() -> Self {
+        Self::Discount
+    }
+}
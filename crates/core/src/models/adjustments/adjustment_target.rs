@@ -0,0 +1,29 @@
+use crate::prelude::*;
+
+/// What an [`Adjustment`] applies to, the subtotal of all line items or only
+/// some of them.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq, Hash, IsVariant)]
+pub enum AdjustmentTarget {
+    /// The sum of all line items of the invoice.
+    #[default]
+    Subtotal,
+    /// The sum of the line items with any of these names, e.g. the service
+    /// `"Consulting"`.
+    Lines(Vec<String>),
+}
+
+impl AdjustmentTarget {
+    /// The line items of `items` this target applies to.
+    pub fn select<'a>(
+        &self,
+        items: &'a [ItemConvertedIntoTargetCurrency],
+    ) -> Vec<&'a ItemConvertedIntoTargetCurrency> {
+        items
+            .iter()
+            .filter(|item| match self {
+                Self::Subtotal => true,
+                Self::Lines(names) => names.iter().any(|name| name == item.name()),
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,40 @@
+use crate::prelude::*;
+
+/// An [`Adjustment`] applied to the line items of an invoice, rendered as a
+/// row below the line items.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, TypedBuilder, Getters)]
+pub struct AppliedAdjustment {
+    /// Whether the adjustment is a discount, surcharge or credit, used to
+    /// label the row.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    kind: AdjustmentKind,
+
+    /// The description of the adjustment, if any, e.g. `"Loyalty discount"`.
+    #[builder(setter(into), default)]
+    #[getset(get = "pub")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+
+    /// The percentage of the adjustment, if it is not a fixed amount.
+    #[builder(setter(into), default)]
+    #[getset(get = "pub")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    percentage: Option<Percentage>,
+
+    /// The amount added to the net total in the currency of the invoice,
+    /// negative for discounts and credits.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    amount: Cost,
+}
+
+impl HasSample for AppliedAdjustment {
+    fn sample() -> Self {
+        Self::builder()
+            .kind(AdjustmentKind::Discount)
+            .percentage(Percentage::from(dec!(10)))
+            .amount(Cost::from(dec!(-35)))
+            .build()
+    }
+}
@@ -0,0 +1,11 @@
+mod adjustment;
+mod adjustment_amount;
+mod adjustment_kind;
+mod adjustment_target;
+mod applied_adjustment;
+
+pub use adjustment::*;
+pub use adjustment_amount::*;
+pub use adjustment_kind::*;
+pub use adjustment_target::*;
+pub use applied_adjustment::*;
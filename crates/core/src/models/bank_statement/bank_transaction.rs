@@ -0,0 +1,51 @@
+use crate::prelude::*;
+
+/// An incoming payment (credit) on a bank statement.
+#[derive(Clone, Debug, Display, PartialEq, Eq, TypedBuilder, Getters)]
+#[display("{date}: {amount} {currency} '{reference}'")]
+pub struct BankTransaction {
+    /// The booking date of the transaction, e.g. `2025-06-28`.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    date: Date,
+
+    /// The amount credited to the account, e.g. `9324`.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    amount: Cost,
+
+    /// The currency of the amount, e.g. `EUR`.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    currency: Currency,
+
+    /// The remittance information the payer provided, e.g. an invoice number
+    /// or an OCR reference, might be empty.
+    #[builder(setter(into), default)]
+    #[getset(get = "pub")]
+    reference: String,
+}
+
+impl BankTransaction {
+    /// The payment towards `invoice` this transaction corresponds to.
+    pub fn payment_towards(&self, invoice: &IssuedInvoice) -> Payment {
+        Payment::builder()
+            .invoice(*invoice.number())
+            .kind(*invoice.kind())
+            .amount(self.amount)
+            .date(self.date)
+            .reference(self.reference.clone())
+            .build()
+    }
+}
+
+impl HasSample for BankTransaction {
+    fn sample() -> Self {
+        Self::builder()
+            .date(Date::from_str("2025-06-28").expect("Valid date"))
+            .amount(Cost::from(dec!(9324)))
+            .currency(Currency::EUR)
+            .reference("Invoice 243")
+            .build()
+    }
+}
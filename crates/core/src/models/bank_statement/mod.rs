@@ -0,0 +1,7 @@
+mod bank_transaction;
+mod reconciliation;
+mod statement_format;
+
+pub use bank_transaction::*;
+pub use reconciliation::*;
+pub use statement_format::*;
@@ -0,0 +1,108 @@
+use crate::prelude::*;
+
+/// How a bank transaction was matched with an open invoice.
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq, IsVariant)]
+pub enum MatchedBy {
+    /// The reference of the transaction contains the invoice number or its
+    /// OCR reference.
+    #[display("reference")]
+    Reference,
+
+    /// The transaction has no usable reference but its amount and currency
+    /// equals the outstanding amount of exactly one open invoice.
+    #[display("amount")]
+    Amount,
+}
+
+/// A bank transaction matched with an open invoice, recorded as a payment.
+#[derive(Clone, Debug, PartialEq, Getters)]
+pub struct MatchedTransaction {
+    /// The matched transaction on the bank statement.
+    #[getset(get = "pub")]
+    transaction: BankTransaction,
+
+    /// The payment recorded for the transaction.
+    #[getset(get = "pub")]
+    payment: Payment,
+
+    /// How the transaction was matched.
+    #[getset(get = "pub")]
+    matched_by: MatchedBy,
+}
+
+impl MatchedTransaction {
+    pub fn new(
+        transaction: BankTransaction,
+        invoice: &IssuedInvoice,
+        matched_by: MatchedBy,
+    ) -> Self {
+        let payment = transaction.payment_towards(invoice);
+        Self {
+            transaction,
+            payment,
+            matched_by,
+        }
+    }
+}
+
+/// The outcome of matching the incoming payments of a bank statement with
+/// open invoices.
+#[derive(Clone, Debug, Default, PartialEq, Getters)]
+pub struct Reconciliation {
+    /// Transactions matched with an open invoice, recorded as payments.
+    #[getset(get = "pub")]
+    matched: Vec<MatchedTransaction>,
+
+    /// Transactions which were already recorded as payments, e.g. by an
+    /// earlier import of the same statement.
+    #[getset(get = "pub")]
+    already_recorded: Vec<BankTransaction>,
+
+    /// Transactions which could not be matched with any open invoice.
+    #[getset(get = "pub")]
+    unmatched: Vec<BankTransaction>,
+}
+
+impl Reconciliation {
+    pub(crate) fn push_matched(&mut self, matched: MatchedTransaction) {
+        self.matched.push(matched);
+    }
+
+    pub(crate) fn push_already_recorded(&mut self, transaction: BankTransaction) {
+        self.already_recorded.push(transaction);
+    }
+
+    pub(crate) fn push_unmatched(&mut self, transaction: BankTransaction) {
+        self.unmatched.push(transaction);
+    }
+}
+
+impl std::fmt::Display for Reconciliation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Matched #{} payments:", self.matched.len())?;
+        for matched in &self.matched {
+            writeln!(
+                f,
+                "  ✅ {} -> invoice {} (by {})",
+                matched.transaction,
+                matched.payment.invoice(),
+                matched.matched_by
+            )?;
+        }
+        if !self.already_recorded.is_empty() {
+            writeln!(
+                f,
+                "Skipped #{} already recorded payments:",
+                self.already_recorded.len()
+            )?;
+            for transaction in &self.already_recorded {
+                writeln!(f, "  ⏭️ {}", transaction)?;
+            }
+        }
+        writeln!(f, "Unmatched #{} payments:", self.unmatched.len())?;
+        for transaction in &self.unmatched {
+            writeln!(f, "  ❓ {}", transaction)?;
+        }
+        Ok(())
+    }
+}
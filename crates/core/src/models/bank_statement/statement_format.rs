@@ -0,0 +1,64 @@
+use crate::prelude::*;
+
+/// The file format of a bank statement to import.
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq, EnumIter)]
+pub enum StatementFormat {
+    /// ISO 20022 `camt.053` Bank to Customer Statement XML.
+    #[display("camt.053")]
+    Camt053,
+
+    /// A generic CSV statement with the header
+    /// `date,amount,currency,reference`, one transaction per row.
+    #[display("CSV")]
+    Csv,
+}
+
+impl StatementFormat {
+    /// Infers the format of the statement at `path` from its file extension,
+    /// `xml` for `camt.053` and `csv` for CSV.
+    ///
+    /// # Errors
+    /// Returns an error if the extension is not recognized.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let format = StatementFormat::from_path("statement_2025_06.XML").unwrap();
+    /// assert_eq!(format, StatementFormat::Camt053);
+    /// ```
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let extension = path
+            .extension()
+            .map(|e| e.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+        match extension.as_str() {
+            "xml" => Ok(Self::Camt053),
+            "csv" => Ok(Self::Csv),
+            _ => Err(Error::UnknownBankStatementFormat {
+                path: path.display().to_string(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn test_from_path_csv() {
+        assert_eq!(
+            StatementFormat::from_path("a/b/statement.csv").unwrap(),
+            StatementFormat::Csv
+        );
+    }
+
+    #[test]
+    fn test_from_path_unknown() {
+        assert!(StatementFormat::from_path("statement.pdf").is_err());
+        assert!(StatementFormat::from_path("statement").is_err());
+    }
+}
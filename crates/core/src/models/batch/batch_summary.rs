@@ -0,0 +1,140 @@
+use crate::prelude::*;
+
+/// An invoice created in a batch.
+#[derive(Clone, Debug, PartialEq, TypedBuilder, Getters)]
+pub struct BatchInvoice {
+    /// The month invoiced.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    month: YearAndMonth,
+
+    /// Whether the invoice is for services or expenses.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    kind: InvoiceKind,
+
+    /// The number of the invoice, e.g. `243`.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    number: InvoiceNumber,
+
+    /// Where the PDF was saved.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    path: PathBuf,
+}
+
+/// Why an invoice of a batch was not created.
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq)]
+pub enum SkipReason {
+    /// The month is in the record of months off.
+    #[display("month off")]
+    MonthOff,
+    /// No expenses are recorded for the month.
+    #[display("no expenses")]
+    NoExpenses,
+    /// The month is before the month of the invoice number offset.
+    #[display("before invoice number offset")]
+    BeforeOffset,
+}
+
+/// An invoice of a batch which was not created, and why.
+#[derive(Clone, Debug, PartialEq, TypedBuilder, Getters)]
+pub struct SkippedBatchInvoice {
+    /// The month which was not invoiced.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    month: YearAndMonth,
+
+    /// The kind of invoice which was not created.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    kind: InvoiceKind,
+
+    /// Why the invoice was not created.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    reason: SkipReason,
+}
+
+/// The outcome of generating invoices for a range of months.
+#[derive(Clone, Debug, Default, PartialEq, Getters)]
+pub struct BatchSummary {
+    /// The invoices created, in order.
+    #[getset(get = "pub")]
+    created: Vec<BatchInvoice>,
+
+    /// The invoices skipped, in order.
+    #[getset(get = "pub")]
+    skipped: Vec<SkippedBatchInvoice>,
+}
+
+impl BatchSummary {
+    /// Adds a created invoice to the summary.
+    pub fn push_created(&mut self, invoice: BatchInvoice) {
+        self.created.push(invoice);
+    }
+
+    /// Adds a skipped invoice to the summary.
+    pub fn push_skipped(&mut self, skipped: SkippedBatchInvoice) {
+        self.skipped.push(skipped);
+    }
+}
+
+impl std::fmt::Display for BatchSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Created {} invoices:", self.created.len())?;
+        for invoice in &self.created {
+            writeln!(
+                f,
+                "{:<8} {:<9} {:>6} {}",
+                invoice.month.to_string(),
+                invoice.kind.to_string(),
+                format!("#{}", invoice.number),
+                invoice.path.display()
+            )?;
+        }
+        if self.skipped.is_empty() {
+            return Ok(());
+        }
+        writeln!(f, "Skipped {} invoices:", self.skipped.len())?;
+        for skipped in &self.skipped {
+            writeln!(
+                f,
+                "{:<8} {:<9} {}",
+                skipped.month.to_string(),
+                skipped.kind.to_string(),
+                skipped.reason
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use insta::assert_snapshot;
+    use test_log::test;
+
+    #[test]
+    fn test_display() {
+        let mut summary = BatchSummary::default();
+        summary.push_created(
+            BatchInvoice::builder()
+                .month(YearAndMonth::january(2025))
+                .kind(InvoiceKind::Services)
+                .number(InvoiceNumber::from(243u16))
+                .path("/invoices/2025-01-31_invoice_243.pdf")
+                .build(),
+        );
+        summary.push_skipped(
+            SkippedBatchInvoice::builder()
+                .month(YearAndMonth::february(2025))
+                .kind(InvoiceKind::Services)
+                .reason(SkipReason::MonthOff)
+                .build(),
+        );
+        assert_snapshot!(summary.to_string());
+    }
+}
@@ -0,0 +1,5 @@
+mod batch_summary;
+mod valid_batch_input;
+
+pub use batch_summary::*;
+pub use valid_batch_input::*;
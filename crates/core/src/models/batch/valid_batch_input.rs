@@ -0,0 +1,95 @@
+use crate::prelude::*;
+
+/// Input which has been validated and is ready for generating invoices for a
+/// range of months, e.g. after parental leave.
+#[derive(Debug, Clone, Display, TypedBuilder, Getters)]
+#[display("Layout: {}, from: {}, to: {}, kinds: {:?}, out: {:?}, language: {}", layout, from, to, kinds, output_directory.as_ref().map(|d| d.display()), language)]
+pub struct ValidBatchInput {
+    /// The language to use for the invoices.
+    #[builder(setter(into), default)]
+    #[getset(get = "pub")]
+    language: Language,
+
+    /// The layout of the invoices to use.
+    #[builder(setter(into), default)]
+    #[getset(get = "pub")]
+    layout: Layout,
+
+    /// The first month to generate invoices for.
+    #[getset(get = "pub")]
+    from: YearAndMonth,
+
+    /// The last month to generate invoices for, inclusive.
+    #[getset(get = "pub")]
+    to: YearAndMonth,
+
+    /// The kinds of invoices to generate for each month, services and expenses
+    /// by default.
+    #[builder(setter(into), default = vec![InvoiceKind::Services, InvoiceKind::Expenses])]
+    #[getset(get = "pub")]
+    kinds: Vec<InvoiceKind>,
+
+    /// An optional directory to save the PDFs in, instead of the default
+    /// invoices folder.
+    #[builder(default, setter(into))]
+    #[getset(get = "pub")]
+    output_directory: Option<PathBuf>,
+
+    /// The quantities of the services whose quantity is specified per run,
+    /// see [`ServiceQuantity::PerRun`], used for every invoice with services.
+    #[builder(default, setter(into))]
+    #[getset(get = "pub")]
+    service_quantities: Vec<ServiceQuantityOverride>,
+
+    /// Discounts, surcharges and credits of every invoice, applied after the
+    /// ones agreed with the client.
+    #[builder(default, setter(into))]
+    #[getset(get = "pub")]
+    adjustments: Vec<Adjustment>,
+}
+
+impl ValidBatchInput {
+    /// The months from `from` through `to`.
+    ///
+    /// # Errors
+    /// Returns an error if `to` is before `from`.
+    pub fn months(&self) -> Result<Vec<YearAndMonth>> {
+        if self.to < self.from {
+            return Err(Error::InvalidMonthRange {
+                from: self.from.to_string(),
+                to: self.to.to_string(),
+            });
+        }
+        Ok(self.from.months_through(self.to))
+    }
+}
+
+impl HasSample for ValidBatchInput {
+    fn sample() -> Self {
+        Self::builder()
+            .from(YearAndMonth::january(2025))
+            .to(YearAndMonth::june(2025))
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn test_months() {
+        let months = ValidBatchInput::sample().months().unwrap();
+        assert_eq!(months.len(), 6);
+    }
+
+    #[test]
+    fn test_months_invalid_range() {
+        let input = ValidBatchInput::builder()
+            .from(YearAndMonth::june(2025))
+            .to(YearAndMonth::january(2025))
+            .build();
+        assert!(input.months().is_err());
+    }
+}
@@ -6,7 +6,7 @@ use crate::prelude::*;
 pub struct Data {
     /// Information about this specific invoice.
     #[builder(setter(into))]
-    #[getset(get = "pub")]
+    #[getset(get = "pub", set_with = "pub")]
     information: ProtoInvoiceInfo,
 
     /// The company that issued the invoice, the vendor/seller/supplier/issuer.
@@ -26,15 +26,22 @@ pub struct Data {
     #[getset(get = "pub")]
     payment_info: PaymentInformation,
 
-    /// Price of service, if applicable.
+    /// The services billed on services invoices, one line per service.
     #[builder(setter(into))]
-    #[getset(get = "pub")]
+    #[getset(get = "pub", set_with = "pub")]
     service_fees: ServiceFees,
 
     /// Any expenses that you might have incurred.
     #[builder(setter(into))]
     #[getset(get = "pub")]
     expensed_months: ExpensedMonths,
+
+    /// The ledger of issued invoices, used to number invoices sequentially.
+    /// Saved in its own file, not part of the data edited by the user.
+    #[builder(setter(into), default)]
+    #[getset(get = "pub", set_with = "pub")]
+    #[serde(skip)]
+    ledger: InvoiceLedger,
 }
 
 impl Data {
@@ -54,17 +61,79 @@ impl Data {
     /// ```
     pub fn validate(self) -> Result<Self> {
         self.information.validate()?;
+        self.vendor.validate()?;
+        self.client.validate()?;
         Ok(self)
     }
 
+    /// Returns the data with the field at the dotted `path`, e.g.
+    /// `vendor.company_name` or `service_fees.services.0.name`, set to
+    /// `value`, validated. Text fields are set to `value` as is, other fields
+    /// to `value` read as JSON, e.g. `true` or `["a@b.com"]`, or else as text.
+    ///
+    /// # Errors
+    /// Returns an error if there is no field at `path`, if `value` is not a
+    /// valid value of the field or if the data is invalid after the change.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let data = Data::sample().with_value_at("vendor.company_name", "Foo AB").unwrap();
+    /// assert_eq!(data.vendor().company_name(), "Foo AB");
+    /// assert!(Data::sample().with_value_at("vendor.unknown", "Foo AB").is_err());
+    /// ```
+    pub fn with_value_at(&self, path: &str, value: &str) -> Result<Self> {
+        let invalid = |reason: String| Error::InvalidDataPath {
+            path: path.to_owned(),
+            reason,
+        };
+        let document = serde_json::to_value(self).map_err(|e| invalid(e.to_string()))?;
+        let pointer = format!("/{}", path.replace('.', "/"));
+        let field = document
+            .pointer(&pointer)
+            .filter(|_| !path.is_empty())
+            .ok_or_else(|| invalid("no such field".to_owned()))?;
+        if field.is_object() {
+            return Err(invalid("not a single field".to_owned()));
+        }
+        let text = Value::String(value.to_owned());
+        let candidates = if field.is_string() {
+            vec![text]
+        } else {
+            serde_json::from_str::<Value>(value)
+                .into_iter()
+                .chain([text])
+                .collect()
+        };
+        let mut error = None;
+        for candidate in candidates {
+            let mut changed = document.clone();
+            if let Some(field) = changed.pointer_mut(&pointer) {
+                *field = candidate;
+            }
+            match serde_json::from_value::<Self>(changed) {
+                Ok(data) => return data.with_ledger(self.ledger.clone()).validate(),
+                Err(e) => error = Some(e),
+            }
+        }
+        Err(invalid(format!(
+            "'{}' is not a valid value: {}",
+            value,
+            error.map(|e| e.to_string()).unwrap_or_default()
+        )))
+    }
+
     /// Converts the `Data` into a `DataWithItemsPricedInSourceCurrency`
     /// using the provided `ValidInput`.
     /// This method prepares the invoice data for rendering by creating an
     /// `InvoiceInfoFull` and populating it with the necessary information.
     /// It also calculates the invoice date, due date, and prepares the output path.
     /// # Errors
-    /// Returns an error if the month is invalid or if there are issues with
-    /// retrieving expenses for the month.
+    /// Returns an error if the month is invalid, if there are issues with
+    /// retrieving expenses for the month, or if a combined invoice is created
+    /// for a month with a services or expenses invoice in the ledger, or the
+    /// reverse.
     /// # Examples
     /// ```
     /// extern crate klirr_core;
@@ -80,7 +149,19 @@ impl Data {
         let invoice_date = target_month.to_date_end_of_month();
         let due_date = invoice_date.advance(self.payment_info().terms());
         let is_expenses = items.is_expenses();
-        let number = input.invoice_number(self.information());
+        let kind = InvoiceKind::from(items);
+        if let Some(issued) = self.ledger.conflicting_with(target_month, kind) {
+            return Err(Error::CombinedAndSeparateInvoices {
+                month: *target_month,
+                kind,
+                issued: *issued.kind(),
+            });
+        }
+        let number = input.invoice_number(self.information(), &self.ledger);
+        let identifier =
+            self.information()
+                .numbering()
+                .identifier(number, &invoice_date, &self.ledger);
         let is_expenses_str_or_empty = if is_expenses { "_expenses" } else { "" };
         let vendor_name = self.vendor.company_name().replace(' ', "_");
 
@@ -92,7 +173,10 @@ impl Data {
             .unwrap_or_else(|| {
                 OutputPath::Name(format!(
                     "{}_{}{}_invoice_{}.pdf",
-                    invoice_date, vendor_name, is_expenses_str_or_empty, number
+                    invoice_date,
+                    vendor_name,
+                    is_expenses_str_or_empty,
+                    identifier.file_name_safe()
                 ))
             });
 
@@ -107,44 +191,134 @@ impl Data {
             )
             .footer_text(self.information().footer_text().clone())
             .number(number)
+            .identifier(identifier)
             .purchase_order(self.information().purchase_order().clone())
+            .vat_rate(*self.information().vat_rate())
+            .days_off(if is_expenses {
+                Vec::new()
+            } else {
+                self.information()
+                    .days_off_record()
+                    .days_off_in(target_month)
+                    .to_vec()
+            })
             .build();
 
+        let services = if items.is_expenses() {
+            Ok(Vec::new())
+        } else {
+            self.services(&input, &invoice_date)
+        };
+
+        // The adjustments agreed with the client apply to services only, so
+        // on a combined invoice those of the subtotal adjust the services.
+        let adjustments = if items.is_expenses() {
+            input.adjustments().clone()
+        } else {
+            let service_lines = AdjustmentTarget::Lines(
+                self.service_fees
+                    .iter()
+                    .map(|fee| fee.name().clone())
+                    .collect(),
+            );
+            self.information()
+                .adjustments()
+                .iter()
+                .cloned()
+                .map(|adjustment| match items {
+                    InvoicedItems::Combined { .. } if adjustment.target().is_subtotal() => {
+                        adjustment.with_target(service_lines.clone())
+                    }
+                    _ => adjustment,
+                })
+                .chain(input.adjustments().iter().cloned())
+                .collect()
+        };
+
         let input_unpriced =
             DataFromDiskWithItemsOfKind::<LineItemsPricedInSourceCurrency>::builder()
                 .client(self.client)
                 .information(full_info)
                 .line_items(match items {
-                    InvoicedItems::Service { days_off } => {
-                        let working_days = working_days_in_month(
-                            target_month,
-                            self.information.months_off_record(),
-                        )?;
-                        let worked_days = working_days - days_off.map(|d| *d).unwrap_or(0);
-
-                        let service = Item::builder()
-                            .name(self.service_fees.name())
-                            .transaction_date(invoice_date)
-                            .quantity(Quantity::from(Decimal::from(worked_days)))
-                            .unit_price(*self.service_fees.unit_price())
-                            .currency(*self.payment_info.currency())
-                            .build();
-                        LineItemsPricedInSourceCurrency::Service(service)
+                    InvoicedItems::Service { .. } => {
+                        LineItemsPricedInSourceCurrency::Services(services?)
                     }
                     InvoicedItems::Expenses => {
                         let expenses = self.expensed_months.get(target_month)?;
                         LineItemsPricedInSourceCurrency::Expenses(expenses.clone())
                     }
+                    InvoicedItems::Combined { .. } => LineItemsPricedInSourceCurrency::Combined {
+                        services: services?,
+                        expenses: if self.expensed_months.contains(target_month) {
+                            self.expensed_months.get(target_month)?
+                        } else {
+                            Vec::new()
+                        },
+                    },
                 })
                 .payment_info(self.payment_info)
                 .vendor(self.vendor)
                 .output_path(output_path)
+                .adjustments(adjustments)
                 .build();
 
         Ok(input_unpriced)
     }
 }
 
+impl Data {
+    /// One item per service of the [`ServiceFees`], its quantity determined
+    /// by the [`ServiceQuantity`] of the service.
+    ///
+    /// # Errors
+    /// Returns an error if the working days of the month cannot be
+    /// calculated, if the quantity of a service specified per run is missing
+    /// in `input`, or if `input` specifies the quantity of any other service.
+    fn services(&self, input: &ValidInput, invoice_date: &Date) -> Result<Vec<Item>> {
+        let overrides = input.service_quantities();
+        if let Some(unexpected) = overrides.iter().find(|o| {
+            !self
+                .service_fees
+                .get(o.name())
+                .is_some_and(|fee| fee.quantity().is_per_run())
+        }) {
+            return Err(Error::UnexpectedServiceQuantity {
+                name: unexpected.name().clone(),
+            });
+        }
+        let target_month = input.month();
+        let working_days = working_days_in_month(
+            target_month,
+            self.information.months_off_record(),
+            self.information.days_off_record(),
+        )?;
+        let worked_days = working_days - input.items().days_off().map(|d| *d).unwrap_or(0);
+        self.service_fees
+            .iter()
+            .map(|fee| {
+                let quantity = match fee.quantity() {
+                    ServiceQuantity::WorkedDays => Quantity::from(Decimal::from(worked_days)),
+                    ServiceQuantity::Fixed(quantity) => *quantity,
+                    ServiceQuantity::PerRun => *overrides
+                        .iter()
+                        .find(|o| o.name() == fee.name())
+                        .ok_or_else(|| Error::MissingServiceQuantity {
+                            name: fee.name().clone(),
+                        })?
+                        .quantity(),
+                };
+                Ok(Item::builder()
+                    .name(fee.name())
+                    .transaction_date(*invoice_date)
+                    .quantity(quantity)
+                    .unit_price(*fee.unit_price())
+                    .currency(*self.payment_info.currency())
+                    .build())
+            })
+            .collect()
+    }
+}
+
 impl HasSample for Data {
     fn sample() -> Self {
         Data::builder()
@@ -177,6 +351,49 @@ mod tests {
         assert_ron_snapshot!(Data::sample())
     }
 
+    #[test]
+    fn test_json_roundtrip() {
+        let json = serde_json::to_string(&Data::sample()).unwrap();
+        assert_eq!(serde_json::from_str::<Data>(&json).unwrap(), Data::sample());
+    }
+
+    #[test]
+    fn test_with_value_at_non_text_fields() {
+        let data = Data::sample()
+            .with_value_at("client.postal_address.zip", "12345")
+            .unwrap();
+        assert_eq!(data.client().postal_address().zip(), "12345");
+
+        let data = Data::sample()
+            .with_value_at("payment_info.currency", "SEK")
+            .unwrap();
+        assert_eq!(*data.payment_info().currency(), Currency::SEK);
+
+        let data = Data::sample()
+            .with_value_at("service_fees.services.0.name", "Design")
+            .unwrap();
+        assert_eq!(data.service_fees()[0].name(), "Design");
+    }
+
+    #[test]
+    fn test_with_value_at_invalid() {
+        for (path, value) in [
+            ("vendor", "Foo AB"),
+            ("service_fees.services.9.name", "Design"),
+            ("payment_info.currency", "Gold"),
+            ("vendor.company_name.first", "Foo AB"),
+        ] {
+            assert!(
+                matches!(
+                    Data::sample().with_value_at(path, value),
+                    Err(Error::InvalidDataPath { .. })
+                ),
+                "{}",
+                path
+            );
+        }
+    }
+
     #[test]
     fn test_worked_days_when_ooo_is_greater_than_0() {
         let sut = Data::sample();
@@ -191,13 +408,275 @@ mod tests {
             )
             .unwrap();
         assert_eq!(
-            partial
-                .line_items()
-                .clone()
-                .try_unwrap_service()
-                .unwrap()
-                .quantity(),
+            partial.line_items().clone().try_unwrap_services().unwrap()[0].quantity(),
             &Quantity::from(dec!(20.0))
         );
     }
+
+    #[test]
+    fn test_worked_days_excludes_recorded_days_off() {
+        let sut = Data::sample();
+        let mut information = sut.information().clone();
+        information.insert_days_off(DateRange::sample());
+        let partial = sut
+            .with_information(information)
+            .to_partial(
+                ValidInput::builder()
+                    .items(InvoicedItems::Service { days_off: None })
+                    .month(YearAndMonth::sample())
+                    .build(),
+            )
+            .unwrap();
+        assert_eq!(partial.information().days_off(), &vec![DateRange::sample()]);
+        assert_eq!(
+            partial.line_items().clone().try_unwrap_services().unwrap()[0].quantity(),
+            &Quantity::from(dec!(17.0))
+        );
+    }
+
+    #[test]
+    fn test_combined_has_number_of_services_and_both_kinds_of_items() {
+        let input = |items| {
+            ValidInput::builder()
+                .items(items)
+                .month(YearAndMonth::sample())
+                .build()
+        };
+        let services = Data::sample()
+            .to_partial(input(InvoicedItems::Service { days_off: None }))
+            .unwrap();
+        let combined = Data::sample()
+            .to_partial(input(InvoicedItems::Combined { days_off: None }))
+            .unwrap();
+        assert_eq!(
+            combined.information().number(),
+            services.information().number()
+        );
+        let LineItemsPricedInSourceCurrency::Combined {
+            services: combined_services,
+            expenses,
+        } = combined.line_items()
+        else {
+            panic!("Expected combined line items");
+        };
+        assert_eq!(
+            combined_services[0].quantity(),
+            services.line_items().clone().try_unwrap_services().unwrap()[0].quantity()
+        );
+        assert_eq!(expenses.len(), 3);
+    }
+
+    #[test]
+    fn test_file_name_is_safe_with_any_prefix() {
+        let sut = Data::sample();
+        let information = sut.information().clone().with_numbering(
+            InvoiceNumbering::sample().with_prefix(Some("ACME: \"Q3\" <EU>|*?\t".to_owned())),
+        );
+        let partial = sut
+            .with_information(information)
+            .to_partial(
+                ValidInput::builder()
+                    .items(InvoicedItems::Service { days_off: None })
+                    .month(YearAndMonth::sample())
+                    .build(),
+            )
+            .unwrap();
+        assert!(
+            partial
+                .information()
+                .identifier()
+                .to_string()
+                .starts_with("ACME: \"Q3\"")
+        );
+        let path = partial.absolute_path().unwrap();
+        assert!(
+            path.to_string_lossy()
+                .ends_with("_invoice_ACME---Q3---EU-----2025-001.pdf")
+        );
+    }
+
+    #[test]
+    fn test_combined_and_separate_invoices_of_a_month_are_exclusive() {
+        let month = YearAndMonth::sample();
+        let input = |items| ValidInput::builder().items(items).month(month).build();
+        let with_issued = |kind| {
+            let mut ledger = InvoiceLedger::default();
+            ledger.record(
+                IssuedInvoice::builder()
+                    .number(InvoiceNumber::from(243))
+                    .kind(kind)
+                    .invoice_date(month.to_date_end_of_month())
+                    .due_date(month.to_date_end_of_month())
+                    .currency(Currency::EUR)
+                    .total(Cost::sample())
+                    .build(),
+            );
+            Data::sample().with_ledger(ledger)
+        };
+
+        let result = with_issued(InvoiceKind::Services)
+            .to_partial(input(InvoicedItems::Combined { days_off: None }));
+        assert!(matches!(
+            result,
+            Err(Error::CombinedAndSeparateInvoices {
+                kind: InvoiceKind::Combined,
+                issued: InvoiceKind::Services,
+                ..
+            })
+        ));
+        let result = with_issued(InvoiceKind::Combined).to_partial(input(InvoicedItems::Expenses));
+        assert!(matches!(
+            result,
+            Err(Error::CombinedAndSeparateInvoices {
+                kind: InvoiceKind::Expenses,
+                issued: InvoiceKind::Combined,
+                ..
+            })
+        ));
+
+        // Generating the same kind again, or the other separate kind, is fine.
+        assert!(
+            with_issued(InvoiceKind::Combined)
+                .to_partial(input(InvoicedItems::Combined { days_off: None }))
+                .is_ok()
+        );
+        assert!(
+            with_issued(InvoiceKind::Services)
+                .to_partial(input(InvoicedItems::Expenses))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_combined_applies_client_adjustments_to_services_only() {
+        let discount = Adjustment::of_subtotal(
+            AdjustmentKind::Discount,
+            AdjustmentAmount::from_str("10%").unwrap(),
+        );
+        let sut = Data::sample();
+        let information = sut
+            .information()
+            .clone()
+            .with_adjustments(vec![discount.clone()]);
+        let combined = sut
+            .with_information(information)
+            .to_partial(
+                ValidInput::builder()
+                    .items(InvoicedItems::Combined { days_off: None })
+                    .month(YearAndMonth::sample())
+                    .adjustments(vec![discount])
+                    .build(),
+            )
+            .unwrap();
+        let LineItemsPricedInSourceCurrency::Combined { services, expenses } =
+            combined.line_items()
+        else {
+            panic!("Expected combined line items");
+        };
+        let items = services
+            .iter()
+            .chain(expenses)
+            .cloned()
+            .map(Item::with_total_cost)
+            .collect::<Vec<_>>();
+        let services_total = items[..services.len()]
+            .iter()
+            .map(|item| **item.total_cost())
+            .sum::<rust_decimal::Decimal>();
+        let subtotal = items
+            .iter()
+            .map(|item| **item.total_cost())
+            .sum::<rust_decimal::Decimal>();
+
+        // The discount agreed with the client is of the services only, the
+        // one given for this invoice is of its subtotal, expenses included.
+        let applied = combined
+            .adjustments()
+            .iter()
+            .map(|adjustment| **adjustment.apply(&items).unwrap().amount())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            applied,
+            vec![-services_total / dec!(10), -subtotal / dec!(10)]
+        );
+    }
+
+    fn data_with_three_services() -> Data {
+        let service_fees = ServiceFees::new([
+            ServiceFee::sample(),
+            ServiceFee::builder()
+                .name("On-call stipend")
+                .unit_price(UnitPrice::from(dec!(500)))
+                .quantity(ServiceQuantity::PerRun)
+                .build(),
+            ServiceFee::builder()
+                .name("Tooling licence")
+                .unit_price(UnitPrice::from(dec!(99)))
+                .quantity(ServiceQuantity::Fixed(Quantity::ONE))
+                .build(),
+        ])
+        .unwrap();
+        Data::sample().with_service_fees(service_fees)
+    }
+
+    #[test]
+    fn test_one_line_per_service_with_quantity_of_its_rule() {
+        let partial = data_with_three_services()
+            .to_partial(
+                ValidInput::builder()
+                    .items(InvoicedItems::Service { days_off: None })
+                    .month(YearAndMonth::sample())
+                    .service_quantities(vec![ServiceQuantityOverride::sample()])
+                    .build(),
+            )
+            .unwrap();
+        let quantities = partial
+            .line_items()
+            .clone()
+            .try_unwrap_services()
+            .unwrap()
+            .iter()
+            .map(|item| *item.quantity())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            quantities,
+            vec![
+                Quantity::from(dec!(22)),
+                Quantity::from(dec!(2)),
+                Quantity::ONE
+            ]
+        );
+    }
+
+    #[test]
+    fn test_per_run_service_without_quantity_fails() {
+        let result = data_with_three_services().to_partial(
+            ValidInput::builder()
+                .items(InvoicedItems::Service { days_off: None })
+                .month(YearAndMonth::sample())
+                .build(),
+        );
+        assert!(matches!(
+            result,
+            Err(Error::MissingServiceQuantity { name }) if name == "On-call stipend"
+        ));
+    }
+
+    #[test]
+    fn test_quantity_of_service_not_per_run_fails() {
+        let result = data_with_three_services().to_partial(
+            ValidInput::builder()
+                .items(InvoicedItems::Service { days_off: None })
+                .month(YearAndMonth::sample())
+                .service_quantities(vec![ServiceQuantityOverride::new(
+                    "Tooling licence",
+                    Quantity::from(dec!(3)),
+                )])
+                .build(),
+        );
+        assert!(matches!(
+            result,
+            Err(Error::UnexpectedServiceQuantity { name }) if name == "Tooling licence"
+        ));
+    }
 }
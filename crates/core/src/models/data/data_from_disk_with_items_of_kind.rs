@@ -47,6 +47,20 @@ pub struct DataFromDiskWithItemsOfKind<Items: Serialize + MaybeIsExpenses> {
     /// Where to save the output PDF file.
     #[builder(setter(into))]
     output_path: OutputPath,
+
+    /// Receipts of the line items, appended to the PDF, not passed to the
+    /// layout.
+    #[serde(skip)]
+    #[builder(default)]
+    #[getset(get = "pub")]
+    receipts: Vec<Receipt>,
+
+    /// Discounts, surcharges and credits to apply to the line items, passed
+    /// to the layout as rows once applied, see [`LineItemsFlat::adjustments`].
+    #[serde(skip)]
+    #[builder(setter(into), default)]
+    #[getset(get = "pub")]
+    adjustments: Vec<Adjustment>,
 }
 
 impl<Items: Serialize + MaybeIsExpenses> DataFromDiskWithItemsOfKind<Items> {
@@ -88,6 +102,16 @@ impl<Items: Serialize + MaybeIsExpenses> DataFromDiskWithItemsOfKind<Items> {
             }
         }
     }
+
+    /// Saves the invoice in `directory` instead of the default invoices
+    /// folder, keeping its default file name. An absolute output path
+    /// specified by the user is kept as is.
+    pub fn in_directory(mut self, directory: impl AsRef<Path>) -> Self {
+        if let OutputPath::Name(name) = &self.output_path {
+            self.output_path = OutputPath::AbsolutePath(directory.as_ref().join(name));
+        }
+        self
+    }
 }
 
 impl<Items: Serialize + MaybeIsExpenses + HasSample> HasSample
@@ -105,6 +129,27 @@ impl<Items: Serialize + MaybeIsExpenses + HasSample> HasSample
     }
 }
 
+impl PreparedData {
+    /// Returns the net, VAT and gross totals of the invoice.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let data = PreparedData::sample();
+    /// assert_eq!(*data.totals().net(), data.line_items().net_total());
+    /// ```
+    pub fn totals(&self) -> InvoiceTotals {
+        InvoiceTotals::new(self.line_items.net_total(), *self.information.vat_rate())
+    }
+
+    /// Returns this data with `receipts` attached, appended to the PDF when
+    /// rendered.
+    pub fn with_receipts(self, receipts: Vec<Receipt>) -> Self {
+        Self { receipts, ..self }
+    }
+}
+
 impl DataWithItemsPricedInSourceCurrency {
     /// Converts the `DataWithItemsPricedInSourceCurrency` into a `PreparedData`
     /// which is compatible with Typst rendering.
@@ -124,7 +169,8 @@ impl DataWithItemsPricedInSourceCurrency {
     /// assert!(result.is_ok(), "Expected conversion to succeed, got: {:?}", result);
     /// ```
     pub fn to_typst(self, exchange_rates: ExchangeRates) -> Result<PreparedData> {
-        let line_items = LineItemsFlat::try_from((self.line_items, exchange_rates))?;
+        let line_items = LineItemsFlat::try_from((self.line_items, exchange_rates))?
+            .with_adjustments(&self.adjustments);
         Ok(PreparedData {
             line_items,
             information: self.information,
@@ -132,6 +178,8 @@ impl DataWithItemsPricedInSourceCurrency {
             client: self.client,
             payment_info: self.payment_info,
             output_path: self.output_path,
+            receipts: self.receipts,
+            adjustments: self.adjustments,
         })
     }
 }
@@ -10,10 +10,10 @@ pub struct CompanyInformation {
     #[builder(setter(into, strip_option))]
     #[getset(get = "pub", set_with = "pub")]
     contact_person: Option<String>,
-    /// The unique organisation number of the company, e.g. `"123456789"`.
+    /// The unique organisation number of the company, e.g. `"556677-8899"`.
     #[builder(setter(into))]
     #[getset(get = "pub", set_with = "pub")]
-    organisation_number: String,
+    organisation_number: OrganisationNumber,
     /// The name of the company
     #[builder(setter(into))]
     #[getset(get = "pub", set_with = "pub")]
@@ -22,10 +22,15 @@ pub struct CompanyInformation {
     #[builder(setter(into))]
     #[getset(get = "pub", set_with = "pub")]
     postal_address: PostalAddress,
-    /// The VAT number of the company, e.g. `"GB123456789"`.
+    /// The VAT number of the company, e.g. `"GB987654353"`.
     #[builder(setter(into))]
     #[getset(get = "pub", set_with = "pub")]
-    vat_number: String,
+    vat_number: VatNumber,
+    /// The email addresses invoices are sent to, for the client, or sent
+    /// from, the first one, for the vendor, e.g. `"billing@holmes.example"`.
+    #[builder(setter(into), default)]
+    #[getset(get = "pub", set_with = "pub")]
+    billing_emails: Vec<EmailAddress>,
 }
 
 impl HasSample for CompanyInformation {
@@ -35,13 +40,28 @@ impl HasSample for CompanyInformation {
 }
 
 impl CompanyInformation {
+    /// Validates the identifiers of the company which depend on where it is,
+    /// i.e. its organisation number in the country of its postal address.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// assert!(CompanyInformation::sample_vendor().validate().is_ok());
+    /// ```
+    pub fn validate(&self) -> Result<()> {
+        self.organisation_number
+            .validate_in(self.postal_address.country())
+    }
+
     pub fn sample_client() -> Self {
         Self::builder()
             .company_name("Holmes Ltd")
             .contact_person("Sherlock Holmes")
-            .organisation_number("9876543-2101")
+            .organisation_number(OrganisationNumber::sample_client())
             .postal_address(PostalAddress::sample_client())
-            .vat_number("GB987654321")
+            .vat_number(VatNumber::sample_client())
+            .billing_emails(vec![EmailAddress::sample_client()])
             .build()
     }
 
@@ -49,9 +69,10 @@ impl CompanyInformation {
         Self::builder()
             .company_name("Lupin et Associés")
             .contact_person("Arsène Lupin")
-            .organisation_number("7418529-3012")
+            .organisation_number(OrganisationNumber::sample_vendor())
             .postal_address(PostalAddress::sample_vendor())
-            .vat_number("FR74185293012")
+            .vat_number(VatNumber::sample_vendor())
+            .billing_emails(vec![EmailAddress::sample_vendor()])
             .build()
     }
 }
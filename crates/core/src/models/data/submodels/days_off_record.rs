@@ -0,0 +1,146 @@
+use crate::prelude::*;
+
+/// Records periods of leave shorter than a month, as ranges of dates per
+/// month, e.g. `{"2025-07": ["2025-07-01..2025-07-18"]}`. A range spanning
+/// several months is split into one range per month.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, From, Deref, Default)]
+#[from(IndexMap<YearAndMonth, Vec<DateRange>>)]
+pub struct DaysOffRecord(IndexMap<YearAndMonth, Vec<DateRange>>);
+
+impl DaysOffRecord {
+    /// Creates a new `DaysOffRecord` from an iterator of `DateRange`s.
+    pub fn new(ranges: impl IntoIterator<Item = DateRange>) -> Self {
+        let mut record = Self::default();
+        for range in ranges {
+            record.insert(range);
+        }
+        record
+    }
+
+    /// Returns the ranges of days off in `month`, in chronological order,
+    /// empty if no days off are recorded for `month`.
+    pub fn days_off_in(&self, month: &YearAndMonth) -> &[DateRange] {
+        self.0.get(month).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Checks if `date` is recorded as a day off.
+    pub fn contains(&self, date: &Date) -> bool {
+        self.days_off_in(&YearAndMonth::from(*date))
+            .iter()
+            .any(|range| range.contains(date))
+    }
+
+    /// Groups the dates of `range` by month, in chronological order.
+    fn dates_by_month(range: &DateRange) -> IndexMap<YearAndMonth, Vec<Date>> {
+        let mut by_month = IndexMap::<YearAndMonth, Vec<Date>>::new();
+        for date in range.dates() {
+            by_month
+                .entry(YearAndMonth::from(date))
+                .or_default()
+                .push(date);
+        }
+        by_month
+    }
+
+    /// Inserts the days of `range` into the record, merging them with
+    /// overlapping or adjacent ranges already recorded.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let mut record = DaysOffRecord::default();
+    /// record.insert("2025-06-30..2025-07-02".parse().unwrap());
+    /// record.insert("2025-07-03".parse().unwrap());
+    /// assert_eq!(record.days_off_in(&YearAndMonth::june(2025)).len(), 1);
+    /// assert_eq!(
+    ///     record.days_off_in(&YearAndMonth::july(2025)),
+    ///     &["2025-07-01..2025-07-03".parse::<DateRange>().unwrap()]
+    /// );
+    /// ```
+    pub fn insert(&mut self, range: DateRange) {
+        for (month, dates) in Self::dates_by_month(&range) {
+            let recorded = self.0.entry(month).or_default();
+            let merged = recorded
+                .iter()
+                .flat_map(DateRange::dates)
+                .chain(dates)
+                .collect::<Vec<_>>();
+            *recorded = DateRange::ranges_of(merged);
+        }
+        self.0
+            .sort_by(|a, _, b, _| a.partial_cmp(b).expect("Months are totally ordered"));
+    }
+
+    /// Removes the days of `range` from the record, returning `true` if any
+    /// of them were recorded as days off.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let mut record = DaysOffRecord::new(["2025-07-01..2025-07-18".parse().unwrap()]);
+    /// assert!(record.remove(&"2025-07-07..2025-07-11".parse().unwrap()));
+    /// assert_eq!(record.days_off_in(&YearAndMonth::july(2025)).len(), 2);
+    /// assert!(!record.remove(&"2025-08-01".parse().unwrap()));
+    /// ```
+    pub fn remove(&mut self, range: &DateRange) -> bool {
+        let mut removed_any = false;
+        for (month, dates) in Self::dates_by_month(range) {
+            let Some(recorded) = self.0.get_mut(&month) else {
+                continue;
+            };
+            let kept = recorded
+                .iter()
+                .flat_map(DateRange::dates)
+                .filter(|date| !dates.contains(date))
+                .collect::<Vec<_>>();
+            let kept = DateRange::ranges_of(kept);
+            removed_any |= kept != *recorded;
+            if kept.is_empty() {
+                self.0.shift_remove(&month);
+            } else {
+                *recorded = kept;
+            }
+        }
+        removed_any
+    }
+}
+
+impl HasSample for DaysOffRecord {
+    fn sample() -> Self {
+        Self::new([DateRange::sample()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn test_insert_splits_range_spanning_months() {
+        let record = DaysOffRecord::new(["2025-06-30..2025-07-01".parse().unwrap()]);
+        assert_eq!(record.len(), 2);
+        assert!(record.contains(&"2025-06-30".parse().unwrap()));
+        assert!(record.contains(&"2025-07-01".parse().unwrap()));
+        assert!(!record.contains(&"2025-07-02".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_remove_all_days_of_month_removes_month() {
+        let mut record = DaysOffRecord::sample();
+        assert!(record.remove(&"2025-05-01..2025-05-31".parse().unwrap()));
+        assert!(record.is_empty());
+    }
+
+    #[test]
+    fn test_serialization_sample() {
+        let ron = ron::to_string(&DaysOffRecord::sample()).unwrap();
+        assert_eq!(ron, "({\"2025-05\":[\"2025-05-12..2025-05-16\"]})");
+        assert_eq!(
+            ron::from_str::<DaysOffRecord>(&ron).unwrap(),
+            DaysOffRecord::sample()
+        );
+    }
+}
@@ -5,6 +5,11 @@ pub struct ExpensedMonths {
     explanation: String,
     #[getset(get = "pub")]
     expenses_for_months: IndexMap<YearAndMonth, ExpensesForMonth>,
+    /// Expenses billed every month, merged into the expenses of each month
+    /// they are due in, unless overridden by an expense with the same name
+    /// recorded for that month.
+    #[getset(get = "pub")]
+    recurring: Vec<RecurringExpense>,
 }
 
 impl ExpensedMonths {
@@ -47,6 +52,8 @@ impl ExpensesForMonth {
                 .unit_price(*item.unit_price())
                 .currency(*item.currency())
                 .quantity(QuantityIgnored)
+                .receipt(item.receipt().clone())
+                .recurring(*item.recurring())
                 .build();
 
             map.entry(marker)
@@ -62,6 +69,8 @@ impl ExpensesForMonth {
                 .unit_price(*marker.unit_price())
                 .currency(*marker.currency())
                 .quantity(quantity)
+                .receipt(marker.receipt().clone())
+                .recurring(*marker.recurring())
                 .build();
             self.0.push(item);
         }
@@ -95,10 +104,17 @@ impl ExpensedMonths {
                 .into_iter()
                 .map(|(month, items)| (month, ExpensesForMonth::new(items)))
                 .collect(),
+            recurring: Vec::new(),
         }
     }
 
-    /// Checks if the given month has expenses recorded.
+    /// Returns these expenses with `recurring` expenses billed every month.
+    pub fn with_recurring(self, recurring: Vec<RecurringExpense>) -> Self {
+        Self { recurring, ..self }
+    }
+
+    /// Checks if the given month has expenses recorded, or recurring
+    /// expenses due.
     ///
     /// # Examples
     /// ```
@@ -113,13 +129,78 @@ impl ExpensedMonths {
     /// ```
     pub fn contains(&self, month: &YearAndMonth) -> bool {
         self.expenses_for_months.contains_key(month)
+            || self
+                .recurring
+                .iter()
+                .any(|expense| expense.is_due_in(month))
+    }
+
+    /// Checks if an expense which is the same as `item`, except for its
+    /// quantity, is recorded for any month.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let expenses = ExpensedMonths::sample();
+    /// assert!(expenses.contains_expense(&Item::sample_expense_coffee()));
+    /// assert!(!ExpensedMonths::default().contains_expense(&Item::sample_expense_coffee()));
+    /// ```
+    pub fn contains_expense(&self, item: &Item) -> bool {
+        self.expenses_for_months.values().any(|expenses| {
+            expenses.0.iter().any(|recorded| {
+                recorded.name() == item.name()
+                    && recorded.transaction_date() == item.transaction_date()
+                    && recorded.unit_price() == item.unit_price()
+                    && recorded.currency() == item.currency()
+            })
+        })
+    }
+
+    /// The recurring expenses due in `month`, except those overridden by an
+    /// expense with the same name recorded for `month`.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let month = YearAndMonth::may(2025);
+    /// let expenses = ExpensedMonths::default().with_recurring(vec![RecurringExpense::sample()]);
+    /// assert_eq!(expenses.recurring_in(&month).len(), 1);
+    /// assert!(expenses.recurring_in(&YearAndMonth::december(2024)).is_empty());
+    /// ```
+    pub fn recurring_in(&self, month: &YearAndMonth) -> Vec<Item> {
+        let recorded = self
+            .expenses_for_months
+            .get(month)
+            .map(ExpensesForMonth::items)
+            .unwrap_or_default();
+        self.recurring
+            .iter()
+            .filter(|expense| !recorded.iter().any(|item| item.name() == expense.name()))
+            .filter_map(|expense| expense.item_in(month))
+            .collect()
+    }
+
+    /// Retrieves the expenses recorded for a specific month, without any
+    /// recurring expenses.
+    fn recorded(&self, target_month: &YearAndMonth) -> Result<Vec<Item>> {
+        if let Some(items) = self.expenses_for_months().get(target_month) {
+            Ok(items.items())
+        } else {
+            Err(Error::TargetMonthMustHaveExpenses {
+                target_month: *target_month,
+            })
+        }
     }
 
     /// Retrieves the expenses for a specific month from a collection of expenses
-    /// organized by `YearAndMonth`.
+    /// organized by `YearAndMonth`, followed by the recurring expenses due in
+    /// that month, which are marked as recurring.
     ///
-    /// /// # Errors
-    /// Returns an error if the target month does not have any expenses recorded.
+    /// # Errors
+    /// Returns an error if the target month does not have any expenses recorded
+    /// nor any recurring expenses due.
     ///
     /// ```
     /// extern crate klirr_core;
@@ -131,12 +212,11 @@ impl ExpensedMonths {
     /// assert_eq!(expenses.unwrap().len(), 1); // January 2024 has one expense
     /// ```
     pub fn get(&self, target_month: &YearAndMonth) -> Result<Vec<Item>> {
-        if let Some(items) = self.expenses_for_months().get(target_month) {
-            Ok(items.items())
-        } else {
-            Err(Error::TargetMonthMustHaveExpenses {
-                target_month: *target_month,
-            })
+        let recurring = self.recurring_in(target_month);
+        match self.recorded(target_month) {
+            Ok(recorded) => Ok(recorded.into_iter().chain(recurring).collect()),
+            Err(_) if !recurring.is_empty() => Ok(recurring),
+            Err(error) => Err(error),
         }
     }
 
@@ -171,9 +251,299 @@ impl ExpensedMonths {
     }
 }
 
+impl ExpensedMonths {
+    /// Returns the expenses of `month` only, and the recurring expenses due
+    /// in `month`, or all expenses if `month` is `None`.
+    pub fn filtered(&self, month: Option<&YearAndMonth>) -> Self {
+        Self {
+            explanation: self.explanation.clone(),
+            expenses_for_months: self
+                .expenses_for_months
+                .iter()
+                .filter(|(m, _)| month.is_none_or(|month| *m == month))
+                .map(|(m, items)| (*m, items.clone()))
+                .collect(),
+            recurring: self
+                .recurring
+                .iter()
+                .filter(|expense| month.is_none_or(|month| expense.is_due_in(month)))
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Adds `expense` to the recurring expenses, replacing a recurring
+    /// expense with the same name, which is returned.
+    pub fn add_recurring(&mut self, expense: RecurringExpense) -> Option<RecurringExpense> {
+        match self
+            .recurring
+            .iter_mut()
+            .find(|recurring| recurring.name() == expense.name())
+        {
+            Some(existing) => Some(std::mem::replace(existing, expense)),
+            None => {
+                self.recurring.push(expense);
+                None
+            }
+        }
+    }
+
+    /// Removes the recurring expense named `name`, returning it.
+    ///
+    /// # Errors
+    /// Returns an error if there is no recurring expense named `name`.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let mut expenses = ExpensedMonths::default().with_recurring(vec![RecurringExpense::sample()]);
+    /// assert!(expenses.remove_recurring("Phone subscription").is_ok());
+    /// assert!(expenses.remove_recurring("Phone subscription").is_err());
+    /// ```
+    pub fn remove_recurring(&mut self, name: &str) -> Result<RecurringExpense> {
+        let index = self
+            .recurring
+            .iter()
+            .position(|expense| expense.name() == name)
+            .ok_or(Error::UnknownRecurringExpense {
+                name: name.to_owned(),
+            })?;
+        Ok(self.recurring.remove(index))
+    }
+
+    /// Skips the recurring expense named `name` in `month`, returning
+    /// `false` if it was not due in `month`.
+    ///
+    /// # Errors
+    /// Returns an error if there is no recurring expense named `name`.
+    pub fn skip_recurring(&mut self, name: &str, month: &YearAndMonth) -> Result<bool> {
+        self.recurring
+            .iter_mut()
+            .find(|expense| expense.name() == name)
+            .map(|expense| expense.skip(month))
+            .ok_or(Error::UnknownRecurringExpense {
+                name: name.to_owned(),
+            })
+    }
+
+    /// Returns a mutable reference to the expenses of `month`, and checks
+    /// that `index` is within bounds of them.
+    fn expenses_at_index(
+        &mut self,
+        month: &YearAndMonth,
+        index: usize,
+    ) -> Result<&mut ExpensesForMonth> {
+        let expenses =
+            self.expenses_for_months
+                .get_mut(month)
+                .ok_or(Error::TargetMonthMustHaveExpenses {
+                    target_month: *month,
+                })?;
+        if index >= expenses.0.len() {
+            return Err(Error::ExpenseIndexOutOfBounds {
+                month: *month,
+                index,
+                count: expenses.0.len(),
+            });
+        }
+        Ok(expenses)
+    }
+
+    /// Returns the expense at `index` of `month`.
+    ///
+    /// # Errors
+    /// Returns an error if `month` has no expenses or `index` is out of bounds.
+    pub fn expense(&self, month: &YearAndMonth, index: usize) -> Result<Item> {
+        let expenses = self.recorded(month)?;
+        let count = expenses.len();
+        expenses
+            .into_iter()
+            .nth(index)
+            .ok_or(Error::ExpenseIndexOutOfBounds {
+                month: *month,
+                index,
+                count,
+            })
+    }
+
+    /// Removes the expense at `index` of `month`, removing the month if it
+    /// has no expenses left, returning the removed expense.
+    ///
+    /// # Errors
+    /// Returns an error if `month` has no expenses or `index` is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let mut expensed_months = ExpensedMonths::sample();
+    /// let month = YearAndMonth::february(2024);
+    /// let removed = expensed_months.remove_expense(&month, 0).unwrap();
+    /// assert_eq!(removed, Item::sample_expense_breakfast());
+    /// assert!(!expensed_months.contains(&month));
+    /// ```
+    pub fn remove_expense(&mut self, month: &YearAndMonth, index: usize) -> Result<Item> {
+        let expenses = self.expenses_at_index(month, index)?;
+        let removed = expenses.0.remove(index);
+        if expenses.0.is_empty() {
+            self.expenses_for_months.shift_remove(month);
+        }
+        Ok(removed)
+    }
+
+    /// Replaces the expense at `index` of `month` with `item`, merging it
+    /// with an expense that is the same except for its quantity, returning
+    /// the replaced expense.
+    ///
+    /// # Errors
+    /// Returns an error if `month` has no expenses or `index` is out of bounds.
+    pub fn replace_expense(
+        &mut self,
+        month: &YearAndMonth,
+        index: usize,
+        item: Item,
+    ) -> Result<Item> {
+        let expenses = self.expenses_at_index(month, index)?;
+        let replaced = std::mem::replace(&mut expenses.0[index], item);
+        expenses.insert(Vec::new());
+        Ok(replaced)
+    }
+
+    /// Moves all expenses of `from` to `to`, merging them with the expenses
+    /// already recorded for `to`, returning the number of moved expenses.
+    ///
+    /// # Errors
+    /// Returns an error if `from` has no expenses.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let mut expensed_months = ExpensedMonths::sample();
+    /// let from = YearAndMonth::january(2024);
+    /// let to = YearAndMonth::february(2024);
+    /// assert_eq!(expensed_months.move_expenses(&from, &to).unwrap(), 2);
+    /// assert!(!expensed_months.contains(&from));
+    /// assert_eq!(expensed_months.get(&to).unwrap().len(), 3);
+    /// ```
+    pub fn move_expenses(&mut self, from: &YearAndMonth, to: &YearAndMonth) -> Result<usize> {
+        let items = self.recorded(from)?;
+        self.expenses_for_months.shift_remove(from);
+        let count = items.len();
+        self.insert_expenses(to, items);
+        Ok(count)
+    }
+}
+
+impl std::fmt::Display for ExpensedMonths {
+    /// Lists the expenses per month, with the index of each expense, used
+    /// to remove or edit it.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.expenses_for_months.is_empty() && self.recurring.is_empty() {
+            return writeln!(f, "No expenses recorded.");
+        }
+        for (month, expenses) in &self.expenses_for_months {
+            writeln!(f, "{}:", month)?;
+            for (index, item) in expenses.0.iter().enumerate() {
+                writeln!(f, "  [{}] {}", index, item)?;
+            }
+        }
+        if !self.recurring.is_empty() {
+            writeln!(f, "Recurring:")?;
+            for expense in &self.recurring {
+                writeln!(f, "  {}", expense)?;
+            }
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use insta::assert_snapshot;
+
+    #[test]
+    fn test_display() {
+        assert_snapshot!(ExpensedMonths::sample().to_string());
+    }
+
+    #[test]
+    fn test_filtered() {
+        let sut = ExpensedMonths::sample().filtered(Some(&YearAndMonth::february(2024)));
+        assert_eq!(sut.expenses_for_months().len(), 1);
+        assert_eq!(
+            ExpensedMonths::sample().filtered(None),
+            ExpensedMonths::sample()
+        );
+    }
+
+    #[test]
+    fn test_remove_expense_keeps_month_with_expenses_left() {
+        let mut sut = ExpensedMonths::sample();
+        let month = YearAndMonth::january(2024);
+        let removed = sut.remove_expense(&month, 1).unwrap();
+        assert_eq!(removed, Item::sample_expense_sandwich());
+        assert_eq!(
+            sut.get(&month).unwrap(),
+            vec![Item::sample_expense_coffee()]
+        );
+    }
+
+    #[test]
+    fn test_remove_expense_index_out_of_bounds() {
+        let mut sut = ExpensedMonths::sample();
+        let result = sut.remove_expense(&YearAndMonth::january(2024), 2);
+        assert!(matches!(
+            result,
+            Err(Error::ExpenseIndexOutOfBounds {
+                index: 2,
+                count: 2,
+                ..
+            })
+        ));
+        assert_eq!(sut, ExpensedMonths::sample());
+    }
+
+    #[test]
+    fn test_remove_expense_month_without_expenses() {
+        let mut sut = ExpensedMonths::sample();
+        let result = sut.remove_expense(&YearAndMonth::january(1970), 0);
+        assert!(matches!(
+            result,
+            Err(Error::TargetMonthMustHaveExpenses { .. })
+        ));
+    }
+
+    #[test]
+    fn test_replace_expense_merges_same_except_quantity() {
+        let mut sut = ExpensedMonths::sample();
+        let month = YearAndMonth::january(2024);
+        let sample = Item::sample_expense_coffee();
+        let coffee = Item::builder()
+            .name(sample.name().clone())
+            .unit_price(*sample.unit_price())
+            .currency(*sample.currency())
+            .quantity(Quantity::from(dec!(1)))
+            .transaction_date(*sample.transaction_date())
+            .build();
+        let replaced = sut.replace_expense(&month, 1, coffee).unwrap();
+        assert_eq!(replaced, Item::sample_expense_sandwich());
+        let expenses = sut.get(&month).unwrap();
+        assert_eq!(expenses.len(), 1);
+        assert_eq!(
+            *expenses[0].quantity(),
+            *Item::sample_expense_coffee().quantity() + Quantity::from(dec!(1))
+        );
+    }
+
+    #[test]
+    fn test_move_expenses_from_month_without_expenses() {
+        let mut sut = ExpensedMonths::sample();
+        let result = sut.move_expenses(&YearAndMonth::january(1970), &YearAndMonth::january(2024));
+        assert!(result.is_err());
+    }
 
     #[test]
     fn test_expensed_months_contains() {
@@ -268,6 +638,77 @@ mod tests {
         assert_eq!(*retrieved_items[0].quantity(), Quantity::from(dec!(7.0))); // 3.0 + 4.0
     }
 
+    fn with_phone_subscription() -> ExpensedMonths {
+        ExpensedMonths::sample().with_recurring(vec![RecurringExpense::sample()])
+    }
+
+    #[test]
+    fn test_get_merges_recurring_after_recorded() {
+        let sut = with_phone_subscription();
+        let expenses = sut.get(&YearAndMonth::may(2025)).unwrap();
+        assert_eq!(expenses.len(), 1);
+        assert!(*expenses[0].recurring());
+        let mut sut = sut;
+        sut.insert_expenses(
+            &YearAndMonth::may(2025),
+            vec![Item::sample_expense_coffee()],
+        );
+        let expenses = sut.get(&YearAndMonth::may(2025)).unwrap();
+        assert_eq!(expenses.len(), 2);
+        assert!(!*expenses[0].recurring());
+        assert!(*expenses[1].recurring());
+    }
+
+    #[test]
+    fn test_recorded_expense_with_same_name_overrides_recurring() {
+        let mut sut = with_phone_subscription();
+        let month = YearAndMonth::may(2025);
+        let phone = Item::builder()
+            .name("Phone subscription")
+            .unit_price(UnitPrice::from(dec!(40)))
+            .currency(Currency::EUR)
+            .quantity(Quantity::ONE)
+            .transaction_date(Date::from_str("2025-05-12").unwrap())
+            .build();
+        sut.insert_expenses(&month, vec![phone.clone()]);
+        assert_eq!(sut.get(&month).unwrap(), vec![phone]);
+    }
+
+    #[test]
+    fn test_skipped_month_without_recorded_expenses_has_no_expenses() {
+        let mut sut = with_phone_subscription();
+        let month = YearAndMonth::may(2025);
+        assert!(sut.contains(&month));
+        assert!(sut.skip_recurring("Phone subscription", &month).unwrap());
+        assert!(!sut.contains(&month));
+        assert!(sut.get(&month).is_err());
+        assert!(sut.skip_recurring("Coworking", &month).is_err());
+    }
+
+    #[test]
+    fn test_recurring_expenses_are_not_moved_nor_indexed() {
+        let mut sut = with_phone_subscription();
+        let may = YearAndMonth::may(2025);
+        assert!(sut.expense(&may, 0).is_err());
+        assert!(sut.move_expenses(&may, &YearAndMonth::june(2025)).is_err());
+    }
+
+    #[test]
+    fn test_add_recurring_replaces_same_name() {
+        let mut sut = ExpensedMonths::default();
+        assert_eq!(sut.add_recurring(RecurringExpense::sample()), None);
+        assert_eq!(
+            sut.add_recurring(RecurringExpense::sample()),
+            Some(RecurringExpense::sample())
+        );
+        assert_eq!(sut.recurring().len(), 1);
+    }
+
+    #[test]
+    fn test_display_with_recurring() {
+        assert_snapshot!(with_phone_subscription().to_string());
+    }
+
     #[test]
     fn default_is_empty() {
         let expensed_months = ExpensedMonths::default();
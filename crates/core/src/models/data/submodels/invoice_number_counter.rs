@@ -0,0 +1,23 @@
+use crate::prelude::*;
+
+/// How invoice numbers are counted.
+#[derive(
+    Clone, Copy, Debug, Default, Display, Serialize, Deserialize, PartialEq, Eq, Hash, IsVariant,
+)]
+pub enum InvoiceNumberCounter {
+    /// Derived from the months elapsed since the offset of the invoice
+    /// information, one number per month for services and the next for
+    /// expenses, skipping months off.
+    #[default]
+    MonthlyDerived,
+    /// The number following the highest number in the ledger of issued
+    /// invoices, giving every invoice its own number even when several are
+    /// issued in a month. Generating an invoice again reuses its number.
+    LedgerSequential,
+}
+
+impl HasSample for InvoiceNumberCounter {
+    fn sample() -> Self {
+        Self::LedgerSequential
+    }
+}
@@ -0,0 +1,195 @@
+use crate::prelude::*;
+
+/// A part of an [`InvoiceNumberFormat`] template.
+#[derive(Clone, Debug, PartialEq)]
+enum Segment {
+    /// Text which is kept as is, e.g. `"-"`.
+    Literal(String),
+    /// The counted number, zero-padded to `width` digits, from `{number}` or
+    /// e.g. `{number:03}`.
+    Number { width: usize },
+    /// The year of the invoice date, from `{year}`.
+    Year,
+    /// The two digit month of the invoice date, from `{month}`.
+    Month,
+    /// The prefix of the numbering scheme, from `{prefix}`.
+    Prefix,
+}
+
+/// A template for formatting invoice numbers, e.g. `"{prefix}{year}-{number:03}"`,
+/// which must contain the counted number `{number}`, optionally zero-padded
+/// like `{number:03}`, and may contain `{year}` and `{month}` of the invoice
+/// date and the `{prefix}` of the numbering scheme.
+#[derive(Clone, Debug, Display, PartialEq, Eq, SerializeDisplay, DeserializeFromStr)]
+pub struct InvoiceNumberFormat(String);
+
+impl Default for InvoiceNumberFormat {
+    /// Just the number, e.g. `"243"`.
+    fn default() -> Self {
+        Self("{number}".to_owned())
+    }
+}
+
+impl InvoiceNumberFormat {
+    fn invalid(&self, reason: impl Into<String>) -> Error {
+        Error::InvalidInvoiceNumberFormat {
+            invalid_string: self.0.clone(),
+            reason: reason.into(),
+        }
+    }
+
+    fn placeholder(&self, name: &str) -> Result<Segment> {
+        match name.split_once(':') {
+            None if name == "number" => Ok(Segment::Number { width: 0 }),
+            None if name == "year" => Ok(Segment::Year),
+            None if name == "month" => Ok(Segment::Month),
+            None if name == "prefix" => Ok(Segment::Prefix),
+            Some(("number", width)) if width.starts_with('0') => width
+                .parse::<usize>()
+                .map(|width| Segment::Number { width })
+                .map_err(|_| self.invalid(format!("invalid width of '{{{}}}'", name))),
+            _ => Err(self.invalid(format!("unknown placeholder '{{{}}}'", name))),
+        }
+    }
+
+    fn segments(&self) -> Result<Vec<Segment>> {
+        let mut segments = Vec::new();
+        let mut rest = self.0.as_str();
+        while let Some(start) = rest.find('{') {
+            if start > 0 {
+                segments.push(Segment::Literal(rest[..start].to_owned()));
+            }
+            let end = rest[start..]
+                .find('}')
+                .ok_or(self.invalid("unclosed '{'"))?;
+            segments.push(self.placeholder(&rest[start + 1..start + end])?);
+            rest = &rest[start + end + 1..];
+        }
+        if rest.contains('}') {
+            return Err(self.invalid("unopened '}'"));
+        }
+        if !rest.is_empty() {
+            segments.push(Segment::Literal(rest.to_owned()));
+        }
+        if !segments
+            .iter()
+            .any(|segment| matches!(segment, Segment::Number { .. }))
+        {
+            return Err(self.invalid("must contain '{number}'"));
+        }
+        Ok(segments)
+    }
+
+    /// Formats the counted `number` of an invoice dated `invoice_date`.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let format: InvoiceNumberFormat = "{prefix}{year}-{number:03}".parse().unwrap();
+    /// let identifier = format.format(
+    ///     InvoiceNumber::from(43),
+    ///     &"2025-05-31".parse().unwrap(),
+    ///     Some("ACME-"),
+    /// );
+    /// assert_eq!(identifier.to_string(), "ACME-2025-043");
+    /// ```
+    pub fn format(
+        &self,
+        number: InvoiceNumber,
+        invoice_date: &Date,
+        prefix: Option<&str>,
+    ) -> InvoiceIdentifier {
+        self.segments()
+            .expect("Validated when created")
+            .into_iter()
+            .map(|segment| match segment {
+                Segment::Literal(text) => text,
+                Segment::Number { width } => format!("{:0width$}", *number, width = width),
+                Segment::Year => invoice_date.year().to_string(),
+                Segment::Month => format!("{:02}", **invoice_date.month()),
+                Segment::Prefix => prefix.unwrap_or_default().to_owned(),
+            })
+            .collect::<String>()
+            .into()
+    }
+}
+
+impl std::str::FromStr for InvoiceNumberFormat {
+    type Err = crate::prelude::Error;
+
+    /// Parses a template of invoice numbers, e.g. `"{year}-{number:03}"`.
+    ///
+    /// # Errors
+    /// Returns an error if the template has an unknown placeholder, or does
+    /// not contain `{number}`.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// assert!("{year}-{number:03}".parse::<InvoiceNumberFormat>().is_ok());
+    /// assert!("{year}-{day}".parse::<InvoiceNumberFormat>().is_err());
+    /// assert!("{year}".parse::<InvoiceNumberFormat>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let format = Self(s.to_owned());
+        format.segments()?;
+        Ok(format)
+    }
+}
+
+impl HasSample for InvoiceNumberFormat {
+    fn sample() -> Self {
+        Self::from_str("{prefix}{year}-{number:03}").expect("Valid format")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn test_default_is_just_the_number() {
+        let identifier = InvoiceNumberFormat::default().format(
+            InvoiceNumber::from(243),
+            &Date::sample(),
+            Some("ACME-"),
+        );
+        assert_eq!(
+            identifier,
+            InvoiceIdentifier::from(InvoiceNumber::from(243))
+        );
+    }
+
+    #[test]
+    fn test_format_month_and_wide_number() {
+        let format = InvoiceNumberFormat::from_str("{year}{month}/{number:03}").unwrap();
+        let identifier = format.format(
+            InvoiceNumber::from(1234),
+            &Date::from_str("2025-07-31").unwrap(),
+            None,
+        );
+        assert_eq!(identifier.to_string(), "202507/1234");
+    }
+
+    #[test]
+    fn test_invalid_formats() {
+        for invalid in ["{number", "number}", "{number:3}", "{number:0x}", "{}"] {
+            assert!(
+                InvoiceNumberFormat::from_str(invalid).is_err(),
+                "Expected '{}' to be invalid",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn test_serde_roundtrip() {
+        let format = InvoiceNumberFormat::sample();
+        let ron = ron::to_string(&format).unwrap();
+        assert_eq!(ron::from_str::<InvoiceNumberFormat>(&ron).unwrap(), format);
+        assert!(ron::from_str::<InvoiceNumberFormat>("\"{day}\"").is_err());
+    }
+}
@@ -0,0 +1,46 @@
+use crate::prelude::*;
+
+/// The scheme used to number invoices, how numbers are counted and how they
+/// are formatted, e.g. `"ACME-2025-043"`, defaulting to plain numbers derived
+/// from the month, e.g. `"243"`.
+#[derive(
+    Clone, Debug, Default, Serialize, Deserialize, PartialEq, TypedBuilder, Getters, WithSetters,
+)]
+pub struct InvoiceNumbering {
+    /// The template of the number printed on invoices, e.g. `"{year}-{number:03}"`.
+    #[builder(setter(into), default)]
+    #[getset(get = "pub", set_with = "pub")]
+    #[serde(default)]
+    format: InvoiceNumberFormat,
+
+    /// How invoice numbers are counted.
+    #[builder(setter(into), default)]
+    #[getset(get = "pub", set_with = "pub")]
+    #[serde(default)]
+    counter: InvoiceNumberCounter,
+
+    /// If the number printed on invoices restarts from `1` every year. The
+    /// numbers in the ledger of issued invoices never restart.
+    #[builder(setter(into), default)]
+    #[getset(get = "pub", set_with = "pub")]
+    #[serde(default)]
+    reset_yearly: bool,
+
+    /// A prefix used by the `{prefix}` placeholder of the format, e.g. one
+    /// per client, like `"ACME-"`.
+    #[builder(setter(into), default)]
+    #[getset(get = "pub", set_with = "pub")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    prefix: Option<String>,
+}
+
+impl HasSample for InvoiceNumbering {
+    fn sample() -> Self {
+        Self::builder()
+            .format(InvoiceNumberFormat::sample())
+            .counter(InvoiceNumberCounter::sample())
+            .reset_yearly(true)
+            .prefix("ACME-".to_owned())
+            .build()
+    }
+}
@@ -1,29 +1,43 @@
 mod company_information;
 mod currency;
+mod days_off_record;
 mod expensed_months;
 mod footer_text;
 mod hex_color;
+mod invoice_number_counter;
+mod invoice_number_format;
+mod invoice_numbering;
 mod months_off_record;
 mod payment_information;
 mod payment_terms;
 mod postal_address;
 mod proto_invoice_info;
 mod purchase_order;
+mod recurring_expense;
+mod service_fee;
 mod service_fees;
+mod service_quantity;
 mod street_address;
 mod timestamped_invoice_number;
 
 pub use company_information::*;
 pub use currency::*;
+pub use days_off_record::*;
 pub use expensed_months::*;
 pub use footer_text::*;
 pub use hex_color::*;
+pub use invoice_number_counter::*;
+pub use invoice_number_format::*;
+pub use invoice_numbering::*;
 pub use months_off_record::*;
 pub use payment_information::*;
 pub use payment_terms::*;
 pub use postal_address::*;
 pub use proto_invoice_info::*;
 pub use purchase_order::*;
+pub use recurring_expense::*;
+pub use service_fee::*;
 pub use service_fees::*;
+pub use service_quantity::*;
 pub use street_address::*;
 pub use timestamped_invoice_number::*;
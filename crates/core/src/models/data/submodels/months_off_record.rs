@@ -29,6 +29,24 @@ impl MonthsOffRecord {
         self.0.insert(year_and_month);
     }
 
+    /// Removes a month off period from the record of months off, returning
+    /// `true` if it was recorded.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let mut record = MonthsOffRecord::new([
+    ///    YearAndMonth::may(2025),
+    /// ]);
+    /// assert!(record.remove(&YearAndMonth::may(2025)));
+    /// assert!(!record.contains(&YearAndMonth::may(2025)));
+    /// assert!(!record.remove(&YearAndMonth::may(2025)));
+    /// ```
+    pub fn remove(&mut self, year_and_month: &YearAndMonth) -> bool {
+        self.0.shift_remove(year_and_month)
+    }
+
     /// Checks if this record contains a specific `YearAndMonth`.
     ///
     /// # Examples
@@ -81,4 +99,18 @@ mod tests {
         record.insert(new_month);
         assert!(record.contains(&new_month));
     }
+
+    #[test]
+    fn test_months_off_record_remove_keeps_order() {
+        let mut record = MonthsOffRecord::new([
+            YearAndMonth::may(2025),
+            YearAndMonth::june(2025),
+            YearAndMonth::july(2025),
+        ]);
+        record.remove(&YearAndMonth::june(2025));
+        assert_eq!(
+            record.iter().copied().collect::<Vec<_>>(),
+            vec![YearAndMonth::may(2025), YearAndMonth::july(2025)]
+        );
+    }
 }
@@ -8,7 +8,7 @@ pub struct PaymentInformation {
     /// The IBAN (International Bank Account Number) of the vendor's bank account,
     #[builder(setter(into))]
     #[getset(get = "pub", set_with = "pub")]
-    iban: String,
+    iban: Iban,
 
     /// The name of the vendor's bank, used for international transfers.
     #[builder(setter(into))]
@@ -18,7 +18,7 @@ pub struct PaymentInformation {
     /// The BIC (Bank Identifier Code) of the vendor's bank, used for international
     #[builder(setter(into))]
     #[getset(get = "pub", set_with = "pub")]
-    bic: String,
+    bic: Bic,
 
     /// The currency of this invoice, e.g. `EUR`
     #[builder(setter(into))]
@@ -35,8 +35,8 @@ impl HasSample for PaymentInformation {
     fn sample() -> Self {
         Self::builder()
             .bank_name("Banque de Paris")
-            .iban("FR76 3000 6000 0112 3456 7890 189")
-            .bic("BNPAFRPP")
+            .iban(Iban::sample())
+            .bic(Bic::sample())
             .currency(Currency::EUR)
             .terms(PaymentTerms::sample())
             .build()
@@ -1,7 +1,9 @@
 use crate::prelude::*;
 
 /// Partial information about the invoice which can be used to derive a [`InvoiceInfoFull`]
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, TypedBuilder, Getters, Setters)]
+#[derive(
+    Clone, Debug, Serialize, Deserialize, PartialEq, TypedBuilder, Getters, Setters, WithSetters,
+)]
 pub struct ProtoInvoiceInfo {
     /// An offset which is used to calculate the invoice number, e.g. `(237, 2025-05)`.
     /// This is enables us to calculate the next invoice number based on the current
@@ -15,6 +17,12 @@ pub struct ProtoInvoiceInfo {
     #[getset(get = "pub", set = "pub")]
     months_off_record: MonthsOffRecord,
 
+    /// Record of days off in months we were partially off, as ranges of
+    /// dates per month, e.g. `{"2025-07": ["2025-07-01..2025-07-18"]}`.
+    #[builder(setter(into), default)]
+    #[getset(get = "pub", set = "pub")]
+    days_off_record: DaysOffRecord,
+
     /// A purchase order number associated with this invoice, e.g. `"PO-12345"`
     /// Typically agreed upon between the vendor and client before the
     /// invoice is issued.
@@ -31,6 +39,24 @@ pub struct ProtoInvoiceInfo {
     #[builder(setter(into), default)]
     #[getset(get = "pub", set_with = "pub")]
     emphasize_color_hex: Option<HexColor>,
+
+    /// The VAT rate charged on invoices, e.g. `25%`, defaults to `0%` which
+    /// is typical for reverse charge of services to clients abroad.
+    #[builder(setter(into), default)]
+    #[getset(get = "pub", set_with = "pub")]
+    vat_rate: Percentage,
+
+    /// How invoices are numbered, e.g. counted sequentially and formatted
+    /// like `"2025-043"`, defaults to plain numbers derived from the month.
+    #[builder(setter(into), default)]
+    #[getset(get = "pub", set_with = "pub")]
+    numbering: InvoiceNumbering,
+
+    /// Discounts, surcharges and credits agreed with the client, applied to
+    /// every invoice with services, e.g. a 10% loyalty discount.
+    #[builder(setter(into), default)]
+    #[getset(get = "pub", set_with = "pub")]
+    adjustments: Vec<Adjustment>,
 }
 
 impl ProtoInvoiceInfo {
@@ -50,6 +76,63 @@ impl ProtoInvoiceInfo {
         months_off.insert(month);
         self.set_months_off_record(months_off);
     }
+
+    /// Removes a month from the months off record, e.g. if it was recorded
+    /// by mistake.
+    ///
+    /// # Errors
+    /// Returns an error if `month` is not in the record of months off.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let mut invoice_info = ProtoInvoiceInfo::sample();
+    /// let month = YearAndMonth::may(2025);
+    /// invoice_info.insert_month_off(month);
+    /// invoice_info.remove_month_off(&month).unwrap();
+    /// assert!(!invoice_info.months_off_record().contains(&month));
+    /// assert!(invoice_info.remove_month_off(&month).is_err());
+    /// ```
+    pub fn remove_month_off(&mut self, month: &YearAndMonth) -> Result<()> {
+        let mut months_off = self.months_off_record.clone();
+        if !months_off.remove(month) {
+            return Err(Error::MonthNotInRecordOfMonthsOff { month: *month });
+        }
+        self.set_months_off_record(months_off);
+        Ok(())
+    }
+
+    /// Inserts the days of `range` into the days off record, which are not
+    /// invoiced for if they are working days.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let mut invoice_info = ProtoInvoiceInfo::sample();
+    /// invoice_info.insert_days_off("2025-07-01..2025-07-18".parse().unwrap());
+    /// assert!(invoice_info.days_off_record().contains(&"2025-07-04".parse().unwrap()));
+    /// ```
+    pub fn insert_days_off(&mut self, range: DateRange) {
+        let mut days_off = self.days_off_record.clone();
+        days_off.insert(range);
+        self.set_days_off_record(days_off);
+    }
+
+    /// Removes the days of `range` from the days off record.
+    ///
+    /// # Errors
+    /// Returns an error if none of the days of `range` are in the record of
+    /// days off.
+    pub fn remove_days_off(&mut self, range: &DateRange) -> Result<()> {
+        let mut days_off = self.days_off_record.clone();
+        if !days_off.remove(range) {
+            return Err(Error::DaysOffNotInRecord { range: *range });
+        }
+        self.set_days_off_record(days_off);
+        Ok(())
+    }
 }
 impl HasSample for ProtoInvoiceInfo {
     fn sample() -> Self {
@@ -127,4 +210,13 @@ mod tests {
         invoice_info.insert_month_off(month);
         assert!(invoice_info.months_off_record.contains(&month));
     }
+
+    #[test]
+    fn test_remove_days_off_not_recorded() {
+        let mut invoice_info = ProtoInvoiceInfo::sample();
+        invoice_info.insert_days_off(DateRange::sample());
+        let result = invoice_info.remove_days_off(&"2025-06-02".parse().unwrap());
+        assert!(matches!(result, Err(Error::DaysOffNotInRecord { .. })));
+        assert_eq!(invoice_info.days_off_record(), &DaysOffRecord::sample());
+    }
 }
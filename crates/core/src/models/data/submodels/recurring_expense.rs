@@ -0,0 +1,251 @@
+use crate::prelude::*;
+
+fn default_quantity() -> Quantity {
+    Quantity::ONE
+}
+
+/// An expense rebilled every month, e.g. a phone subscription or a
+/// coworking fee, merged into the expenses of each month from `start` until
+/// `end`, except for the `skipped` months.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, TypedBuilder, Getters)]
+pub struct RecurringExpense {
+    /// The short name of the expense, e.g. `"Phone subscription"`. An
+    /// expense with the same name recorded for a month overrides this one
+    /// in that month.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    name: String,
+
+    /// The cost per item.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    unit_price: UnitPrice,
+
+    /// The currency the expense is paid in, e.g. `"EUR"`.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    currency: Currency,
+
+    /// The quantity billed each month, defaults to `1`.
+    #[builder(setter(into), default = Quantity::ONE)]
+    #[getset(get = "pub")]
+    #[serde(default = "default_quantity")]
+    quantity: Quantity,
+
+    /// The day of the month used as transaction date, the last day of the
+    /// month is used for months shorter than this, e.g. `28`.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    day_of_month: Day,
+
+    /// The first month of the expense, e.g. `2025-01`.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    start: YearAndMonth,
+
+    /// The last month of the expense, inclusive, or `None` if it recurs
+    /// until removed.
+    #[builder(setter(into), default)]
+    #[getset(get = "pub")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    end: Option<YearAndMonth>,
+
+    /// Months between `start` and `end` in which the expense is not billed.
+    #[builder(setter(into), default)]
+    #[getset(get = "pub")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    skipped: Vec<YearAndMonth>,
+}
+
+impl RecurringExpense {
+    /// Returns `true` if this expense is billed in `month`.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let expense = RecurringExpense::sample();
+    /// assert!(expense.is_due_in(&YearAndMonth::may(2025)));
+    /// assert!(!expense.is_due_in(&YearAndMonth::december(2024)));
+    /// ```
+    pub fn is_due_in(&self, month: &YearAndMonth) -> bool {
+        self.start <= *month
+            && self.end.is_none_or(|end| *month <= end)
+            && !self.skipped.contains(month)
+    }
+
+    /// The expense billed in `month`, marked as recurring, or `None` if it
+    /// is not due in `month`.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let item = RecurringExpense::sample().item_in(&YearAndMonth::february(2025)).unwrap();
+    /// assert!(*item.recurring());
+    /// assert_eq!(item.transaction_date().to_string(), "2025-02-28");
+    /// ```
+    pub fn item_in(&self, month: &YearAndMonth) -> Option<Item> {
+        if !self.is_due_in(month) {
+            return None;
+        }
+        let transaction_date = Date::builder()
+            .year(*month.year())
+            .month(*month.month())
+            .day(self.day_of_month.min(month.last_day_of_month()))
+            .build();
+        let item = Item::builder()
+            .name(self.name.clone())
+            .unit_price(self.unit_price)
+            .currency(self.currency)
+            .quantity(self.quantity)
+            .transaction_date(transaction_date)
+            .recurring(true)
+            .build();
+        Some(item)
+    }
+
+    /// Skips this expense in `month`, returning `false` if it was not due
+    /// in `month`.
+    pub fn skip(&mut self, month: &YearAndMonth) -> bool {
+        if !self.is_due_in(month) {
+            return false;
+        }
+        self.skipped.push(*month);
+        self.skipped
+            .sort_by(|a, b| a.partial_cmp(b).expect("Months are totally ordered"));
+        true
+    }
+}
+
+impl std::fmt::Display for RecurringExpense {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: {}{} #{} on day {} from {}",
+            self.name, self.unit_price, self.currency, self.quantity, self.day_of_month, self.start
+        )?;
+        if let Some(end) = self.end {
+            write!(f, " until {}", end)?;
+        }
+        if !self.skipped.is_empty() {
+            let skipped = self
+                .skipped
+                .iter()
+                .map(YearAndMonth::to_string)
+                .collect::<Vec<_>>();
+            write!(f, ", skipping {}", skipped.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for RecurringExpense {
+    type Err = crate::prelude::Error;
+
+    /// Parses a string in the format: "name, unit_price, currency, quantity, day_of_month, start",
+    /// e.g. "Phone subscription, 25, EUR, 1, 28, 2025-01", optionally followed by the last
+    /// month, e.g. "Phone subscription, 25, EUR, 1, 28, 2025-01, 2025-12".
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let expense: RecurringExpense = "Coworking, 150, EUR, 1, 1, 2025-01, 2025-06".parse().unwrap();
+    /// assert_eq!(expense.end(), &Some(YearAndMonth::june(2025)));
+    /// assert!("Coworking, 150, EUR, 1, 1, 2025-06, 2025-01".parse::<RecurringExpense>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = |reason: String| Error::InvalidRecurringExpense {
+            invalid_string: s.to_string(),
+            reason,
+        };
+        let parts: Vec<&str> = s.split(',').map(str::trim).collect();
+        if parts.len() != 6 && parts.len() != 7 {
+            return Err(invalid("Expected 6 or 7 comma-separated values, on format: \"Phone subscription, 25, EUR, 1, 28, 2025-01\" or \"Phone subscription, 25, EUR, 1, 28, 2025-01, 2025-12\"".to_string()));
+        }
+        let unit_price = parts[1]
+            .parse::<Decimal>()
+            .map_err(|e| invalid(format!("Failed to parse unit_price: {e}")))?;
+        let currency = Currency::from_str(parts[2])
+            .map_err(|e| invalid(format!("Failed to parse currency: {e}")))?;
+        let quantity = parts[3]
+            .parse::<Decimal>()
+            .map_err(|e| invalid(format!("Failed to parse quantity: {e}")))?;
+        if Quantity::from(quantity) < Quantity::ZERO {
+            return Err(invalid("Quantity cannot be negative".to_string()));
+        }
+        let day_of_month = Day::from_str(parts[4])
+            .map_err(|e| invalid(format!("Failed to parse day_of_month: {e}")))?;
+        let start = YearAndMonth::from_str(parts[5])
+            .map_err(|e| invalid(format!("Failed to parse start: {e}")))?;
+        let end = parts
+            .get(6)
+            .map(|end| YearAndMonth::from_str(end))
+            .transpose()
+            .map_err(|e| invalid(format!("Failed to parse end: {e}")))?;
+        if end.is_some_and(|end| end < start) {
+            return Err(invalid(
+                "The end month cannot be before the start month".to_string(),
+            ));
+        }
+        Ok(Self::builder()
+            .name(parts[0])
+            .unit_price(UnitPrice::from(unit_price))
+            .currency(currency)
+            .quantity(Quantity::from(quantity))
+            .day_of_month(day_of_month)
+            .start(start)
+            .end(end)
+            .build())
+    }
+}
+
+impl HasSample for RecurringExpense {
+    fn sample() -> Self {
+        Self::builder()
+            .name("Phone subscription")
+            .unit_price(UnitPrice::from(dec!(25)))
+            .currency(Currency::EUR)
+            .day_of_month(Day::try_from(30).expect("Valid day"))
+            .start(YearAndMonth::january(2025))
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn test_not_due_after_end_or_when_skipped() {
+        let mut expense = RecurringExpense::sample();
+        expense.end = Some(YearAndMonth::june(2025));
+        assert!(expense.skip(&YearAndMonth::march(2025)));
+        assert!(!expense.skip(&YearAndMonth::march(2025)));
+        assert!(!expense.is_due_in(&YearAndMonth::march(2025)));
+        assert!(!expense.is_due_in(&YearAndMonth::july(2025)));
+        assert!(expense.is_due_in(&YearAndMonth::june(2025)));
+    }
+
+    #[test]
+    fn test_display() {
+        let mut expense = RecurringExpense::sample();
+        expense.end = Some(YearAndMonth::december(2025));
+        expense.skip(&YearAndMonth::july(2025));
+        assert_eq!(
+            expense.to_string(),
+            "Phone subscription: 25EUR #1 on day 30 from 2025-01 until 2025-12, skipping 2025-07"
+        );
+    }
+
+    #[test]
+    fn test_serde_defaults() {
+        let expense: RecurringExpense = ron::from_str(
+            "(name: \"Phone subscription\", unit_price: UnitPrice(25), currency: \"EUR\", day_of_month: Day(30), start: \"2025-01\")",
+        )
+        .unwrap();
+        assert_eq!(expense, RecurringExpense::sample());
+    }
+}
@@ -0,0 +1,31 @@
+use crate::prelude::*;
+
+/// A service sold by the vendor to the client, rendered as a line on the
+/// services invoice.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, TypedBuilder, Getters, WithSetters)]
+pub struct ServiceFee {
+    /// Description of the service, e.g. `"Agreed Consulting Fees"`
+    #[builder(setter(into))]
+    #[getset(get = "pub", set_with = "pub")]
+    name: String,
+
+    /// The cost per item
+    #[builder(setter(into))]
+    #[getset(get = "pub", set_with = "pub")]
+    unit_price: UnitPrice,
+
+    /// How the quantity of this service is determined, defaults to the
+    /// number of days worked in the month.
+    #[builder(setter(into), default)]
+    #[getset(get = "pub", set_with = "pub")]
+    quantity: ServiceQuantity,
+}
+
+impl HasSample for ServiceFee {
+    fn sample() -> Self {
+        Self::builder()
+            .name("Discreet Investigative Services".to_string())
+            .unit_price(UnitPrice::from(dec!(777.0)))
+            .build()
+    }
+}
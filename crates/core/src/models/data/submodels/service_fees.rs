@@ -1,22 +1,137 @@
 use crate::prelude::*;
 
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, TypedBuilder, Getters, WithSetters)]
+/// The services billed on each services invoice, one line per service, e.g.
+/// consulting days, an on-call stipend and a fixed tooling licence.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Deref)]
+#[serde(try_from = "ServiceFeesOnDisk")]
 pub struct ServiceFees {
-    /// Description of the consulting service, e.g. `"Agreed Consulting Fees"`
-    #[builder(setter(into))]
-    #[getset(get = "pub", set_with = "pub")]
-    name: String,
-    /// The cost per item
-    #[builder(setter(into))]
-    #[getset(get = "pub", set_with = "pub")]
-    unit_price: UnitPrice,
+    /// The services in the order they are rendered, never empty.
+    services: Vec<ServiceFee>,
 }
 
 impl ServiceFees {
-    pub fn sample() -> Self {
-        Self::builder()
-            .name("Discreet Investigative Services".to_string())
-            .unit_price(UnitPrice::from(dec!(777.0)))
-            .build()
+    /// Creates the services billed on each services invoice, in the order
+    /// they are rendered.
+    ///
+    /// # Errors
+    /// Returns an error if `fees` is empty or if two services have the same
+    /// name.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// assert!(ServiceFees::new([ServiceFee::sample()]).is_ok());
+    /// assert!(ServiceFees::new([]).is_err());
+    /// assert!(ServiceFees::new([ServiceFee::sample(), ServiceFee::sample()]).is_err());
+    /// ```
+    pub fn new(fees: impl IntoIterator<Item = ServiceFee>) -> Result<Self> {
+        let fees = fees.into_iter().collect::<Vec<_>>();
+        if fees.is_empty() {
+            return Err(Error::NoServiceFees);
+        }
+        for (index, fee) in fees.iter().enumerate() {
+            if fees[..index].iter().any(|other| other.name() == fee.name()) {
+                return Err(Error::DuplicateServiceFee {
+                    name: fee.name().clone(),
+                });
+            }
+        }
+        Ok(Self { services: fees })
+    }
+
+    /// The service named `name`, if any.
+    pub fn get(&self, name: &str) -> Option<&ServiceFee> {
+        self.services.iter().find(|fee| fee.name() == name)
+    }
+}
+
+impl From<ServiceFee> for ServiceFees {
+    fn from(fee: ServiceFee) -> Self {
+        Self {
+            services: vec![fee],
+        }
+    }
+}
+
+/// The service fees as saved on disk, validated when read.
+#[derive(Deserialize)]
+#[serde(rename = "ServiceFees")]
+struct ServiceFeesOnDisk {
+    services: Vec<ServiceFee>,
+}
+
+impl TryFrom<ServiceFeesOnDisk> for ServiceFees {
+    type Error = crate::prelude::Error;
+
+    fn try_from(on_disk: ServiceFeesOnDisk) -> Result<Self> {
+        Self::new(on_disk.services)
+    }
+}
+
+impl HasSample for ServiceFees {
+    fn sample() -> Self {
+        Self::from(ServiceFee::sample())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn test_deserialize_single_service_is_rejected() {
+        let result = ron::from_str::<ServiceFees>(
+            r#"ServiceFees(name: "Consulting", unit_price: UnitPrice(350.0))"#,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_serde_roundtrip_many_services() {
+        let fees = ServiceFees::new([
+            ServiceFee::sample(),
+            ServiceFee::builder()
+                .name("On-call stipend")
+                .unit_price(UnitPrice::from(dec!(500)))
+                .quantity(ServiceQuantity::PerRun)
+                .build(),
+            ServiceFee::builder()
+                .name("Tooling licence")
+                .unit_price(UnitPrice::from(dec!(99)))
+                .quantity(ServiceQuantity::Fixed(Quantity::ONE))
+                .build(),
+        ])
+        .unwrap();
+        let ron = ron::to_string(&fees).unwrap();
+        assert_eq!(ron::from_str::<ServiceFees>(&ron).unwrap(), fees);
+    }
+
+    #[test]
+    fn test_deserialize_with_struct_names() {
+        let fees: ServiceFees = ron::from_str(
+            r#"ServiceFees(
+                services: [
+                    ServiceFee(name: "Consulting", unit_price: UnitPrice(1000.0), quantity: WorkedDays),
+                    ServiceFee(name: "On-call stipend", unit_price: UnitPrice(500.0), quantity: PerRun),
+                    ServiceFee(name: "Tooling licence", unit_price: UnitPrice(99.0), quantity: Fixed(Quantity(1.0))),
+                ],
+            )"#,
+        )
+        .unwrap();
+        assert_eq!(
+            fees.get("Tooling licence").unwrap().quantity(),
+            &ServiceQuantity::Fixed(Quantity::ONE)
+        );
+    }
+
+    #[test]
+    fn test_deserialize_duplicate_names_fails() {
+        let ron = format!(
+            "(services: {})",
+            ron::to_string(&vec![ServiceFee::sample(), ServiceFee::sample()]).unwrap()
+        );
+        assert!(ron::from_str::<ServiceFees>(&ron).is_err());
     }
 }
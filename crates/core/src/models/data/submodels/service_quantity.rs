@@ -0,0 +1,20 @@
+use crate::prelude::*;
+
+/// How the quantity of a service line is determined for each invoice.
+#[derive(Clone, Copy, Debug, Default, Display, Serialize, Deserialize, PartialEq, IsVariant)]
+pub enum ServiceQuantity {
+    /// The number of days worked in the month, i.e. the working days minus
+    /// the days off, e.g. consulting days.
+    #[default]
+    #[display("worked days")]
+    WorkedDays,
+
+    /// The same quantity every month, e.g. `1` for a monthly tooling licence.
+    #[display("fixed {}", _0)]
+    Fixed(Quantity),
+
+    /// Specified every time an invoice is generated, e.g. the number of weeks
+    /// on call, using `--quantity "On-call stipend=2"`.
+    #[display("per run")]
+    PerRun,
+}
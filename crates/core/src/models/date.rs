@@ -10,6 +10,8 @@ use crate::prelude::*;
     PartialEq,
     Eq,
     Hash,
+    PartialOrd,
+    Ord,
     SerializeDisplay,
     DeserializeFromStr,
     TypedBuilder,
@@ -111,6 +113,27 @@ impl Date {
             PaymentTerms::Net(days) => self.advance_days(days.due_in()),
         }
     }
+
+    /// Returns today's date, according to the local time zone.
+    pub fn today() -> Self {
+        Self::from(Local::now().date_naive())
+    }
+
+    /// Returns the number of days elapsed since `start`, negative if `start`
+    /// is after this date.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let start: Date = "2025-05-31".parse().unwrap();
+    /// let end: Date = "2025-07-01".parse().unwrap();
+    /// assert_eq!(end.elapsed_days_since(&start), 31);
+    /// assert_eq!(start.elapsed_days_since(&end), -31);
+    /// ```
+    pub fn elapsed_days_since(&self, start: &Self) -> i64 {
+        (self.to_datetime() - start.to_datetime()).num_days()
+    }
 }
 
 impl Date {
@@ -0,0 +1,194 @@
+use crate::prelude::*;
+
+/// An inclusive range of dates, e.g. a period of leave, formatted as
+/// `"2025-07-01..2025-07-18"`, or as a single date if it spans one day.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    SerializeDisplay,
+    DeserializeFromStr,
+    Getters,
+)]
+pub struct DateRange {
+    /// The first date of the range, e.g. `2025-07-01`.
+    #[getset(get = "pub")]
+    start: Date,
+
+    /// The last date of the range, inclusive, e.g. `2025-07-18`.
+    #[getset(get = "pub")]
+    end: Date,
+}
+
+/// Converts `date` into a [`NaiveDate`], failing if it does not exist in
+/// the calendar, e.g. `2025-02-30`.
+fn naive_date(date: &Date) -> Result<NaiveDate> {
+    NaiveDate::from_ymd_opt(
+        **date.year() as i32,
+        **date.month() as u32,
+        **date.day() as u32,
+    )
+    .ok_or(Error::FailedToParseDate {
+        underlying: format!("{} is not a date in the calendar", date),
+    })
+}
+
+impl DateRange {
+    /// Creates a new range from `start` to `end`, both inclusive.
+    ///
+    /// # Errors
+    /// Returns an error if `end` is before `start` or if any of the dates
+    /// does not exist in the calendar.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let start: Date = "2025-07-18".parse().unwrap();
+    /// let end: Date = "2025-07-01".parse().unwrap();
+    /// assert!(DateRange::new(start, end).is_err());
+    /// assert!(DateRange::new(end, start).is_ok());
+    /// ```
+    pub fn new(start: Date, end: Date) -> Result<Self> {
+        naive_date(&start)?;
+        naive_date(&end)?;
+        if end < start {
+            return Err(Error::InvalidDateRange {
+                invalid_string: format!("{}..{}", start, end),
+            });
+        }
+        Ok(Self { start, end })
+    }
+
+    /// Creates a range spanning the single day `date`.
+    pub fn day(date: Date) -> Result<Self> {
+        Self::new(date, date)
+    }
+
+    /// Returns `true` if `date` is within this range.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let range: DateRange = "2025-07-01..2025-07-18".parse().unwrap();
+    /// assert!(range.contains(&"2025-07-18".parse().unwrap()));
+    /// assert!(!range.contains(&"2025-07-19".parse().unwrap()));
+    /// ```
+    pub fn contains(&self, date: &Date) -> bool {
+        self.start <= *date && *date <= self.end
+    }
+
+    /// Returns all dates of this range, in chronological order.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let range: DateRange = "2025-06-29..2025-07-02".parse().unwrap();
+    /// let dates = range.dates();
+    /// assert_eq!(dates.len(), 4);
+    /// assert_eq!(dates.last(), Some(range.end()));
+    /// ```
+    pub fn dates(&self) -> Vec<Date> {
+        let end = naive_date(&self.end).expect("Validated when created");
+        naive_date(&self.start)
+            .expect("Validated when created")
+            .iter_days()
+            .take_while(|day| *day <= end)
+            .map(Date::from)
+            .collect()
+    }
+
+    /// Creates the fewest ranges spanning exactly `dates`, merging
+    /// consecutive dates into one range, in chronological order.
+    pub(crate) fn ranges_of(dates: impl IntoIterator<Item = Date>) -> Vec<Self> {
+        let mut dates = dates.into_iter().collect::<Vec<_>>();
+        dates.sort();
+        dates.dedup();
+        let mut ranges: Vec<Self> = Vec::new();
+        for date in dates {
+            match ranges.last_mut() {
+                Some(range) if date.elapsed_days_since(&range.end) == 1 => range.end = date,
+                _ => ranges.push(Self::day(date).expect("Valid date")),
+            }
+        }
+        ranges
+    }
+}
+
+impl std::fmt::Display for DateRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.start == self.end {
+            write!(f, "{}", self.start)
+        } else {
+            write!(f, "{}..{}", self.start, self.end)
+        }
+    }
+}
+
+impl std::str::FromStr for DateRange {
+    type Err = crate::prelude::Error;
+
+    /// Parses a range in the format "YYYY-MM-DD..YYYY-MM-DD", both dates
+    /// inclusive, or a single date "YYYY-MM-DD" for a range of one day.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let range: DateRange = "2025-07-01..2025-07-18".parse().unwrap();
+    /// assert_eq!(range.to_string(), "2025-07-01..2025-07-18");
+    /// let day: DateRange = "2025-07-04".parse().unwrap();
+    /// assert_eq!(day.start(), day.end());
+    /// assert!("2025-07-18..2025-07-01".parse::<DateRange>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().split_once("..") {
+            Some((start, end)) => Self::new(start.trim().parse()?, end.trim().parse()?),
+            None => Self::day(s.trim().parse()?),
+        }
+    }
+}
+
+impl HasSample for DateRange {
+    fn sample() -> Self {
+        Self::from_str("2025-05-12..2025-05-16").expect("Valid range")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn test_parse_range_with_invalid_date() {
+        assert!(DateRange::from_str("2025-02-27..2025-02-30").is_err());
+    }
+
+    #[test]
+    fn test_serde_roundtrip() {
+        let range = DateRange::sample();
+        let ron = ron::to_string(&range).unwrap();
+        assert_eq!(ron, "\"2025-05-12..2025-05-16\"");
+        assert_eq!(ron::from_str::<DateRange>(&ron).unwrap(), range);
+    }
+
+    #[test]
+    fn test_ranges_of_merges_consecutive_dates() {
+        let dates = ["2025-07-04", "2025-06-30", "2025-07-01", "2025-07-02"]
+            .into_iter()
+            .map(|date| Date::from_str(date).unwrap());
+        let ranges = DateRange::ranges_of(dates)
+            .into_iter()
+            .map(|range| range.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(ranges, vec!["2025-06-30..2025-07-02", "2025-07-04"]);
+    }
+}
@@ -14,18 +14,15 @@ pub fn type_name<T>() -> String {
     std::any::type_name::<T>().to_string()
 }
 
-/// Tries to load the contents of a file at the given path and deserialize it from RON format into the specified type.
+/// Tries to load the contents of a file at the given path, decrypting it if
+/// it is encrypted, and deserialize it from RON format into the specified type.
 ///
 /// # Throws
 /// - `Error::FileNotFound` if the file does not exist or cannot be read
+/// - `Error::WrongDataPassphrase` if the file is encrypted with another passphrase
 /// - `Error::Deserialize` if the contents cannot be deserialized into the specified type
 pub fn deserialize_contents_of_ron<T: DeserializeOwned>(path: impl AsRef<Path>) -> Result<T> {
-    use std::fs;
-    let path = path.as_ref();
-    let ron_str = fs::read_to_string(path).map_err(|e| Error::FileNotFound {
-        path: path.display().to_string(),
-        underlying: format!("{:?}", e),
-    })?;
+    let ron_str = read_data_file(path)?;
     deserialize_ron_str(&ron_str)
 }
 
@@ -100,15 +97,20 @@ mod tests {
             path,
             r#"
             ServiceFees(
-                name: "Agreed Consulting Service",
-            	unit_price: UnitPrice(350.0)
+                services: [
+                    ServiceFee(
+                        name: "Agreed Consulting Service",
+                    	unit_price: UnitPrice(350.0),
+                        quantity: WorkedDays,
+                    ),
+                ],
             )"#,
         )
         .unwrap();
 
         let result: Result<ServiceFees> = deserialize_contents_of_ron(path);
         assert!(result.is_ok(), "Expected success, got: {:?}", result);
-        let consulting_service = result.unwrap();
+        let consulting_service = result.unwrap()[0].clone();
         assert_eq!(consulting_service.name(), "Agreed Consulting Service");
         assert_eq!(
             *consulting_service.unit_price(),
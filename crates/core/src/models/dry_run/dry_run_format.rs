@@ -0,0 +1,11 @@
+use crate::prelude::*;
+
+/// The format an [`InvoiceDryRun`] is printed in.
+#[derive(Clone, Copy, Debug, Display, Default, PartialEq, Eq, EnumIter)]
+pub enum DryRunFormat {
+    /// A human readable table.
+    #[default]
+    Table,
+    /// JSON, e.g. for scripting.
+    Json,
+}
@@ -0,0 +1,221 @@
+use crate::prelude::*;
+
+/// Everything klirr computes for an invoice, without rendering it, used to
+/// check e.g. days off and expenses before generating the PDF.
+#[derive(Clone, Debug, Serialize, TypedBuilder, Getters)]
+pub struct InvoiceDryRun {
+    /// The number of the invoice, e.g. `243`.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    number: InvoiceNumber,
+
+    /// The number as printed on the invoice, e.g. `"2025-043"`.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    identifier: InvoiceIdentifier,
+
+    /// Whether the invoice is for services or expenses.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    kind: InvoiceKind,
+
+    /// The date of the invoice, e.g. `2025-05-31`.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    invoice_date: Date,
+
+    /// When the payment is due, e.g. `2025-06-30`.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    due_date: Date,
+
+    /// The currency of the invoice, e.g. `EUR`.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    currency: Currency,
+
+    /// The number of worked days invoiced, `None` for expenses invoices.
+    #[builder(setter(into), default)]
+    #[getset(get = "pub")]
+    worked_days: Option<Quantity>,
+
+    /// The line items, with their total cost in the currency of the invoice.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    line_items: Vec<ItemConvertedIntoTargetCurrency>,
+
+    /// The discounts, surcharges and credits applied to the line items.
+    #[builder(setter(into), default)]
+    #[getset(get = "pub")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    adjustments: Vec<AppliedAdjustment>,
+
+    /// The exchange rates used to convert the line items into the currency
+    /// of the invoice, keyed by the currency the items were paid in.
+    #[builder(setter(into), default)]
+    #[getset(get = "pub")]
+    exchange_rates: ExchangeRatesMap,
+
+    /// The VAT rate charged on the line items, e.g. `25%`.
+    #[builder(setter(into), default)]
+    #[getset(get = "pub")]
+    vat_rate: Percentage,
+
+    /// The net amount, VAT and gross amount of the invoice.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    totals: InvoiceTotals,
+}
+
+impl InvoiceDryRun {
+    /// Creates a dry run of the invoice `data`, which was prepared using
+    /// `exchange_rates`. Only the rates of currencies other than the
+    /// currency of the invoice are kept.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let data = PreparedData::sample();
+    /// let dry_run = InvoiceDryRun::new(&data, ExchangeRates::hard_coded());
+    /// assert_eq!(dry_run.totals(), &data.totals());
+    /// ```
+    pub fn new(data: &PreparedData, exchange_rates: ExchangeRates) -> Self {
+        let issued = IssuedInvoice::from(data);
+        let line_items = data.line_items().items().clone();
+        let worked_days = (!data.line_items().is_expenses())
+            .then(|| line_items.first().map(|item| *item.quantity()))
+            .flatten();
+        let exchange_rates = exchange_rates
+            .rates()
+            .iter()
+            .filter(|(currency, _)| *currency != issued.currency())
+            .map(|(currency, rate)| (*currency, *rate))
+            .collect::<ExchangeRatesMap>();
+        Self::builder()
+            .number(*issued.number())
+            .identifier(issued.identifier())
+            .kind(*issued.kind())
+            .invoice_date(*issued.invoice_date())
+            .due_date(*issued.due_date())
+            .currency(*issued.currency())
+            .worked_days(worked_days)
+            .line_items(line_items)
+            .adjustments(data.line_items().adjustments().clone())
+            .exchange_rates(exchange_rates)
+            .vat_rate(*data.information().vat_rate())
+            .totals(data.totals())
+            .build()
+    }
+
+    /// Formats the dry run in `format`.
+    ///
+    /// # Errors
+    /// Returns an error if the dry run cannot be serialized into JSON.
+    pub fn format(&self, format: DryRunFormat) -> Result<String> {
+        match format {
+            DryRunFormat::Table => Ok(self.to_string()),
+            DryRunFormat::Json => {
+                serde_json::to_string_pretty(self).map_err(|e| Error::FailedToSerializeReport {
+                    format: DryRunFormat::Json.to_string(),
+                    underlying: e.to_string(),
+                })
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for InvoiceDryRun {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let amount = |cost: &Cost| format!("{:.2} {}", **cost, self.currency);
+        writeln!(f, "Dry run of {} invoice {}", self.kind, self.identifier)?;
+        writeln!(f, "{:<14} {}", "Invoice date:", self.invoice_date)?;
+        writeln!(f, "{:<14} {}", "Due date:", self.due_date)?;
+        if let Some(worked_days) = &self.worked_days {
+            writeln!(f, "{:<14} {}", "Worked days:", worked_days)?;
+        }
+        writeln!(f)?;
+        writeln!(
+            f,
+            "{:<24} {:<10} {:>8} {:>16} {:>16}",
+            "Item", "Date", "Quantity", "Unit price", "Total"
+        )?;
+        for item in &self.line_items {
+            let name = if *item.recurring() {
+                format!("{} (recurring)", item.name())
+            } else {
+                item.name().clone()
+            };
+            writeln!(
+                f,
+                "{:<24} {:<10} {:>8} {:>16} {:>16}",
+                name,
+                item.transaction_date(),
+                item.quantity(),
+                format!("{:.2} {}", **item.unit_price(), item.currency()),
+                amount(item.total_cost()),
+            )?;
+        }
+        if !self.exchange_rates.is_empty() {
+            writeln!(f)?;
+            writeln!(f, "Exchange rates:")?;
+            for (currency, rate) in &self.exchange_rates {
+                writeln!(f, "  1 {} = {} {}", currency, rate, self.currency)?;
+            }
+        }
+        writeln!(f)?;
+        for adjustment in &self.adjustments {
+            let mut label = adjustment.kind().to_string();
+            if let Some(percentage) = adjustment.percentage() {
+                label = format!("{} {}", label, percentage);
+            }
+            if let Some(description) = adjustment.description() {
+                label = format!("{} ({})", label, description);
+            }
+            writeln!(f, "{:<24} {:>16}", label, amount(adjustment.amount()))?;
+        }
+        writeln!(f, "{:<24} {:>16}", "Net", amount(self.totals.net()))?;
+        writeln!(
+            f,
+            "{:<24} {:>16}",
+            format!("VAT {}", self.vat_rate),
+            amount(self.totals.vat())
+        )?;
+        writeln!(f, "{:<24} {:>16}", "Gross", amount(self.totals.gross()))
+    }
+}
+
+impl HasSample for InvoiceDryRun {
+    fn sample() -> Self {
+        Self::new(&PreparedData::sample(), ExchangeRates::hard_coded())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use insta::assert_snapshot;
+    use test_log::test;
+
+    #[test]
+    fn test_worked_days_of_services() {
+        let sut = InvoiceDryRun::sample();
+        assert_eq!(
+            sut.worked_days(),
+            &Some(*ItemConvertedIntoTargetCurrency::sample().quantity())
+        );
+    }
+
+    #[test]
+    fn test_display_table() {
+        assert_snapshot!(InvoiceDryRun::sample().format(DryRunFormat::Table).unwrap());
+    }
+
+    #[test]
+    fn test_format_json() {
+        let json = InvoiceDryRun::sample().format(DryRunFormat::Json).unwrap();
+        let value = serde_json::from_str::<serde_json::Value>(&json).unwrap();
+        assert!(value["totals"]["gross"].is_number());
+        assert!(value["line_items"].is_array());
+    }
+}
@@ -0,0 +1,5 @@
+mod dry_run_format;
+mod invoice_dry_run;
+
+pub use dry_run_format::*;
+pub use invoice_dry_run::*;
@@ -0,0 +1,72 @@
+use crate::prelude::*;
+
+/// A valid email address, e.g. `"billing@holmes.example"`.
+#[derive(Clone, Debug, Display, PartialEq, Eq, Hash, SerializeDisplay, DeserializeFromStr)]
+#[display("{}", _0)]
+pub struct EmailAddress(lettre::Address);
+
+impl FromStr for EmailAddress {
+    type Err = crate::prelude::Error;
+
+    /// Parses an email address, e.g. `"billing@holmes.example"`.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// assert!(EmailAddress::from_str("billing@holmes.example").is_ok());
+    /// assert!(EmailAddress::from_str("holmes.example").is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self> {
+        lettre::Address::from_str(s.trim())
+            .map(Self)
+            .map_err(|e| Error::InvalidEmailAddress {
+                invalid: s.to_owned(),
+                underlying: e.to_string(),
+            })
+    }
+}
+
+impl From<EmailAddress> for lettre::Address {
+    fn from(address: EmailAddress) -> Self {
+        address.0
+    }
+}
+
+impl HasSample for EmailAddress {
+    fn sample() -> Self {
+        Self::sample_client()
+    }
+}
+
+impl EmailAddress {
+    pub fn sample_client() -> Self {
+        Self::from_str("billing@holmes.example").expect("Valid email address")
+    }
+
+    pub fn sample_vendor() -> Self {
+        Self::from_str("invoices@lupin.example").expect("Valid email address")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn test_roundtrip_ron() {
+        let sut = EmailAddress::sample();
+        let ron = ron::to_string(&sut).unwrap();
+        assert_eq!(ron, "\"billing@holmes.example\"");
+        assert_eq!(ron::from_str::<EmailAddress>(&ron).unwrap(), sut);
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(matches!(
+            EmailAddress::from_str("not an email"),
+            Err(Error::InvalidEmailAddress { .. })
+        ));
+    }
+}
@@ -0,0 +1,11 @@
+use crate::prelude::*;
+
+/// What to do with the email of a generated invoice.
+#[derive(Clone, Copy, Debug, Display, Default, PartialEq, Eq, EnumIter)]
+pub enum EmailDelivery {
+    /// Only save the email as an `.eml` draft next to the PDF.
+    #[default]
+    Draft,
+    /// Save the draft and send it using the SMTP server in `smtp.ron`.
+    Send,
+}
@@ -0,0 +1,178 @@
+use crate::prelude::*;
+use lettre::{
+    Message,
+    message::{Attachment, Mailbox, MultiPart, SinglePart, header::ContentType},
+};
+
+/// The email sending an invoice to the client, with a localized subject and
+/// body, to which the PDF is attached.
+#[derive(Clone, Debug, PartialEq, Eq, TypedBuilder, Getters)]
+pub struct InvoiceEmail {
+    /// The sender, the first billing email of the vendor.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    from: EmailAddress,
+
+    /// The recipients, the billing emails of the client.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    to: Vec<EmailAddress>,
+
+    /// E.g. `"Invoice 243 – May 2025"`.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    subject: String,
+
+    /// The text of the email.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    body: String,
+}
+
+impl InvoiceEmail {
+    /// Creates the email for the invoice `data`, filling in the subject and
+    /// body templates of `l18n`.
+    ///
+    /// # Errors
+    /// Returns an error if the client or the vendor has no billing email.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let email = InvoiceEmail::new(&PreparedData::sample(), &L18nContent::english()).unwrap();
+    /// assert_eq!(email.subject(), "Invoice 9876 – May 2025");
+    /// ```
+    pub fn new(data: &PreparedData, l18n: &L18nContent) -> Result<Self> {
+        let missing = |company: &CompanyInformation| Error::MissingBillingEmail {
+            company: company.company_name().clone(),
+        };
+        let from = data
+            .vendor()
+            .billing_emails()
+            .first()
+            .cloned()
+            .ok_or_else(|| missing(data.vendor()))?;
+        let to = data.client().billing_emails().clone();
+        if to.is_empty() {
+            return Err(missing(data.client()));
+        }
+        let information = data.information();
+        let invoice_date = information.invoice_date();
+        let period = format!(
+            "{} {}",
+            l18n.month_names()[**invoice_date.month() as usize - 1],
+            invoice_date.year()
+        );
+        let total = format!(
+            "{:.2} {}",
+            **data.totals().gross(),
+            data.payment_info().currency()
+        );
+        let vendor = data.vendor();
+        let signature = match vendor.contact_person() {
+            Some(contact_person) => format!("{}\n{}", contact_person, vendor.company_name()),
+            None => vendor.company_name().clone(),
+        };
+        let fill = |template: &str| {
+            template
+                .replace("{number}", &information.identifier().to_string())
+                .replace("{period}", &period)
+                .replace("{total}", &total)
+                .replace("{due_date}", &information.due_date().to_string())
+                .replace("{signature}", &signature)
+        };
+        Ok(Self::builder()
+            .from(from)
+            .to(to)
+            .subject(fill(l18n.email().subject()))
+            .body(fill(l18n.email().body()))
+            .build())
+    }
+
+    /// Creates the RFC 5322 message of this email, with `pdf` attached as
+    /// `file_name`.
+    ///
+    /// # Errors
+    /// Returns an error if the message cannot be built.
+    pub fn message(&self, file_name: impl Into<String>, pdf: &Pdf) -> Result<Message> {
+        let mut builder = Message::builder()
+            .from(Mailbox::new(None, self.from.clone().into()))
+            .subject(self.subject.clone());
+        for to in &self.to {
+            builder = builder.to(Mailbox::new(None, to.clone().into()));
+        }
+        let content_type = ContentType::parse("application/pdf").expect("Valid MIME type for PDF");
+        builder
+            .multipart(
+                MultiPart::mixed()
+                    .singlepart(SinglePart::plain(self.body.clone()))
+                    .singlepart(
+                        Attachment::new(file_name.into()).body(pdf.as_ref().clone(), content_type),
+                    ),
+            )
+            .map_err(|e| Error::FailedToCreateEmail {
+                underlying: e.to_string(),
+            })
+    }
+
+    /// Returns the RFC 5322 formatted bytes of this email, with `pdf`
+    /// attached as `file_name`, e.g. to save as an `.eml` file.
+    ///
+    /// # Errors
+    /// Returns an error if the message cannot be built.
+    pub fn to_eml(&self, file_name: impl Into<String>, pdf: &Pdf) -> Result<Vec<u8>> {
+        self.message(file_name, pdf)
+            .map(|message| message.formatted())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    fn data(client: CompanyInformation) -> PreparedData {
+        PreparedData::builder()
+            .information(InvoiceInfoFull::sample())
+            .vendor(CompanyInformation::sample_vendor())
+            .client(client)
+            .line_items(LineItemsFlat::sample())
+            .payment_info(PaymentInformation::sample())
+            .output_path(OutputPath::Name("invoice.pdf".into()))
+            .build()
+    }
+
+    #[test]
+    fn test_localized_subject_and_body() {
+        let data = data(CompanyInformation::sample_client());
+        let sut = InvoiceEmail::new(&data, &L18nContent::swedish()).unwrap();
+        assert_eq!(sut.subject(), "Faktura 9876 – Maj 2025");
+        assert!(sut.body().contains("Arsène Lupin\nLupin et Associés"));
+        assert_eq!(sut.from(), &EmailAddress::sample_vendor());
+        assert_eq!(sut.to(), &vec![EmailAddress::sample_client()]);
+    }
+
+    #[test]
+    fn test_missing_client_billing_email() {
+        let data = data(CompanyInformation::sample_client().with_billing_emails(Vec::new()));
+        assert!(matches!(
+            InvoiceEmail::new(&data, &L18nContent::english()),
+            Err(Error::MissingBillingEmail { company }) if company == "Holmes Ltd"
+        ));
+    }
+
+    #[test]
+    fn test_eml_has_pdf_attachment() {
+        let data = data(CompanyInformation::sample_client());
+        let sut = InvoiceEmail::new(&data, &L18nContent::english()).unwrap();
+        let eml = sut
+            .to_eml("invoice.pdf", &Pdf::from(Vec::from(b"%PDF-1.4")))
+            .unwrap();
+        let eml = String::from_utf8(eml).unwrap();
+        assert!(eml.contains("To: billing@holmes.example"));
+        assert!(eml.contains("From: invoices@lupin.example"));
+        assert!(eml.contains("Content-Type: application/pdf"));
+        assert!(eml.contains("filename=\"invoice.pdf\""));
+    }
+}
@@ -0,0 +1,9 @@
+mod email_address;
+mod email_delivery;
+mod invoice_email;
+mod smtp_settings;
+
+pub use email_address::*;
+pub use email_delivery::*;
+pub use invoice_email::*;
+pub use smtp_settings::*;
@@ -0,0 +1,72 @@
+use crate::prelude::*;
+
+/// The name of the environment variable holding the SMTP password, which is
+/// not saved in the data directory.
+pub const ENV_VAR_SMTP_PASSWORD: &str = "KLIRR_SMTP_PASSWORD";
+
+/// How the connection to the SMTP server is encrypted.
+#[derive(Clone, Copy, Debug, Display, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SmtpEncryption {
+    /// Unencrypted, only suitable for a local server.
+    None,
+    /// Upgrades the connection using `STARTTLS`, typically on port 587.
+    #[default]
+    StartTls,
+    /// Implicit TLS, typically on port 465.
+    Tls,
+}
+
+/// The SMTP server used to send invoices, saved in `smtp.ron`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, TypedBuilder, Getters)]
+pub struct SmtpSettings {
+    /// The host name of the SMTP server, e.g. `"smtp.example.com"`.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    host: String,
+
+    /// The port of the SMTP server, e.g. `587`.
+    #[builder(default = 587)]
+    #[getset(get = "pub")]
+    #[serde(default = "default_port")]
+    port: u16,
+
+    /// The user name to log in with, if the server requires it, the
+    /// password is read from the environment variable `KLIRR_SMTP_PASSWORD`.
+    #[builder(setter(into, strip_option), default)]
+    #[getset(get = "pub")]
+    #[serde(default)]
+    username: Option<String>,
+
+    /// How the connection is encrypted.
+    #[builder(default)]
+    #[getset(get = "pub")]
+    #[serde(default)]
+    encryption: SmtpEncryption,
+}
+
+fn default_port() -> u16 {
+    587
+}
+
+impl HasSample for SmtpSettings {
+    fn sample() -> Self {
+        Self::builder()
+            .host("smtp.lupin.example")
+            .username("arsene")
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn test_defaults() {
+        let sut: SmtpSettings = ron::from_str(r#"(host: "localhost")"#).unwrap();
+        assert_eq!(*sut.port(), 587);
+        assert_eq!(*sut.encryption(), SmtpEncryption::StartTls);
+        assert_eq!(*sut.username(), None);
+    }
+}
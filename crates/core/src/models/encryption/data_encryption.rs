@@ -0,0 +1,23 @@
+use crate::prelude::*;
+
+/// The settings of an encrypted data directory, saved unencrypted in its
+/// `encryption.ron`, the presence of which makes klirr encrypt every data
+/// file it writes in the directory.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, TypedBuilder, Getters)]
+pub struct DataEncryption {
+    /// The base 2 logarithm of the scrypt cost parameter `N` used to derive
+    /// the key from the passphrase.
+    #[getset(get = "pub")]
+    log_n: u8,
+
+    /// The hex encoded salt used to derive the key from the passphrase.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    salt: String,
+
+    /// A known text encrypted with the key, used to tell if a passphrase is
+    /// correct before any file is written with it.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    verifier: String,
+}
@@ -0,0 +1,135 @@
+use crate::prelude::*;
+
+/// The prefix of the contents of every encrypted data file, followed by the
+/// parameters needed to decrypt it.
+pub const ENCRYPTED_CONTENTS_PREFIX: &str = "klirr-encrypted-v1";
+
+/// The contents of an encrypted data file, which is text on the format
+/// `klirr-encrypted-v1:<log_n>:<salt>:<nonce>:<ciphertext>`, all but `log_n`
+/// hex encoded, so that a file can be decrypted with only the passphrase.
+#[derive(Clone, Debug, PartialEq, Eq, TypedBuilder, Getters)]
+pub struct EncryptedContents {
+    /// The base 2 logarithm of the scrypt cost parameter `N` used to derive
+    /// the key from the passphrase.
+    #[getset(get = "pub")]
+    log_n: u8,
+
+    /// The salt used to derive the key from the passphrase.
+    #[getset(get = "pub")]
+    salt: Vec<u8>,
+
+    /// The XChaCha20-Poly1305 nonce, unique for every encryption.
+    #[getset(get = "pub")]
+    nonce: Vec<u8>,
+
+    /// The encrypted contents followed by the authentication tag.
+    #[getset(get = "pub")]
+    ciphertext: Vec<u8>,
+}
+
+impl EncryptedContents {
+    /// Returns `true` if `contents` of a data file are encrypted.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// assert!(EncryptedContents::is_encrypted("klirr-encrypted-v1:15:00:00:00"));
+    /// assert!(!EncryptedContents::is_encrypted("CompanyInformation("));
+    /// ```
+    pub fn is_encrypted(contents: &str) -> bool {
+        contents.starts_with(ENCRYPTED_CONTENTS_PREFIX)
+    }
+}
+
+/// Encodes `bytes` as lowercase hex.
+pub fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Decodes the hex string `hex`, returning `None` if it is not valid hex.
+pub fn from_hex(hex: &str) -> Option<Vec<u8>> {
+    hex.as_bytes()
+        .chunks(2)
+        .map(|pair| {
+            let pair = std::str::from_utf8(pair)
+                .ok()
+                .filter(|pair| pair.len() == 2)?;
+            u8::from_str_radix(pair, 16).ok()
+        })
+        .collect()
+}
+
+impl std::fmt::Display for EncryptedContents {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{}:{}:{}:{}:{}",
+            ENCRYPTED_CONTENTS_PREFIX,
+            self.log_n,
+            to_hex(&self.salt),
+            to_hex(&self.nonce),
+            to_hex(&self.ciphertext)
+        )
+    }
+}
+
+impl FromStr for EncryptedContents {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || Error::InvalidEncryptedContents;
+        let mut parts = s.trim().split(':');
+        if parts.next() != Some(ENCRYPTED_CONTENTS_PREFIX) {
+            return Err(invalid());
+        }
+        let log_n = parts
+            .next()
+            .and_then(|log_n| log_n.parse().ok())
+            .ok_or_else(invalid)?;
+        let mut next_bytes = || parts.next().and_then(from_hex).ok_or_else(invalid);
+        let salt = next_bytes()?;
+        let nonce = next_bytes()?;
+        let ciphertext = next_bytes()?;
+        if parts.next().is_some() {
+            return Err(invalid());
+        }
+        Ok(Self::builder()
+            .log_n(log_n)
+            .salt(salt)
+            .nonce(nonce)
+            .ciphertext(ciphertext)
+            .build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn test_roundtrip() {
+        let contents = EncryptedContents::builder()
+            .log_n(15)
+            .salt(vec![0x01, 0xab])
+            .nonce(vec![0xff])
+            .ciphertext(vec![0x00, 0x10])
+            .build();
+        let text = contents.to_string();
+        assert_eq!(text, "klirr-encrypted-v1:15:01ab:ff:0010\n");
+        assert_eq!(EncryptedContents::from_str(&text).unwrap(), contents);
+    }
+
+    #[test]
+    fn test_invalid() {
+        for text in [
+            "CompanyInformation()",
+            "klirr-encrypted-v1:15:01ab:ff",
+            "klirr-encrypted-v1:15:01ab:ff:0g",
+            "klirr-encrypted-v1:15:01ab:ff:00:00",
+        ] {
+            assert!(EncryptedContents::from_str(text).is_err(), "{}", text);
+        }
+    }
+}
@@ -0,0 +1,5 @@
+mod data_encryption;
+mod encrypted_contents;
+
+pub use data_encryption::*;
+pub use encrypted_contents::*;
@@ -53,6 +53,31 @@ pub enum Error {
     #[error("Failed to build ServiceFees from Terminal UI input, because: {reason}")]
     InvalidServiceFees { reason: String },
 
+    /// At least one service must be billed on services invoices.
+    #[error("Service fees must contain at least one service")]
+    NoServiceFees,
+
+    /// The names of the services must be unique, since they identify the
+    /// service when specifying its quantity.
+    #[error("Found more than one service named '{name}' in the service fees")]
+    DuplicateServiceFee { name: String },
+
+    /// A service whose quantity is specified per run was invoiced without
+    /// specifying its quantity.
+    #[error("The quantity of the service '{name}' must be specified, e.g. `--quantity '{name}=2'`")]
+    MissingServiceQuantity { name: String },
+
+    /// A quantity was specified for a service which is not among the service
+    /// fees, or whose quantity is not specified per run.
+    #[error(
+        "Cannot specify the quantity of '{name}', it is not a service with a quantity specified per run"
+    )]
+    UnexpectedServiceQuantity { name: String },
+
+    /// Failed to parse the quantity of a service, e.g. `"On-call=2"`.
+    #[error("Invalid quantity of service: '{invalid_string}', expected e.g. 'On-call=2'")]
+    InvalidServiceQuantity { invalid_string: String },
+
     /// The offset month must not be in the record of months off.
     #[error("Offset month must not be in the record of months off: {offset_month}")]
     OffsetMonthMustNotBeInRecordOfMonthsOff { offset_month: YearAndMonth },
@@ -72,10 +97,95 @@ pub enum Error {
     )]
     TargetMonthMustHaveExpenses { target_month: YearAndMonth },
 
+    /// There is no expense at the index of the month.
+    #[error("No expense at index {index} of {month}, which has {count} expenses")]
+    ExpenseIndexOutOfBounds {
+        month: YearAndMonth,
+        index: usize,
+        count: usize,
+    },
+
+    /// Failed to parse expenses to import.
+    #[error("Failed to parse expenses to import, because: {underlying}")]
+    FailedToParseExpenses { underlying: String },
+
     /// Failed to parse year
     #[error("Failed to parse year: {invalid_string}")]
     FailedToParseYear { invalid_string: String },
 
+    /// The data was written by a newer version of klirr, with a schema this
+    /// version cannot read.
+    #[error(
+        "The data is at schema {found}, but this version of klirr only supports up to {supported}, please upgrade klirr"
+    )]
+    DataSchemaTooNew {
+        found: SchemaVersion,
+        supported: SchemaVersion,
+    },
+
+    /// The data directory is locked by another running klirr, which did not
+    /// release the lock in time.
+    #[error(
+        "The data directory is in use by another running klirr, try again when it has finished (lock file: {path})"
+    )]
+    DataDirectoryLocked { path: String },
+
+    /// A data file was changed by a concurrently running klirr while it was
+    /// being edited.
+    #[error(
+        "The data in '{file_name}.ron' was changed by another running klirr while you were editing it, edit it again."
+    )]
+    DataChangedMeanwhile { file_name: String },
+
+    /// The data directory is encrypted but no passphrase was given.
+    #[error(
+        "The data is encrypted, set the passphrase in the environment variable {env_var} or run klirr in a terminal to be asked for it"
+    )]
+    MissingDataPassphrase { env_var: String },
+
+    /// The passphrase does not decrypt the data.
+    #[error("Wrong passphrase, the data could not be decrypted")]
+    WrongDataPassphrase,
+
+    /// A data file in an encrypted data directory is not encrypted, e.g. if
+    /// it was copied there or edited by hand.
+    #[error(
+        "The data file {path} is not encrypted, though the data is, refusing to read it. Run `klirr data decrypt` and `klirr data encrypt` to encrypt it, or remove it"
+    )]
+    UnencryptedDataFile { path: String },
+
+    /// The contents of an encrypted data file are malformed.
+    #[error("The encrypted data file is malformed")]
+    InvalidEncryptedContents,
+
+    /// Failed to derive a key or to encrypt data.
+    #[error("Failed to encrypt data, because: {underlying}")]
+    FailedToEncryptData { underlying: String },
+
+    /// The data directory is already encrypted.
+    #[error("The data is already encrypted")]
+    DataAlreadyEncrypted,
+
+    /// The data directory is not encrypted.
+    #[error("The data is not encrypted")]
+    DataNotEncrypted,
+
+    /// No field of the data at the dotted path, or the value is invalid.
+    #[error("Cannot set data at '{path}', because: {reason}")]
+    InvalidDataPath { path: String, reason: String },
+
+    /// The data file is neither JSON nor RON.
+    #[error("Unsupported data file: {path}, expected a '.json' or '.ron' file")]
+    UnsupportedDataFileFormat { path: String },
+
+    /// There is no revision of the data to undo or compare with.
+    #[error("No history of the data recorded, nothing to undo")]
+    NoDataHistory,
+
+    /// No revision of the data with this number is recorded.
+    #[error("Unknown data revision #{revision}, see `klirr data history`")]
+    UnknownDataRevision { revision: u32 },
+
     /// Failed to load file
     #[error("Failed to load file: {path}, underlying: {underlying}")]
     FileNotFound { path: String, underlying: String },
@@ -118,6 +228,42 @@ pub enum Error {
         reason: String,
     },
 
+    /// Failed to parse a recurring expense from a string, e.g. when the format is incorrect.
+    #[error("Failed to parse recurring expense from: '{invalid_string}': {reason}")]
+    InvalidRecurringExpense {
+        invalid_string: String,
+        reason: String,
+    },
+
+    /// There is no recurring expense with the name.
+    #[error("No recurring expense named '{name}'")]
+    UnknownRecurringExpense { name: String },
+
+    /// Failed to parse the template of invoice numbers.
+    #[error("Invalid invoice number format: '{invalid_string}', {reason}")]
+    InvalidInvoiceNumberFormat {
+        invalid_string: String,
+        reason: String,
+    },
+
+    /// Invoices numbered sequentially must not have gaps in their numbers.
+    #[error("Invoice numbers are missing from the ledger of issued invoices: {missing}")]
+    InvoiceNumberGaps { missing: String },
+
+    /// The month to remove is not in the record of months off.
+    #[error("Month {month} is not in the record of months off")]
+    MonthNotInRecordOfMonthsOff { month: YearAndMonth },
+
+    /// Failed to parse a range of dates, e.g. when the end is before the start.
+    #[error(
+        "Invalid date range: {invalid_string}, expected 'YYYY-MM-DD..YYYY-MM-DD' ending on or after its start"
+    )]
+    InvalidDateRange { invalid_string: String },
+
+    /// None of the days to remove are in the record of days off.
+    #[error("No days of {range} are in the record of days off")]
+    DaysOffNotInRecord { range: DateRange },
+
     /// The target month is in the record of months off, but it must not be.
     #[error("Target month {target_month} is in the record of months off, but it must not be.")]
     TargetMonthMustNotBeInRecordOfMonthsOff { target_month: YearAndMonth },
@@ -173,4 +319,173 @@ pub enum Error {
     /// Error when parsing the response from the exchange rate API.
     #[error("Failed to parse exchange rate response, because: {underlying}")]
     ParseError { underlying: String },
+
+    /// Failed to parse a percentage from a string, e.g. `"8%"`.
+    #[error("Failed to parse percentage from string: {invalid_string}")]
+    InvalidPercentage { invalid_string: String },
+
+    /// Failed to parse the amount of an adjustment, e.g. `"10%"` or `"500"`.
+    #[error(
+        "Invalid adjustment amount: '{invalid_string}', expected a positive percentage like '10%' or amount like '500'"
+    )]
+    InvalidAdjustmentAmount { invalid_string: String },
+
+    /// The invoice was not found in the ledger of issued invoices.
+    #[error(
+        "Invoice {number} was not found in the ledger of issued invoices, only invoices generated with `klirr invoice` are recorded."
+    )]
+    InvoiceNotFoundInLedger { number: InvoiceNumber },
+
+    /// Several issued invoices have the same number, e.g. an expenses and a
+    /// services invoice, and which one is meant was not specified.
+    #[error("Several invoices have number {number}, specify which one with `--kind`.")]
+    AmbiguousInvoiceNumber { number: InvoiceNumber },
+
+    /// The sequential number allocated for an invoice was taken by an invoice
+    /// issued meanwhile by a concurrently running klirr.
+    #[error(
+        "Invoice number {number} was taken by an invoice issued meanwhile by another running klirr, create the invoice again to get the next number."
+    )]
+    InvoiceNumberTaken { number: InvoiceNumber },
+
+    /// A month is invoiced either with one combined invoice or with separate
+    /// services and expenses invoices, never both, which would bill the same
+    /// work twice and give two invoices the same number.
+    #[error(
+        "Cannot create a {kind} invoice for {month}, a {issued} invoice has already been issued for it. A month is invoiced either combined or with separate services and expenses invoices."
+    )]
+    CombinedAndSeparateInvoices {
+        month: YearAndMonth,
+        kind: InvoiceKind,
+        issued: InvoiceKind,
+    },
+
+    /// Cannot send a reminder for an invoice which is not yet overdue.
+    #[error("Invoice {number} is due {due_date}, it is not overdue on {reminder_date}.")]
+    InvoiceIsNotOverdue {
+        number: InvoiceNumber,
+        due_date: Date,
+        reminder_date: Date,
+    },
+
+    /// Cannot send a reminder for an invoice which has been paid in full.
+    #[error("Invoice {number} has been paid in full.")]
+    InvoiceAlreadyPaid { number: InvoiceNumber },
+
+    /// The amount of a payment must be positive.
+    #[error("Payment amount must be positive, but got: {amount}")]
+    NonPositivePaymentAmount { amount: Cost },
+
+    /// The format of a bank statement could not be inferred from its path.
+    #[error(
+        "Unknown bank statement format of '{path}', expected a `.xml` (camt.053) or `.csv` file"
+    )]
+    UnknownBankStatementFormat { path: String },
+
+    /// Failed to parse a bank statement.
+    #[error("Failed to parse {format} bank statement, because: {underlying}")]
+    FailedToParseBankStatement { format: String, underlying: String },
+
+    /// A quarter must be between 1 and 4.
+    #[error("Invalid quarter: {quarter}, must be between 1 and 4")]
+    InvalidQuarter { quarter: u8 },
+
+    /// Failed to serialize a report.
+    #[error("Failed to serialize report into {format}, because: {underlying}")]
+    FailedToSerializeReport { format: String, underlying: String },
+
+    /// The last month of a range of months is before the first month.
+    #[error("Invalid range of months, from: {from}, is after to: {to}")]
+    InvalidMonthRange { from: String, to: String },
+
+    /// A batch cannot create both combined and separate invoices, see
+    /// [`Error::CombinedAndSeparateInvoices`].
+    #[error(
+        "Cannot create combined invoices together with services or expenses invoices, a month is invoiced either combined or with separate services and expenses invoices."
+    )]
+    CombinedAndSeparateKindsInBatch,
+
+    /// Failed to render a preview image of an invoice.
+    #[error("Failed to render preview, because: {underlying}")]
+    FailedToRenderPreview { underlying: String },
+
+    /// Error when saving a preview image to a file.
+    #[error("Failed to save preview, because: {underlying}")]
+    SavePreview { underlying: String },
+
+    /// Previews can only be rendered of invoices, not of e.g. reminders.
+    #[error("Previews are only supported for invoices, not for: {items}")]
+    PreviewNotSupported { items: String },
+
+    /// Dry runs can only be made of invoices, not of e.g. reminders.
+    #[error("Dry runs are only supported for invoices, not for: {items}")]
+    DryRunNotSupported { items: String },
+
+    /// Only invoices can be emailed, not e.g. reminders.
+    #[error("Emails are only supported for invoices, not for: {items}")]
+    EmailNotSupported { items: String },
+
+    /// Quantities of services and adjustments can only be given for
+    /// invoices, not for e.g. reminders.
+    #[error(
+        "Service quantities, discounts, surcharges and credits are only supported for invoices, not for: {items}"
+    )]
+    QuantitiesAndAdjustmentsNotSupported { items: String },
+
+    /// An email address could not be parsed.
+    #[error("Invalid email address: '{invalid}', because: {underlying}")]
+    InvalidEmailAddress { invalid: String, underlying: String },
+
+    /// An IBAN has the wrong format or check digits.
+    #[error("Invalid IBAN: '{invalid}', because: {reason}")]
+    InvalidIban { invalid: String, reason: String },
+
+    /// A BIC has the wrong format.
+    #[error("Invalid BIC: '{invalid}', because: {reason}")]
+    InvalidBic { invalid: String, reason: String },
+
+    /// A VAT number has the wrong format or check digit for its country.
+    #[error("Invalid VAT number: '{invalid}', because: {reason}")]
+    InvalidVatNumber { invalid: String, reason: String },
+
+    /// An organisation number has the wrong format or check digit for the
+    /// country of the company.
+    #[error("Invalid organisation number: '{invalid}', because: {reason}")]
+    InvalidOrganisationNumber { invalid: String, reason: String },
+
+    /// An invoice cannot be emailed without billing email addresses.
+    #[error("No billing email address of company: '{company}', add one with `klirr data edit`")]
+    MissingBillingEmail { company: String },
+
+    /// Failed to create the email of an invoice.
+    #[error("Failed to create email, because: {underlying}")]
+    FailedToCreateEmail { underlying: String },
+
+    /// Failed to save the email draft of an invoice.
+    #[error("Failed to save email draft, because: {underlying}")]
+    SaveEmail { underlying: String },
+
+    /// Sending emails requires an SMTP server to be configured.
+    #[error("No SMTP server configured, create the file: {path}")]
+    MissingSmtpSettings { path: String },
+
+    /// Failed to send the email of an invoice.
+    #[error("Failed to send email, because: {underlying}")]
+    FailedToSendEmail { underlying: String },
+
+    /// A receipt must be a PDF, PNG or JPG file.
+    #[error("Unsupported receipt: '{path}', must be a PDF, PNG or JPG file")]
+    UnsupportedReceiptFormat { path: String },
+
+    /// Failed to read the receipt of an expense.
+    #[error("Failed to read receipt: '{path}', because: {underlying}")]
+    FailedToReadReceipt { path: String, underlying: String },
+
+    /// A receipt with the same file name is already in the receipts folder.
+    #[error("A receipt already exists at: '{path}', rename the file to attach")]
+    ReceiptAlreadyExists { path: String },
+
+    /// Failed to append the receipts to the PDF of an invoice.
+    #[error("Failed to append receipts to the PDF, because: {underlying}")]
+    FailedToAppendReceipts { underlying: String },
 }
@@ -0,0 +1,62 @@
+use crate::prelude::*;
+
+/// How to read expenses from a CSV file, e.g. a card export, with columns
+/// referenced by their header.
+#[derive(Clone, Debug, PartialEq, Eq, TypedBuilder, Getters)]
+pub struct ExpenseCsvFormat {
+    /// The header of the column with the name of the expense.
+    #[builder(setter(into), default = "name".to_owned())]
+    #[getset(get = "pub")]
+    name_column: String,
+
+    /// The header of the column with the amount, i.e. the unit price, the
+    /// sign is ignored since card exports often list purchases as negative.
+    #[builder(setter(into), default = "amount".to_owned())]
+    #[getset(get = "pub")]
+    amount_column: String,
+
+    /// The header of the column with the currency, ignored if `currency`
+    /// is set.
+    #[builder(setter(into), default = "currency".to_owned())]
+    #[getset(get = "pub")]
+    currency_column: String,
+
+    /// The currency of all expenses, for files without a currency column.
+    #[builder(setter(into), default)]
+    #[getset(get = "pub")]
+    currency: Option<Currency>,
+
+    /// The header of the column with the quantity, if `None` the quantity
+    /// of every expense is `1`.
+    #[builder(setter(into), default)]
+    #[getset(get = "pub")]
+    quantity_column: Option<String>,
+
+    /// The header of the column with the transaction date.
+    #[builder(setter(into), default = "date".to_owned())]
+    #[getset(get = "pub")]
+    date_column: String,
+
+    /// The format of the transaction dates, e.g. `"%d/%m/%Y"`, see
+    /// [`chrono::format::strftime`].
+    #[builder(setter(into), default = "%Y-%m-%d".to_owned())]
+    #[getset(get = "pub")]
+    date_format: String,
+
+    /// The decimal separator of amounts and quantities, `.` or `,`, the other
+    /// one and spaces are treated as thousands separators.
+    #[builder(default = '.')]
+    #[getset(get = "pub")]
+    decimal_separator: char,
+
+    /// The delimiter between columns, e.g. `;`.
+    #[builder(default = ',')]
+    #[getset(get = "pub")]
+    delimiter: char,
+}
+
+impl Default for ExpenseCsvFormat {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
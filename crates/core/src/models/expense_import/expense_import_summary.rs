@@ -0,0 +1,102 @@
+use crate::prelude::*;
+
+/// The outcome of importing expenses for a month, or a preview of it.
+#[derive(Clone, Debug, PartialEq, TypedBuilder, Getters)]
+pub struct ExpenseImport {
+    /// The month the expenses are recorded for.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    month: YearAndMonth,
+
+    /// True if nothing was saved.
+    #[builder(default)]
+    #[getset(get = "pub")]
+    is_preview: bool,
+
+    /// The expenses recorded, or to record if this is a preview.
+    #[builder(setter(into), default)]
+    #[getset(get = "pub")]
+    imported: Vec<Item>,
+
+    /// Expenses skipped since they are already recorded.
+    #[builder(setter(into), default)]
+    #[getset(get = "pub")]
+    duplicates: Vec<Item>,
+
+    /// Files in a receipt folder skipped since their name is not on the
+    /// receipt format.
+    #[builder(setter(into), default)]
+    #[getset(get = "pub")]
+    skipped_files: Vec<String>,
+}
+
+impl std::fmt::Display for ExpenseImport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_preview {
+            writeln!(
+                f,
+                "Preview, would import #{} expenses for {}, nothing saved:",
+                self.imported.len(),
+                self.month
+            )?;
+        } else {
+            writeln!(
+                f,
+                "Imported #{} expenses for {}:",
+                self.imported.len(),
+                self.month
+            )?;
+        }
+        for item in &self.imported {
+            writeln!(f, "  {}", item)?;
+        }
+        if !self.duplicates.is_empty() {
+            writeln!(
+                f,
+                "Skipped #{} expenses already recorded:",
+                self.duplicates.len()
+            )?;
+            for item in &self.duplicates {
+                writeln!(f, "  {}", item)?;
+            }
+        }
+        if !self.skipped_files.is_empty() {
+            writeln!(
+                f,
+                "Skipped #{} files not named as `<date>_<name>_<amount>_<currency>[_<quantity>]`:",
+                self.skipped_files.len()
+            )?;
+            for file in &self.skipped_files {
+                writeln!(f, "  {}", file)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl HasSample for ExpenseImport {
+    fn sample() -> Self {
+        Self::builder()
+            .month(YearAndMonth::may(2025))
+            .is_preview(true)
+            .imported(vec![
+                Item::sample_expense_coffee(),
+                Item::sample_expense_sandwich(),
+            ])
+            .duplicates(vec![Item::sample_expense_breakfast()])
+            .skipped_files(vec!["notes.txt".to_owned()])
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use insta::assert_snapshot;
+    use test_log::test;
+
+    #[test]
+    fn test_display() {
+        assert_snapshot!(ExpenseImport::sample().to_string());
+    }
+}
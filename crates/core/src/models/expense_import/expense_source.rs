@@ -0,0 +1,25 @@
+use crate::prelude::*;
+
+/// Where to import expenses from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExpenseSource {
+    /// A CSV file, e.g. a card export, read using an [`ExpenseCsvFormat`].
+    Csv {
+        path: PathBuf,
+        format: ExpenseCsvFormat,
+    },
+    /// A folder of receipts, with one expense per file, named as
+    /// `<date>_<name>_<amount>_<currency>[_<quantity>]`, e.g.
+    /// `2025-05-31_Coffee_2.5_EUR_3.pdf`.
+    ReceiptFolder { path: PathBuf },
+}
+
+impl ExpenseSource {
+    /// The path of the CSV file or receipt folder.
+    pub fn path(&self) -> &Path {
+        match self {
+            Self::Csv { path, .. } => path,
+            Self::ReceiptFolder { path } => path,
+        }
+    }
+}
@@ -0,0 +1,7 @@
+mod expense_csv_format;
+mod expense_import_summary;
+mod expense_source;
+
+pub use expense_csv_format::*;
+pub use expense_import_summary::*;
+pub use expense_source::*;
@@ -0,0 +1,18 @@
+use crate::prelude::*;
+
+/// The revisions of the data, oldest first, as listed by
+/// `klirr data history`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deref, From)]
+pub struct DataHistory(Vec<DataRevision>);
+
+impl std::fmt::Display for DataHistory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.0.is_empty() {
+            return writeln!(f, "No history recorded.");
+        }
+        for revision in &self.0 {
+            writeln!(f, "{}", revision)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,73 @@
+use crate::prelude::*;
+
+/// A snapshot of the data files changed by a mutation of the data, taken
+/// before the change, which `klirr data undo` restores.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, TypedBuilder, Getters)]
+pub struct DataRevision {
+    /// The number of the revision, starting at 1 and increasing with every
+    /// mutation.
+    #[getset(get = "pub")]
+    number: u32,
+
+    /// When the mutation was made, formatted as `%Y-%m-%d %H:%M:%S` in local
+    /// time.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    created: String,
+
+    /// What the mutation changed, e.g. `"Recording month off for: 2025-05"`.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    description: String,
+
+    /// The contents of each file changed by the mutation as it was before,
+    /// keyed by the name of the file without extension, `None` if the file
+    /// did not exist.
+    #[getset(get = "pub")]
+    files: IndexMap<String, Option<String>>,
+}
+
+impl std::fmt::Display for DataRevision {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "#{}  {}  {} ({})",
+            self.number,
+            self.created,
+            self.description,
+            self.files
+                .keys()
+                .map(String::as_str)
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+impl HasSample for DataRevision {
+    fn sample() -> Self {
+        Self::builder()
+            .number(1)
+            .created("2025-05-31 12:00:00")
+            .description("Recording month off for: 2025-05")
+            .files(IndexMap::from_iter([(
+                DATA_FILE_NAME_PROTO_INVOICE_INFO.to_owned(),
+                Some(to_ron_string(&ProtoInvoiceInfo::sample()).unwrap()),
+            )]))
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            DataRevision::sample().to_string(),
+            "#1  2025-05-31 12:00:00  Recording month off for: 2025-05 (invoice_info)"
+        );
+    }
+}
@@ -0,0 +1,5 @@
+mod data_history;
+mod data_revision;
+
+pub use data_history::*;
+pub use data_revision::*;
@@ -0,0 +1,82 @@
+use crate::prelude::*;
+
+/// A valid Business Identifier Code of a bank, also known as SWIFT code,
+/// e.g. `"BNPAFRPP"`, of 8 or 11 characters: bank, country, location and
+/// optionally branch.
+#[derive(Clone, Debug, Display, PartialEq, Eq, Hash, SerializeDisplay, DeserializeFromStr)]
+#[display("{}", _0)]
+pub struct Bic(String);
+
+impl Bic {
+    /// The country code of the bank, e.g. `"FR"`.
+    pub fn country_code(&self) -> &str {
+        &self.0[4..6]
+    }
+}
+
+impl FromStr for Bic {
+    type Err = crate::prelude::Error;
+
+    /// Parses a BIC, ignoring spaces and case.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// assert_eq!(Bic::from_str("nda sse ss").unwrap().to_string(), "NDASSESS");
+    /// assert!(Bic::from_str("NDAS1ESS").is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self> {
+        let compact = s
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>()
+            .to_uppercase();
+        if !matches_pattern(&compact, "aaaaaaxx") && !matches_pattern(&compact, "aaaaaaxxxxx") {
+            return Err(Error::InvalidBic {
+                invalid: s.to_owned(),
+                reason: "expected 4 letters for the bank, 2 for the country, 2 characters for the location and optionally 3 for the branch".to_owned(),
+            });
+        }
+        Ok(Self(compact))
+    }
+}
+
+impl HasSample for Bic {
+    fn sample() -> Self {
+        Self::from_str("BNPAFRPP").expect("Valid BIC")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn test_valid() {
+        for bic in ["BNPAFRPP", "DEUTDEFF500", "ESSESESS"] {
+            assert!(Bic::from_str(bic).is_ok(), "{}", bic);
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        for bic in ["", "BNPAFRP", "BNPAFRPP5", "BNP1FRPP", "BNPAFRPP50O0"] {
+            assert!(
+                matches!(Bic::from_str(bic), Err(Error::InvalidBic { .. })),
+                "{}",
+                bic
+            );
+        }
+    }
+
+    #[test]
+    fn test_roundtrip_ron() {
+        let sut = Bic::sample();
+        let ron = ron::to_string(&sut).unwrap();
+        assert_eq!(ron, "\"BNPAFRPP\"");
+        assert_eq!(ron::from_str::<Bic>(&ron).unwrap(), sut);
+        assert_eq!(sut.country_code(), "FR");
+    }
+}
@@ -0,0 +1,108 @@
+//! Check digit algorithms and format checks shared by the identifiers of
+//! banks and companies.
+
+/// Returns the digits of `s`, or `None` if `s` is not only ASCII digits.
+pub fn digits_of(s: &str) -> Option<Vec<u32>> {
+    s.chars().map(|c| c.to_digit(10)).collect()
+}
+
+/// Returns `true` if `s` matches `pattern`, in which `d` is any digit, `a`
+/// any uppercase letter, `x` any digit or uppercase letter and any other
+/// character is matched literally.
+///
+/// # Examples
+/// ```
+/// extern crate klirr_core;
+/// use klirr_core::prelude::*;
+/// assert!(matches_pattern("U12345678", "Udddddddd"));
+/// assert!(!matches_pattern("U1234567", "Udddddddd"));
+/// ```
+pub fn matches_pattern(s: &str, pattern: &str) -> bool {
+    s.chars().count() == pattern.chars().count()
+        && s.chars().zip(pattern.chars()).all(|(c, p)| match p {
+            'd' => c.is_ascii_digit(),
+            'a' => c.is_ascii_uppercase(),
+            'x' => c.is_ascii_digit() || c.is_ascii_uppercase(),
+            literal => c == literal,
+        })
+}
+
+/// Returns `true` if the digits of `s` pass the Luhn algorithm, used by
+/// e.g. Swedish organisation numbers.
+///
+/// # Examples
+/// ```
+/// extern crate klirr_core;
+/// use klirr_core::prelude::*;
+/// assert!(luhn_is_valid("5566778899"));
+/// assert!(!luhn_is_valid("5566778898"));
+/// ```
+pub fn luhn_is_valid(s: &str) -> bool {
+    let Some(digits) = digits_of(s) else {
+        return false;
+    };
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &digit)| match (i % 2 == 1, digit * 2) {
+            (true, doubled) if doubled > 9 => doubled - 9,
+            (true, doubled) => doubled,
+            (false, _) => digit,
+        })
+        .sum();
+    !digits.is_empty() && sum.is_multiple_of(10)
+}
+
+/// The remainder of dividing the number `s` by 97, where letters count as
+/// the two digit numbers `A = 10` to `Z = 35`, as in ISO 7064 MOD 97-10,
+/// or `None` if `s` has other characters.
+pub fn mod_97(s: &str) -> Option<u32> {
+    s.chars().try_fold(0u32, |remainder, c| {
+        let value = c.to_digit(36)?;
+        let shift = if value > 9 { 100 } else { 10 };
+        Some((remainder * shift + value) % 97)
+    })
+}
+
+/// Returns `true` if the digits of `s` pass ISO 7064 MOD 11,10, used by
+/// e.g. German and Croatian VAT numbers.
+pub fn mod_11_10_is_valid(s: &str) -> bool {
+    let Some(digits) = digits_of(s) else {
+        return false;
+    };
+    let Some((check, body)) = digits.split_last() else {
+        return false;
+    };
+    let product = body.iter().fold(10, |product, digit| {
+        let sum = (digit + product) % 10;
+        let sum = if sum == 0 { 10 } else { sum };
+        (sum * 2) % 11
+    });
+    (11 - product) % 10 == *check
+}
+
+/// The sum of `digits` each multiplied by the weight at the same
+/// position, ignoring digits beyond the last weight.
+pub fn weighted_sum(digits: &[u32], weights: &[u32]) -> u32 {
+    digits.iter().zip(weights).map(|(d, w)| d * w).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn test_mod_97() {
+        assert_eq!(mod_97("30006000011234567890189FR76"), Some(1));
+        assert_eq!(mod_97("30006000011234567890189FR77"), Some(2));
+        assert_eq!(mod_97("12-3"), None);
+    }
+
+    #[test]
+    fn test_mod_11_10() {
+        assert!(mod_11_10_is_valid("136695976"));
+        assert!(!mod_11_10_is_valid("136695977"));
+    }
+}
@@ -0,0 +1,222 @@
+use crate::prelude::*;
+
+/// The length of the IBANs of each country, as listed in the IBAN registry.
+const IBAN_LENGTHS: &[(&str, usize)] = &[
+    ("AD", 24),
+    ("AE", 23),
+    ("AL", 28),
+    ("AT", 20),
+    ("AZ", 28),
+    ("BA", 20),
+    ("BE", 16),
+    ("BG", 22),
+    ("BH", 22),
+    ("BI", 27),
+    ("BR", 29),
+    ("BY", 28),
+    ("CH", 21),
+    ("CR", 22),
+    ("CY", 28),
+    ("CZ", 24),
+    ("DE", 22),
+    ("DJ", 27),
+    ("DK", 18),
+    ("DO", 28),
+    ("EE", 20),
+    ("EG", 29),
+    ("ES", 24),
+    ("FI", 18),
+    ("FK", 18),
+    ("FO", 18),
+    ("FR", 27),
+    ("GB", 22),
+    ("GE", 22),
+    ("GI", 23),
+    ("GL", 18),
+    ("GR", 27),
+    ("GT", 28),
+    ("HN", 28),
+    ("HR", 21),
+    ("HU", 28),
+    ("IE", 22),
+    ("IL", 23),
+    ("IQ", 23),
+    ("IS", 26),
+    ("IT", 27),
+    ("JO", 30),
+    ("KW", 30),
+    ("KZ", 20),
+    ("LB", 28),
+    ("LC", 32),
+    ("LI", 21),
+    ("LT", 20),
+    ("LU", 20),
+    ("LV", 21),
+    ("LY", 25),
+    ("MC", 27),
+    ("MD", 24),
+    ("ME", 22),
+    ("MK", 19),
+    ("MN", 20),
+    ("MR", 27),
+    ("MT", 31),
+    ("MU", 30),
+    ("NI", 28),
+    ("NL", 18),
+    ("NO", 15),
+    ("OM", 23),
+    ("PK", 24),
+    ("PL", 28),
+    ("PS", 29),
+    ("PT", 25),
+    ("QA", 29),
+    ("RO", 24),
+    ("RS", 22),
+    ("RU", 33),
+    ("SA", 24),
+    ("SC", 31),
+    ("SD", 18),
+    ("SE", 24),
+    ("SI", 19),
+    ("SK", 24),
+    ("SM", 27),
+    ("SO", 23),
+    ("ST", 25),
+    ("SV", 28),
+    ("TL", 23),
+    ("TN", 24),
+    ("TR", 26),
+    ("UA", 29),
+    ("VA", 22),
+    ("VG", 24),
+    ("XK", 20),
+    ("YE", 30),
+];
+
+/// A valid International Bank Account Number, e.g.
+/// `"FR76 3000 6000 0112 3456 7890 189"`, with the length of its country and
+/// correct check digits, displayed in groups of four characters.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, SerializeDisplay, DeserializeFromStr)]
+pub struct Iban(String);
+
+impl Iban {
+    /// The country code of the IBAN, e.g. `"FR"`.
+    pub fn country_code(&self) -> &str {
+        &self.0[..2]
+    }
+
+    /// The IBAN without spaces, e.g. `"FR7630006000011234567890189"`.
+    pub fn compact(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for Iban {
+    type Err = crate::prelude::Error;
+
+    /// Parses an IBAN, ignoring spaces and case, checking its length for
+    /// its country and its check digits with the MOD 97 algorithm.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let iban = Iban::from_str("se45 5000 0000 0583 9825 7466").unwrap();
+    /// assert_eq!(iban.to_string(), "SE45 5000 0000 0583 9825 7466");
+    /// assert!(Iban::from_str("SE46 5000 0000 0583 9825 7466").is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = |reason: &str| Error::InvalidIban {
+            invalid: s.to_owned(),
+            reason: reason.to_owned(),
+        };
+        let compact = s
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>()
+            .to_uppercase();
+        if !compact.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(invalid("only letters and digits are allowed"));
+        }
+        let country = compact.get(..2).unwrap_or_default();
+        let Some(&(_, length)) = IBAN_LENGTHS.iter().find(|(code, _)| *code == country) else {
+            return Err(invalid("unknown country code"));
+        };
+        if compact.len() != length {
+            return Err(invalid(&format!(
+                "an IBAN of {} has {} characters, not {}",
+                country,
+                length,
+                compact.len()
+            )));
+        }
+        let rearranged = format!("{}{}", &compact[4..], &compact[..4]);
+        if !compact[2..4].chars().all(|c| c.is_ascii_digit()) || mod_97(&rearranged) != Some(1) {
+            return Err(invalid("wrong check digits, is there a typo?"));
+        }
+        Ok(Self(compact))
+    }
+}
+
+impl std::fmt::Display for Iban {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let groups = self
+            .0
+            .as_bytes()
+            .chunks(4)
+            .map(|group| std::str::from_utf8(group).expect("IBAN is ASCII"))
+            .collect::<Vec<_>>();
+        write!(f, "{}", groups.join(" "))
+    }
+}
+
+impl HasSample for Iban {
+    fn sample() -> Self {
+        Self::from_str("FR76 3000 6000 0112 3456 7890 189").expect("Valid IBAN")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn test_valid() {
+        for iban in [
+            "FR7630006000011234567890189",
+            "DE89 3704 0044 0532 0130 00",
+            "GB82 WEST 1234 5698 7654 32",
+            "NO93 8601 1117 947",
+        ] {
+            assert!(Iban::from_str(iban).is_ok(), "{}", iban);
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        for iban in [
+            "",
+            "FR76 3000 6000 0112 3456 7890 18",
+            "FR76 3000 6000 0112 3456 7890 188",
+            "XX76 3000 6000 0112 3456 7890 189",
+            "FR76-3000-6000-0112-3456-7890-189",
+            "FRAB 3000 6000 0112 3456 7890 189",
+        ] {
+            assert!(
+                matches!(Iban::from_str(iban), Err(Error::InvalidIban { .. })),
+                "{}",
+                iban
+            );
+        }
+    }
+
+    #[test]
+    fn test_roundtrip_ron() {
+        let sut = Iban::sample();
+        let ron = ron::to_string(&sut).unwrap();
+        assert_eq!(ron, "\"FR76 3000 6000 0112 3456 7890 189\"");
+        assert_eq!(ron::from_str::<Iban>(&ron).unwrap(), sut);
+        assert_eq!(sut.country_code(), "FR");
+    }
+}
@@ -0,0 +1,11 @@
+mod bic;
+mod checksums;
+mod iban;
+mod organisation_number;
+mod vat_number;
+
+pub use bic::*;
+pub use checksums::*;
+pub use iban::*;
+pub use organisation_number::*;
+pub use vat_number::*;
@@ -0,0 +1,133 @@
+use crate::prelude::*;
+
+/// The names of Sweden as written in a postal address, in lower case, for
+/// which organisation numbers are checked with the Luhn algorithm.
+const SWEDEN: [&str; 4] = ["sweden", "sverige", "se", "swe"];
+
+/// The organisation number of a company, e.g. `"556677-8899"`, shown as
+/// given. Its format differs per country, so only Swedish organisation
+/// numbers are validated further, see [`OrganisationNumber::validate_in`].
+#[derive(Clone, Debug, Display, PartialEq, Eq, Hash, SerializeDisplay, DeserializeFromStr)]
+#[display("{}", _0)]
+pub struct OrganisationNumber(String);
+
+impl OrganisationNumber {
+    /// Validates the organisation number of a company in `country`, as
+    /// written in its postal address, e.g. `"Sweden"`. Swedish organisation
+    /// numbers, and personal identity numbers of sole traders, must have 10
+    /// or 12 digits, optionally with a `-` or `+`, the last of which is a
+    /// Luhn check digit.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let number = OrganisationNumber::from_str("556677-8899").unwrap();
+    /// assert!(number.validate_in("Sweden").is_ok());
+    /// let number = OrganisationNumber::from_str("556677-8898").unwrap();
+    /// assert!(number.validate_in("Sweden").is_err());
+    /// assert!(number.validate_in("France").is_ok());
+    /// ```
+    pub fn validate_in(&self, country: impl AsRef<str>) -> Result<()> {
+        if !SWEDEN.contains(&country.as_ref().trim().to_lowercase().as_str()) {
+            return Ok(());
+        }
+        let invalid = |reason: &str| Error::InvalidOrganisationNumber {
+            invalid: self.0.clone(),
+            reason: reason.to_owned(),
+        };
+        let digits = self
+            .0
+            .chars()
+            .filter(|c| *c != '-' && *c != '+')
+            .collect::<String>();
+        if digits_of(&digits).is_none() || (digits.len() != 10 && digits.len() != 12) {
+            return Err(invalid(
+                "a Swedish organisation number has 10 or 12 digits, e.g. 556677-8899",
+            ));
+        }
+        if !luhn_is_valid(&digits[digits.len() - 10..]) {
+            return Err(invalid("wrong check digit, is there a typo?"));
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for OrganisationNumber {
+    type Err = crate::prelude::Error;
+
+    /// Parses an organisation number, which must not be empty.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// assert!(OrganisationNumber::from_str("9876543-2101").is_ok());
+    /// assert!(OrganisationNumber::from_str(" ").is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self> {
+        let trimmed = s.trim();
+        if trimmed.is_empty() {
+            return Err(Error::InvalidOrganisationNumber {
+                invalid: s.to_owned(),
+                reason: "it is empty".to_owned(),
+            });
+        }
+        Ok(Self(trimmed.to_owned()))
+    }
+}
+
+impl HasSample for OrganisationNumber {
+    fn sample() -> Self {
+        Self::sample_client()
+    }
+}
+
+impl OrganisationNumber {
+    pub fn sample_client() -> Self {
+        Self::from_str("9876543-2101").expect("Valid organisation number")
+    }
+
+    pub fn sample_vendor() -> Self {
+        Self::from_str("7418529-3012").expect("Valid organisation number")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn test_swedish() {
+        for valid in ["556677-8899", "5566778899", "16556677-8899", "121212+1212"] {
+            let sut = OrganisationNumber::from_str(valid).unwrap();
+            assert!(sut.validate_in("sverige").is_ok(), "{}", valid);
+        }
+        for invalid in ["556677-8898", "556677-889", "55667A-8899"] {
+            let sut = OrganisationNumber::from_str(invalid).unwrap();
+            assert!(
+                matches!(
+                    sut.validate_in("Sweden"),
+                    Err(Error::InvalidOrganisationNumber { .. })
+                ),
+                "{}",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn test_other_countries_are_not_checked() {
+        let sut = OrganisationNumber::sample_vendor();
+        assert!(sut.validate_in("France").is_ok());
+    }
+
+    #[test]
+    fn test_roundtrip_ron() {
+        let sut = OrganisationNumber::sample();
+        let ron = ron::to_string(&sut).unwrap();
+        assert_eq!(ron, "\"9876543-2101\"");
+        assert_eq!(ron::from_str::<OrganisationNumber>(&ron).unwrap(), sut);
+    }
+}
@@ -0,0 +1,352 @@
+use crate::prelude::*;
+
+/// The suffixes of Swiss VAT numbers, in German, French, Italian and
+/// Romansh, e.g. `"CHE-107.787.577 MWST"`.
+const SWISS_VAT_SUFFIXES: [&str; 4] = ["MWST", "TVA", "IVA", "TPV"];
+
+/// A VAT number of a company, e.g. `"GB987654353"`, prefixed with the code
+/// of its country. The format and, where there is one, the check digit of
+/// VAT numbers of EU countries, Switzerland, Norway and the United Kingdom
+/// are validated, those of other countries are accepted as given.
+#[derive(Clone, Debug, Display, PartialEq, Eq, Hash, SerializeDisplay, DeserializeFromStr)]
+#[display("{}", _0)]
+pub struct VatNumber(String);
+
+impl VatNumber {
+    /// The country prefix of the VAT number, e.g. `"GB"`, or `None` if it
+    /// does not start with two letters.
+    pub fn country_code(&self) -> Option<&str> {
+        self.0
+            .get(..2)
+            .filter(|prefix| prefix.chars().all(|c| c.is_ascii_alphabetic()))
+    }
+}
+
+/// The VAT number `s` in upper case without spaces, dots and dashes.
+fn compact(s: &str) -> String {
+    s.chars()
+        .filter(|c| !c.is_whitespace() && *c != '.' && *c != '-')
+        .collect::<String>()
+        .to_uppercase()
+}
+
+/// The reason `number` is not a valid VAT number of `country`, if it is
+/// one of the countries checked, without the country prefix.
+fn check_country(country: &str, number: &str) -> Option<std::result::Result<(), String>> {
+    let format = |patterns: &[&str]| {
+        if patterns
+            .iter()
+            .any(|pattern| matches_pattern(number, pattern))
+        {
+            Ok(())
+        } else {
+            Err(format!(
+                "expected {} followed by {}",
+                country,
+                patterns
+                    .iter()
+                    .map(|pattern| format!("`{}`", pattern))
+                    .collect::<Vec<_>>()
+                    .join(" or ")
+            ))
+        }
+    };
+    let checked = |patterns: &[&str], is_valid: &dyn Fn(&[u32]) -> bool| {
+        format(patterns)?;
+        let digits = number
+            .chars()
+            .filter_map(|c| c.to_digit(10))
+            .collect::<Vec<_>>();
+        if is_valid(&digits) {
+            Ok(())
+        } else {
+            Err("wrong check digit, is there a typo?".to_owned())
+        }
+    };
+    // The check digit `11 - sum % 11`, where 11 is written as 0 and 10 is
+    // never a valid check digit.
+    let mod_11 = |digits: &[u32], weights: &[u32]| {
+        let check = 11 - weighted_sum(digits, weights) % 11;
+        match check {
+            11 => Some(0),
+            10 => None,
+            check => Some(check),
+        }
+    };
+    let result = match country {
+        "AT" => checked(&["Udddddddd"], &|d| {
+            let sum: u32 = d[..7]
+                .iter()
+                .enumerate()
+                .map(|(i, &digit)| match i % 2 {
+                    0 => digit,
+                    _ => digit * 2 / 10 + digit * 2 % 10,
+                })
+                .sum();
+            (10 - (sum + 4) % 10) % 10 == d[7]
+        }),
+        "BE" => checked(&["dddddddddd", "ddddddddd"], &|d| {
+            let number = d.iter().fold(0u64, |n, &digit| n * 10 + digit as u64);
+            97 - (number / 100) % 97 == number % 100
+        }),
+        "BG" => format(&["ddddddddd", "dddddddddd"]),
+        "CY" => format(&["dddddddda"]),
+        "CZ" => format(&["dddddddd", "ddddddddd", "dddddddddd"]),
+        "DE" => checked(&["ddddddddd"], &|_| mod_11_10_is_valid(number)),
+        "DK" => checked(&["dddddddd"], &|d| {
+            weighted_sum(d, &[2, 7, 6, 5, 4, 3, 2, 1]).is_multiple_of(11)
+        }),
+        "EE" => checked(&["ddddddddd"], &|d| {
+            (10 - weighted_sum(d, &[3, 7, 1, 3, 7, 1, 3, 7]) % 10) % 10 == d[8]
+        }),
+        "EL" => checked(&["ddddddddd"], &|d| {
+            weighted_sum(d, &[256, 128, 64, 32, 16, 8, 4, 2]) % 11 % 10 == d[8]
+        }),
+        "ES" => format(&["xdddddddx"]),
+        "FI" => checked(&["dddddddd"], &|d| {
+            mod_11(d, &[7, 9, 10, 5, 8, 4, 2]) == Some(d[7])
+        }),
+        "FR" => format(&["xxddddddddd"]).and_then(|()| match number[..2].parse::<u64>() {
+            Ok(key) => {
+                let siren = number[2..].parse::<u64>().unwrap_or_default();
+                if (12 + 3 * (siren % 97)) % 97 == key {
+                    Ok(())
+                } else {
+                    Err("wrong check digits, is there a typo?".to_owned())
+                }
+            }
+            Err(_) => Ok(()),
+        }),
+        "HR" => checked(&["ddddddddddd"], &|_| mod_11_10_is_valid(number)),
+        "HU" => checked(&["dddddddd"], &|d| {
+            (10 - weighted_sum(d, &[9, 7, 3, 1, 9, 7, 3]) % 10) % 10 == d[7]
+        }),
+        "IE" => format(&["ddddddda", "dddddddaa", "dxddddda"]),
+        "IT" => checked(&["ddddddddddd"], &|_| luhn_is_valid(number)),
+        "LT" => format(&["ddddddddd", "dddddddddddd"]),
+        "LU" => checked(&["dddddddd"], &|_| {
+            let first = number[..6].parse::<u32>().unwrap_or_default();
+            let check = number[6..].parse::<u32>().unwrap_or_default();
+            first % 89 == check
+        }),
+        "LV" => format(&["ddddddddddd"]),
+        "MT" => format(&["dddddddd"]),
+        "NL" => checked(&["dddddddddBdd"], &|d| {
+            let sum = weighted_sum(d, &[9, 8, 7, 6, 5, 4, 3, 2]);
+            sum % 11 == d[8] || mod_97(&format!("NL{}", number)) == Some(1)
+        }),
+        "PL" => checked(&["dddddddddd"], &|d| {
+            weighted_sum(d, &[6, 5, 7, 2, 3, 4, 5, 6, 7]) % 11 == d[9]
+        }),
+        "PT" => checked(&["ddddddddd"], &|d| {
+            let check = 11 - weighted_sum(d, &[9, 8, 7, 6, 5, 4, 3, 2]) % 11;
+            (if check > 9 { 0 } else { check }) == d[8]
+        }),
+        "RO" => format(&[
+            "dd",
+            "ddd",
+            "dddd",
+            "ddddd",
+            "dddddd",
+            "ddddddd",
+            "dddddddd",
+            "ddddddddd",
+            "dddddddddd",
+        ]),
+        "SE" => checked(&["dddddddddd01"], &|_| luhn_is_valid(&number[..10])),
+        "SI" => checked(
+            &["dddddddd"],
+            &|d| match 11 - weighted_sum(d, &[8, 7, 6, 5, 4, 3, 2]) % 11 {
+                11 => false,
+                check => check % 10 == d[7],
+            },
+        ),
+        "SK" => checked(&["dddddddddd"], &|_| {
+            number.parse::<u64>().unwrap_or_default() % 11 == 0
+        }),
+        "GB" | "XI" if number.starts_with("GD") || number.starts_with("HA") => {
+            format(&["GDddd", "HAddd"])
+        }
+        "GB" | "XI" => checked(&["ddddddddd", "dddddddddddd"], &|d| {
+            let sum = weighted_sum(d, &[8, 7, 6, 5, 4, 3, 2]) + d[7] * 10 + d[8];
+            sum.is_multiple_of(97) || (sum + 55).is_multiple_of(97)
+        }),
+        "CH" => {
+            let number = SWISS_VAT_SUFFIXES
+                .iter()
+                .find_map(|suffix| number.strip_suffix(suffix))
+                .unwrap_or(number);
+            let digits = number.strip_prefix('E').and_then(digits_of);
+            match digits {
+                Some(d) if d.len() == 9 => {
+                    if mod_11(&d, &[5, 4, 3, 2, 7, 6, 5, 4]) == Some(d[8]) {
+                        Ok(())
+                    } else {
+                        Err("wrong check digit, is there a typo?".to_owned())
+                    }
+                }
+                _ => Err(
+                    "expected CHE followed by 9 digits and optionally MWST, TVA or IVA".to_owned(),
+                ),
+            }
+        }
+        "NO" => {
+            let number = number.strip_suffix("MVA").unwrap_or(number);
+            match digits_of(number) {
+                Some(d) if d.len() == 9 => {
+                    if mod_11(&d, &[3, 2, 7, 6, 5, 4, 3, 2]) == Some(d[8]) {
+                        Ok(())
+                    } else {
+                        Err("wrong check digit, is there a typo?".to_owned())
+                    }
+                }
+                _ => Err("expected NO followed by 9 digits and optionally MVA".to_owned()),
+            }
+        }
+        _ => return None,
+    };
+    Some(result)
+}
+
+impl FromStr for VatNumber {
+    type Err = crate::prelude::Error;
+
+    /// Parses a VAT number, ignoring case, spaces, dots and dashes if it is
+    /// one of a country whose VAT numbers are validated.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// assert_eq!(VatNumber::from_str("de 136 695 976").unwrap().to_string(), "DE136695976");
+    /// assert!(VatNumber::from_str("DE136695977").is_err());
+    /// assert_eq!(VatNumber::from_str("US 12-3456789").unwrap().to_string(), "US 12-3456789");
+    /// ```
+    fn from_str(s: &str) -> Result<Self> {
+        let trimmed = s.trim();
+        if trimmed.is_empty() {
+            return Err(Error::InvalidVatNumber {
+                invalid: s.to_owned(),
+                reason: "it is empty".to_owned(),
+            });
+        }
+        let compact = compact(trimmed);
+        let Some((country, number)) = compact.split_at_checked(2) else {
+            return Ok(Self(trimmed.to_owned()));
+        };
+        match check_country(country, number) {
+            None => Ok(Self(trimmed.to_owned())),
+            Some(Ok(())) => Ok(Self(compact)),
+            Some(Err(reason)) => Err(Error::InvalidVatNumber {
+                invalid: s.to_owned(),
+                reason,
+            }),
+        }
+    }
+}
+
+impl HasSample for VatNumber {
+    fn sample() -> Self {
+        Self::sample_client()
+    }
+}
+
+impl VatNumber {
+    pub fn sample_client() -> Self {
+        Self::from_str("GB987654353").expect("Valid VAT number")
+    }
+
+    pub fn sample_vendor() -> Self {
+        Self::from_str("FR81185293012").expect("Valid VAT number")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn test_valid() {
+        for vat_number in [
+            "ATU13585627",
+            "BE0403019261",
+            "DE136695976",
+            "DK13585628",
+            "EE100931558",
+            "EL094259216",
+            "ESX2482300W",
+            "FI20774740",
+            "FR40303265045",
+            "HR33392005961",
+            "HU12892312",
+            "IT00743110157",
+            "LU15027442",
+            "NL004495445B01",
+            "PL8567346215",
+            "PT501964843",
+            "SE123456789701",
+            "SI50223054",
+            "SK2022749619",
+            "GB980780684",
+            "GB 980 7806 84",
+            "XI980780684",
+            "CHE-107.787.577 IVA",
+            "NO 995 525 828 MVA",
+        ] {
+            assert!(
+                VatNumber::from_str(vat_number).is_ok(),
+                "{}: {:?}",
+                vat_number,
+                VatNumber::from_str(vat_number)
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        for vat_number in [
+            "",
+            "ATU13585626",
+            "BE0403019262",
+            "DE13669597",
+            "DK13585629",
+            "EL094259217",
+            "FI20774741",
+            "FR41303265045",
+            "IT00743110158",
+            "NL004495446B01",
+            "PL8567346216",
+            "SE123456789801",
+            "SE123456789702",
+            "SK2022749618",
+            "GB980780685",
+            "CHE-107.787.578",
+            "NO 995 525 829 MVA",
+        ] {
+            assert!(
+                matches!(
+                    VatNumber::from_str(vat_number),
+                    Err(Error::InvalidVatNumber { .. })
+                ),
+                "{}",
+                vat_number
+            );
+        }
+    }
+
+    #[test]
+    fn test_unknown_country_is_kept_as_given() {
+        let sut = VatNumber::from_str(" US 12-3456789 ").unwrap();
+        assert_eq!(sut.to_string(), "US 12-3456789");
+        assert_eq!(sut.country_code(), Some("US"));
+    }
+
+    #[test]
+    fn test_roundtrip_ron() {
+        let sut = VatNumber::sample_vendor();
+        let ron = ron::to_string(&sut).unwrap();
+        assert_eq!(ron, "\"FR81185293012\"");
+        assert_eq!(ron::from_str::<VatNumber>(&ron).unwrap(), sut);
+    }
+}
@@ -0,0 +1,49 @@
+use crate::prelude::*;
+
+/// The invoice number as printed on the invoice, formatted according to the
+/// [`InvoiceNumbering`] scheme, e.g. `"2025-043"` or just `"243"`.
+#[derive(Clone, Debug, Display, Serialize, Deserialize, PartialEq, Eq, Hash, From)]
+#[serde(transparent)]
+pub struct InvoiceIdentifier(String);
+
+impl InvoiceIdentifier {
+    /// This identifier with every character but ASCII letters, digits, `.`,
+    /// `_` and `-` replaced by `-`, making it safe to use in file names on
+    /// every platform, since the prefix of the format is free text.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let identifier = InvoiceIdentifier::from("ACME/2025/043".to_owned());
+    /// assert_eq!(identifier.file_name_safe(), "ACME-2025-043");
+    /// let identifier = InvoiceIdentifier::from(r#"Q3: "ACME" <EU>|*?\243"#.to_owned());
+    /// assert_eq!(identifier.file_name_safe(), "Q3---ACME---EU-----243");
+    /// ```
+    pub fn file_name_safe(&self) -> String {
+        self.0
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-') {
+                    c
+                } else {
+                    '-'
+                }
+            })
+            .collect()
+    }
+}
+
+impl From<InvoiceNumber> for InvoiceIdentifier {
+    /// The identifier of an invoice numbered using the default scheme, being
+    /// just the number.
+    fn from(number: InvoiceNumber) -> Self {
+        Self(number.to_string())
+    }
+}
+
+impl HasSample for InvoiceIdentifier {
+    fn sample() -> Self {
+        Self::from(InvoiceNumber::sample())
+    }
+}
@@ -10,6 +10,12 @@ pub struct InvoiceInfoFull {
     #[getset(get = "pub")]
     number: InvoiceNumber,
 
+    /// The number as printed on the invoice, formatted according to the
+    /// numbering scheme, e.g. `"2025-043"`.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    identifier: InvoiceIdentifier,
+
     /// When the payment is due, calculated from the invoice date and payment terms.
     #[builder(setter(into))]
     #[getset(get = "pub")]
@@ -36,12 +42,32 @@ pub struct InvoiceInfoFull {
     #[builder(setter(into), default)]
     #[getset(get = "pub")]
     emphasize_color_hex: HexColor,
+
+    /// The VAT rate charged on the line items, e.g. `25%`, `0%` if no VAT.
+    #[builder(setter(into), default)]
+    #[getset(get = "pub")]
+    #[serde(default)]
+    vat_rate: Percentage,
+
+    /// The days off in the invoiced month, listed on invoices for services,
+    /// e.g. `["2025-07-01..2025-07-18"]`.
+    #[builder(setter(into), default)]
+    #[getset(get = "pub")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    days_off: Vec<DateRange>,
+
+    /// Set only when rendering a reminder of this invoice, being overdue.
+    #[builder(setter(into), default)]
+    #[getset(get = "pub")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    reminder: Option<ReminderInfo>,
 }
 
 impl InvoiceInfoFull {
     pub fn sample() -> Self {
         Self::builder()
             .number(InvoiceNumber::sample())
+            .identifier(InvoiceIdentifier::sample())
             .invoice_date(Date::sample())
             .due_date(Date::sample())
             .purchase_order(PurchaseOrder::sample())
@@ -0,0 +1,29 @@
+use crate::prelude::*;
+
+/// The kind of an issued invoice, either for services or for expenses, or
+/// for both combined.
+#[derive(Clone, Copy, Debug, Display, Serialize, Deserialize, PartialEq, Eq, Hash, IsVariant)]
+pub enum InvoiceKind {
+    /// An invoice for services, e.g. consulting days worked.
+    Services,
+    /// An invoice for expenses, e.g. travel expenses.
+    Expenses,
+    /// An invoice for both the services and the expenses of a month.
+    Combined,
+}
+
+impl From<&InvoicedItems> for InvoiceKind {
+    fn from(items: &InvoicedItems) -> Self {
+        match items {
+            InvoicedItems::Service { .. } => Self::Services,
+            InvoicedItems::Expenses => Self::Expenses,
+            InvoicedItems::Combined { .. } => Self::Combined,
+        }
+    }
+}
+
+impl HasSample for InvoiceKind {
+    fn sample() -> Self {
+        Self::Services
+    }
+}
@@ -1,7 +1,22 @@
 use crate::prelude::*;
 
 /// A unique number for the invoice, e.g. `90`
-#[derive(Clone, Debug, Default, Display, Serialize, Deserialize, PartialEq, Eq, From, Deref)]
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Display,
+    Serialize,
+    Deserialize,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    From,
+    Deref,
+)]
 #[serde(transparent)]
 pub struct InvoiceNumber(u16);
 
@@ -29,6 +44,77 @@ impl std::str::FromStr for InvoiceNumber {
     }
 }
 
+/// Returns the Luhn (modulus 10) check digit for the digits in `payload`.
+fn luhn_check_digit(payload: &str) -> u32 {
+    let sum: u32 = payload
+        .chars()
+        .rev()
+        .filter_map(|c| c.to_digit(10))
+        .enumerate()
+        .map(|(index, digit)| {
+            if index % 2 == 0 {
+                let doubled = digit * 2;
+                if doubled > 9 { doubled - 9 } else { doubled }
+            } else {
+                digit
+            }
+        })
+        .sum();
+    (10 - sum % 10) % 10
+}
+
+impl InvoiceNumber {
+    /// Returns the OCR reference of this invoice number, as used by Swedish
+    /// banks, being the number followed by a Luhn check digit.
+    ///
+    /// # Examples
+    /// ```
+    /// use klirr_core::prelude::*;
+    /// assert_eq!(InvoiceNumber::from(243).ocr_reference(), "2436");
+    /// ```
+    pub fn ocr_reference(&self) -> String {
+        let payload = self.0.to_string();
+        format!("{}{}", payload, luhn_check_digit(&payload))
+    }
+
+    /// Returns the OCR reference of this invoice number including a length
+    /// digit, being the number followed by the length of the reference
+    /// (modulo 10) and a Luhn check digit.
+    ///
+    /// # Examples
+    /// ```
+    /// use klirr_core::prelude::*;
+    /// assert_eq!(InvoiceNumber::from(243).ocr_reference_with_length_digit(), "24356");
+    /// ```
+    pub fn ocr_reference_with_length_digit(&self) -> String {
+        let number = self.0.to_string();
+        let payload = format!("{}{}", number, (number.len() + 2) % 10);
+        format!("{}{}", payload, luhn_check_digit(&payload))
+    }
+
+    /// Returns true if the free text payment `reference` refers to this
+    /// invoice, i.e. if it contains a number which is either this invoice
+    /// number or its OCR reference, with or without length digit.
+    ///
+    /// # Examples
+    /// ```
+    /// use klirr_core::prelude::*;
+    /// let number = InvoiceNumber::from(243);
+    /// assert!(number.is_referenced_by("Invoice 243"));
+    /// assert!(number.is_referenced_by("2436"));
+    /// assert!(!number.is_referenced_by("Invoice 2430"));
+    /// ```
+    pub fn is_referenced_by(&self, reference: &str) -> bool {
+        let number = self.0.to_string();
+        let ocr = self.ocr_reference();
+        let ocr_with_length = self.ocr_reference_with_length_digit();
+        reference
+            .split(|c: char| !c.is_ascii_digit())
+            .map(|token| token.trim_start_matches('0'))
+            .any(|token| token == number || token == ocr || token == ocr_with_length)
+    }
+}
+
 impl HasSample for InvoiceNumber {
     fn sample() -> Self {
         Self::from(9876)
@@ -45,6 +131,25 @@ mod tests {
         assert_eq!(*sample, 9876);
     }
 
+    #[test]
+    fn test_ocr_reference_is_luhn_valid() {
+        for number in [1u16, 9, 42, 243, 9876, u16::MAX] {
+            let ocr = InvoiceNumber::from(number).ocr_reference();
+            let payload = &ocr[..ocr.len() - 1];
+            let check = ocr.chars().last().unwrap().to_digit(10).unwrap();
+            assert_eq!(luhn_check_digit(payload), check);
+        }
+    }
+
+    #[test]
+    fn test_is_referenced_by_ocr_with_length_digit() {
+        let number = InvoiceNumber::from(243);
+        assert!(number.is_referenced_by("OCR 24356"));
+        assert!(number.is_referenced_by("Faktura nr: 00243"));
+        assert!(!number.is_referenced_by("Faktura 244"));
+        assert!(!number.is_referenced_by(""));
+    }
+
     #[test]
     fn test_invoice_number_default_is_zero() {
         let default = InvoiceNumber::default();
@@ -0,0 +1,88 @@
+use crate::prelude::*;
+use rust_decimal::RoundingStrategy;
+
+/// The totals of an invoice: net amount, VAT and gross amount, all in the
+/// currency of the invoice.
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, TypedBuilder, Getters,
+)]
+pub struct InvoiceTotals {
+    /// The sum of all line items, excluding VAT.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    net: Cost,
+
+    /// The VAT charged on the net amount.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    vat: Cost,
+
+    /// The amount to pay, net plus VAT.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    gross: Cost,
+}
+
+impl InvoiceTotals {
+    /// Calculates the totals for `net` with VAT at `vat_rate`, where VAT is
+    /// rounded to two decimals, half away from zero, just like the layouts do.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let totals = InvoiceTotals::new(Cost::from(dec!(1000.10)), Percentage::from(dec!(25)));
+    /// assert_eq!(*totals.vat(), Cost::from(dec!(250.03)));
+    /// assert_eq!(*totals.gross(), Cost::from(dec!(1250.13)));
+    /// ```
+    pub fn new(net: Cost, vat_rate: Percentage) -> Self {
+        let vat = (*net * vat_rate.as_fraction())
+            .round_dp_with_strategy(2, RoundingStrategy::MidpointAwayFromZero);
+        Self {
+            net,
+            vat: Cost::from(vat),
+            gross: Cost::from(*net + vat),
+        }
+    }
+}
+
+impl std::ops::Add for InvoiceTotals {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            net: Cost::from(*self.net + *rhs.net),
+            vat: Cost::from(*self.vat + *rhs.vat),
+            gross: Cost::from(*self.gross + *rhs.gross),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn test_no_vat() {
+        let totals = InvoiceTotals::new(Cost::from(dec!(9324)), Percentage::ZERO);
+        assert_eq!(*totals.vat(), Cost::from(dec!(0)));
+        assert_eq!(totals.gross(), totals.net());
+    }
+
+    #[test]
+    fn test_vat_rounds_half_away_from_zero() {
+        // 0.50 * 25% = 0.125
+        let totals = InvoiceTotals::new(Cost::from(dec!(0.50)), Percentage::from(dec!(25)));
+        assert_eq!(*totals.vat(), Cost::from(dec!(0.13)));
+    }
+
+    #[test]
+    fn test_add() {
+        let lhs = InvoiceTotals::new(Cost::from(dec!(100)), Percentage::from(dec!(25)));
+        let rhs = InvoiceTotals::new(Cost::from(dec!(50)), Percentage::ZERO);
+        let sum = lhs + rhs;
+        assert_eq!(*sum.net(), Cost::from(dec!(150)));
+        assert_eq!(*sum.vat(), Cost::from(dec!(25)));
+        assert_eq!(*sum.gross(), Cost::from(dec!(175)));
+    }
+}
@@ -1,12 +1,17 @@
 use crate::prelude::*;
 
-/// The items being invoiced this month, either services or expenses.
+/// The items being invoiced this month, either services or expenses, or
+/// both combined into a single invoice.
 #[derive(Clone, Debug, Display, Serialize, Deserialize, IsVariant, PartialEq)]
 pub enum InvoicedItems {
     #[display("Service {{ days_off: {} }} ", days_off.map(|d| *d).unwrap_or(0))]
     Service { days_off: Option<Day> },
     #[display("Expenses")]
     Expenses,
+    /// The services and the expenses of the month on a single invoice,
+    /// numbered like the services invoice.
+    #[display("Combined {{ days_off: {} }} ", days_off.map(|d| *d).unwrap_or(0))]
+    Combined { days_off: Option<Day> },
 }
 impl MaybeIsExpenses for InvoicedItems {
     fn is_expenses(&self) -> bool {
@@ -14,6 +19,16 @@ impl MaybeIsExpenses for InvoicedItems {
     }
 }
 
+impl InvoicedItems {
+    /// The number of days off in the month, if the items include services.
+    pub fn days_off(&self) -> Option<Day> {
+        match self {
+            Self::Service { days_off } | Self::Combined { days_off } => *days_off,
+            Self::Expenses => None,
+        }
+    }
+}
+
 impl Default for InvoicedItems {
     fn default() -> Self {
         Self::Service { days_off: None }
@@ -39,5 +54,8 @@ mod tests {
         assert!(!MaybeIsExpenses::is_expenses(&InvoicedItems::Service {
             days_off: None
         }));
+        assert!(!MaybeIsExpenses::is_expenses(&InvoicedItems::Combined {
+            days_off: None
+        }));
     }
 }
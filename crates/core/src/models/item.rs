@@ -49,6 +49,18 @@ macro_rules! define_item_struct {
             #[builder(setter(into))]
             #[getset(get = "pub")]
             transaction_date: Date,
+            /// The receipt of the expense, a PDF, JPG or PNG file in the
+            /// `receipts` folder of the data directory, e.g. `"coffee.pdf"`
+            #[builder(default, setter(into))]
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            #[getset(get = "pub")]
+            receipt: Option<PathBuf>,
+            /// Whether this is an expense billed every month, merged from a
+            /// [`RecurringExpense`] rather than recorded for the month.
+            #[builder(default)]
+            #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+            #[getset(get = "pub")]
+            recurring: bool,
         }
     };
 }
@@ -167,21 +179,41 @@ impl Item {
             .unit_price(converted_unit_price)
             .quantity(self.quantity)
             .currency(*exchange_rates.target_currency())
+            .receipt(self.receipt)
+            .recurring(self.recurring)
             .build())
     }
+
+    /// Returns this item with `receipt` attached, a file in the `receipts`
+    /// folder of the data directory.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let item = Item::sample_expense_coffee().with_receipt("coffee.pdf");
+    /// assert_eq!(item.receipt(), &Some(PathBuf::from("coffee.pdf")));
+    /// ```
+    pub fn with_receipt(self, receipt: impl Into<PathBuf>) -> Self {
+        Self {
+            receipt: Some(receipt.into()),
+            ..self
+        }
+    }
 }
 
 impl FromStr for Item {
     type Err = crate::prelude::Error;
 
     /// Parses a string in the format: "name, unit_price, currency, quantity, transaction_date", or
-    /// without spaces after commas, even mixed, e.g. "Coffee, 2.5,EUR, 3.0,2025-05-31".
+    /// without spaces after commas, even mixed, e.g. "Coffee, 2.5,EUR, 3.0,2025-05-31", optionally
+    /// followed by a receipt file in the `receipts` folder, e.g. "Coffee, 2.5, EUR, 3.0, 2025-05-31, coffee.pdf".
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let parts: Vec<&str> = s.split(',').map(str::trim).collect();
-        if parts.len() != 5 {
+        if parts.len() != 5 && parts.len() != 6 {
             return Err(Error::InvalidExpenseItem {
                 invalid_string: s.to_string(),
-                reason: "Expected 5 comma-separated values, on format: \"Coffee, 2.5, EUR, 3.0, 2025-05-31\"".to_string(),
+                reason: "Expected 5 or 6 comma-separated values, on format: \"Coffee, 2.5, EUR, 3.0, 2025-05-31\" or \"Coffee, 2.5, EUR, 3.0, 2025-05-31, coffee.pdf\"".to_string(),
             });
         }
 
@@ -218,12 +250,25 @@ impl FromStr for Item {
             reason: format!("Failed to parse transaction_date: {e}"),
         })?;
 
+        let receipt = parts
+            .get(5)
+            .map(|receipt| {
+                let receipt = PathBuf::from(receipt);
+                ReceiptFormat::from_path(&receipt).map(|_| receipt)
+            })
+            .transpose()
+            .map_err(|e| Error::InvalidExpenseItem {
+                invalid_string: s.to_string(),
+                reason: format!("Failed to parse receipt: {e}"),
+            })?;
+
         Ok(Item::builder()
             .name(name)
             .unit_price(unit_price)
             .currency(currency)
             .quantity(quantity)
             .transaction_date(transaction_date)
+            .receipt(receipt)
             .build())
     }
 }
@@ -248,6 +293,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_from_str_with_receipt() {
+        let sut = Item::from_str("Coffee,2.5, EUR,3.0, 2025-05-31, receipts 05/coffee.PDF")
+            .expect("Failed to parse Item");
+        assert_eq!(
+            sut.receipt(),
+            &Some(PathBuf::from("receipts 05/coffee.PDF"))
+        );
+        assert_eq!(
+            sut.with_receipt("coffee.jpg"),
+            Item::from_str("Coffee,2.5,EUR,3.0,2025-05-31,coffee.jpg").unwrap()
+        );
+    }
+
     #[test]
     fn inequal() {
         let item1 = Item::sample_expense_coffee();
@@ -266,6 +325,7 @@ mod tests {
             "Coffee,2.5, invalid_currency,3.0, 2025-05-31", // Invalid currency
             "Coffee,2.5, EUR,-3.0, 2025-05-31",             // Negative quantity
             "Coffee,2.5, EUR,a, 2025-05-31",                // Negative quantity
+            "Coffee,2.5, EUR,3.0, 2025-05-31, coffee.txt",  // Unsupported receipt
         ];
 
         for &s in &invalid_strings {
@@ -21,6 +21,14 @@ pub struct L18nContent {
     #[getset(get = "pub")]
     line_items: L18nLineItems,
 
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    reminder: L18nReminder,
+
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    email: L18nEmail,
+
     #[builder(setter(into))]
     #[getset(get = "pub")]
     month_names: [String; 12],
@@ -33,6 +41,8 @@ impl L18nContent {
             .invoice_info(L18nInvoiceInfo::english())
             .vendor_info(L18nVendorInfo::english())
             .line_items(L18nLineItems::english())
+            .reminder(L18nReminder::english())
+            .email(L18nEmail::english())
             .month_names([
                 "January".to_string(),
                 "February".to_string(),
@@ -0,0 +1,26 @@
+use crate::prelude::*;
+
+/// Localization for the email sending an invoice, the placeholders
+/// `{number}`, `{period}`, `{total}`, `{due_date}` and `{signature}` are
+/// replaced with the values of the invoice.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters, TypedBuilder)]
+pub struct L18nEmail {
+    /// EN: "Invoice {number} – {period}"
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    subject: String,
+
+    /// EN: "Hello,\n\nPlease find attached invoice {number} for {period}..."
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    body: String,
+}
+
+impl L18nEmail {
+    pub fn english() -> Self {
+        Self::builder()
+            .subject("Invoice {number} – {period}".to_string())
+            .body("Hello,\n\nPlease find attached invoice {number} for {period} of {total}, due {due_date}.\n\nBest regards,\n{signature}".to_string())
+            .build()
+    }
+}
@@ -38,6 +38,11 @@ pub struct L18nInvoiceInfo {
     #[builder(setter(into))]
     #[getset(get = "pub")]
     terms: String,
+
+    /// EN: "Days off:"
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    days_off: String,
 }
 
 impl L18nInvoiceInfo {
@@ -50,6 +55,7 @@ impl L18nInvoiceInfo {
             .client_contact("For the attention of:".to_string())
             .vendor_contact("Our reference:".to_string())
             .terms("Terms:".to_string())
+            .days_off("Days off:".to_string())
             .build()
     }
 }
@@ -29,10 +29,65 @@ pub struct L18nLineItems {
     #[getset(get = "pub")]
     total_cost: String,
 
+    /// EN: "Net total:", shown only if VAT is charged.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    net_total: String,
+
+    /// EN: "VAT", shown only if VAT is charged.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    vat: String,
+
     /// EN: "Grand Total:"
     #[builder(setter(into))]
     #[getset(get = "pub")]
     grand_total: String,
+
+    /// EN: "Receipt", the reference of a line item to its receipt, and the
+    /// heading of the receipt appended to the invoice.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    receipt: String,
+
+    /// EN: "Services", the heading of the services of an invoice combining
+    /// services and expenses.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    services: String,
+
+    /// EN: "Expenses", the heading of the expenses of an invoice combining
+    /// services and expenses.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    expenses: String,
+
+    /// EN: "Subtotal:", the sum of each section of an invoice combining
+    /// services and expenses.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    subtotal: String,
+
+    /// EN: "Discount", the label of a row lowering the price.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    discount: String,
+
+    /// EN: "Surcharge", the label of a row raising the price.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    surcharge: String,
+
+    /// EN: "Credit", the label of a row deducting an amount owed to the
+    /// client.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    credit: String,
+
+    /// EN: "Recurring", marking an expense billed every month.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    recurring: String,
 }
 
 impl L18nLineItems {
@@ -43,7 +98,17 @@ impl L18nLineItems {
             .quantity("Quantity".to_string())
             .unit_price("Unit price".to_string())
             .total_cost("Total cost".to_string())
+            .net_total("Net total:".to_string())
+            .vat("VAT".to_string())
             .grand_total("Grand Total:".to_string())
+            .receipt("Receipt".to_string())
+            .services("Services".to_string())
+            .expenses("Expenses".to_string())
+            .subtotal("Subtotal:".to_string())
+            .discount("Discount".to_string())
+            .surcharge("Surcharge".to_string())
+            .credit("Credit".to_string())
+            .recurring("Recurring".to_string())
             .build()
     }
 }
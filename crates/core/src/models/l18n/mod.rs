@@ -1,16 +1,20 @@
 mod client_info;
 mod content;
+mod email;
 mod invoice_info;
 mod language;
 mod line_items;
 mod localization;
+mod reminder;
 mod swedish;
 mod vendor_info;
 
 pub use client_info::*;
 pub use content::*;
+pub use email::*;
 pub use invoice_info::*;
 pub use language::*;
 pub use line_items::*;
 pub use localization::*;
+pub use reminder::*;
 pub use vendor_info::*;
@@ -0,0 +1,61 @@
+use crate::prelude::*;
+
+/// Localization for reminders of overdue invoices, used in the reminder
+/// layout and for the line items added to the reminder.
+#[derive(Debug, Clone, Serialize, Deserialize, Getters, TypedBuilder)]
+pub struct L18nReminder {
+    /// EN: "Payment reminder"
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    title: String,
+
+    /// EN: "According to our records the invoice below has not been paid..."
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    text: String,
+
+    /// EN: "Reminder date:"
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    reminder_date: String,
+
+    /// EN: "Days overdue:"
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    days_overdue: String,
+
+    /// EN: "Outstanding amount, invoice"
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    outstanding_amount: String,
+
+    /// EN: "Late interest"
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    late_interest: String,
+
+    /// EN: "days"
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    days: String,
+
+    /// EN: "Reminder fee"
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    reminder_fee: String,
+}
+
+impl L18nReminder {
+    pub fn english() -> Self {
+        Self::builder()
+            .title("Payment reminder".to_string())
+            .text("According to our records the invoice below has not been paid. Please pay the outstanding amount, including late interest, as soon as possible. If you have already paid, please disregard this reminder.".to_string())
+            .reminder_date("Reminder date:".to_string())
+            .days_overdue("Days overdue:".to_string())
+            .outstanding_amount("Outstanding amount, invoice".to_string())
+            .late_interest("Late interest".to_string())
+            .days("days".to_string())
+            .reminder_fee("Reminder fee".to_string())
+            .build()
+    }
+}
@@ -28,6 +28,7 @@ impl L18nInvoiceInfo {
             .client_contact("Er referens:".to_string())
             .vendor_contact("Vår referens:".to_string())
             .terms("Villkor".to_string())
+            .days_off("Lediga dagar:".to_string())
             .build()
     }
 }
@@ -53,7 +54,41 @@ impl L18nLineItems {
             .quantity("Antal".to_string())
             .unit_price("Enhetspris".to_string())
             .total_cost("Kostnad".to_string())
+            .net_total("Summa exkl. moms:".to_string())
+            .vat("Moms".to_string())
             .grand_total("Totalt:".to_string())
+            .receipt("Kvitto".to_string())
+            .services("Tjänster".to_string())
+            .expenses("Utlägg".to_string())
+            .subtotal("Delsumma:".to_string())
+            .discount("Rabatt".to_string())
+            .surcharge("Tillägg".to_string())
+            .credit("Kreditering".to_string())
+            .recurring("Återkommande".to_string())
+            .build()
+    }
+}
+
+impl L18nReminder {
+    pub fn swedish() -> Self {
+        Self::builder()
+            .title("Betalningspåminnelse".to_string())
+            .text("Enligt våra noteringar har nedanstående faktura inte betalats. Vänligen betala det utestående beloppet, inklusive dröjsmålsränta, snarast. Om betalning redan har skett kan ni bortse från denna påminnelse.".to_string())
+            .reminder_date("Påminnelsedatum:".to_string())
+            .days_overdue("Dagar försenad:".to_string())
+            .outstanding_amount("Utestående belopp, faktura".to_string())
+            .late_interest("Dröjsmålsränta".to_string())
+            .days("dagar".to_string())
+            .reminder_fee("Påminnelseavgift".to_string())
+            .build()
+    }
+}
+
+impl L18nEmail {
+    pub fn swedish() -> Self {
+        Self::builder()
+            .subject("Faktura {number} – {period}".to_string())
+            .body("Hej,\n\nBifogat finns faktura {number} för {period} på {total}, att betala senast {due_date}.\n\nVänliga hälsningar,\n{signature}".to_string())
             .build()
     }
 }
@@ -65,6 +100,8 @@ impl L18nContent {
             .invoice_info(L18nInvoiceInfo::swedish())
             .vendor_info(L18nVendorInfo::swedish())
             .line_items(L18nLineItems::swedish())
+            .reminder(L18nReminder::swedish())
+            .email(L18nEmail::swedish())
             .month_names([
                 "Januari".to_string(),
                 "Februari".to_string(),
@@ -4,11 +4,14 @@ use derive_more::FromStr;
 /// The Typst layout "Aioo" as a string.
 const TYPST_LAYOUT_AIOO: &str = include_str!("../../layouts/aioo.typ");
 
+/// The Typst layout for reminders of overdue invoices as a string.
+const TYPST_LAYOUT_REMINDER: &str = include_str!("../../layouts/reminder.typ");
+
 /// A layout used for testing only.
 const TYPST_LAYOUT_TEST: &str = include_str!("../../layouts/test.typ");
 
 /// Represents different Typst layouts used to render the invoice.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Display, Default, FromStr, EnumIter)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display, Default, FromStr, EnumIter)]
 pub enum Layout {
     /// Originally created by [Andreas Lundblad][author], see his
     /// [blog post][blog] presenting his [Latex Template][latex].
@@ -19,6 +22,10 @@ pub enum Layout {
     #[default]
     Aioo,
 
+    /// A reminder of an overdue invoice, in the style of `Aioo`, listing the
+    /// outstanding amount, late interest and any reminder fee.
+    Reminder,
+
     /// A Test layout to test if CMU font is installed.
     Test,
 }
@@ -28,6 +35,7 @@ impl ToTypstFn for Layout {
     fn to_typst_fn(&self) -> String {
         match self {
             Self::Aioo => TYPST_LAYOUT_AIOO.to_string(),
+            Self::Reminder => TYPST_LAYOUT_REMINDER.to_string(),
             Self::Test => TYPST_LAYOUT_TEST.to_string(),
         }
     }
@@ -36,7 +44,7 @@ impl ToTypstFn for Layout {
 impl Layout {
     pub fn required_fonts(&self) -> IndexSet<FontIdentifier> {
         match self {
-            Self::Aioo => {
+            Self::Aioo | Self::Reminder => {
                 let mut fonts = IndexSet::new();
                 fonts.insert(FontIdentifier::ComputerModern(FontWeight::Regular));
                 fonts.insert(FontIdentifier::ComputerModern(FontWeight::Bold));
@@ -0,0 +1,229 @@
+use crate::prelude::*;
+
+/// A record of all invoices issued by klirr, sorted by invoice number, saved
+/// in the data directory.
+///
+/// Several invoices can have the same number, e.g. with the default
+/// numbering the expenses invoice of one month has the same number as the
+/// services invoice of the next month, so invoices are told apart by their
+/// number, kind and month.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct InvoiceLedger {
+    issued: Vec<IssuedInvoice>,
+}
+
+impl InvoiceLedger {
+    /// Records an issued invoice, replacing any earlier record of the same
+    /// invoice, i.e. with the same number, kind and month, since generating
+    /// the same invoice again yields the same number.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let mut ledger = InvoiceLedger::default();
+    /// ledger.record(IssuedInvoice::sample());
+    /// ledger.record(IssuedInvoice::sample());
+    /// assert_eq!(ledger.iter().count(), 1);
+    /// ```
+    pub fn record(&mut self, invoice: IssuedInvoice) {
+        match self
+            .issued
+            .iter_mut()
+            .find(|issued| issued.is_same_invoice_as(&invoice))
+        {
+            Some(issued) => *issued = invoice,
+            None => self.issued.push(invoice),
+        }
+        self.issued
+            .sort_by_key(|issued| (*issued.number(), *issued.invoice_date()));
+    }
+
+    /// Looks up the issued invoice with the given `number`, and `kind` if
+    /// several invoices have that number.
+    ///
+    /// # Errors
+    /// Returns an error if no invoice with that number, and kind, has been
+    /// recorded, or if several have and `kind` does not tell them apart.
+    pub fn get(&self, number: &InvoiceNumber, kind: Option<InvoiceKind>) -> Result<&IssuedInvoice> {
+        let mut matching = self.issued.iter().filter(|issued| {
+            issued.number() == number && kind.is_none_or(|kind| *issued.kind() == kind)
+        });
+        match (matching.next(), matching.next()) {
+            (Some(issued), None) => Ok(issued),
+            (Some(_), Some(_)) => Err(Error::AmbiguousInvoiceNumber { number: *number }),
+            (None, _) => Err(Error::InvoiceNotFoundInLedger { number: *number }),
+        }
+    }
+
+    /// Returns an iterator over all issued invoices, sorted by number.
+    pub fn iter(&self) -> impl Iterator<Item = &IssuedInvoice> {
+        self.issued.iter()
+    }
+
+    /// Returns the highest number of all issued invoices, if any.
+    pub fn last_number(&self) -> Option<InvoiceNumber> {
+        self.issued.last().map(|issued| *issued.number())
+    }
+
+    /// Returns the invoice of `kind` issued for `month` with the highest
+    /// number, if any.
+    pub fn issued_for(&self, month: &YearAndMonth, kind: InvoiceKind) -> Option<&IssuedInvoice> {
+        self.issued
+            .iter()
+            .rev()
+            .find(|invoice| invoice.month() == *month && *invoice.kind() == kind)
+    }
+
+    /// Returns an invoice issued for `month` which an invoice of `kind` would
+    /// bill twice, i.e. a services or expenses invoice if `kind` is combined,
+    /// or a combined invoice otherwise, if any.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let ledger = InvoiceLedger::sample();
+    /// let may = YearAndMonth::may(2025);
+    /// assert!(ledger.conflicting_with(&may, InvoiceKind::Combined).is_some());
+    /// assert!(ledger.conflicting_with(&may, InvoiceKind::Expenses).is_none());
+    /// ```
+    pub fn conflicting_with(
+        &self,
+        month: &YearAndMonth,
+        kind: InvoiceKind,
+    ) -> Option<&IssuedInvoice> {
+        self.issued.iter().find(|invoice| {
+            invoice.month() == *month && invoice.kind().is_combined() != kind.is_combined()
+        })
+    }
+
+    /// Returns the lowest number of the invoices dated in `year`, if any.
+    pub fn first_number_in(&self, year: &Year) -> Option<InvoiceNumber> {
+        self.iter()
+            .find(|invoice| invoice.invoice_date().year() == year)
+            .map(|invoice| *invoice.number())
+    }
+
+    /// Returns the numbers missing between the lowest and highest number of
+    /// the issued invoices, in ascending order.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let mut ledger = InvoiceLedger::sample();
+    /// assert!(ledger.gaps().is_empty());
+    /// ledger.record(
+    ///     IssuedInvoice::builder()
+    ///         .number(InvoiceNumber::from(246))
+    ///         .kind(InvoiceKind::Expenses)
+    ///         .invoice_date(Date::sample())
+    ///         .due_date(Date::sample())
+    ///         .currency(Currency::EUR)
+    ///         .total(Cost::sample())
+    ///         .build(),
+    /// );
+    /// assert_eq!(ledger.gaps(), vec![InvoiceNumber::from(244), InvoiceNumber::from(245)]);
+    /// ```
+    pub fn gaps(&self) -> Vec<InvoiceNumber> {
+        self.issued
+            .iter()
+            .zip(self.issued.iter().skip(1))
+            .flat_map(|(lower, higher)| (**lower.number() + 1)..**higher.number())
+            .map(InvoiceNumber::from)
+            .collect()
+    }
+}
+
+impl HasSample for InvoiceLedger {
+    fn sample() -> Self {
+        let mut ledger = Self::default();
+        ledger.record(IssuedInvoice::sample());
+        ledger
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    fn issued(number: u16, kind: InvoiceKind, date: &str) -> IssuedInvoice {
+        IssuedInvoice::builder()
+            .number(InvoiceNumber::from(number))
+            .kind(kind)
+            .invoice_date(Date::from_str(date).unwrap())
+            .due_date(Date::from_str(date).unwrap())
+            .currency(Currency::EUR)
+            .total(Cost::sample())
+            .build()
+    }
+
+    #[test]
+    fn test_get_found() {
+        let ledger = InvoiceLedger::sample();
+        let number = InvoiceNumber::from(243);
+        assert_eq!(*ledger.get(&number, None).unwrap().number(), number);
+    }
+
+    #[test]
+    fn test_get_not_found() {
+        let ledger = InvoiceLedger::sample();
+        let result = ledger.get(&InvoiceNumber::from(1), None);
+        assert!(matches!(result, Err(Error::InvoiceNotFoundInLedger { .. })));
+    }
+
+    #[test]
+    fn test_record_keeps_sorted() {
+        let mut ledger = InvoiceLedger::default();
+        ledger.record(
+            IssuedInvoice::builder()
+                .number(InvoiceNumber::from(2))
+                .kind(InvoiceKind::Expenses)
+                .invoice_date(Date::sample())
+                .due_date(Date::sample())
+                .currency(Currency::EUR)
+                .total(Cost::sample())
+                .build(),
+        );
+        ledger.record(IssuedInvoice::sample());
+        ledger.record(
+            IssuedInvoice::builder()
+                .number(InvoiceNumber::from(1))
+                .kind(InvoiceKind::Services)
+                .invoice_date(Date::sample())
+                .due_date(Date::sample())
+                .currency(Currency::EUR)
+                .total(Cost::sample())
+                .build(),
+        );
+        let numbers = ledger.iter().map(|i| **i.number()).collect::<Vec<_>>();
+        assert_eq!(numbers, vec![1, 2, 243]);
+    }
+
+    #[test]
+    fn test_record_both_kinds_over_consecutive_months() {
+        // With the default numbering May's expenses invoice and June's
+        // services invoice both get number 244.
+        let mut ledger = InvoiceLedger::default();
+        ledger.record(issued(243, InvoiceKind::Services, "2025-05-31"));
+        ledger.record(issued(244, InvoiceKind::Expenses, "2025-05-31"));
+        ledger.record(issued(244, InvoiceKind::Services, "2025-06-30"));
+        ledger.record(issued(245, InvoiceKind::Expenses, "2025-06-30"));
+        // Generating June's services invoice again replaces its record.
+        ledger.record(issued(244, InvoiceKind::Services, "2025-06-30"));
+
+        assert_eq!(ledger.iter().count(), 4);
+        let number = InvoiceNumber::from(244);
+        assert!(matches!(
+            ledger.get(&number, None),
+            Err(Error::AmbiguousInvoiceNumber { .. })
+        ));
+        let expenses = ledger.get(&number, Some(InvoiceKind::Expenses)).unwrap();
+        assert_eq!(expenses.month(), YearAndMonth::may(2025));
+        let services = ledger.get(&number, Some(InvoiceKind::Services)).unwrap();
+        assert_eq!(services.month(), YearAndMonth::june(2025));
+        assert!(ledger.gaps().is_empty());
+    }
+}
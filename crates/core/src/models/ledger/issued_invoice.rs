@@ -0,0 +1,152 @@
+use crate::prelude::*;
+
+/// An invoice which has been issued, i.e. rendered into a PDF, recorded in
+/// the [`InvoiceLedger`] so that we can later follow up on it, e.g. send a
+/// reminder if it is overdue.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, TypedBuilder, Getters)]
+pub struct IssuedInvoice {
+    /// The number of the invoice, e.g. `243`.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    number: InvoiceNumber,
+
+    /// The number as printed on the invoice, e.g. `"2025-043"`, missing for
+    /// invoices issued before numbering schemes were introduced.
+    #[builder(setter(into), default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    identifier: Option<InvoiceIdentifier>,
+
+    /// Whether the invoice was for services or expenses, or both.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    kind: InvoiceKind,
+
+    /// The date of the invoice, e.g. `2025-05-31`.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    invoice_date: Date,
+
+    /// When the payment is due, e.g. `2025-06-30`.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    due_date: Date,
+
+    /// The currency of the invoice, e.g. `EUR`.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    currency: Currency,
+
+    /// The total amount to pay of the invoice, including any VAT, in `currency`.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    total: Cost,
+}
+
+impl IssuedInvoice {
+    /// The number as printed on the invoice, e.g. `"2025-043"`, being just
+    /// the number for invoices issued before numbering schemes were
+    /// introduced.
+    pub fn identifier(&self) -> InvoiceIdentifier {
+        self.identifier
+            .clone()
+            .unwrap_or_else(|| InvoiceIdentifier::from(self.number))
+    }
+
+    /// Returns true if the free text payment `reference` refers to this
+    /// invoice, by its number or OCR reference, see
+    /// [`InvoiceNumber::is_referenced_by`], or by the number printed on the
+    /// invoice, e.g. `"Invoice ACME-2025-043"`, as a whole word, so that
+    /// `"ACME-2025-0430"` does not refer to `"ACME-2025-043"`.
+    pub fn is_referenced_by(&self, reference: &str) -> bool {
+        self.number.is_referenced_by(reference)
+            || self.identifier.as_ref().is_some_and(|identifier| {
+                let identifier = identifier.to_string();
+                reference.match_indices(&identifier).any(|(start, _)| {
+                    let before = reference[..start].chars().next_back();
+                    let after = reference[start + identifier.len()..].chars().next();
+                    !before.is_some_and(char::is_alphanumeric)
+                        && !after.is_some_and(char::is_alphanumeric)
+                })
+            })
+    }
+
+    /// Returns true if `other` is a record of the same invoice, i.e. of the
+    /// same number, kind and month, e.g. when generating an invoice again.
+    pub fn is_same_invoice_as(&self, other: &Self) -> bool {
+        self.number == other.number && self.kind == other.kind && self.month() == other.month()
+    }
+
+    /// The month the invoice was issued for, e.g. `2025-05`.
+    pub fn month(&self) -> YearAndMonth {
+        YearAndMonth::from(self.invoice_date)
+    }
+}
+
+impl From<&PreparedData> for IssuedInvoice {
+    /// Records the invoice number, dates and total cost of the rendered
+    /// invoice `data`.
+    fn from(data: &PreparedData) -> Self {
+        let information = data.information();
+        Self::builder()
+            .number(*information.number())
+            .identifier(information.identifier().clone())
+            .kind(data.line_items().kind())
+            .invoice_date(*information.invoice_date())
+            .due_date(*information.due_date())
+            .currency(*data.payment_info().currency())
+            .total(*data.totals().gross())
+            .build()
+    }
+}
+
+impl HasSample for IssuedInvoice {
+    fn sample() -> Self {
+        Self::builder()
+            .number(InvoiceNumber::from(243))
+            .kind(InvoiceKind::Services)
+            .invoice_date(Date::from_str("2025-05-31").expect("Valid date"))
+            .due_date(Date::from_str("2025-06-30").expect("Valid date"))
+            .currency(Currency::EUR)
+            .total(Cost::from(dec!(9324)))
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn test_from_prepared_data() {
+        let data = PreparedData::sample();
+        let issued = IssuedInvoice::from(&data);
+        assert_eq!(issued.number(), data.information().number());
+        assert_eq!(*issued.kind(), InvoiceKind::Services);
+        assert_eq!(issued.total(), data.totals().gross());
+    }
+
+    #[test]
+    fn test_month() {
+        assert_eq!(IssuedInvoice::sample().month(), YearAndMonth::may(2025));
+    }
+
+    #[test]
+    fn test_is_referenced_by_identifier() {
+        let issued = IssuedInvoice::builder()
+            .number(InvoiceNumber::from(12))
+            .identifier(InvoiceIdentifier::from("ACME-2025-001".to_owned()))
+            .kind(InvoiceKind::Services)
+            .invoice_date(Date::sample())
+            .due_date(Date::sample())
+            .currency(Currency::EUR)
+            .total(Cost::sample())
+            .build();
+        assert!(issued.is_referenced_by("Payment for ACME-2025-001"));
+        assert!(issued.is_referenced_by("Invoice 12"));
+        assert!(!issued.is_referenced_by("Payment for ACME-2025-002"));
+        assert!(!issued.is_referenced_by("Payment for ACME-2025-0010"));
+        assert!(!issued.is_referenced_by("Payment for XACME-2025-001"));
+        assert!(issued.is_referenced_by("ACME-2025-0010, ACME-2025-001."));
+    }
+}
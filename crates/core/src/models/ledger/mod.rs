@@ -0,0 +1,5 @@
+mod invoice_ledger;
+mod issued_invoice;
+
+pub use invoice_ledger::*;
+pub use issued_invoice::*;
@@ -6,9 +6,25 @@ pub struct LineItemsFlat {
     #[getset(get = "pub")]
     is_expenses: bool,
 
-    /// Either a single item (Serivec) or one or more expenses
+    /// Either one item per service or one or more expenses, or both with
+    /// the services first.
     #[getset(get = "pub")]
     items: Vec<ItemConvertedIntoTargetCurrency>,
+
+    /// For an invoice combining services and expenses, the number of
+    /// service items leading `items`, the rest being expenses, which are
+    /// rendered in separate sections with subtotals.
+    #[builder(setter(into), default)]
+    #[getset(get = "pub")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    combined_service_items: Option<usize>,
+
+    /// Discounts, surcharges and credits applied to the items, rendered as
+    /// rows below them, in the order they were applied.
+    #[builder(setter(into), default)]
+    #[getset(get = "pub")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    adjustments: Vec<AppliedAdjustment>,
 }
 
 impl MaybeIsExpenses for LineItemsFlat {
@@ -17,6 +33,81 @@ impl MaybeIsExpenses for LineItemsFlat {
     }
 }
 
+impl LineItemsFlat {
+    /// The kind of invoice these line items are for.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// assert_eq!(LineItemsFlat::sample().kind(), InvoiceKind::Services);
+    /// ```
+    pub fn kind(&self) -> InvoiceKind {
+        if self.is_expenses {
+            InvoiceKind::Expenses
+        } else if self.combined_service_items.is_some() {
+            InvoiceKind::Combined
+        } else {
+            InvoiceKind::Services
+        }
+    }
+
+    /// Returns the sum of the total cost of all items, in the target currency.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let line_items = LineItemsFlat::sample();
+    /// assert_eq!(line_items.total_cost(), Cost::sample());
+    /// ```
+    pub fn total_cost(&self) -> Cost {
+        Cost::from(
+            self.items
+                .iter()
+                .map(|item| **item.total_cost())
+                .sum::<rust_decimal::Decimal>(),
+        )
+    }
+
+    /// Returns the total cost of all items with all adjustments applied, in
+    /// the target currency, which VAT is calculated on.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let line_items = LineItemsFlat::sample().with_adjustments(&[Adjustment::of_subtotal(
+    ///     AdjustmentKind::Credit,
+    ///     "50".parse().unwrap(),
+    /// )]);
+    /// assert_eq!(*line_items.net_total(), *Cost::sample() - dec!(50));
+    /// ```
+    pub fn net_total(&self) -> Cost {
+        Cost::from(
+            *self.total_cost()
+                + self
+                    .adjustments
+                    .iter()
+                    .map(|adjustment| **adjustment.amount())
+                    .sum::<rust_decimal::Decimal>(),
+        )
+    }
+
+    /// Returns these line items with `adjustments` applied to the items,
+    /// skipping adjustments of lines which are not among the items.
+    pub fn with_adjustments(self, adjustments: &[Adjustment]) -> Self {
+        let adjustments = adjustments
+            .iter()
+            .filter_map(|adjustment| adjustment.apply(&self.items))
+            .collect();
+        Self {
+            adjustments,
+            ..self
+        }
+    }
+}
+
 impl TryFrom<(LineItemsPricedInSourceCurrency, ExchangeRates)> for LineItemsFlat {
     type Error = crate::prelude::Error;
 
@@ -26,10 +117,13 @@ impl TryFrom<(LineItemsPricedInSourceCurrency, ExchangeRates)> for LineItemsFlat
         (line_items, exchange_rates): (LineItemsPricedInSourceCurrency, ExchangeRates),
     ) -> Result<Self> {
         match line_items {
-            LineItemsPricedInSourceCurrency::Service(item) => {
-                let service = item.total_cost_in_target_currency(&exchange_rates)?;
+            LineItemsPricedInSourceCurrency::Services(services) => {
+                let services = services
+                    .into_iter()
+                    .map(|service| service.total_cost_in_target_currency(&exchange_rates))
+                    .collect::<Result<Vec<_>>>()?;
                 let flat = LineItemsFlat::builder()
-                    .items(vec![service])
+                    .items(services)
                     .is_expenses(false)
                     .build();
                 Ok(flat)
@@ -45,6 +139,20 @@ impl TryFrom<(LineItemsPricedInSourceCurrency, ExchangeRates)> for LineItemsFlat
                     .build();
                 Ok(flat)
             }
+            LineItemsPricedInSourceCurrency::Combined { services, expenses } => {
+                let service_items = services.len();
+                let items = services
+                    .into_iter()
+                    .chain(expenses)
+                    .map(|item| item.total_cost_in_target_currency(&exchange_rates))
+                    .collect::<Result<Vec<_>>>()?;
+                let flat = LineItemsFlat::builder()
+                    .items(items)
+                    .is_expenses(false)
+                    .combined_service_items(service_items)
+                    .build();
+                Ok(flat)
+            }
         }
     }
 }
@@ -81,6 +189,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_combined_conversion_keeps_services_first() {
+        let line_items = LineItemsPricedInSourceCurrency::Combined {
+            services: vec![Item::sample()],
+            expenses: vec![
+                Item::sample_expense_breakfast(),
+                Item::sample_expense_coffee(),
+            ],
+        };
+        let flat = LineItemsFlat::try_from((line_items, ExchangeRates::hard_coded())).unwrap();
+        assert_eq!(flat.kind(), InvoiceKind::Combined);
+        assert_eq!(*flat.combined_service_items(), Some(1));
+        assert_eq!(flat.items().len(), 3);
+        assert_eq!(flat.items()[0].name(), Item::sample().name());
+    }
+
     #[test]
     fn test_is_expenses() {
         assert!(!MaybeIsExpenses::is_expenses(&LineItemsFlat::sample()));
@@ -12,20 +12,51 @@ impl MaybeIsExpenses for LineItemsPricedInSourceCurrency {
     }
 }
 
-/// Services or expenses included in this invoice to be paid by the client.
-#[derive(Clone, Debug, Serialize, Deserialize, From, TryUnwrap, IsVariant)]
-#[from(Vec<Item>, Item)]
+/// Services or expenses, or both, included in this invoice to be paid by the
+/// client.
+#[derive(Clone, Debug, Serialize, Deserialize, TryUnwrap, IsVariant)]
 pub enum LineItemsPricedInSourceCurrency {
-    /// Service sold by the vendor to the client, e.g. `"Agreed Consulting Fees"`
-    Service(Item),
+    /// Services sold by the vendor to the client, e.g. `"Agreed Consulting Fees"`,
+    /// one item per service.
+    Services(Vec<Item>),
     /// Expense incurred by the vendor, travel expenses for a conference/summit/
     /// retreat
     Expenses(Vec<Item>),
+    /// The services and the expenses of the month on a single invoice,
+    /// rendered in separate sections.
+    #[try_unwrap(ignore)]
+    Combined {
+        services: Vec<Item>,
+        expenses: Vec<Item>,
+    },
+}
+
+impl LineItemsPricedInSourceCurrency {
+    /// The expenses among these line items, empty if only services are
+    /// invoiced.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let combined = LineItemsPricedInSourceCurrency::Combined {
+    ///     services: vec![Item::sample()],
+    ///     expenses: vec![Item::sample_expense_coffee()],
+    /// };
+    /// assert_eq!(combined.expenses().len(), 1);
+    /// assert!(LineItemsPricedInSourceCurrency::sample().expenses().is_empty());
+    /// ```
+    pub fn expenses(&self) -> &[Item] {
+        match self {
+            Self::Services(_) => &[],
+            Self::Expenses(expenses) | Self::Combined { expenses, .. } => expenses,
+        }
+    }
 }
 
 impl HasSample for LineItemsPricedInSourceCurrency {
     fn sample() -> Self {
-        Self::Service(Item::sample())
+        Self::Services(vec![Item::sample()])
     }
 }
 
@@ -0,0 +1,43 @@
+use crate::prelude::*;
+
+/// A data file changed by a migration, with the unified diff of its
+/// contents before and after the migration.
+#[derive(Clone, Debug, PartialEq, Eq, TypedBuilder, Getters)]
+pub struct DataFileChange {
+    /// The name of the file, e.g. `"service_fees.ron"`.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    file_name: String,
+
+    /// The unified diff of the contents, lines removed prefixed with `-` and
+    /// lines added with `+`.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    diff: String,
+}
+
+impl DataFileChange {
+    /// The change of the file `file_name` from `before` to `after`, or
+    /// `None` if the contents are the same.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let change = DataFileChange::between("vendor.ron", "a\nb\n", "a\nc\n").unwrap();
+    /// assert!(change.diff().contains("-b\n+c\n"));
+    /// assert!(DataFileChange::between("vendor.ron", "a\n", "a\n").is_none());
+    /// ```
+    pub fn between(file_name: impl Into<String>, before: &str, after: &str) -> Option<Self> {
+        if before == after {
+            return None;
+        }
+        let file_name = file_name.into();
+        let diff = similar::TextDiff::from_lines(before, after)
+            .unified_diff()
+            .context_radius(2)
+            .header(&file_name, &file_name)
+            .to_string();
+        Some(Self::builder().file_name(file_name).diff(diff).build())
+    }
+}
@@ -0,0 +1,77 @@
+use crate::prelude::*;
+
+/// The outcome of migrating the data directory, or of a dry run of it,
+/// listing the migrations applied and the files they changed.
+#[derive(Clone, Debug, PartialEq, Eq, TypedBuilder, Getters)]
+pub struct MigrationReport {
+    /// The schema version of the data before migrating.
+    #[getset(get = "pub")]
+    from: SchemaVersion,
+
+    /// The schema version of the data after migrating.
+    #[getset(get = "pub")]
+    to: SchemaVersion,
+
+    /// The description of each migration applied, in order, prefixed with
+    /// the version it migrates to.
+    #[builder(default)]
+    #[getset(get = "pub")]
+    migrations: Vec<(SchemaVersion, String)>,
+
+    /// The files changed by the migrations.
+    #[builder(default)]
+    #[getset(get = "pub")]
+    changes: Vec<DataFileChange>,
+
+    /// The folder with a copy of the files before migrating, `None` if
+    /// nothing was migrated or if this is a dry run.
+    #[builder(default)]
+    #[getset(get = "pub")]
+    backup: Option<PathBuf>,
+
+    /// Whether the files were left untouched, only reporting what would
+    /// change.
+    #[builder(default)]
+    #[getset(get = "pub")]
+    dry_run: bool,
+}
+
+impl MigrationReport {
+    /// Returns `true` if the data was already at the current schema version.
+    pub fn is_up_to_date(&self) -> bool {
+        self.from == self.to
+    }
+}
+
+impl std::fmt::Display for MigrationReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_up_to_date() {
+            return writeln!(f, "Data is up to date, at schema {}.", self.to);
+        }
+        let verb = if self.dry_run {
+            "Would migrate"
+        } else {
+            "Migrated"
+        };
+        writeln!(f, "{} data from schema {} to {}:", verb, self.from, self.to)?;
+        for (version, description) in &self.migrations {
+            writeln!(f, "  {}: {}", version, description)?;
+        }
+        if self.changes.is_empty() {
+            writeln!(f, "No files changed.")?;
+        }
+        for change in &self.changes {
+            writeln!(f)?;
+            write!(f, "{}", change.diff())?;
+        }
+        if let Some(backup) = &self.backup {
+            writeln!(f)?;
+            writeln!(
+                f,
+                "Backup of the data before migrating: {}",
+                backup.display()
+            )?;
+        }
+        Ok(())
+    }
+}
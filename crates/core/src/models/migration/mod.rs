@@ -0,0 +1,7 @@
+mod data_file_change;
+mod migration_report;
+mod schema_version;
+
+pub use data_file_change::*;
+pub use migration_report::*;
+pub use schema_version::*;
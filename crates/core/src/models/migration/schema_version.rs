@@ -0,0 +1,40 @@
+use crate::prelude::*;
+
+/// The version of the format of the files in the data directory, saved in
+/// `schema_version.ron` and bumped by every migration of the files.
+#[derive(
+    Clone, Copy, Debug, Display, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, Deref,
+)]
+#[display("v{_0}")]
+pub struct SchemaVersion(u16);
+
+impl SchemaVersion {
+    /// The version of data directories created before the format of the
+    /// files was versioned, which have no `schema_version.ron`.
+    pub const UNVERSIONED: Self = Self(0);
+
+    /// The version of the files written by this version of klirr.
+    pub const CURRENT: Self = Self(1);
+
+    pub const fn new(version: u16) -> Self {
+        Self(version)
+    }
+
+    /// The version following this one.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// assert_eq!(SchemaVersion::UNVERSIONED.next(), SchemaVersion::new(1));
+    /// ```
+    pub const fn next(&self) -> Self {
+        Self(self.0 + 1)
+    }
+}
+
+impl HasSample for SchemaVersion {
+    fn sample() -> Self {
+        Self::CURRENT
+    }
+}
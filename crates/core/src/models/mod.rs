@@ -1,52 +1,98 @@
+mod adjustments;
+mod bank_statement;
+mod batch;
 mod cost;
 mod data;
 mod date;
+mod date_range;
 mod day;
 mod decimal;
 mod deserialize_contents_of_ron;
+mod dry_run;
+mod email;
+mod encryption;
 mod error;
 mod exchange_rates;
+mod expense_import;
 mod font_identifier;
 mod font_weight;
+mod history;
+mod identifiers;
+mod invoice_identifier;
 mod invoice_info_full;
+mod invoice_kind;
 mod invoice_number;
+mod invoice_totals;
 mod invoiced_items;
 mod item;
 mod item_converted_into_target_currency;
 mod l18n;
 mod layout;
+mod ledger;
 mod line_items;
+mod migration;
 mod month;
 mod output_path;
+mod payments;
 mod pdf;
+mod percentage;
+mod preview;
 mod quantity;
+mod receipts;
+mod reminder;
+mod report;
+mod service_quantity_override;
+mod sie;
 mod unit_price;
 mod valid_input;
 mod year;
 mod year_and_month;
 
+pub use adjustments::*;
+pub use bank_statement::*;
+pub use batch::*;
 pub use cost::*;
 pub use data::*;
 pub use date::*;
+pub use date_range::*;
 pub use day::*;
 pub use decimal::*;
 pub use deserialize_contents_of_ron::*;
+pub use dry_run::*;
+pub use email::*;
+pub use encryption::*;
 pub use error::*;
 pub use exchange_rates::*;
+pub use expense_import::*;
 pub use font_identifier::*;
 pub use font_weight::*;
+pub use history::*;
+pub use identifiers::*;
+pub use invoice_identifier::*;
 pub use invoice_info_full::*;
+pub use invoice_kind::*;
 pub use invoice_number::*;
+pub use invoice_totals::*;
 pub use invoiced_items::*;
 pub use item::*;
 pub use item_converted_into_target_currency::*;
 pub use l18n::*;
 pub use layout::*;
+pub use ledger::*;
 pub use line_items::*;
+pub use migration::*;
 pub use month::*;
 pub use output_path::*;
+pub use payments::*;
 pub use pdf::*;
+pub use percentage::*;
+pub use preview::*;
 pub use quantity::*;
+pub use receipts::*;
+pub use reminder::*;
+pub use report::*;
+pub use service_quantity_override::*;
+pub use sie::*;
 pub use unit_price::*;
 pub use valid_input::*;
 pub use year::*;
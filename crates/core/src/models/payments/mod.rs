@@ -0,0 +1,8 @@
+mod payment;
+#[allow(clippy::module_inception)]
+mod payments;
+mod receivable;
+
+pub use payment::*;
+pub use payments::*;
+pub use receivable::*;
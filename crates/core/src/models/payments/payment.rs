@@ -0,0 +1,56 @@
+use crate::prelude::*;
+
+/// A payment received from the client towards an issued invoice.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, TypedBuilder, Getters)]
+pub struct Payment {
+    /// The number of the invoice this payment is for, e.g. `243`.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    invoice: InvoiceNumber,
+
+    /// The kind of the invoice this payment is for, telling apart invoices
+    /// with the same number, missing for payments recorded before several
+    /// invoices could have the same number.
+    #[builder(setter(into, strip_option), default)]
+    #[getset(get = "pub")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    kind: Option<InvoiceKind>,
+
+    /// The amount paid, in the currency of the invoice, e.g. `9324`.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    amount: Cost,
+
+    /// The date the payment was received, e.g. `2025-06-28`.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    date: Date,
+
+    /// The remittance information of the bank transaction the payment was
+    /// imported from, e.g. `"Invoice 243"`, missing for payments recorded by
+    /// hand.
+    #[builder(setter(into, strip_option), default)]
+    #[getset(get = "pub")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    reference: Option<String>,
+}
+
+impl Payment {
+    /// Returns true if this payment is towards `invoice`, by its number and
+    /// kind, or by its number only if the kind of the invoice paid was not
+    /// recorded.
+    pub fn is_towards(&self, invoice: &IssuedInvoice) -> bool {
+        self.invoice == *invoice.number() && self.kind.is_none_or(|kind| kind == *invoice.kind())
+    }
+}
+
+impl HasSample for Payment {
+    fn sample() -> Self {
+        Self::builder()
+            .invoice(InvoiceNumber::from(243))
+            .kind(InvoiceKind::Services)
+            .amount(Cost::from(dec!(4000)))
+            .date(Date::from_str("2025-06-28").expect("Valid date"))
+            .build()
+    }
+}
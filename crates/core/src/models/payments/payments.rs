@@ -0,0 +1,59 @@
+use crate::prelude::*;
+
+/// All payments received towards issued invoices, saved in the data directory.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct Payments {
+    payments: Vec<Payment>,
+}
+
+impl Payments {
+    /// Records a received payment.
+    pub fn record(&mut self, payment: Payment) {
+        self.payments.push(payment);
+    }
+
+    /// Returns an iterator over all recorded payments, in the order they
+    /// were recorded.
+    pub fn iter(&self) -> impl Iterator<Item = &Payment> {
+        self.payments.iter()
+    }
+
+    /// Returns the sum of all payments towards `invoice`.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let mut payments = Payments::default();
+    /// payments.record(Payment::sample());
+    /// payments.record(Payment::sample());
+    /// let invoice = IssuedInvoice::sample();
+    /// assert_eq!(payments.paid_towards(&invoice), Cost::from(dec!(8000)));
+    /// let other = IssuedInvoice::builder()
+    ///     .number(InvoiceNumber::from(243))
+    ///     .kind(InvoiceKind::Expenses)
+    ///     .invoice_date(Date::sample())
+    ///     .due_date(Date::sample())
+    ///     .currency(Currency::EUR)
+    ///     .total(Cost::sample())
+    ///     .build();
+    /// assert_eq!(payments.paid_towards(&other), Cost::default());
+    /// ```
+    pub fn paid_towards(&self, invoice: &IssuedInvoice) -> Cost {
+        Cost::from(
+            self.payments
+                .iter()
+                .filter(|payment| payment.is_towards(invoice))
+                .map(|payment| **payment.amount())
+                .sum::<rust_decimal::Decimal>(),
+        )
+    }
+}
+
+impl HasSample for Payments {
+    fn sample() -> Self {
+        let mut payments = Self::default();
+        payments.record(Payment::sample());
+        payments
+    }
+}
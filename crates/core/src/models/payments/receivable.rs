@@ -0,0 +1,228 @@
+use crate::prelude::*;
+
+/// Whether nothing or only part of an invoice has been paid.
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq, IsVariant)]
+pub enum ReceivableStatus {
+    /// No payment has been received.
+    #[display("Unpaid")]
+    Unpaid,
+    /// Some, but not all, of the invoiced amount has been received.
+    #[display("Partially paid")]
+    PartiallyPaid,
+}
+
+/// An issued invoice which has not been paid in full.
+#[derive(Clone, Debug, PartialEq, Getters)]
+pub struct Receivable {
+    /// The issued invoice.
+    #[getset(get = "pub")]
+    invoice: IssuedInvoice,
+
+    /// The sum of payments received towards the invoice.
+    #[getset(get = "pub")]
+    paid: Cost,
+
+    /// The amount still to be paid, `total - paid`.
+    #[getset(get = "pub")]
+    outstanding: Cost,
+
+    /// Number of days passed since the due date, `None` if not yet due.
+    #[getset(get = "pub")]
+    days_overdue: Option<u32>,
+}
+
+impl Receivable {
+    /// Returns a `Receivable` for `invoice` if it is not paid in full by
+    /// `payments`, with days overdue counted at `date`.
+    pub fn new(invoice: &IssuedInvoice, payments: &Payments, date: &Date) -> Option<Self> {
+        let paid = payments.paid_towards(invoice);
+        let outstanding = **invoice.total() - *paid;
+        if outstanding <= rust_decimal::Decimal::ZERO {
+            return None;
+        }
+        let days_overdue = date.elapsed_days_since(invoice.due_date());
+        Some(Self {
+            invoice: invoice.clone(),
+            paid,
+            outstanding: Cost::from(outstanding),
+            days_overdue: (days_overdue > 0).then_some(days_overdue as u32),
+        })
+    }
+
+    /// Whether nothing or only part of the invoice has been paid.
+    pub fn status(&self) -> ReceivableStatus {
+        if self.paid.is_zero() {
+            ReceivableStatus::Unpaid
+        } else {
+            ReceivableStatus::PartiallyPaid
+        }
+    }
+
+    /// Whether the due date of the invoice has passed.
+    pub fn is_overdue(&self) -> bool {
+        self.days_overdue.is_some()
+    }
+}
+
+/// All issued invoices not paid in full, which can be displayed as a table.
+#[derive(Clone, Debug, PartialEq, Deref)]
+pub struct Receivables(Vec<Receivable>);
+
+impl Receivables {
+    /// Returns the receivables of all invoices in `ledger` not paid in full by
+    /// `payments`, with days overdue counted at `date`.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let receivables = Receivables::new(
+    ///     &InvoiceLedger::sample(),
+    ///     &Payments::sample(),
+    ///     &Date::from_str("2025-07-10").unwrap(),
+    /// );
+    /// assert_eq!(receivables.len(), 1);
+    /// assert_eq!(*receivables[0].days_overdue(), Some(10));
+    /// assert!(receivables[0].status().is_partially_paid());
+    /// ```
+    pub fn new(ledger: &InvoiceLedger, payments: &Payments, date: &Date) -> Self {
+        Self(
+            ledger
+                .iter()
+                .filter_map(|invoice| Receivable::new(invoice, payments, date))
+                .collect(),
+        )
+    }
+
+    /// Returns the sum of outstanding amounts per currency.
+    pub fn total_outstanding(&self) -> IndexMap<Currency, Cost> {
+        let mut totals = IndexMap::<Currency, rust_decimal::Decimal>::new();
+        for receivable in &self.0 {
+            *totals.entry(*receivable.invoice.currency()).or_default() += *receivable.outstanding;
+        }
+        totals
+            .into_iter()
+            .map(|(currency, total)| (currency, Cost::from(total)))
+            .collect()
+    }
+}
+
+impl std::fmt::Display for Receivables {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.0.is_empty() {
+            return writeln!(
+                f,
+                "No outstanding receivables, all issued invoices are paid."
+            );
+        }
+        writeln!(
+            f,
+            "{:<8} {:<10} {:>14} {:>14} {:>14}  {:<15} {:>12}",
+            "Invoice", "Due date", "Total", "Paid", "Outstanding", "Status", "Days overdue"
+        )?;
+        for receivable in &self.0 {
+            let invoice = &receivable.invoice;
+            let currency = invoice.currency();
+            writeln!(
+                f,
+                "{:<8} {:<10} {:>14} {:>14} {:>14}  {:<15} {:>12}",
+                invoice.number(),
+                invoice.due_date(),
+                format!("{:.2} {}", **invoice.total(), currency),
+                format!("{:.2} {}", *receivable.paid, currency),
+                format!("{:.2} {}", *receivable.outstanding, currency),
+                receivable.status(),
+                receivable
+                    .days_overdue
+                    .map(|days| days.to_string())
+                    .unwrap_or_else(|| "-".to_owned()),
+            )?;
+        }
+        for (currency, total) in self.total_outstanding() {
+            writeln!(f, "Total outstanding: {:.2} {}", *total, currency)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    fn date(s: &str) -> Date {
+        Date::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn test_unpaid_not_yet_due() {
+        let receivable = Receivable::new(
+            &IssuedInvoice::sample(),
+            &Payments::default(),
+            &date("2025-06-30"),
+        )
+        .unwrap();
+        assert!(receivable.status().is_unpaid());
+        assert!(!receivable.is_overdue());
+        assert_eq!(*receivable.outstanding(), Cost::from(dec!(9324)));
+    }
+
+    #[test]
+    fn test_paid_in_full_is_not_receivable() {
+        let mut payments = Payments::default();
+        payments.record(
+            Payment::builder()
+                .invoice(InvoiceNumber::from(243))
+                .amount(Cost::from(dec!(9324)))
+                .date(date("2025-06-28"))
+                .build(),
+        );
+        let receivables =
+            Receivables::new(&InvoiceLedger::sample(), &payments, &date("2025-07-30"));
+        assert!(receivables.is_empty());
+    }
+
+    #[test]
+    fn test_partially_paid_overdue() {
+        let receivables = Receivables::new(
+            &InvoiceLedger::sample(),
+            &Payments::sample(),
+            &date("2025-07-30"),
+        );
+        let receivable = &receivables[0];
+        assert!(receivable.status().is_partially_paid());
+        assert_eq!(*receivable.days_overdue(), Some(30));
+        assert_eq!(*receivable.outstanding(), Cost::from(dec!(5324)));
+        assert_eq!(
+            receivables.total_outstanding(),
+            IndexMap::<_, _>::from_iter([(Currency::EUR, Cost::from(dec!(5324)))])
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let receivables = Receivables::new(
+            &InvoiceLedger::sample(),
+            &Payments::sample(),
+            &date("2025-07-30"),
+        );
+        let table = receivables.to_string();
+        assert!(table.contains("5324.00 EUR"), "{}", table);
+        assert!(table.contains("Partially paid"), "{}", table);
+        assert!(
+            table.contains("Total outstanding: 5324.00 EUR"),
+            "{}",
+            table
+        );
+    }
+
+    #[test]
+    fn test_display_empty() {
+        let receivables = Receivables::new(
+            &InvoiceLedger::default(),
+            &Payments::default(),
+            &date("2025-07-30"),
+        );
+        assert!(receivables.to_string().contains("No outstanding"));
+    }
+}
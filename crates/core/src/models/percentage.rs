@@ -0,0 +1,106 @@
+use crate::prelude::*;
+
+/// A percentage, e.g. `8` for 8%, used for interest and VAT rates.
+#[derive(
+    Clone,
+    Copy,
+    Display,
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Default,
+    Serialize,
+    Deserialize,
+    From,
+    Deref,
+)]
+#[display("{}%", _0)]
+#[from(forward)]
+#[deref(forward)]
+pub struct Percentage(Decimal);
+
+impl Percentage {
+    pub const ZERO: Self = Self(Decimal::ZERO);
+
+    /// Returns this percentage as a fraction, e.g. `0.08` for `8%`.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let percentage = Percentage::from(dec!(8));
+    /// assert_eq!(percentage.as_fraction(), dec!(0.08));
+    /// ```
+    pub fn as_fraction(&self) -> rust_decimal::Decimal {
+        **self / rust_decimal::Decimal::ONE_HUNDRED
+    }
+}
+
+impl std::ops::Add for Percentage {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::from(*self + *rhs)
+    }
+}
+
+impl FromStr for Percentage {
+    type Err = crate::prelude::Error;
+
+    /// Parses a percentage from a string, with or without a trailing `%`, e.g.
+    /// `"8"`, `"8%"` or `"2.25 %"`.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let percentage: Percentage = "2.25 %".parse().unwrap();
+    /// assert_eq!(*percentage, dec!(2.25));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim().trim_end_matches('%').trim();
+        trimmed
+            .parse::<Decimal>()
+            .map(Self)
+            .map_err(|_| Error::InvalidPercentage {
+                invalid_string: s.to_owned(),
+            })
+    }
+}
+
+impl HasSample for Percentage {
+    fn sample() -> Self {
+        Self::from(dec!(8))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn test_from_str_without_percent_sign() {
+        let percentage = Percentage::from_str("8").unwrap();
+        assert_eq!(*percentage, dec!(8));
+    }
+
+    #[test]
+    fn test_from_str_invalid() {
+        let result = Percentage::from_str("eight");
+        assert!(result.is_err(), "Expected error, got: {:?}", result);
+    }
+
+    #[test]
+    fn test_add() {
+        let sum = Percentage::from(dec!(2.25)) + Percentage::from(dec!(8));
+        assert_eq!(*sum, dec!(10.25));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Percentage::from(dec!(10.25)).to_string(), "10.25%");
+    }
+}
@@ -0,0 +1,33 @@
+use crate::prelude::*;
+
+/// The image format of a preview of an invoice.
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq, Hash, EnumIter)]
+pub enum PreviewFormat {
+    /// A raster image, all pages stacked vertically.
+    #[display("PNG")]
+    Png,
+    /// A vector image, all pages stacked vertically.
+    #[display("SVG")]
+    Svg,
+}
+
+impl PreviewFormat {
+    /// The file extension of this format, e.g. `"png"`.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// assert_eq!(PreviewFormat::Svg.extension(), "svg");
+    /// ```
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Png => "png",
+            Self::Svg => "svg",
+        }
+    }
+}
+
+/// Bytes of a preview image of an invoice, in a [`PreviewFormat`].
+#[derive(Clone, From, AsRef)]
+pub struct Preview(pub Vec<u8>);
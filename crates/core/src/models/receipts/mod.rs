@@ -0,0 +1,5 @@
+mod receipt;
+mod receipt_format;
+
+pub use receipt::*;
+pub use receipt_format::*;
@@ -0,0 +1,45 @@
+use crate::prelude::*;
+
+/// A receipt attached to a line item of an expenses invoice, read from the
+/// `receipts` folder of the data directory and appended to the PDF.
+#[derive(Clone, derive_more::Debug, PartialEq, Eq, TypedBuilder, Getters)]
+pub struct Receipt {
+    /// The reference number of the receipt, which is the number of its line
+    /// item, starting at `1`.
+    #[getset(get = "pub")]
+    reference: usize,
+
+    /// The name of the line item, e.g. `"Coffee"`.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    name: String,
+
+    /// The file format of the receipt.
+    #[getset(get = "pub")]
+    format: ReceiptFormat,
+
+    /// The contents of the receipt file.
+    #[debug("{} bytes", content.len())]
+    #[getset(get = "pub")]
+    content: Vec<u8>,
+}
+
+impl Receipt {
+    /// The name of the receipt file when rendering, e.g. `"receipt_1.png"`.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let receipt = Receipt::builder()
+    ///     .reference(3)
+    ///     .name("Coffee")
+    ///     .format(ReceiptFormat::Png)
+    ///     .content(Vec::new())
+    ///     .build();
+    /// assert_eq!(receipt.file_name(), "receipt_3.png");
+    /// ```
+    pub fn file_name(&self) -> String {
+        format!("receipt_{}.{}", self.reference, self.format.extension())
+    }
+}
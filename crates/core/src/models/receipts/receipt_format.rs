@@ -0,0 +1,87 @@
+use crate::prelude::*;
+
+/// The file format of a receipt attached to an expense.
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq, Hash, EnumIter)]
+pub enum ReceiptFormat {
+    /// A PDF document, all its pages are appended to the invoice.
+    #[display("PDF")]
+    Pdf,
+    /// A PNG image, scaled to fit one page.
+    #[display("PNG")]
+    Png,
+    /// A JPEG image, scaled to fit one page.
+    #[display("JPG")]
+    Jpg,
+}
+
+impl ReceiptFormat {
+    /// The format of the receipt at `path`, from its file extension.
+    ///
+    /// # Errors
+    /// Returns [`Error::UnsupportedReceiptFormat`] if the extension is not
+    /// `pdf`, `png`, `jpg` or `jpeg`, in any case.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// assert_eq!(ReceiptFormat::from_path(Path::new("coffee.JPEG")).unwrap(), ReceiptFormat::Jpg);
+    /// assert!(ReceiptFormat::from_path(Path::new("coffee.txt")).is_err());
+    /// ```
+    pub fn from_path(path: &Path) -> Result<Self> {
+        let extension = path
+            .extension()
+            .map(|extension| extension.to_string_lossy().to_lowercase());
+        match extension.as_deref() {
+            Some("pdf") => Ok(Self::Pdf),
+            Some("png") => Ok(Self::Png),
+            Some("jpg" | "jpeg") => Ok(Self::Jpg),
+            _ => Err(Error::UnsupportedReceiptFormat {
+                path: path.display().to_string(),
+            }),
+        }
+    }
+
+    /// The file extension of this format, e.g. `"pdf"`.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// assert_eq!(ReceiptFormat::Jpg.extension(), "jpg");
+    /// ```
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Pdf => "pdf",
+            Self::Png => "png",
+            Self::Jpg => "jpg",
+        }
+    }
+
+    /// True if this format is an image, rendered onto a page of its own.
+    pub fn is_image(&self) -> bool {
+        !matches!(self, Self::Pdf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn test_from_path_roundtrip_extension() {
+        for format in ReceiptFormat::iter() {
+            let path = PathBuf::from(format!("receipt.{}", format.extension()));
+            assert_eq!(ReceiptFormat::from_path(&path).unwrap(), format);
+        }
+    }
+
+    #[test]
+    fn test_from_path_without_extension() {
+        assert!(matches!(
+            ReceiptFormat::from_path(Path::new("receipt")),
+            Err(Error::UnsupportedReceiptFormat { .. })
+        ));
+    }
+}
@@ -0,0 +1,108 @@
+use crate::prelude::*;
+
+/// How late-payment interest and fees are charged on an overdue invoice, e.g.
+/// the Swedish statutory interest which is the reference rate of Riksbanken
+/// plus 8 percentage points.
+#[derive(Clone, Debug, PartialEq, TypedBuilder, Getters)]
+pub struct LateInterestPolicy {
+    /// The reference rate, e.g. `2.25%`, set by the central bank.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    reference_rate: Percentage,
+
+    /// The margin added on top of the reference rate, which by Swedish
+    /// law (Räntelagen 6 §) is 8 percentage points.
+    #[builder(setter(into), default = Percentage::from(dec!(8)))]
+    #[getset(get = "pub")]
+    margin: Percentage,
+
+    /// An optional fixed fee added to the reminder, e.g. `60` SEK.
+    #[builder(setter(into), default)]
+    #[getset(get = "pub")]
+    reminder_fee: Option<Cost>,
+}
+
+impl LateInterestPolicy {
+    /// The yearly interest rate, being the reference rate plus the margin.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let policy = LateInterestPolicy::builder()
+    ///     .reference_rate(Percentage::from(dec!(2.25)))
+    ///     .build();
+    /// assert_eq!(policy.interest_rate(), Percentage::from(dec!(10.25)));
+    /// ```
+    pub fn interest_rate(&self) -> Percentage {
+        self.reference_rate + self.margin
+    }
+
+    /// Calculates the simple late interest on `amount` for `days_overdue`
+    /// days, using a 365 day year, rounded to two decimals.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let policy = LateInterestPolicy::builder()
+    ///     .reference_rate(Percentage::from(dec!(2)))
+    ///     .build();
+    /// let interest = policy.late_interest(Cost::from(dec!(36500)), 10);
+    /// assert_eq!(*interest, dec!(100));
+    /// ```
+    pub fn late_interest(&self, amount: Cost, days_overdue: i64) -> Cost {
+        if days_overdue <= 0 {
+            return Cost::default();
+        }
+        let interest = *amount
+            * self.interest_rate().as_fraction()
+            * rust_decimal::Decimal::from(days_overdue)
+            / rust_decimal::Decimal::from(365);
+        Cost::from(interest.round_dp(2))
+    }
+}
+
+impl HasSample for LateInterestPolicy {
+    fn sample() -> Self {
+        Self::builder()
+            .reference_rate(Percentage::from(dec!(2.25)))
+            .reminder_fee(Cost::from(dec!(60)))
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn test_late_interest_zero_when_not_overdue() {
+        let sut = LateInterestPolicy::sample();
+        assert_eq!(
+            sut.late_interest(Cost::from(dec!(1000)), 0),
+            Cost::default()
+        );
+        assert_eq!(
+            sut.late_interest(Cost::from(dec!(1000)), -3),
+            Cost::default()
+        );
+    }
+
+    #[test]
+    fn test_late_interest_rounded() {
+        let sut = LateInterestPolicy::sample();
+        // 9324 * 10.25% * 30 / 365 = 78.5531...
+        assert_eq!(*sut.late_interest(Cost::from(dec!(9324)), 30), dec!(78.55));
+    }
+
+    #[test]
+    fn test_default_margin_is_eight() {
+        let sut = LateInterestPolicy::builder()
+            .reference_rate(Percentage::ZERO)
+            .build();
+        assert_eq!(*sut.margin(), Percentage::from(dec!(8)));
+        assert!(sut.reminder_fee().is_none());
+    }
+}
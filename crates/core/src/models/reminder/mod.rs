@@ -0,0 +1,7 @@
+mod late_interest_policy;
+mod reminder_info;
+mod valid_reminder_input;
+
+pub use late_interest_policy::*;
+pub use reminder_info::*;
+pub use valid_reminder_input::*;
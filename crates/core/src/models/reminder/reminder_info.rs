@@ -0,0 +1,31 @@
+use crate::prelude::*;
+
+/// Information about a reminder of an overdue invoice, rendered in the
+/// reminder layout.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Getters, TypedBuilder)]
+pub struct ReminderInfo {
+    /// The date of the reminder, typically today, e.g. `2025-07-30`.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    reminder_date: Date,
+
+    /// Number of days passed since the due date, e.g. `30`.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    days_overdue: u32,
+
+    /// The yearly late-payment interest rate charged, e.g. `10.25%`.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    interest_rate: Percentage,
+}
+
+impl HasSample for ReminderInfo {
+    fn sample() -> Self {
+        Self::builder()
+            .reminder_date(Date::from_str("2025-07-30").expect("Valid date"))
+            .days_overdue(30u32)
+            .interest_rate(Percentage::from(dec!(10.25)))
+            .build()
+    }
+}
@@ -0,0 +1,50 @@
+use crate::prelude::*;
+
+/// Input which has been validated and is ready for rendering a reminder of an
+/// overdue, previously issued, invoice.
+#[derive(Debug, Clone, Display, TypedBuilder, Getters)]
+#[display("Invoice: {}, date: {}, out: {:?}, language: {}", number, reminder_date, maybe_output_path.as_ref().map(|d|d.display()), language)]
+pub struct ValidReminderInput {
+    /// The language to use for the reminder, used on labels, headers etc.
+    /// Defaults to English (`Language::EN`).
+    #[builder(setter(into), default)]
+    #[getset(get = "pub")]
+    language: Language,
+
+    /// The number of the overdue invoice to remind about, must be present
+    /// in the [`InvoiceLedger`].
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    number: InvoiceNumber,
+
+    /// The kind of the overdue invoice, needed only if several invoices
+    /// have `number`.
+    #[builder(default, setter(into))]
+    #[getset(get = "pub")]
+    kind: Option<InvoiceKind>,
+
+    /// How late-payment interest and fees are charged.
+    #[getset(get = "pub")]
+    policy: LateInterestPolicy,
+
+    /// The date of the reminder, up to which interest is calculated.
+    /// Defaults to today.
+    #[builder(setter(into), default = Date::today())]
+    #[getset(get = "pub")]
+    reminder_date: Date,
+
+    /// An optional override of where to save the output PDF file.
+    #[builder(default, setter(into))]
+    #[getset(get = "pub")]
+    maybe_output_path: Option<PathBuf>,
+}
+
+impl HasSample for ValidReminderInput {
+    fn sample() -> Self {
+        Self::builder()
+            .number(InvoiceNumber::from(243))
+            .policy(LateInterestPolicy::sample())
+            .reminder_date(Date::from_str("2025-07-30").expect("Valid date"))
+            .build()
+    }
+}
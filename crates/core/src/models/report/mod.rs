@@ -0,0 +1,9 @@
+mod quarter;
+mod report_format;
+mod report_period;
+mod revenue_report;
+
+pub use quarter::*;
+pub use report_format::*;
+pub use report_period::*;
+pub use revenue_report::*;
@@ -0,0 +1,66 @@
+use crate::prelude::*;
+
+/// A quarter of a year, e.g. `Q2` being April to June.
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq, Hash, PartialOrd, Ord, EnumIter)]
+pub enum Quarter {
+    /// January to March
+    Q1 = 1,
+    /// April to June
+    Q2,
+    /// July to September
+    Q3,
+    /// October to December
+    Q4,
+}
+
+impl Quarter {
+    /// Returns the three months of this quarter.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// assert_eq!(Quarter::Q2.months(), [Month::April, Month::May, Month::June]);
+    /// ```
+    pub fn months(&self) -> [Month; 3] {
+        let first = (*self as u8 - 1) * 3 + 1;
+        [first, first + 1, first + 2]
+            .map(|month| Month::try_from(month).expect("Quarter months are valid"))
+    }
+}
+
+impl TryFrom<u8> for Quarter {
+    type Error = crate::prelude::Error;
+
+    /// Attempts to convert an integer between 1 and 4 to a `Quarter`.
+    fn try_from(quarter: u8) -> Result<Self> {
+        match quarter {
+            1 => Ok(Self::Q1),
+            2 => Ok(Self::Q2),
+            3 => Ok(Self::Q3),
+            4 => Ok(Self::Q4),
+            _ => Err(Error::InvalidQuarter { quarter }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn test_try_from_invalid() {
+        assert!(Quarter::try_from(0).is_err());
+        assert!(Quarter::try_from(5).is_err());
+    }
+
+    #[test]
+    fn test_all_months_covered_once() {
+        let months = Quarter::iter()
+            .flat_map(|q| q.months())
+            .map(|m| *m)
+            .collect::<Vec<_>>();
+        assert_eq!(months, (1..=12).collect::<Vec<u8>>());
+    }
+}
@@ -0,0 +1,13 @@
+use crate::prelude::*;
+
+/// The format a report is printed or exported in.
+#[derive(Clone, Copy, Debug, Display, Default, PartialEq, Eq, EnumIter)]
+pub enum ReportFormat {
+    /// A human readable table.
+    #[default]
+    Table,
+    /// Comma separated values, one row per invoice.
+    Csv,
+    /// JSON, including totals.
+    Json,
+}
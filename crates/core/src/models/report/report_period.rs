@@ -0,0 +1,60 @@
+use crate::prelude::*;
+
+/// The period a report covers, a whole year or a quarter of it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, TypedBuilder, Getters)]
+pub struct ReportPeriod {
+    /// The year, e.g. `2025`.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    year: Year,
+
+    /// The quarter of `year`, or `None` for the whole year.
+    #[builder(setter(into), default)]
+    #[getset(get = "pub")]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_quarter"
+    )]
+    quarter: Option<Quarter>,
+}
+
+fn serialize_quarter<S: serde::Serializer>(
+    quarter: &Option<Quarter>,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    match quarter {
+        Some(quarter) => serializer.serialize_u8(*quarter as u8),
+        None => serializer.serialize_none(),
+    }
+}
+
+impl ReportPeriod {
+    /// Returns the months in this period, in chronological order.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let period = ReportPeriod::builder().year(Year::from(2025)).quarter(Quarter::Q2).build();
+    /// assert_eq!(period.months().first(), Some(&YearAndMonth::april(2025)));
+    /// assert_eq!(period.months().len(), 3);
+    /// ```
+    pub fn months(&self) -> Vec<YearAndMonth> {
+        match self.quarter {
+            Some(quarter) => quarter.months().to_vec(),
+            None => Quarter::iter().flat_map(|q| q.months()).collect(),
+        }
+        .into_iter()
+        .map(|month| YearAndMonth::new(self.year, month))
+        .collect()
+    }
+}
+
+impl std::fmt::Display for ReportPeriod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.quarter {
+            Some(quarter) => write!(f, "{} {}", self.year, quarter),
+            None => write!(f, "{}", self.year),
+        }
+    }
+}
@@ -0,0 +1,288 @@
+use crate::prelude::*;
+
+/// An invoice in a [`RevenueReport`].
+#[derive(Clone, Debug, PartialEq, Serialize, TypedBuilder, Getters)]
+pub struct RevenueReportRow {
+    /// The number of the invoice, e.g. `243`.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    number: InvoiceNumber,
+
+    /// The number as printed on the invoice, e.g. `"2025-043"`.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    identifier: InvoiceIdentifier,
+
+    /// Whether the invoice is for services or expenses.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    kind: InvoiceKind,
+
+    /// The date of the invoice, e.g. `2025-05-31`.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    date: Date,
+
+    /// The currency of the invoice, e.g. `EUR`.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    currency: Currency,
+
+    /// The net amount, VAT and gross amount of the invoice.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    #[serde(flatten)]
+    totals: InvoiceTotals,
+}
+
+impl From<&PreparedData> for RevenueReportRow {
+    fn from(data: &PreparedData) -> Self {
+        let issued = IssuedInvoice::from(data);
+        Self::builder()
+            .number(*issued.number())
+            .identifier(issued.identifier())
+            .kind(*issued.kind())
+            .date(*issued.invoice_date())
+            .currency(*issued.currency())
+            .totals(data.totals())
+            .build()
+    }
+}
+
+/// An overview of all invoices in a period, with totals per currency.
+#[derive(Clone, Debug, PartialEq, Serialize, Getters)]
+pub struct RevenueReport {
+    /// The period covered by this report.
+    #[getset(get = "pub")]
+    period: ReportPeriod,
+
+    /// The invoices, sorted by date and number.
+    #[getset(get = "pub")]
+    invoices: Vec<RevenueReportRow>,
+
+    /// The sum of all invoices, per currency.
+    #[getset(get = "pub")]
+    totals: IndexMap<Currency, InvoiceTotals>,
+}
+
+impl RevenueReport {
+    /// Creates a report for `period` with `invoices`, sorting them by date
+    /// and number and summing up totals per currency.
+    pub fn new(period: ReportPeriod, mut invoices: Vec<RevenueReportRow>) -> Self {
+        invoices.sort_by_key(|row| (row.date, row.number));
+        let mut totals = IndexMap::<Currency, InvoiceTotals>::new();
+        for row in &invoices {
+            let total = totals.entry(row.currency).or_default();
+            *total = *total + row.totals;
+        }
+        Self {
+            period,
+            invoices,
+            totals,
+        }
+    }
+
+    /// Formats the report in `format`.
+    ///
+    /// # Errors
+    /// Returns an error if the report cannot be serialized into CSV or JSON.
+    pub fn format(&self, format: ReportFormat) -> Result<String> {
+        match format {
+            ReportFormat::Table => Ok(self.to_string()),
+            ReportFormat::Csv => self.to_csv(),
+            ReportFormat::Json => self.to_json(),
+        }
+    }
+
+    /// Returns the invoices as CSV, one row per invoice, with the header
+    /// `number,identifier,kind,date,currency,net,vat,gross`.
+    fn to_csv(&self) -> Result<String> {
+        let error = |e: &dyn std::fmt::Display| Error::FailedToSerializeReport {
+            format: ReportFormat::Csv.to_string(),
+            underlying: e.to_string(),
+        };
+        let mut writer = csv::Writer::from_writer(Vec::new());
+        for row in &self.invoices {
+            writer.serialize(CsvRow::from(row)).map_err(|e| error(&e))?;
+        }
+        let bytes = writer.into_inner().map_err(|e| error(&e))?;
+        String::from_utf8(bytes).map_err(|e| error(&e))
+    }
+
+    /// Returns the report as pretty printed JSON.
+    fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self).map_err(|e| Error::FailedToSerializeReport {
+            format: ReportFormat::Json.to_string(),
+            underlying: e.to_string(),
+        })
+    }
+}
+
+/// A row in the CSV export, with amounts formatted with two decimals, since
+/// `csv` does not support flattened structs.
+#[derive(Serialize)]
+struct CsvRow {
+    number: InvoiceNumber,
+    identifier: InvoiceIdentifier,
+    kind: InvoiceKind,
+    date: Date,
+    currency: Currency,
+    net: String,
+    vat: String,
+    gross: String,
+}
+
+impl From<&RevenueReportRow> for CsvRow {
+    fn from(row: &RevenueReportRow) -> Self {
+        Self {
+            number: row.number,
+            identifier: row.identifier.clone(),
+            kind: row.kind,
+            date: row.date,
+            currency: row.currency,
+            net: format!("{:.2}", **row.totals.net()),
+            vat: format!("{:.2}", **row.totals.vat()),
+            gross: format!("{:.2}", **row.totals.gross()),
+        }
+    }
+}
+
+impl std::fmt::Display for RevenueReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Revenue report for {}", self.period)?;
+        if self.invoices.is_empty() {
+            return writeln!(f, "No invoices in this period.");
+        }
+        let amount = |cost: &Cost, currency: &Currency| format!("{:.2} {}", **cost, currency);
+        let width = self
+            .invoices
+            .iter()
+            .map(|row| row.identifier.to_string().len())
+            .max()
+            .unwrap_or_default()
+            .max("Invoice".len());
+        writeln!(
+            f,
+            "{:<width$} {:<9} {:<10} {:>16} {:>16} {:>16}",
+            "Invoice", "Kind", "Date", "Net", "VAT", "Gross"
+        )?;
+        for row in &self.invoices {
+            writeln!(
+                f,
+                "{:<width$} {:<9} {:<10} {:>16} {:>16} {:>16}",
+                row.identifier.to_string(),
+                row.kind,
+                row.date,
+                amount(row.totals.net(), &row.currency),
+                amount(row.totals.vat(), &row.currency),
+                amount(row.totals.gross(), &row.currency),
+            )?;
+        }
+        let total_width = width + 21;
+        for (currency, totals) in &self.totals {
+            writeln!(
+                f,
+                "{:<total_width$} {:>16} {:>16} {:>16}",
+                "Total",
+                amount(totals.net(), currency),
+                amount(totals.vat(), currency),
+                amount(totals.gross(), currency),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl HasSample for RevenueReport {
+    fn sample() -> Self {
+        let row = |number: u16, kind: InvoiceKind, date: &str, net| {
+            RevenueReportRow::builder()
+                .number(InvoiceNumber::from(number))
+                .identifier(InvoiceIdentifier::from(format!("2025-{:03}", number - 200)))
+                .kind(kind)
+                .date(Date::from_str(date).expect("Valid date"))
+                .currency(Currency::EUR)
+                .totals(InvoiceTotals::new(
+                    Cost::from(net),
+                    Percentage::from(dec!(25)),
+                ))
+                .build()
+        };
+        Self::new(
+            ReportPeriod::builder()
+                .year(Year::from(2025))
+                .quarter(Quarter::Q2)
+                .build(),
+            vec![
+                row(244, InvoiceKind::Expenses, "2025-05-31", dec!(150)),
+                row(243, InvoiceKind::Services, "2025-05-31", dec!(9324)),
+                row(242, InvoiceKind::Services, "2025-04-30", dec!(10000)),
+            ],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn test_sorted_and_totals() {
+        let report = RevenueReport::sample();
+        let numbers = report
+            .invoices()
+            .iter()
+            .map(|r| **r.number())
+            .collect::<Vec<_>>();
+        assert_eq!(numbers, vec![242, 243, 244]);
+        let totals = report.totals().get(&Currency::EUR).unwrap();
+        assert_eq!(*totals.net(), Cost::from(dec!(19474)));
+        assert_eq!(*totals.vat(), Cost::from(dec!(4868.5)));
+        assert_eq!(*totals.gross(), Cost::from(dec!(24342.5)));
+    }
+
+    #[test]
+    fn test_table() {
+        let table = RevenueReport::sample().format(ReportFormat::Table).unwrap();
+        assert!(table.contains("Revenue report for 2025 Q2"), "{}", table);
+        assert!(table.contains("24342.50 EUR"), "{}", table);
+        assert!(table.contains("2025-043 "), "{}", table);
+    }
+
+    #[test]
+    fn test_csv() {
+        let csv = RevenueReport::sample().format(ReportFormat::Csv).unwrap();
+        let mut lines = csv.lines();
+        assert_eq!(
+            lines.next(),
+            Some("number,identifier,kind,date,currency,net,vat,gross")
+        );
+        assert_eq!(
+            lines.next(),
+            Some("242,2025-042,Services,2025-04-30,EUR,10000.00,2500.00,12500.00")
+        );
+        assert_eq!(lines.count(), 2);
+    }
+
+    #[test]
+    fn test_json() {
+        let json = RevenueReport::sample().format(ReportFormat::Json).unwrap();
+        let value: Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["period"]["year"], 2025);
+        assert_eq!(value["period"]["quarter"], 2);
+        assert_eq!(value["invoices"][0]["number"], 242);
+        assert_eq!(value["invoices"][0]["identifier"], "2025-042");
+        assert_eq!(value["invoices"][0]["gross"], 12500.0);
+        assert_eq!(value["totals"]["EUR"]["vat"], 4868.5);
+    }
+
+    #[test]
+    fn test_empty_table() {
+        let report = RevenueReport::new(
+            ReportPeriod::builder().year(Year::from(2025)).build(),
+            vec![],
+        );
+        assert!(report.to_string().contains("No invoices"));
+    }
+}
@@ -0,0 +1,66 @@
+use crate::prelude::*;
+
+/// The quantity of a service whose quantity is specified per run, given when
+/// generating an invoice, formatted as `"On-call stipend=2"`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, SerializeDisplay, DeserializeFromStr, Getters)]
+pub struct ServiceQuantityOverride {
+    /// The name of the service, e.g. `"On-call stipend"`.
+    #[getset(get = "pub")]
+    name: String,
+
+    /// The quantity of the service on this invoice, e.g. `2`.
+    #[getset(get = "pub")]
+    quantity: Quantity,
+}
+
+impl ServiceQuantityOverride {
+    /// Creates the quantity of the service named `name`.
+    pub fn new(name: impl Into<String>, quantity: Quantity) -> Self {
+        Self {
+            name: name.into(),
+            quantity,
+        }
+    }
+}
+
+impl std::fmt::Display for ServiceQuantityOverride {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}={}", self.name, self.quantity)
+    }
+}
+
+impl std::str::FromStr for ServiceQuantityOverride {
+    type Err = crate::prelude::Error;
+
+    /// Parses the name of a service and its quantity, separated by the last
+    /// `=`, e.g. `"On-call stipend=2"`.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate klirr_core;
+    /// use klirr_core::prelude::*;
+    /// let parsed: ServiceQuantityOverride = "On-call stipend=1.5".parse().unwrap();
+    /// assert_eq!(parsed.name(), "On-call stipend");
+    /// assert_eq!(parsed.quantity().to_string(), "1.5");
+    /// assert!("On-call stipend".parse::<ServiceQuantityOverride>().is_err());
+    /// assert!("=2".parse::<ServiceQuantityOverride>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::InvalidServiceQuantity {
+            invalid_string: s.to_owned(),
+        };
+        let (name, quantity) = s.rsplit_once('=').ok_or_else(invalid)?;
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(invalid());
+        }
+        let quantity = quantity.trim().parse().map_err(|_| invalid())?;
+        Ok(Self::new(name, quantity))
+    }
+}
+
+impl HasSample for ServiceQuantityOverride {
+    fn sample() -> Self {
+        Self::new("On-call stipend", Quantity::from(dec!(2)))
+    }
+}
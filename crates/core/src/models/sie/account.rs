@@ -0,0 +1,28 @@
+use crate::prelude::*;
+
+/// An account in the chart of accounts, e.g. `1510 Kundfordringar`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, TypedBuilder, Getters)]
+pub struct Account {
+    /// The account number, e.g. `1510`.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    number: u16,
+
+    /// The name of the account, e.g. `"Kundfordringar"`.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    name: String,
+}
+
+impl Account {
+    /// Creates an account with `number` and `name`.
+    pub fn new(number: u16, name: impl Into<String>) -> Self {
+        Self::builder().number(number).name(name).build()
+    }
+}
+
+impl HasSample for Account {
+    fn sample() -> Self {
+        Self::new(1510, "Kundfordringar")
+    }
+}
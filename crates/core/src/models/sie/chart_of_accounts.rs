@@ -0,0 +1,124 @@
+use crate::prelude::*;
+
+/// Which accounts to use when exporting invoices as bookkeeping vouchers,
+/// read from `chart_of_accounts.ron` in the data directory, defaulting to the
+/// Swedish BAS chart of accounts.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, TypedBuilder, Getters)]
+pub struct ChartOfAccounts {
+    /// The currency of the bookkeeping, e.g. `SEK`, invoices in other
+    /// currencies are converted using the exchange rate of the invoice date.
+    #[builder(setter(into), default = Currency::SEK)]
+    #[getset(get = "pub")]
+    #[serde(default = "default_currency")]
+    currency: Currency,
+
+    /// Debited with the gross amount of each invoice, e.g. `1510`.
+    #[builder(setter(into), default = default_accounts_receivable())]
+    #[getset(get = "pub")]
+    #[serde(default = "default_accounts_receivable")]
+    accounts_receivable: Account,
+
+    /// Credited with the net amount of invoices for services, e.g. `3011`.
+    #[builder(setter(into), default = default_services_revenue())]
+    #[getset(get = "pub")]
+    #[serde(default = "default_services_revenue")]
+    services_revenue: Account,
+
+    /// Credited with the net amount of invoices for expenses, e.g. `3590`.
+    #[builder(setter(into), default = default_expenses_revenue())]
+    #[getset(get = "pub")]
+    #[serde(default = "default_expenses_revenue")]
+    expenses_revenue: Account,
+
+    /// Credited with the VAT of each invoice, e.g. `2611`.
+    #[builder(setter(into), default = default_output_vat())]
+    #[getset(get = "pub")]
+    #[serde(default = "default_output_vat")]
+    output_vat: Account,
+}
+
+fn default_currency() -> Currency {
+    Currency::SEK
+}
+
+fn default_accounts_receivable() -> Account {
+    Account::new(1510, "Kundfordringar")
+}
+
+fn default_services_revenue() -> Account {
+    Account::new(3011, "Försäljning tjänster")
+}
+
+fn default_expenses_revenue() -> Account {
+    Account::new(3590, "Övriga fakturerade kostnader")
+}
+
+fn default_output_vat() -> Account {
+    Account::new(2611, "Utgående moms på försäljning inom Sverige, 25 %")
+}
+
+impl ChartOfAccounts {
+    /// The revenue account for invoices of `kind`, invoices combining
+    /// services and expenses are booked as services.
+    pub fn revenue(&self, kind: InvoiceKind) -> &Account {
+        match kind {
+            InvoiceKind::Services | InvoiceKind::Combined => &self.services_revenue,
+            InvoiceKind::Expenses => &self.expenses_revenue,
+        }
+    }
+
+    /// All accounts, in the order they are declared in a SIE file.
+    pub fn accounts(&self) -> Vec<&Account> {
+        let mut accounts = vec![
+            &self.accounts_receivable,
+            &self.output_vat,
+            &self.services_revenue,
+            &self.expenses_revenue,
+        ];
+        accounts.sort_by_key(|account| account.number());
+        accounts.dedup();
+        accounts
+    }
+}
+
+impl Default for ChartOfAccounts {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+impl HasSample for ChartOfAccounts {
+    fn sample() -> Self {
+        Self::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn test_deserialize_partial_uses_defaults() {
+        let chart: ChartOfAccounts = ron::from_str(
+            r#"(services_revenue: (number: 3041, name: "Försäljning tjänst, 25 % moms"))"#,
+        )
+        .unwrap();
+        assert_eq!(*chart.services_revenue().number(), 3041);
+        assert_eq!(chart.accounts_receivable(), &default_accounts_receivable());
+        assert_eq!(*chart.currency(), Currency::SEK);
+    }
+
+    #[test]
+    fn test_accounts_sorted_and_deduplicated() {
+        let chart = ChartOfAccounts::builder()
+            .expenses_revenue(default_services_revenue())
+            .build();
+        let numbers = chart
+            .accounts()
+            .into_iter()
+            .map(|account| *account.number())
+            .collect::<Vec<_>>();
+        assert_eq!(numbers, vec![1510, 2611, 3011]);
+    }
+}
@@ -0,0 +1,9 @@
+mod account;
+mod chart_of_accounts;
+mod pc8;
+mod sie_document;
+
+pub use account::*;
+pub use chart_of_accounts::*;
+pub use pc8::*;
+pub use sie_document::*;
@@ -0,0 +1,57 @@
+/// The characters of code page 437 from `0x80` to `0xAF`, which covers the
+/// letters used in Swedish and most western European languages.
+const CP437_HIGH: [char; 48] = [
+    'Ç', 'ü', 'é', 'â', 'ä', 'à', 'å', 'ç', 'ê', 'ë', 'è', 'ï', 'î', 'ì', 'Ä', 'Å', //
+    'É', 'æ', 'Æ', 'ô', 'ö', 'ò', 'û', 'ù', 'ÿ', 'Ö', 'Ü', '¢', '£', '¥', '₧', 'ƒ', //
+    'á', 'í', 'ó', 'ú', 'ñ', 'Ñ', 'ª', 'º', '¿', '⌐', '¬', '½', '¼', '¡', '«', '»', //
+];
+
+/// Encodes `text` as PC8, i.e. code page 437, the character encoding mandated
+/// by the SIE format. Characters without a PC8 representation are replaced
+/// with `?`.
+///
+/// # Examples
+/// ```
+/// extern crate klirr_core;
+/// use klirr_core::prelude::*;
+/// assert_eq!(encode_pc8("Kundfordringar"), b"Kundfordringar".to_vec());
+/// assert_eq!(encode_pc8("Försäljning"), b"F\x94rs\x84ljning".to_vec());
+/// ```
+pub fn encode_pc8(text: &str) -> Vec<u8> {
+    text.chars()
+        .map(|c| {
+            if c.is_ascii() {
+                return c as u8;
+            }
+            match c {
+                'ß' => 0xE1,
+                'µ' => 0xE6,
+                '°' => 0xF8,
+                _ => CP437_HIGH
+                    .iter()
+                    .position(|&high| high == c)
+                    .map(|index| 0x80 + index as u8)
+                    .unwrap_or(b'?'),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn test_swedish_letters() {
+        assert_eq!(
+            encode_pc8("åäöÅÄÖé"),
+            vec![0x86, 0x84, 0x94, 0x8F, 0x8E, 0x99, 0x82]
+        );
+    }
+
+    #[test]
+    fn test_unsupported_replaced() {
+        assert_eq!(encode_pc8("€"), b"?".to_vec());
+    }
+}
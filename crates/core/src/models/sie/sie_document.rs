@@ -0,0 +1,220 @@
+use crate::prelude::*;
+
+/// A transaction of a [`SieVerification`], where a positive amount is a
+/// debit and a negative amount a credit of `account`.
+#[derive(Clone, Debug, PartialEq, Eq, TypedBuilder, Getters)]
+pub struct SieTransaction {
+    /// The account number, e.g. `1510`.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    account: u16,
+
+    /// The amount, in the currency of the bookkeeping.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    amount: Cost,
+}
+
+/// A verification, i.e. a bookkeeping voucher, of a [`SieDocument`].
+#[derive(Clone, Debug, PartialEq, Eq, TypedBuilder, Getters)]
+pub struct SieVerification {
+    /// The date of the verification, e.g. the invoice date.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    date: Date,
+
+    /// A description of the verification, e.g. `"Faktura 243"`.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    text: String,
+
+    /// The transactions, which sum to zero.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    transactions: Vec<SieTransaction>,
+}
+
+impl SieVerification {
+    /// Whether debits equal credits.
+    pub fn is_balanced(&self) -> bool {
+        self.transactions
+            .iter()
+            .map(|transaction| **transaction.amount())
+            .sum::<rust_decimal::Decimal>()
+            .is_zero()
+    }
+}
+
+/// A SIE4 file, the Swedish standard for exchanging bookkeeping data, with
+/// verifications of the invoices of a fiscal year, e.g. for import into
+/// Fortnox or Visma.
+#[derive(Clone, Debug, PartialEq, Eq, TypedBuilder, Getters)]
+pub struct SieDocument {
+    /// The date this document was generated.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    generated: Date,
+
+    /// The name of the company the bookkeeping is for, the vendor.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    company_name: String,
+
+    /// The organisation number of the company, e.g. `"556677-8899"`.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    organisation_number: String,
+
+    /// The fiscal year, assumed to be the calendar year.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    year: Year,
+
+    /// The currency of the bookkeeping, e.g. `SEK`.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    currency: Currency,
+
+    /// The accounts used by the verifications.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    accounts: Vec<Account>,
+
+    /// One verification per invoice.
+    #[builder(setter(into))]
+    #[getset(get = "pub")]
+    verifications: Vec<SieVerification>,
+}
+
+/// Formats `date` as `YYYYMMDD`.
+fn sie_date(date: &Date) -> String {
+    date.to_string().replace('-', "")
+}
+
+/// Quotes `text`, escaping any quotes in it.
+fn sie_string(text: &str) -> String {
+    format!("\"{}\"", text.replace('"', "\\\""))
+}
+
+impl SieDocument {
+    /// The contents of the SIE file encoded as PC8, as mandated by the format.
+    pub fn to_pc8_bytes(&self) -> Vec<u8> {
+        encode_pc8(&self.to_string())
+    }
+}
+
+impl std::fmt::Display for SieDocument {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let first_day = Date::builder()
+            .year(self.year)
+            .month(Month::January)
+            .day(Day::try_from(1).expect("1 is a valid day"))
+            .build();
+        let last_day = YearAndMonth::new(self.year, Month::December).to_date_end_of_month();
+        writeln!(f, "#FLAGGA 0")?;
+        writeln!(
+            f,
+            "#PROGRAM {} {}",
+            sie_string(BINARY_NAME),
+            sie_string(env!("CARGO_PKG_VERSION"))
+        )?;
+        writeln!(f, "#FORMAT PC8")?;
+        writeln!(f, "#GEN {}", sie_date(&self.generated))?;
+        writeln!(f, "#SIETYP 4")?;
+        writeln!(f, "#ORGNR {}", self.organisation_number.replace(' ', ""))?;
+        writeln!(f, "#FNAMN {}", sie_string(&self.company_name))?;
+        writeln!(f, "#RAR 0 {} {}", sie_date(&first_day), sie_date(&last_day))?;
+        writeln!(f, "#VALUTA {}", self.currency)?;
+        for account in &self.accounts {
+            writeln!(
+                f,
+                "#KONTO {} {}",
+                account.number(),
+                sie_string(account.name())
+            )?;
+        }
+        for verification in &self.verifications {
+            writeln!(
+                f,
+                "#VER \"\" \"\" {} {}",
+                sie_date(verification.date()),
+                sie_string(verification.text())
+            )?;
+            writeln!(f, "{{")?;
+            for transaction in verification.transactions() {
+                writeln!(
+                    f,
+                    "\t#TRANS {} {{}} {:.2}",
+                    transaction.account(),
+                    **transaction.amount()
+                )?;
+            }
+            writeln!(f, "}}")?;
+        }
+        Ok(())
+    }
+}
+
+impl HasSample for SieDocument {
+    fn sample() -> Self {
+        let chart = ChartOfAccounts::sample();
+        let transaction = |account: &Account, amount: rust_decimal::Decimal| {
+            SieTransaction::builder()
+                .account(*account.number())
+                .amount(Cost::from(amount))
+                .build()
+        };
+        Self::builder()
+            .generated(Date::from_str("2025-07-01").unwrap())
+            .company_name("Lupin et Associés")
+            .organisation_number("7418529-3012")
+            .year(Year::from(2025))
+            .currency(Currency::SEK)
+            .accounts(chart.accounts().into_iter().cloned().collect::<Vec<_>>())
+            .verifications(vec![
+                SieVerification::builder()
+                    .date(Date::from_str("2025-05-31").unwrap())
+                    .text("Faktura 243 \"Holmes\" Ltd")
+                    .transactions(vec![
+                        transaction(chart.accounts_receivable(), dec!(12500)),
+                        transaction(chart.services_revenue(), dec!(-10000)),
+                        transaction(chart.output_vat(), dec!(-2500)),
+                    ])
+                    .build(),
+            ])
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use insta::assert_snapshot;
+    use test_log::test;
+
+    #[test]
+    fn test_sample_is_balanced() {
+        assert!(
+            SieDocument::sample()
+                .verifications()
+                .iter()
+                .all(SieVerification::is_balanced)
+        );
+    }
+
+    #[test]
+    fn test_sample_sie() {
+        assert_snapshot!(
+            SieDocument::sample()
+                .to_string()
+                .replace(env!("CARGO_PKG_VERSION"), "VERSION")
+        );
+    }
+
+    #[test]
+    fn test_pc8_bytes() {
+        let bytes = SieDocument::sample().to_pc8_bytes();
+        assert!(bytes.windows(4).any(|w| w == b"F\x94rs"));
+        assert!(!bytes.is_ascii());
+    }
+}
@@ -32,6 +32,18 @@ pub struct ValidInput {
     #[builder(default, setter(into))]
     #[getset(get = "pub")]
     maybe_output_path: Option<PathBuf>,
+
+    /// The quantities of the services whose quantity is specified per run,
+    /// see [`ServiceQuantity::PerRun`].
+    #[builder(default, setter(into))]
+    #[getset(get = "pub")]
+    service_quantities: Vec<ServiceQuantityOverride>,
+
+    /// Discounts, surcharges and credits of this invoice only, applied after
+    /// the ones agreed with the client.
+    #[builder(default, setter(into))]
+    #[getset(get = "pub")]
+    adjustments: Vec<Adjustment>,
 }
 
 impl HasSample for ValidInput {
@@ -0,0 +1,68 @@
+//! Compares rendering invoices with a new Typst environment per invoice, as
+//! [`render`] does, against reusing one [`RenderEngine`], in sequence and in
+//! parallel. Run with `cargo bench -p klirr-render`.
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use klirr_render::prelude::*;
+
+fn job(month: YearAndMonth) -> RenderJob {
+    let data = prepare_invoice_input_data(
+        Data::sample(),
+        ValidInput::builder()
+            .items(InvoicedItems::Service { days_off: None })
+            .month(month)
+            .build(),
+        ExchangeRatesFetcher::default(),
+    )
+    .expect("Sample data should be valid");
+    RenderJob::builder()
+        .l18n(L18n::new(Language::EN).expect("English should be available"))
+        .data(data)
+        .build()
+}
+
+fn jobs() -> Vec<RenderJob> {
+    YearAndMonth::january(2025)
+        .months_through(YearAndMonth::april(2025))
+        .into_iter()
+        .map(job)
+        .collect()
+}
+
+fn bench_render(c: &mut Criterion) {
+    let jobs = jobs();
+    let mut group = c.benchmark_group("render 4 invoices");
+    group.sample_size(10);
+
+    group.bench_function("new environment per invoice", |b| {
+        b.iter(|| {
+            for job in jobs.iter() {
+                render(job.l18n().clone(), job.data().clone(), *job.layout()).unwrap();
+            }
+        })
+    });
+
+    let engine = RenderEngine::new();
+    group.bench_function("reused engine, in sequence", |b| {
+        b.iter(|| {
+            for job in jobs.iter() {
+                engine
+                    .render(job.l18n().clone(), job.data().clone(), *job.layout())
+                    .unwrap();
+            }
+        })
+    });
+
+    group.bench_function("reused engine, in parallel", |b| {
+        b.iter(|| {
+            for pdf in engine.render_all(jobs.clone()) {
+                pdf.unwrap();
+            }
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_render);
+criterion_main!(benches);
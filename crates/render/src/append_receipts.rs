@@ -0,0 +1,134 @@
+use crate::prelude::*;
+use lopdf::{Document, Object, ObjectId};
+
+/// The attributes a page inherits from its ancestors in the page tree, which
+/// must be copied onto the page when moving it to another page tree.
+const INHERITABLE_PAGE_ATTRIBUTES: [&[u8]; 4] = [b"Resources", b"MediaBox", b"CropBox", b"Rotate"];
+
+fn append_error(underlying: impl std::fmt::Debug) -> Error {
+    Error::FailedToAppendReceipts {
+        underlying: format!("{:?}", underlying),
+    }
+}
+
+/// Copies the attributes the page `page_id` inherits from its ancestors onto
+/// the page itself.
+fn inline_inherited_attributes(document: &mut Document, page_id: ObjectId) -> Result<()> {
+    let page = document.get_dictionary(page_id).map_err(append_error)?;
+    let mut missing = INHERITABLE_PAGE_ATTRIBUTES
+        .into_iter()
+        .filter(|key| !page.has(key))
+        .collect::<Vec<_>>();
+    let mut inherited = Vec::new();
+    let mut parent = page.get(b"Parent").and_then(Object::as_reference).ok();
+    while let Some(parent_id) = parent {
+        if missing.is_empty() {
+            break;
+        }
+        let node = document.get_dictionary(parent_id).map_err(append_error)?;
+        missing.retain(|key| match node.get(key) {
+            Ok(value) => {
+                inherited.push((key.to_vec(), value.clone()));
+                false
+            }
+            Err(_) => true,
+        });
+        parent = node.get(b"Parent").and_then(Object::as_reference).ok();
+    }
+    let page = document.get_dictionary_mut(page_id).map_err(append_error)?;
+    for (key, value) in inherited {
+        page.set(key, value);
+    }
+    Ok(())
+}
+
+/// True if `object` is a node of a page tree or a catalog, which are not
+/// needed when moving the pages of a document into another one.
+fn is_page_tree_node_or_catalog(object: &Object) -> bool {
+    object
+        .as_dict()
+        .is_ok_and(|dict| dict.has_type(b"Pages") || dict.has_type(b"Catalog"))
+}
+
+/// Appends the PDF `receipts` to `pdf` in the order of their references.
+///
+/// The last pages of `pdf` must be the pages of the image receipts, in the
+/// order of their references, since images are rendered by Typst, whereas
+/// all pages of PDF receipts are merged into `pdf`.
+pub(crate) fn append_receipts(pdf: Pdf, receipts: &[Receipt]) -> Result<Pdf> {
+    if receipts.iter().all(|receipt| receipt.format().is_image()) {
+        return Ok(pdf);
+    }
+    let mut document = Document::load_mem(pdf.as_ref()).map_err(append_error)?;
+    let pages = document.get_pages().into_values().collect::<Vec<_>>();
+    let image_count = receipts
+        .iter()
+        .filter(|receipt| receipt.format().is_image())
+        .count();
+    let invoice_page_count = pages.len().checked_sub(image_count).ok_or_else(|| {
+        append_error(format!(
+            "Expected at least #{} pages of image receipts, found #{} pages",
+            image_count,
+            pages.len()
+        ))
+    })?;
+    for page in &pages {
+        inline_inherited_attributes(&mut document, *page)?;
+    }
+    let mut image_pages = pages[invoice_page_count..].iter().copied();
+    let mut ordered = pages[..invoice_page_count].to_vec();
+    for receipt in receipts {
+        if receipt.format().is_image() {
+            ordered.extend(image_pages.next());
+            continue;
+        }
+        debug!("Appending PDF receipt #{}", receipt.reference());
+        let mut receipt_document = Document::load_mem(receipt.content()).map_err(|e| {
+            append_error(format!(
+                "Invalid PDF receipt #{}: {:?}",
+                receipt.reference(),
+                e
+            ))
+        })?;
+        receipt_document.renumber_objects_with(document.max_id + 1);
+        let receipt_pages = receipt_document
+            .get_pages()
+            .into_values()
+            .collect::<Vec<_>>();
+        for page in &receipt_pages {
+            inline_inherited_attributes(&mut receipt_document, *page)?;
+        }
+        document.max_id = receipt_document.max_id;
+        document.objects.extend(
+            receipt_document
+                .objects
+                .into_iter()
+                .filter(|(_, object)| !is_page_tree_node_or_catalog(object)),
+        );
+        ordered.extend(receipt_pages);
+    }
+
+    let pages_id = document
+        .catalog()
+        .and_then(|catalog| catalog.get(b"Pages"))
+        .and_then(Object::as_reference)
+        .map_err(append_error)?;
+    for page in &ordered {
+        document
+            .get_dictionary_mut(*page)
+            .map_err(append_error)?
+            .set("Parent", Object::Reference(pages_id));
+    }
+    let pages_node = document
+        .get_dictionary_mut(pages_id)
+        .map_err(append_error)?;
+    pages_node.set(
+        "Kids",
+        Object::Array(ordered.iter().copied().map(Object::Reference).collect()),
+    );
+    pages_node.set("Count", Object::Integer(ordered.len() as i64));
+
+    let mut bytes = Vec::new();
+    document.save_to(&mut bytes).map_err(append_error)?;
+    Ok(Pdf::from(bytes))
+}
@@ -1,11 +1,15 @@
+mod append_receipts;
 mod render;
+mod render_engine;
 mod typst_context;
 
 #[cfg(test)]
 mod render_test_helpers;
 
 pub mod prelude {
+    pub(crate) use crate::append_receipts::*;
     pub use crate::render::*;
+    pub use crate::render_engine::*;
     pub(crate) use crate::typst_context::*;
 
     pub use getset::Getters;
@@ -1,37 +1,87 @@
 use crate::prelude::*;
-use typst::layout::PagedDocument;
+use typst::layout::{Abs, PagedDocument};
+use typst::visualize::Color;
 use typst_pdf::PdfOptions;
 use typst_pdf::pdf;
 
+use std::sync::Arc;
+
 pub const TYPST_VIRTUAL_NAME_MAIN: &str = "main.typ";
 pub const TYPST_VIRTUAL_NAME_LAYOUT: &str = "layout.typ";
 pub const TYPST_VIRTUAL_NAME_DATA: &str = "data.typ";
 pub const TYPST_VIRTUAL_NAME_L18N: &str = "l18n.typ";
 
 /// Renders a PDF document using Typst with the provided layout, localization, and data.
+///
+/// Creates a new Typst environment for every call, use a [`RenderEngine`] to
+/// render many invoices.
 pub fn render(l18n: L18n, data: PreparedData, layout: Layout) -> Result<Pdf> {
+    RenderEngine::new().render(l18n, data, layout)
+}
+
+/// Renders a preview image of the invoice in `format`, all pages stacked
+/// vertically.
+///
+/// Creates a new Typst environment for every call, use a [`RenderEngine`] to
+/// render many previews.
+pub fn render_preview(
+    l18n: L18n,
+    data: PreparedData,
+    layout: Layout,
+    format: PreviewFormat,
+) -> Result<Preview> {
+    RenderEngine::new().render_preview(l18n, data, layout, format)
+}
+
+/// The resolution of PNG previews, `2` pixels per point is 144 DPI.
+const PREVIEW_PIXELS_PER_POINT: f32 = 2.0;
+
+/// Compiles the invoice into a Typst document using the already created `environment`.
+pub(crate) fn compile_with_environment(
+    environment: Arc<Environment>,
+    l18n: L18n,
+    data: PreparedData,
+    layout: Layout,
+) -> Result<PagedDocument> {
     let l18n_typst_str = l18n.content().to_typst_fn();
     let data_typst_str = data.to_typst_fn();
     let layout_typst_str = layout.to_typst_fn();
+    let image_receipts = data
+        .receipts()
+        .iter()
+        .filter(|receipt| receipt.format().is_image())
+        .collect::<Vec<_>>();
     let main = format!(
         r#"
     #import "{}": provide as provide_data
     #import "{}": provide as provide_localization
     #import "{}": render_invoice
-    #render_invoice(provide_data(), provide_localization())
+    #let data = provide_data()
+    #let l18n = provide_localization()
+    #render_invoice(data, l18n)
+    {}
     "#,
-        TYPST_VIRTUAL_NAME_DATA, TYPST_VIRTUAL_NAME_L18N, TYPST_VIRTUAL_NAME_LAYOUT
+        TYPST_VIRTUAL_NAME_DATA,
+        TYPST_VIRTUAL_NAME_L18N,
+        TYPST_VIRTUAL_NAME_LAYOUT,
+        image_receipts
+            .iter()
+            .map(|receipt| receipt_page(receipt))
+            .collect::<String>()
     );
 
-    debug!("☑️ Creating typst 'World' (environment/context), this usually takes ~2 seconds.");
     let context = TypstContext::with_inline(
-        layout.required_fonts(),
+        environment,
         main,
         layout_typst_str,
         l18n_typst_str,
         data_typst_str,
-    )?;
-    debug!("✅ Created typst 'World' (environment/context)");
+    )?
+    .with_files(
+        image_receipts
+            .into_iter()
+            .map(|receipt| (receipt.file_name(), receipt.content().clone())),
+    );
 
     debug!("☑️ Compiling typst...");
     let compile_result = typst::compile::<PagedDocument>(&context);
@@ -39,8 +89,29 @@ pub fn render(l18n: L18n, data: PreparedData, layout: Layout) -> Result<Pdf> {
         underlying: format!("{:?}", e),
     })?;
     debug!("✅ Compiled typst source: #{} pages", doc.pages.len());
+    Ok(doc)
+}
+
+/// A page with the image `receipt`, scaled to fit the page, below a heading
+/// with its reference, matching the number of its line item.
+fn receipt_page(receipt: &Receipt) -> String {
+    format!(
+        r#"
+    #page(margin: 1.5cm)[
+      #strong[#l18n.line_items.receipt {reference}: #data.line_items.items.at({index}).name]
+      #image("{file_name}", width: 100%, height: 1fr, fit: "contain")
+    ]
+    "#,
+        reference = receipt.reference(),
+        index = receipt.reference() - 1,
+        file_name = receipt.file_name(),
+    )
+}
+
+/// Exports the compiled `doc` as a PDF.
+pub(crate) fn export_pdf(doc: &PagedDocument) -> Result<Pdf> {
     let export_pdf_options = &PdfOptions::default();
-    let pdf_bytes = pdf(&doc, export_pdf_options).map_err(|e| Error::ExportDocumentToPdf {
+    let pdf_bytes = pdf(doc, export_pdf_options).map_err(|e| Error::ExportDocumentToPdf {
         underlying: format!("{:?}", e),
     })?;
     // Convert the exported PDF bytes into a Pdf type.
@@ -48,6 +119,24 @@ pub fn render(l18n: L18n, data: PreparedData, layout: Layout) -> Result<Pdf> {
     Ok(pdf)
 }
 
+/// Exports the compiled `doc` as a preview image in `format`.
+pub(crate) fn export_preview(doc: &PagedDocument, format: PreviewFormat) -> Result<Preview> {
+    let bytes = match format {
+        PreviewFormat::Png => typst_render::render_merged(
+            doc,
+            PREVIEW_PIXELS_PER_POINT,
+            Abs::zero(),
+            Some(Color::WHITE),
+        )
+        .encode_png()
+        .map_err(|e| Error::FailedToRenderPreview {
+            underlying: format!("{:?}", e),
+        })?,
+        PreviewFormat::Svg => typst_svg::svg_merged(doc, Abs::zero()).into_bytes(),
+    };
+    Ok(Preview::from(bytes))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -56,10 +145,6 @@ mod tests {
 
     #[test]
     fn sample_expenses() {
-        if running_in_ci() {
-            // Skip this test in CI, as it requires imagemagick to be installed.
-            return;
-        }
         compare_image_against_expected(
             Data::sample(),
             ValidInput::builder()
@@ -73,10 +158,6 @@ mod tests {
 
     #[test]
     fn sample_services() {
-        if running_in_ci() {
-            // Skip this test in CI, as it requires imagemagick to be installed.
-            return;
-        }
         compare_image_against_expected(
             Data::sample(),
             ValidInput::builder()
@@ -87,4 +168,259 @@ mod tests {
             fixture("expected_services.png"),
         );
     }
+
+    #[test]
+    fn sample_services_with_vat() {
+        let data = Data::sample();
+        let information = data
+            .information()
+            .clone()
+            .with_vat_rate(Percentage::from(25));
+        let prepared = prepare_invoice_input_data(
+            data.with_information(information),
+            ValidInput::builder()
+                .items(InvoicedItems::Service { days_off: None })
+                .month(YearAndMonth::sample())
+                .build(),
+            ExchangeRatesFetcher::default(),
+        )
+        .unwrap();
+        assert_eq!(*prepared.information().vat_rate(), Percentage::from(25));
+        let pdf = render(L18n::new(Language::EN).unwrap(), prepared, Layout::Aioo).unwrap();
+        assert!(pdf.as_ref().starts_with(b"%PDF"));
+    }
+
+    #[test]
+    fn sample_services_with_days_off() {
+        let data = Data::sample();
+        let mut information = data.information().clone();
+        information.insert_days_off(DateRange::sample());
+        let prepared = prepare_invoice_input_data(
+            data.with_information(information),
+            ValidInput::builder()
+                .items(InvoicedItems::Service { days_off: None })
+                .month(YearAndMonth::sample())
+                .build(),
+            ExchangeRatesFetcher::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            prepared.information().days_off(),
+            &vec![DateRange::sample()]
+        );
+        let pdf = render(L18n::new(Language::EN).unwrap(), prepared, Layout::Aioo).unwrap();
+        assert!(pdf.as_ref().starts_with(b"%PDF"));
+    }
+
+    #[test]
+    fn sample_combined() {
+        let data = DataWithItemsPricedInSourceCurrency::builder()
+            .information(InvoiceInfoFull::sample())
+            .vendor(CompanyInformation::sample())
+            .client(CompanyInformation::sample())
+            .line_items(LineItemsPricedInSourceCurrency::Combined {
+                services: vec![Item::sample(), Item::sample_expense_sandwich()],
+                expenses: vec![
+                    Item::sample_expense_breakfast(),
+                    Item::sample_expense_coffee(),
+                ],
+            })
+            .payment_info(PaymentInformation::sample())
+            .output_path(OutputPath::Name("invoice.pdf".into()))
+            .build()
+            .to_typst(ExchangeRates::hard_coded())
+            .unwrap();
+        assert_eq!(data.line_items().kind(), InvoiceKind::Combined);
+        let pdf = render(L18n::new(Language::EN).unwrap(), data, Layout::Aioo).unwrap();
+        assert!(pdf.as_ref().starts_with(b"%PDF"));
+    }
+
+    #[test]
+    fn sample_expenses_with_recurring() {
+        let recurring = RecurringExpense::sample()
+            .item_in(&YearAndMonth::may(2025))
+            .unwrap();
+        let data = DataWithItemsPricedInSourceCurrency::builder()
+            .information(InvoiceInfoFull::sample())
+            .vendor(CompanyInformation::sample())
+            .client(CompanyInformation::sample())
+            .line_items(LineItemsPricedInSourceCurrency::Expenses(vec![
+                Item::sample_expense_coffee(),
+                recurring,
+            ]))
+            .payment_info(PaymentInformation::sample())
+            .output_path(OutputPath::Name("invoice.pdf".into()))
+            .build()
+            .to_typst(ExchangeRates::hard_coded())
+            .unwrap();
+        assert!(*data.line_items().items()[1].recurring());
+        let pdf = render(L18n::new(Language::EN).unwrap(), data, Layout::Aioo).unwrap();
+        assert!(pdf.as_ref().starts_with(b"%PDF"));
+    }
+
+    #[test]
+    fn sample_services_with_adjustments() {
+        let data = Data::sample();
+        let information = data
+            .information()
+            .clone()
+            .with_vat_rate(Percentage::from(25))
+            .with_adjustments(vec![Adjustment::sample()]);
+        let prepared = prepare_invoice_input_data(
+            data.with_information(information),
+            ValidInput::builder()
+                .items(InvoicedItems::Service { days_off: None })
+                .month(YearAndMonth::sample())
+                .adjustments(vec![Adjustment::of_subtotal(
+                    AdjustmentKind::Credit,
+                    "500".parse().unwrap(),
+                )])
+                .build(),
+            ExchangeRatesFetcher::default(),
+        )
+        .unwrap();
+        assert_eq!(prepared.line_items().adjustments().len(), 2);
+        let pdf = render(L18n::new(Language::EN).unwrap(), prepared, Layout::Aioo).unwrap();
+        assert!(pdf.as_ref().starts_with(b"%PDF"));
+    }
+
+    #[test]
+    fn sample_services_svg_preview() {
+        let prepared = prepare_invoice_input_data(
+            Data::sample(),
+            ValidInput::builder()
+                .items(InvoicedItems::Service { days_off: None })
+                .month(YearAndMonth::sample())
+                .build(),
+            ExchangeRatesFetcher::default(),
+        )
+        .unwrap();
+        let svg = render_preview(
+            L18n::new(Language::EN).unwrap(),
+            prepared,
+            Layout::Aioo,
+            PreviewFormat::Svg,
+        )
+        .unwrap();
+        assert!(svg.as_ref().starts_with(b"<svg"));
+    }
+
+    fn png_receipt() -> Vec<u8> {
+        let mut bytes = Vec::new();
+        image::RgbImage::from_pixel(40, 60, image::Rgb([200, 30, 30]))
+            .write_to(
+                &mut std::io::Cursor::new(&mut bytes),
+                image::ImageFormat::Png,
+            )
+            .unwrap();
+        bytes
+    }
+
+    #[test]
+    fn sample_expenses_with_receipts() {
+        let l18n = L18n::new(Language::EN).unwrap();
+        let pdf_receipt = render(l18n.clone(), PreparedData::sample(), Layout::Aioo).unwrap();
+        let pdf_receipt_pages = lopdf::Document::load_mem(pdf_receipt.as_ref())
+            .unwrap()
+            .get_pages()
+            .len();
+        let data = DataWithItemsPricedInSourceCurrency::builder()
+            .information(InvoiceInfoFull::sample())
+            .vendor(CompanyInformation::sample())
+            .client(CompanyInformation::sample())
+            .line_items(LineItemsPricedInSourceCurrency::Expenses(vec![
+                Item::sample_expense_breakfast(),
+                Item::sample_expense_coffee().with_receipt("coffee.pdf"),
+                Item::sample_expense_sandwich().with_receipt("sandwich.png"),
+            ]))
+            .payment_info(PaymentInformation::sample())
+            .output_path(OutputPath::Name("invoice.pdf".into()))
+            .build()
+            .to_typst(ExchangeRates::hard_coded())
+            .unwrap()
+            .with_receipts(vec![
+                Receipt::builder()
+                    .reference(2)
+                    .name("Coffee")
+                    .format(ReceiptFormat::Pdf)
+                    .content(pdf_receipt.as_ref().to_vec())
+                    .build(),
+                Receipt::builder()
+                    .reference(3)
+                    .name("Sandwich")
+                    .format(ReceiptFormat::Png)
+                    .content(png_receipt())
+                    .build(),
+            ]);
+        let without_receipts = render(
+            l18n.clone(),
+            data.clone().with_receipts(Vec::new()),
+            Layout::Aioo,
+        )
+        .unwrap();
+        let invoice_pages = lopdf::Document::load_mem(without_receipts.as_ref())
+            .unwrap()
+            .get_pages()
+            .len();
+
+        let pdf = render(l18n, data, Layout::Aioo).unwrap();
+        let document = lopdf::Document::load_mem(pdf.as_ref()).unwrap();
+        let pages = document.get_pages().into_values().collect::<Vec<_>>();
+        assert_eq!(pages.len(), invoice_pages + pdf_receipt_pages + 1);
+        let has_image = |page: lopdf::ObjectId| {
+            let (resources, resource_ids) = document.get_page_resources(page).unwrap();
+            resources
+                .into_iter()
+                .chain(
+                    resource_ids
+                        .into_iter()
+                        .map(|id| document.get_dictionary(id).unwrap()),
+                )
+                .filter_map(|resources| resources.get_deref(b"XObject", &document).ok())
+                .filter_map(|x_objects| x_objects.as_dict().ok())
+                .flat_map(|x_objects| x_objects.iter())
+                .filter_map(|(_, x_object)| document.dereference(x_object).ok())
+                .filter_map(|(_, x_object)| x_object.as_stream().ok())
+                .any(|x_object| {
+                    x_object
+                        .dict
+                        .get(b"Subtype")
+                        .and_then(lopdf::Object::as_name)
+                        .is_ok_and(|subtype| subtype == b"Image")
+                })
+        };
+        // The receipts are in the order of their line items, the PDF receipt
+        // of the coffee first and the image receipt of the sandwich last.
+        assert!(!has_image(pages[invoice_pages]));
+        assert!(has_image(*pages.last().unwrap()));
+    }
+
+    #[test]
+    fn sample_expenses_with_invalid_pdf_receipt() {
+        let data = PreparedData::sample().with_receipts(vec![
+            Receipt::builder()
+                .reference(1)
+                .name("Coffee")
+                .format(ReceiptFormat::Pdf)
+                .content(b"not a pdf".to_vec())
+                .build(),
+        ]);
+        let result = render(L18n::new(Language::EN).unwrap(), data, Layout::Aioo);
+        assert!(matches!(result, Err(Error::FailedToAppendReceipts { .. })));
+    }
+
+    #[test]
+    fn sample_reminder() {
+        let l18n = L18n::new(Language::EN).unwrap();
+        let data = prepare_reminder_data(
+            Data::sample(),
+            &InvoiceLedger::sample(),
+            &Payments::default(),
+            &ValidReminderInput::sample(),
+            l18n.content().reminder(),
+        )
+        .unwrap();
+        let pdf = render(l18n, data, Layout::Reminder).unwrap();
+        assert!(pdf.as_ref().starts_with(b"%PDF"));
+    }
 }
@@ -0,0 +1,167 @@
+use crate::prelude::*;
+
+use std::sync::{Arc, Mutex};
+
+/// An invoice to render with a [`RenderEngine`].
+#[derive(Clone, Debug, TypedBuilder, Getters)]
+pub struct RenderJob {
+    /// The localization of the labels of the invoice.
+    #[getset(get = "pub")]
+    l18n: L18n,
+
+    /// The prepared data of the invoice.
+    #[getset(get = "pub")]
+    data: PreparedData,
+
+    /// The layout to render the invoice with.
+    #[builder(default)]
+    #[getset(get = "pub")]
+    layout: Layout,
+}
+
+/// A reusable engine for rendering invoices, which creates the Typst
+/// environment, i.e. the fonts, font book and standard library, once per
+/// layout and shares it between all renders, in sequence or in parallel. The
+/// current date is read anew for every render.
+///
+/// Creating the environment is by far the most expensive part of rendering
+/// an invoice, so batch jobs and long running processes should create one
+/// engine and reuse it.
+#[derive(Debug, Default)]
+pub struct RenderEngine {
+    environments: Mutex<IndexMap<Layout, Arc<Environment>>>,
+}
+
+impl RenderEngine {
+    /// Creates an engine without any cached environment, the environment of
+    /// a layout is created the first time it is rendered.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the cached environment for `layout`, creating it if needed.
+    fn environment(&self, layout: Layout) -> Result<Arc<Environment>> {
+        let mut environments = self
+            .environments
+            .lock()
+            .expect("No render panics while holding the lock");
+        if let Some(environment) = environments.get(&layout) {
+            return Ok(Arc::clone(environment));
+        }
+        debug!(
+            "☑️ Creating typst environment for layout: {}, this usually takes ~2 seconds.",
+            layout
+        );
+        let environment = Arc::new(Environment::new(layout.required_fonts())?);
+        debug!("✅ Created typst environment for layout: {}", layout);
+        environments.insert(layout, Arc::clone(&environment));
+        Ok(environment)
+    }
+
+    /// Renders a PDF document of `data` using `layout` and `l18n`, with the
+    /// receipts of `data` appended, reusing the environment of `layout` if it
+    /// has been rendered before.
+    pub fn render(&self, l18n: L18n, data: PreparedData, layout: Layout) -> Result<Pdf> {
+        let environment = self.environment(layout)?;
+        let receipts = data.receipts().clone();
+        let doc = compile_with_environment(environment, l18n, data, layout)?;
+        append_receipts(export_pdf(&doc)?, &receipts)
+    }
+
+    /// Renders a preview image of `data` in `format`, reusing the environment
+    /// of `layout` if it has been rendered before.
+    pub fn render_preview(
+        &self,
+        l18n: L18n,
+        data: PreparedData,
+        layout: Layout,
+        format: PreviewFormat,
+    ) -> Result<Preview> {
+        let environment = self.environment(layout)?;
+        let doc = compile_with_environment(environment, l18n, data, layout)?;
+        export_preview(&doc, format)
+    }
+
+    /// Renders all `jobs` in parallel, spread over the available cores,
+    /// returning the results in the same order as `jobs`.
+    pub fn render_all(&self, jobs: Vec<RenderJob>) -> Vec<Result<Pdf>> {
+        let threads = std::thread::available_parallelism()
+            .map(usize::from)
+            .unwrap_or(1);
+        let chunk_size = jobs.len().div_ceil(threads).max(1);
+        let render_chunk = |chunk: &[RenderJob]| {
+            chunk
+                .iter()
+                .cloned()
+                .map(|job| self.render(job.l18n, job.data, job.layout))
+                .collect::<Vec<_>>()
+        };
+        std::thread::scope(|scope| {
+            jobs.chunks(chunk_size)
+                .map(|chunk| scope.spawn(move || render_chunk(chunk)))
+                .collect::<Vec<_>>()
+                .into_iter()
+                .flat_map(|handle| handle.join().expect("Rendering should not panic"))
+                .collect()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    fn job(month: YearAndMonth) -> RenderJob {
+        let data = prepare_invoice_input_data(
+            Data::sample(),
+            ValidInput::builder()
+                .items(InvoicedItems::Service { days_off: None })
+                .month(month)
+                .build(),
+            ExchangeRatesFetcher::default(),
+        )
+        .unwrap();
+        RenderJob::builder()
+            .l18n(L18n::new(Language::EN).unwrap())
+            .data(data)
+            .build()
+    }
+
+    #[test]
+    fn render_in_sequence_reuses_environment() {
+        let engine = RenderEngine::new();
+        for month in [YearAndMonth::january(2025), YearAndMonth::february(2025)] {
+            let job = job(month);
+            let pdf = engine.render(job.l18n, job.data, job.layout).unwrap();
+            assert!(pdf.as_ref().starts_with(b"%PDF"));
+        }
+        assert_eq!(engine.environments.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn render_all_in_parallel_keeps_order() {
+        let engine = RenderEngine::new();
+        let months = YearAndMonth::january(2025).months_through(YearAndMonth::april(2025));
+        let jobs = months.iter().map(|month| job(*month)).collect::<Vec<_>>();
+        let sequential = jobs
+            .iter()
+            .cloned()
+            .map(|job| engine.render(job.l18n, job.data, job.layout).unwrap())
+            .collect::<Vec<_>>();
+        let parallel = engine
+            .render_all(jobs)
+            .into_iter()
+            .map(Result::unwrap)
+            .collect::<Vec<_>>();
+        assert_eq!(parallel.len(), sequential.len());
+        for (parallel, sequential) in parallel.iter().zip(sequential.iter()) {
+            assert_eq!(parallel.as_ref().len(), sequential.as_ref().len());
+        }
+    }
+
+    #[test]
+    fn render_all_empty() {
+        assert!(RenderEngine::new().render_all(Vec::new()).is_empty());
+    }
+}
@@ -1,7 +1,6 @@
 use crate::prelude::*;
 
-use std::{env, error::Error, io::Write, process::Command};
-use tempfile::NamedTempFile;
+use std::env;
 
 /// Resolves a path relative to the crate this function is defined in.
 ///
@@ -10,29 +9,6 @@ pub fn path_to_resource(relative: impl AsRef<Path>) -> PathBuf {
     PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(relative)
 }
 
-/// Checks if ImageMagick is installed by trying to run `magick -version` or `convert -version`.
-pub fn is_imagemagick_installed() -> bool {
-    // Try `magick` (modern) first
-    if std::process::Command::new("magick")
-        .arg("-version")
-        .output()
-        .is_ok()
-    {
-        return true;
-    }
-
-    // Fallback to `convert` (legacy)
-    if std::process::Command::new("convert")
-        .arg("-version")
-        .output()
-        .is_ok()
-    {
-        return true;
-    }
-
-    false
-}
-
 /// Resolves a path relative to the crate this function is defined in.
 ///
 /// The base is the folder containing this crate’s `Cargo.toml`.
@@ -52,11 +28,7 @@ pub fn compare_image_against_expected(
     input: ValidInput,
     path_to_expected_image: impl AsRef<Path>,
 ) {
-    assert!(
-        is_imagemagick_installed(),
-        "Imagemagick not installed, but required to run. `brew install imagemagick`"
-    );
-    let new_image = generate_pdf_into_png_image(L18n::new(Language::EN).unwrap(), sample, input);
+    let new_image = generate_png_image(L18n::new(Language::EN).unwrap(), sample, input);
 
     let save_new_image_as_expected = |new_image: Vec<u8>| {
         if !running_in_ci() {
@@ -113,84 +85,32 @@ pub fn compare_image_against_expected(
     }
 }
 
-#[cfg(test)]
-trait TestExchangeRatesFetcher {
-    fn tmp() -> ExchangeRatesFetcher<tempfile::TempDir>;
-}
-#[cfg(test)]
-impl TestExchangeRatesFetcher for ExchangeRatesFetcher<tempfile::TempDir> {
-    fn tmp() -> ExchangeRatesFetcher<tempfile::TempDir> {
-        use tempfile::tempdir;
-
-        let tempdir = tempdir().expect("Failed to create temporary directory");
-        ExchangeRatesFetcher::builder()
-            .path_to_cache(tempdir.path().to_path_buf())
-            .extra(tempdir)
-            .build()
+/// Exchange rates which are hard coded, so that image tests do not depend on
+/// the network nor on the rates of the day.
+struct HardCodedExchangeRates;
+impl FetchExchangeRates for HardCodedExchangeRates {
+    fn fetch_for_items(
+        &self,
+        target_currency: Currency,
+        _items: Vec<Item>,
+    ) -> Result<ExchangeRates> {
+        assert_eq!(target_currency, Currency::EUR);
+        let rate = |rate: &str| UnitPrice::from(Decimal::from_str(rate).unwrap());
+        Ok(ExchangeRates::builder()
+            .target_currency(target_currency)
+            .rates(ExchangeRatesMap::from_iter([
+                (Currency::GBP, rate("1.1741")),
+                (Currency::SEK, rate("0.0911")),
+                (Currency::USD, rate("0.8812")),
+            ]))
+            .build())
     }
 }
 
-/// Generates a PNG image from a PDF rendered from the given layout path and input data.
-fn generate_pdf_into_png_image(l18n: L18n, sample: Data, input: ValidInput) -> Vec<u8> {
+/// Generates a PNG preview image of the invoice rendered from the given input data.
+fn generate_png_image(l18n: L18n, sample: Data, input: ValidInput) -> Vec<u8> {
     let layout = *input.layout();
-    let data = prepare_invoice_input_data(sample, input, ExchangeRatesFetcher::tmp()).unwrap();
-    let pdf = render(l18n, data, layout).unwrap();
-    convert_pdf_to_pngs(pdf.as_ref(), 85.0).expect("Should be able to convert")
-}
-
-/// Converts PDF bytes to a single PNG image, with a white background and correct size.
-///
-/// # Arguments
-/// * `pdf_bytes` - A slice of bytes representing the PDF file.
-/// * `dpi` - Resolution in dots per inch (e.g., 300 for high quality)
-///
-/// # Returns
-/// Result containing the number of pages converted or an error.
-fn convert_pdf_to_pngs(pdf_bytes: &[u8], dpi: f64) -> Result<Vec<u8>, Box<dyn Error>> {
-    // Write PDF bytes to a temporary file
-    let mut temp_pdf = NamedTempFile::new()?;
-    temp_pdf.write_all(pdf_bytes)?;
-    let pdf_path = temp_pdf.path();
-
-    // Create another temp file for the output PNG
-    let temp_png = NamedTempFile::new()?;
-    let png_path = temp_png.path().with_extension("png");
-
-    // Construct DPI argument (as integer string)
-    let dpi_arg = format!("{}", dpi as u32);
-
-    // Run the `convert` command using ImageMagick
-    let status = Command::new("magick")
-        .arg("-density")
-        .arg(&dpi_arg)
-        .arg("-units")
-        .arg("PixelsPerInch")
-        .arg(pdf_path)
-        .arg("-colorspace")
-        .arg("RGB")
-        .arg("-background")
-        .arg("white")
-        .arg("-alpha")
-        .arg("remove")
-        .arg("-flatten")
-        .arg("+profile")
-        .arg("*")
-        .arg("-strip")
-        .arg("-define")
-        .arg("png:compression-filter=0")
-        .arg("-define")
-        .arg("png:compression-level=9")
-        .arg("-define")
-        .arg("png:compression-strategy=1")
-        .arg(&png_path)
-        .status()?;
-
-    if !status.success() {
-        return Err("ImageMagick convert command failed".into());
-    }
-
-    // Read the resulting PNG bytes
-    let png_bytes = std::fs::read(&png_path)?;
-
-    Ok(png_bytes)
+    let data = prepare_invoice_input_data(sample, input, HardCodedExchangeRates).unwrap();
+    let preview = render_preview(l18n, data, layout, PreviewFormat::Png).unwrap();
+    preview.as_ref().to_vec()
 }
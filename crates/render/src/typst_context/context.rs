@@ -1,10 +1,12 @@
 use crate::prelude::*;
 
 use chrono::FixedOffset;
+use std::sync::Arc;
 use typst::{
     Library, World,
+    diag::{FileError, FileResult},
     foundations::{Bytes, Datetime},
-    syntax::{FileId, Source},
+    syntax::{FileId, Source, VirtualPath},
     text::{Font, FontBook},
     utils::LazyHash,
 };
@@ -17,14 +19,23 @@ pub struct TypstContext {
     #[getset(get = "pub")]
     content: Content,
 
-    /// The environment containing the library, font book, and current time.
+    /// The environment containing the library and font book, shared between
+    /// contexts rendering with the same fonts.
     #[getset(get = "pub")]
-    environment: Environment,
+    environment: Arc<Environment>,
+
+    /// The local date and time when this context was created, used as the
+    /// current date by the typst sources.
+    #[getset(get = "pub")]
+    now: DateTime<Local>,
+
+    /// Files which the typst sources can read, e.g. images of receipts.
+    files: IndexMap<FileId, Bytes>,
 }
 
 impl TypstContext {
     fn new(
-        fonts_used_by_layout: IndexSet<FontIdentifier>,
+        environment: Arc<Environment>,
         main: Source,
         layout: Source,
         l18n: Source,
@@ -37,24 +48,36 @@ impl TypstContext {
             .data(data)
             .l18n(l18n)
             .build();
-        let environment = Environment::new(fonts_used_by_layout)?;
-
         trace!("Creating TypstContext END");
         Ok(Self {
             content,
             environment,
+            now: Local::now(),
+            files: IndexMap::new(),
         })
     }
 
+    /// Makes `files` readable by the typst sources, each at the virtual path
+    /// of its name, e.g. `/receipt_1.png`.
+    pub fn with_files(mut self, files: impl IntoIterator<Item = (String, Vec<u8>)>) -> Self {
+        self.files.extend(files.into_iter().map(|(name, content)| {
+            (
+                FileId::new(None, VirtualPath::new(Path::new(&name))),
+                Bytes::new(content),
+            )
+        }));
+        self
+    }
+
     pub fn with_inline(
-        fonts_used_by_layout: IndexSet<FontIdentifier>,
+        environment: Arc<Environment>,
         main_inline: String,
         layout_inline: String,
         l18n_inline: String,
         data_inline: String,
     ) -> Result<Self> {
         Self::new(
-            fonts_used_by_layout,
+            environment,
             Source::inline(main_inline, Path::new(TYPST_VIRTUAL_NAME_MAIN))?,
             Source::inline(layout_inline, Path::new(TYPST_VIRTUAL_NAME_LAYOUT))?,
             Source::inline(l18n_inline, Path::new(TYPST_VIRTUAL_NAME_L18N))?,
@@ -76,7 +99,7 @@ impl World for TypstContext {
         self.content().main().id()
     }
 
-    fn source(&self, id: FileId) -> typst::diag::FileResult<Source> {
+    fn source(&self, id: FileId) -> FileResult<Source> {
         if id == self.content().main().id() {
             let source = self.content().main().clone();
             Ok(source)
@@ -94,8 +117,11 @@ impl World for TypstContext {
         }
     }
 
-    fn file(&self, _id: FileId) -> typst::diag::FileResult<Bytes> {
-        panic!("File access not implemented in this minimal example")
+    fn file(&self, id: FileId) -> FileResult<Bytes> {
+        self.files
+            .get(&id)
+            .cloned()
+            .ok_or_else(|| FileError::NotFound(id.vpath().as_rootless_path().into()))
     }
 
     fn font(&self, index: usize) -> Option<Font> {
@@ -107,7 +133,7 @@ impl World for TypstContext {
     }
 
     fn today(&self, offset: Option<i64>) -> Option<Datetime> {
-        let now = self.environment().now();
+        let now = self.now();
         let with_offset = match offset {
             None => now.with_timezone(now.offset()).fixed_offset(),
             Some(hours) => {
@@ -129,11 +155,10 @@ impl World for TypstContext {
 mod tests {
     use super::*;
     use test_log::test;
-    use typst::syntax::VirtualPath;
 
     fn sut() -> TypstContext {
         TypstContext::new(
-            IndexSet::default(),
+            Arc::new(Environment::new(IndexSet::default()).unwrap()),
             Source::detached("main"),
             Source::detached("layout"),
             Source::detached("l18n"),
@@ -163,6 +188,21 @@ mod tests {
         let _ = sut.source(FileId::new_fake(VirtualPath::new(Path::new("unknown.typ"))));
     }
 
+    #[test]
+    fn now_is_read_when_context_is_created() {
+        let environment = Arc::new(Environment::new(IndexSet::default()).unwrap());
+        let before = Local::now();
+        let sut = TypstContext::new(
+            environment,
+            Source::detached("main"),
+            Source::detached("layout"),
+            Source::detached("l18n"),
+            Source::detached("data"),
+        )
+        .unwrap();
+        assert!(*sut.now() >= before);
+    }
+
     #[test]
     fn today_with_offset() {
         let sut = sut();
@@ -173,9 +213,27 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
-    fn file_access_not_implemented() {
-        let sut = sut();
-        let _ = sut.file(FileId::new_fake(VirtualPath::new(Path::new("unknown.typ"))));
+    fn file_access_with_files() {
+        let sut = sut().with_files([("receipt_1.png".to_owned(), vec![1, 2, 3])]);
+        let file = sut
+            .file(FileId::new(
+                None,
+                VirtualPath::new(Path::new("receipt_1.png")),
+            ))
+            .unwrap();
+        assert_eq!(file.as_slice(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn unknown_file_is_not_found() {
+        let sut = sut().with_files([("receipt_1.png".to_owned(), vec![1, 2, 3])]);
+        let result = sut.file(FileId::new(
+            None,
+            VirtualPath::new(Path::new("receipt_2.png")),
+        ));
+        assert_eq!(
+            result,
+            Err(FileError::NotFound(PathBuf::from("receipt_2.png")))
+        );
     }
 }
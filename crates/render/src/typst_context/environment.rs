@@ -17,9 +17,6 @@ pub struct Environment {
 
     #[getset(get = "pub")]
     fonts: Vec<Font>,
-
-    #[getset(get = "pub")]
-    now: DateTime<Local>,
 }
 
 impl Environment {
@@ -41,8 +38,6 @@ impl Environment {
             fonts.push(font);
         }
 
-        // Get the current local date and time
-        let now = Local::now();
         let book = LazyHash::new(font_book);
         let library = LazyHash::new(lib);
 
@@ -50,7 +45,6 @@ impl Environment {
             library,
             book,
             fonts,
-            now,
         })
     }
 }